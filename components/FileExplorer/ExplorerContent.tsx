@@ -90,6 +90,7 @@ interface ExplorerContentProps {
     current?: number;
     total?: number;
   } | null;
+  onCancelVideoCompression: () => void;
   ffmpegSetup: boolean;
   t: (key: TranslationKey) => string;
 }
@@ -164,6 +165,7 @@ export default function ExplorerContent({
   onFuzzyFilterClear,
   onFilterInputFocus,
   videoCompression,
+  onCancelVideoCompression,
   ffmpegSetup,
   t,
 }: ExplorerContentProps) {
@@ -293,6 +295,13 @@ export default function ExplorerContent({
               <span className="text-[var(--qf-muted)]">
                 ({Math.floor(videoCompression.percent)}초{videoCompression.speed ? ` · ${videoCompression.speed}` : ''})
               </span>
+              <button
+                type="button"
+                className="ml-auto shrink-0 rounded px-2 py-0.5 hover:bg-[var(--qf-surface-hover)]"
+                onClick={onCancelVideoCompression}
+              >
+                {t('common.cancel')}
+              </button>
             </div>
           )}
 
@@ -512,6 +512,9 @@ function FolderSizeInfoDialog({ dialog, themeVars, t, onChildOpen, onClose }: {
                 <InfoPopupRow label={t('folderSize.exactBytes')} value={`${dialog.bytes ?? '0'} bytes`} themeVars={themeVars} />
                 <InfoPopupRow label={t('folderSize.files')} value={formatMessage(t('folderSize.count'), { count: (dialog.fileCount ?? 0).toLocaleString() })} themeVars={themeVars} />
                 <InfoPopupRow label={t('folderSize.folders')} value={formatMessage(t('folderSize.count'), { count: (dialog.folderCount ?? 0).toLocaleString() })} themeVars={themeVars} />
+                {(dialog.symlinkCount ?? 0) > 0 && (
+                  <InfoPopupRow label={t('folderSize.symlinks')} value={formatMessage(t('folderSize.count'), { count: (dialog.symlinkCount ?? 0).toLocaleString() })} themeVars={themeVars} />
+                )}
               </div>
 
               <div className="min-w-0">
@@ -2,7 +2,7 @@ import React, { useState, useEffect, useRef, useCallback, memo } from 'react';
 import { convertFileSrc } from '@tauri-apps/api/core';
 import { FileEntry, ThumbnailSize } from '../../types';
 import { ThemeVars } from './types';
-import { Play, RefreshCw } from 'lucide-react';
+import { Link2, Play, RefreshCw } from 'lucide-react';
 import { FileTypeIcon, iconColor, formatSize, formatTooltip, getFileIconShadowStyle } from './fileUtils';
 import { useRenameInput } from './hooks/useRenameInput';
 import { useNativeIcon } from './hooks/useNativeIcon';
@@ -125,7 +125,7 @@ export default memo(function FileCard({
   useEffect(() => {
     if (!isVisible || isPending) return;
     const ft = entry.file_type;
-    if (ft !== 'image' && ft !== 'video') return;
+    if (ft !== 'image' && ft !== 'video' && ft !== 'audio') return;
 
     const cached = getThumb(thumbnailCacheKey);
     if (cached !== undefined) {
@@ -159,7 +159,9 @@ export default memo(function FileCard({
           ? 'get_psd_thumbnail_path'
           : ft === 'image'
             ? 'get_file_thumbnail_path'
-            : 'get_video_thumbnail_path';
+            : ft === 'audio'
+              ? 'get_audio_thumbnail_path' // 앨범 아트 (없으면 null → 아이콘)
+              : 'get_video_thumbnail_path';
       const { promise, cancel } = queuedInvokeLow<string | null>(cmd, { path: thumbSourcePath, size: renderSize });
       cancelFn = cancel;
       promise
@@ -394,6 +396,15 @@ export default memo(function FileCard({
             {tag}
           </div>
         )}
+        {/* 심볼릭 링크 뱃지 */}
+        {entry.is_symlink && (
+          <div
+            className="absolute bottom-0.5 left-0.5 p-px rounded pointer-events-none"
+            style={{ backgroundColor: 'rgba(0,0,0,0.55)', color: '#fff' }}
+          >
+            <Link2 size={Math.max(10, thumbnailSize * 0.12)} />
+          </div>
+        )}
       </div>
 
       {/* 파일명 */}
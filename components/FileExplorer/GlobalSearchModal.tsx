@@ -4,6 +4,8 @@ import { ThemeVars } from './types';
 import { Search, Folder, FileText, Loader2 } from 'lucide-react';
 import { invokeTauriCommand as invoke } from '../../utils/tauriInvoke';
 
+type SearchSort = 'relevance' | 'size' | 'modified';
+
 interface GlobalSearchModalProps {
   isOpen: boolean;
   onClose: () => void;
@@ -17,12 +19,22 @@ export default function GlobalSearchModal({ isOpen, onClose, currentPath, onSele
   const [results, setResults] = useState<FileEntry[]>([]);
   const [loading, setLoading] = useState(false);
   const [selectedIndex, setSelectedIndex] = useState(0);
+  const [sort, setSort] = useState<SearchSort>('relevance');
+  const [includeArchives, setIncludeArchives] = useState(false);
   const inputRef = useRef<HTMLInputElement>(null);
   const listRef = useRef<HTMLDivElement>(null);
   const debounceRef = useRef<ReturnType<typeof setTimeout>>(undefined);
   const requestIdRef = useRef(0);
+  const jobIdRef = useRef<string | null>(null);
+
+  // 진행 중인 검색 중단 (새 검색어 입력·모달 닫힘 시 백엔드 탐색을 멈춤)
+  const cancelRunningSearch = useCallback(() => {
+    const jobId = jobIdRef.current;
+    jobIdRef.current = null;
+    if (jobId) invoke<boolean>('cancel_file_search', { jobId }).catch(() => {});
+  }, []);
 
-  // 모달 열릴 때 초기화
+  // 모달 열릴 때 초기화, 닫힐 때 진행 중인 검색 중단
   useEffect(() => {
     if (isOpen) {
       setQuery('');
@@ -31,12 +43,15 @@ export default function GlobalSearchModal({ isOpen, onClose, currentPath, onSele
       setSelectedIndex(0);
       requestIdRef.current++;
       setTimeout(() => inputRef.current?.focus(), 0);
+    } else {
+      cancelRunningSearch();
     }
-  }, [isOpen]);
+  }, [isOpen, cancelRunningSearch]);
 
   // 디바운스 검색
   const performSearch = useCallback((searchQuery: string) => {
     if (debounceRef.current) clearTimeout(debounceRef.current);
+    cancelRunningSearch();
 
     if (!searchQuery.trim()) {
       setResults([]);
@@ -47,11 +62,16 @@ export default function GlobalSearchModal({ isOpen, onClose, currentPath, onSele
     setLoading(true);
     debounceRef.current = setTimeout(async () => {
       const reqId = ++requestIdRef.current;
+      const jobId = `file-search-${Date.now()}-${reqId}`;
+      jobIdRef.current = jobId;
       try {
         const res = await invoke<FileEntry[]>('search_files', {
           root: currentPath,
           query: searchQuery.trim(),
           maxResults: 200,
+          sort,
+          includeArchives,
+          jobId,
         });
         // stale 응답 무시
         if (reqId !== requestIdRef.current) return;
@@ -61,10 +81,11 @@ export default function GlobalSearchModal({ isOpen, onClose, currentPath, onSele
         if (reqId !== requestIdRef.current) return;
         setResults([]);
       } finally {
+        if (jobIdRef.current === jobId) jobIdRef.current = null;
         if (reqId === requestIdRef.current) setLoading(false);
       }
     }, 300);
-  }, [currentPath]);
+  }, [currentPath, sort, includeArchives, cancelRunningSearch]);
 
   // query 변경 시 검색
   useEffect(() => {
@@ -144,11 +165,29 @@ export default function GlobalSearchModal({ isOpen, onClose, currentPath, onSele
             value={query}
             onChange={e => setQuery(e.target.value)}
             onKeyDown={handleKeyDown}
-            placeholder="파일 이름으로 검색..."
+            placeholder="파일 이름 또는 ext:psd size:>500mb modified:<30d type:video..."
             className="flex-1 bg-transparent text-sm outline-none"
             style={{ color: textColor }}
           />
           {loading && <Loader2 size={16} className="animate-spin" style={{ color: mutedColor, flexShrink: 0 }} />}
+          <label className="flex items-center gap-1 text-xs whitespace-nowrap" style={{ color: mutedColor }}>
+            <input
+              type="checkbox"
+              checked={includeArchives}
+              onChange={e => setIncludeArchives(e.target.checked)}
+            />
+            압축 파일 안
+          </label>
+          <select
+            value={sort}
+            onChange={e => setSort(e.target.value as SearchSort)}
+            className="bg-transparent text-xs outline-none"
+            style={{ color: mutedColor }}
+          >
+            <option value="relevance">관련도순</option>
+            <option value="size">크기순</option>
+            <option value="modified">최근 수정순</option>
+          </select>
         </div>
 
         {/* 결과 리스트 */}
@@ -1,5 +1,5 @@
 import React from 'react';
-import { Folder, File, FileImage, FileVideo, FileText, FileCode, Archive, Cog } from 'lucide-react';
+import { Folder, File, FileImage, FileVideo, FileAudio, FileText, FileCode, Archive, Cog } from 'lucide-react';
 import type { ThemeVars } from './types';
 
 // 파일명에서 확장자 추출 (소문자)
@@ -65,6 +65,7 @@ export function FileTypeIcon({ fileType, size, fileName }: { fileType: string; s
     case 'document':  return <FileText {...iconProps} />;
     case 'code':      return <FileCode {...iconProps} />;
     case 'archive':   return <Archive {...iconProps} />;
+    case 'audio':     return <FileAudio {...iconProps} />;
     default:          return <File {...iconProps} />;
   }
 }
@@ -83,6 +84,7 @@ export function iconColor(fileType: string, fileName?: string): string {
     case 'document':  return '#fbbf24';
     case 'code':      return '#22d3ee';
     case 'archive':   return '#fb923c';
+    case 'audio':     return '#f472b6';
     default:          return '#94a3b8';
   }
 }
@@ -4,6 +4,7 @@ import {
   failTransferJob,
   startTransferJob,
   type TransferOperation,
+  type TransferOptions,
   type TransferQueueProgress,
 } from '../../../stores/taskQueueStore';
 import { invokeTauriCommand as invoke } from '../../../utils/tauriInvoke';
@@ -18,6 +19,7 @@ export async function runTransferWithProgress(
   dest: string,
   overwrite: boolean,
   label: string,
+  options: Omit<TransferOptions, 'jobId'> = {},
 ): Promise<void> {
   const jobId = startTransferJob(operation, label);
   const channel = new Channel<TransferQueueProgress>();
@@ -30,6 +32,7 @@ export async function runTransferWithProgress(
       sources,
       dest,
       overwrite,
+      options: { ...options, jobId },
       onProgress: channel,
     });
   } catch (e) {
@@ -44,6 +47,7 @@ export async function runCopyWithProgress(
   dest: string,
   overwrite: boolean,
   label: string,
+  options: Omit<TransferOptions, 'jobId'> = {},
 ): Promise<void> {
-  return runTransferWithProgress('copy', sources, dest, overwrite, label);
+  return runTransferWithProgress('copy', sources, dest, overwrite, label, options);
 }
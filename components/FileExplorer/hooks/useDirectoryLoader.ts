@@ -1,13 +1,16 @@
 import { useCallback, useRef } from 'react';
 import type { Dispatch, MutableRefObject, RefObject, SetStateAction } from 'react';
-import type { FileEntry, ThumbnailSize, ViewMode } from '../../../types';
-import { convertFileSrc } from '@tauri-apps/api/core';
+import type { DirectoryBatch, FileEntry, ThumbnailSize, ViewMode } from '../../../types';
+import { Channel, convertFileSrc } from '@tauri-apps/api/core';
 import { tauriCommands } from '../../../utils/tauriCommands';
 import { RECENT_PATH, SYSTEM_ROOT_PATH } from '../constants';
 import type { EntrySortBy, EntrySortDir } from '../entrySorting';
 import { cancelAllQueued, queuedInvokeLow } from './invokeQueue';
 import { thumbKey, getThumb, setThumb, FIXED_GRID_THUMB_SIZE } from './thumbnailCache';
-import { isCloudPath } from '../../../utils/pathUtils';
+import { isArchiveVirtualPath, isCloudPath } from '../../../utils/pathUtils';
+
+// 스트리밍 목록 묶음 크기 — 첫 묶음만 먼저 그리고 나머지는 다 모은 뒤 한 번에 정렬
+const LISTING_BATCH_SIZE = 2000;
 
 interface UseDirectoryLoaderOptions {
   gridRef: RefObject<HTMLDivElement | null>;
@@ -49,6 +52,7 @@ export function useDirectoryLoader({
   const loadRequestRef = useRef(0);
   const entriesCacheRef = useRef<Map<string, FileEntry[]>>(new Map());
   const prefetchInFlightRef = useRef<Set<string>>(new Set());
+  const listingJobRef = useRef<string | null>(null);
   const thumbnailSizeRef = useRef(thumbnailSize);
   thumbnailSizeRef.current = thumbnailSize;
 
@@ -116,6 +120,26 @@ export function useDirectoryLoader({
     else setTimeout(run, 300);
   }, []);
 
+  // 대용량 폴더도 웹뷰가 멈추지 않도록 묶음 단위로 받는다. 첫 묶음은 캐시가 없을 때 바로 표시.
+  const streamListing = useCallback(async (path: string, requestId: number, showFirstBatch: boolean) => {
+    const jobId = `listing-${requestId}-${Date.now()}`;
+    listingJobRef.current = jobId;
+    const collected: FileEntry[] = [];
+    const onBatch = new Channel<DirectoryBatch>();
+    onBatch.onmessage = (batch) => {
+      for (const entry of batch.entries) collected.push(entry);
+      if (showFirstBatch && batch.offset === 0 && requestId === loadRequestRef.current) {
+        setEntries(sortEntries(collected, sortBy, sortDir));
+      }
+    };
+    try {
+      await tauriCommands.listDirectoryStream(path, onBatch, { jobId, batchSize: LISTING_BATCH_SIZE });
+    } finally {
+      if (listingJobRef.current === jobId) listingJobRef.current = null;
+    }
+    return collected;
+  }, [setEntries, sortEntries, sortBy, sortDir]);
+
   const loadDirectory = useCallback(async (path: string, options: LoadDirectoryOptions = {}) => {
     if (!path) return;
     const previousPath = currentPathRef.current;
@@ -127,6 +151,11 @@ export function useDirectoryLoader({
     // 같은 경로 재로딩(qf-files-changed 새로고침 등)에서 취소하면 아직 onLoad 전인
     // 카드들의 썸네일 요청이 몰살되어 일제히 스피너로 복귀한다.
     if (previousPath !== path) cancelAllQueued();
+    // 이전 폴더를 아직 읽는 중이면 중단 (결과는 requestId로 어차피 버려짐)
+    if (listingJobRef.current) {
+      tauriCommands.cancelDirectoryListing(listingJobRef.current).catch(() => {});
+      listingJobRef.current = null;
+    }
     setError(null);
 
     const isRecent = path === RECENT_PATH;
@@ -158,7 +187,9 @@ export function useDirectoryLoader({
         ? await tauriCommands.getRecentFiles(recentRoots, 7)
         : isSystemRoot
           ? await tauriCommands.listSystemRoots()
-          : await tauriCommands.listDirectory(path);
+          : isArchiveVirtualPath(path)
+            ? await tauriCommands.listDirectory(path)
+            : await streamListing(path, requestId, !cached);
       if (requestId !== loadRequestRef.current) return;
       freshArrived = true;
       if (!isRecent && !isSystemRoot) {
@@ -208,6 +239,7 @@ export function useDirectoryLoader({
     sortBy,
     sortDir,
     sortEntries,
+    streamListing,
     viewModeRef,
   ]);
 
@@ -95,6 +95,8 @@ export function useFileOperations(config: UseFileOperationsConfig) {
     current?: number;
     total?: number;
   } | null>(null);
+  /** 진행 중인 동영상 압축 작업 (취소 버튼이 cancelMediaJob으로 중단) */
+  const videoJobRef = useRef<{ jobId: string; cancelled: boolean } | null>(null);
 
   /** 비디오 기능: FFmpeg 다운로드 폴백 진행 중 (번들 LGPL 빌드가 없을 때만) */
   const [ffmpegSetup, setFfmpegSetup] = useState<boolean>(false);
@@ -279,9 +281,15 @@ export function useFileOperations(config: UseFileOperationsConfig) {
   // --- 일괄 이름변경 적용 ---
   const handleBulkRenameApply = useCallback(async (renames: { oldPath: string; newPath: string }[]) => {
     if (!ensureWritableContext(renames.flatMap(({ oldPath, newPath }) => [oldPath, newPath]))) return;
-    for (const { oldPath, newPath } of renames) {
-      await renameItemWithThumbCache(oldPath, newPath);
-    }
+    // 백엔드가 충돌 검사 후 연쇄·순환(a→b, b→a) 이름 변경까지 한 번에 처리
+    const applied = await tauriCommands.bulkRenameApply(renames);
+    // 메모리 썸네일 키도 순환이 섞이지 않게 임시 키를 거쳐 이관
+    const tempKeys = applied.map((_, i) => `\0bulk-rename-${i}`);
+    applied.forEach(({ oldPath }, i) => remapThumbsForRename(oldPath, tempKeys[i]));
+    applied.forEach(({ oldPath, newPath }, i) => {
+      remapThumbsForRename(tempKeys[i], newPath);
+      rewriteThumbUrlsForRenamedPath(oldPath, newPath);
+    });
     if (currentPath) {
       const result = await tauriCommands.listDirectory(currentPath);
       cacheListing?.(currentPath, result);
@@ -768,7 +776,9 @@ export function useFileOperations(config: UseFileOperationsConfig) {
         };
 
         setVideoCompression({ fileName, percent: 0, speed: t('toast.preparing'), current, total: paths.length });
-        await tauriCommands.compressVideo(path, quality, scalePercent, onProgress);
+        const jobId = `media-compress-${Date.now()}-${i}`;
+        videoJobRef.current = { jobId, cancelled: false };
+        await tauriCommands.compressVideo(path, quality, scalePercent, onProgress, jobId);
         successCount += 1;
       }
 
@@ -779,10 +789,26 @@ export function useFileOperations(config: UseFileOperationsConfig) {
         : t('toast.videoCompressComplete'));
     } catch (e) {
       setVideoCompression(null);
-      showCopyToast(formatToast('toast.compressFailed', { message: String(e) }));
+      if (videoJobRef.current?.cancelled) {
+        // 부분 출력은 백엔드가 지움 — 앞서 끝난 항목만 목록에 반영
+        if (currentPath) loadDirectory(currentPath);
+        showCopyToast(t('toast.videoCompressCancelled'));
+      } else {
+        showCopyToast(formatToast('toast.compressFailed', { message: String(e) }));
+      }
+    } finally {
+      videoJobRef.current = null;
     }
   }, [currentPath, ensureWritableContext, ensureFfmpeg, loadDirectory, showCopyToast, formatToast, t]);
 
+  // --- 동영상 압축 취소 ---
+  const handleCancelVideoCompression = useCallback(() => {
+    const job = videoJobRef.current;
+    if (!job) return;
+    job.cancelled = true;
+    void tauriCommands.cancelMediaJob(job.jobId);
+  }, []);
+
   // --- GIF → MP4 변환 ---
   const handleGifToMp4 = useCallback(async (paths: string[]) => {
     if (paths.length === 0) return;
@@ -925,6 +951,7 @@ export function useFileOperations(config: UseFileOperationsConfig) {
     handleRemoveWhiteBgApply,
     handleSpritePack,
     handleCompressVideo,
+    handleCancelVideoCompression,
     handleVideoToGif,
     handleGifToMp4,
     handleCompressPdf,
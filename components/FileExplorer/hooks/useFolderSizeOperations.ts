@@ -9,6 +9,8 @@ type FolderSizeResponse = {
   fileCount?: number;
   folder_count?: number;
   folderCount?: number;
+  symlink_count?: number;
+  symlinkCount?: number;
   children?: FolderSizeChildResponse[];
 };
 
@@ -43,6 +45,7 @@ export type FolderSizeDialogState = {
   bytes?: string;
   fileCount?: number;
   folderCount?: number;
+  symlinkCount?: number;
   children?: FolderSizeChildInfo[];
   error?: string;
 };
@@ -78,6 +81,7 @@ export function useFolderSizeOperations({
       const sizeText = Number.isFinite(bytes) ? formatSize(bytes, false) : `${info.bytes} bytes`;
       const fileCount = info.file_count ?? info.fileCount ?? 0;
       const folderCount = info.folder_count ?? info.folderCount ?? 0;
+      const symlinkCount = info.symlink_count ?? info.symlinkCount ?? 0;
       const children = (info.children ?? [])
         .map((child): FolderSizeChildInfo => {
           const childBytes = Number(child.bytes);
@@ -102,6 +106,7 @@ export function useFolderSizeOperations({
         bytes: info.bytes,
         fileCount,
         folderCount,
+        symlinkCount,
         children,
       });
     } catch (error) {
@@ -1,5 +1,5 @@
 import React, { useState, useEffect, useCallback, useRef, useMemo } from 'react';
-import { FileEntry, ClipboardData, ThumbnailSize, ViewMode } from '../../types';
+import { FileEntry, ClipboardData, DirectoryChangeBatch, ThumbnailSize, ViewMode } from '../../types';
 import { ThemeVars, ContextMenuSection } from './types';
 import {
   ExternalLink, Folder, Copy, CopyPlus, Scissors, Clipboard as ClipboardIcon,
@@ -18,6 +18,7 @@ import { runTransferWithProgress } from './hooks/runTransferWithProgress';
 import { detectFolderMergeScenario } from '../../utils/folderMerge';
 import { useColumnView } from './hooks/useColumnView';
 import { getCurrentWebview } from '@tauri-apps/api/webview';
+import { Channel } from '@tauri-apps/api/core';
 import {
   buildArchiveBrowsePath,
   getArchiveVirtualParent,
@@ -846,9 +847,49 @@ export default function FileExplorer({
     return () => { window.removeEventListener('focus', handleFocus); clearTimeout(timeoutId); };
   }, [refreshCurrentPathIfChanged]);
 
+  // --- 다른 앱의 파일 변경 실시간 반영 ---
+  // 백엔드 감시(watch_directory)가 보낸 변경 묶음을 현재 목록에 바로 반영한다 (폴더를 다시 읽지 않음).
+  // 이름 변경 중이라 반영을 미룬 묶음이 있으면 다음 묶음에서 전체 비교 새로고침으로 따라잡는다.
+  // 정렬 등이 바뀌어도 감시를 다시 시작하지 않도록 반영 함수는 ref로 참조한다.
+  const watchStaleRef = useRef(false);
+  const applyDirectoryChanges = useCallback(async (batch: DirectoryChangeBatch) => {
+    if (!currentPath || batch.path !== currentPath) return;
+    if (modals.renamingPath) {
+      watchStaleRef.current = true;
+      return;
+    }
+    if (batch.rootRemoved || watchStaleRef.current) {
+      watchStaleRef.current = false;
+      await refreshCurrentPathIfChanged();
+      return;
+    }
+
+    const prev = entriesRef.current;
+    const byPath = new Map(prev.map((entry) => [entry.path, entry]));
+    for (const change of batch.changes) {
+      if (change.oldPath) byPath.delete(change.oldPath);
+      if (change.kind === 'removed') byPath.delete(change.path);
+      else if (change.entry) byPath.set(change.path, change.entry);
+    }
+    const sorted = sortEntries([...byPath.values()], sortBy, sortDir);
+    const next = isCloudPath(currentPath) ? stabilizeCloudEntries(prev, sorted) : sorted;
+    // 디스크 썸네일 캐시는 감시 쪽에서 이미 무효화함 — 메모리 썸네일만 비운다
+    const changedThumbnailPaths = collectChangedThumbnailPaths(prev, next);
+    if (changedThumbnailPaths.length > 0) deleteThumbsForPaths(changedThumbnailPaths);
+    if (isSameListingSnapshot(prev, next)) return;
+    setEntries(next);
+  }, [currentPath, modals.renamingPath, refreshCurrentPathIfChanged, sortBy, sortDir]);
+  const applyDirectoryChangesRef = useRef(applyDirectoryChanges);
+  applyDirectoryChangesRef.current = applyDirectoryChanges;
+  const refreshCurrentPathIfChangedRef = useRef(refreshCurrentPathIfChanged);
+  refreshCurrentPathIfChangedRef.current = refreshCurrentPathIfChanged;
+  // 포커스를 잃었다 되찾은 패널은 감시가 멈춰 있던 동안의 변경을 한 번 비교해 반영
+  const lastWatchedPathRef = useRef<string | null>(null);
+
   useEffect(() => {
     if (!isFocused) return;
     if (
+      !isTauri() ||
       !currentPath ||
       currentPath === RECENT_PATH ||
       currentPath === SYSTEM_ROOT_PATH ||
@@ -856,20 +897,30 @@ export default function FileExplorer({
     ) return;
 
     let cancelled = false;
-    let inFlight = false;
-    const timer = window.setInterval(() => {
-      if (cancelled || inFlight) return;
-      inFlight = true;
-      refreshCurrentPathIfChanged().catch(() => {}).finally(() => {
-        inFlight = false;
+    let watchId: string | null = null;
+    // 묶음은 도착 순서대로 하나씩 반영 (건너뛰면 변경이 사라짐)
+    let queue = Promise.resolve();
+    const channel = new Channel<DirectoryChangeBatch>();
+    channel.onmessage = (batch) => {
+      queue = queue.then(() => {
+        if (cancelled) return;
+        return applyDirectoryChangesRef.current(batch).catch(() => {});
       });
-    }, 1500);
+    };
+    if (lastWatchedPathRef.current === currentPath) {
+      refreshCurrentPathIfChangedRef.current().catch(() => {});
+    }
+    lastWatchedPathRef.current = currentPath;
+    tauriCommands.watchDirectory(currentPath, channel).then((id) => {
+      if (cancelled) tauriCommands.unwatchDirectory(id).catch(() => {});
+      else watchId = id;
+    }).catch(() => {});
 
     return () => {
       cancelled = true;
-      window.clearInterval(timer);
+      if (watchId) tauriCommands.unwatchDirectory(watchId).catch(() => {});
     };
-  }, [currentPath, isFocused, refreshCurrentPathIfChanged]);
+  }, [currentPath, isFocused]);
 
   // --- 다른 패널에서 파일 이동 시 새로고침 ---
   // 자기 자신이 쏜 이벤트는 무시한다 — 각 작업 흐름이 자기 패널 재로딩을 이미 수행하며,
@@ -1239,6 +1290,7 @@ export default function FileExplorer({
         onFuzzyFilterClear={handleFuzzyFilterClear}
         onFilterInputFocus={focusFilterInput}
         videoCompression={fileOps.videoCompression}
+        onCancelVideoCompression={fileOps.handleCancelVideoCompression}
         ffmpegSetup={fileOps.ffmpegSetup}
         t={t}
       />
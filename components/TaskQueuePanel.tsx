@@ -7,6 +7,7 @@ import {
   setTaskQueuePanelExpanded,
   dismissTaskQueuePanel,
   hasActiveTransferJobs,
+  isTransferJobActive,
   TASK_QUEUE_AUTO_DISMISS_MS,
 } from '../stores/taskQueueStore';
 
@@ -18,7 +19,7 @@ export default function TaskQueuePanel({ themeVars }: TaskQueuePanelProps) {
   const { jobs, panelExpanded, panelVisible } = useTaskQueue();
   const listRef = useRef<HTMLDivElement>(null);
 
-  const activeJob = jobs.find((j) => j.status === 'scanning' || j.status === 'running') ?? jobs[0];
+  const activeJob = jobs.find((j) => isTransferJobActive(j.status)) ?? jobs[0];
   const runningCount = jobs.filter((j) => j.status === 'scanning' || j.status === 'running').length;
 
   const sortedFiles = useMemo(() => {
@@ -60,6 +61,8 @@ export default function TaskQueuePanel({ themeVars }: TaskQueuePanelProps) {
       : activeJob?.status === 'scanning'
         ? '목록 준비 중…'
         : '';
+  const statusLabel =
+    activeJob?.status === 'paused' ? '일시정지됨' : activeJob?.status === 'cancelled' ? '취소됨' : '';
 
   return (
     <div
@@ -91,6 +94,11 @@ export default function TaskQueuePanel({ themeVars }: TaskQueuePanelProps) {
                 {headerCount}
               </span>
             )}
+            {statusLabel && (
+              <span className="ml-1.5 font-normal" style={{ color: muted }}>
+                · {statusLabel}
+              </span>
+            )}
           </div>
           {activeJob && (
             <div className="text-[10px] truncate mt-0.5" style={{ color: muted }}>
@@ -169,7 +177,13 @@ export default function TaskQueuePanel({ themeVars }: TaskQueuePanelProps) {
                 >
                   <div className="flex items-center gap-2 mb-1">
                     {isDone && <CheckCircle2 size={12} style={{ color: accent }} className="flex-shrink-0" />}
-                    {isActive && <Loader2 size={12} className="animate-spin flex-shrink-0" style={{ color: accent }} />}
+                    {isActive && (
+                      <Loader2
+                        size={12}
+                        className={activeJob.status === 'paused' ? 'flex-shrink-0' : 'animate-spin flex-shrink-0'}
+                        style={{ color: accent }}
+                      />
+                    )}
                     {isFailed && <AlertCircle size={12} className="flex-shrink-0 text-red-400" />}
                     {!isDone && !isActive && !isFailed && (
                       <span className="w-3 h-3 flex-shrink-0 rounded-full border" style={{ borderColor: muted }} />
@@ -183,7 +197,7 @@ export default function TaskQueuePanel({ themeVars }: TaskQueuePanelProps) {
                     </span>
                     {isActive && (
                       <span className="text-[10px] flex-shrink-0" style={{ color: accent }}>
-                        진행 중
+                        {activeJob.status === 'paused' ? '일시정지' : '진행 중'}
                       </span>
                     )}
                   </div>
@@ -7,6 +7,7 @@
 //! - 경로 중복 회피 (`find_unique_path`, `get_copy_destination`)
 //! - 이미지 처리 (`create_sprite_canvas`)
 //! - 파일 시스템 유틸리티 (`is_hidden_file`, `is_system_filename`)
+//! - 파일명 비교·매칭 (`natural_cmp`, `glob_match`)
 
 use image::{imageops, RgbaImage};
 use std::path::{Path, PathBuf};
@@ -334,6 +335,107 @@ pub fn is_system_filename(name: &str) -> bool {
         || name_lower.starts_with("photoshop temp")
 }
 
+/// 파일명 자연 정렬 비교 (숫자 구간은 값으로, 나머지는 대소문자 무시)
+///
+/// 프런트엔드 `utils/naturalCompare.ts`와 같은 순서: 숫자 구간이 문자 구간보다 앞서고,
+/// 값이 같은 숫자는 자릿수가 짧은 쪽(`1` < `01`)이 먼저다.
+///
+/// # 예제
+///
+/// ```rust
+/// use app_lib::natural_cmp;
+/// use std::cmp::Ordering;
+///
+/// assert_eq!(natural_cmp("frame2.png", "frame10.png"), Ordering::Less);
+/// assert_eq!(natural_cmp("B.txt", "a.txt"), Ordering::Greater);
+/// ```
+pub fn natural_cmp(a: &str, b: &str) -> std::cmp::Ordering {
+    use std::cmp::Ordering;
+
+    fn chunks(s: &str) -> Vec<(bool, &str)> {
+        let mut out = Vec::new();
+        let mut start = 0;
+        let mut prev_digit = None;
+        for (i, c) in s.char_indices() {
+            let digit = c.is_ascii_digit();
+            if prev_digit.is_some_and(|prev| prev != digit) {
+                out.push((prev_digit == Some(true), &s[start..i]));
+                start = i;
+            }
+            prev_digit = Some(digit);
+        }
+        if start < s.len() {
+            out.push((prev_digit == Some(true), &s[start..]));
+        }
+        out
+    }
+
+    let (a_chunks, b_chunks) = (chunks(a), chunks(b));
+    for ((a_num, a_part), (b_num, b_part)) in a_chunks.iter().zip(&b_chunks) {
+        let ord = match (a_num, b_num) {
+            (true, true) => {
+                let (a_trim, b_trim) = (
+                    a_part.trim_start_matches('0'),
+                    b_part.trim_start_matches('0'),
+                );
+                a_trim
+                    .len()
+                    .cmp(&b_trim.len())
+                    .then_with(|| a_trim.cmp(b_trim))
+                    .then_with(|| a_part.len().cmp(&b_part.len()))
+            }
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => a_part.to_lowercase().cmp(&b_part.to_lowercase()),
+        };
+        if ord != Ordering::Equal {
+            return ord;
+        }
+    }
+    // 대소문자만 다른 이름도 순서가 고정되도록 마지막에 원문 비교
+    a_chunks.len().cmp(&b_chunks.len()).then_with(|| a.cmp(b))
+}
+
+/// 파일명 와일드카드 매칭 (`*` 임의 길이, `?` 한 글자, 대소문자 무시)
+///
+/// # 예제
+///
+/// ```rust
+/// use app_lib::glob_match;
+///
+/// assert!(glob_match("*.PNG", "frame_0001.png"));
+/// assert!(glob_match("shot_??.exr", "shot_12.exr"));
+/// assert!(!glob_match("*.png", "frame.png.tmp"));
+/// ```
+pub fn glob_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.to_lowercase().chars().collect();
+    let name: Vec<char> = name.to_lowercase().chars().collect();
+    // 마지막 `*` 위치로 되돌아가는 탐욕적 매칭 (백트래킹 없이 O(n·m))
+    let (mut p, mut n) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match star {
+                Some((star_p, star_n)) => {
+                    p = star_p + 1;
+                    n = star_n + 1;
+                    star = Some((star_p, star_n + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
 // ===== 테스트 =====
 
 #[cfg(test)]
@@ -359,6 +461,31 @@ mod tests {
         assert_eq!(is_system_filename("normal_file.txt"), false);
     }
 
+    #[test]
+    fn test_natural_cmp() {
+        use std::cmp::Ordering;
+
+        let mut names = vec!["img10.png", "img2.png", "IMG1.png", "img02.png", "a", "10"];
+        names.sort_by(|a, b| natural_cmp(a, b));
+        assert_eq!(
+            names,
+            ["10", "a", "IMG1.png", "img2.png", "img02.png", "img10.png"]
+        );
+        assert_eq!(
+            natural_cmp("frame99999999999999999999", "frame100000000000000000000"),
+            Ordering::Less
+        );
+    }
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("*", ""));
+        assert!(glob_match("*render*.exr", "final_RENDER_0001.exr"));
+        assert!(glob_match("a*b*c", "aXXbYYbc"));
+        assert!(!glob_match("a?c", "ac"));
+        assert!(!glob_match("*.jpg", "photo.jpeg"));
+    }
+
     #[test]
     fn test_find_unique_path() {
         use std::fs;
@@ -23,6 +23,11 @@ pub fn run() {
             copy_path,
             select_folder,
             list_directory,
+            list_directory_stream,
+            cancel_directory_listing,
+            detect_file_type,
+            watch_directory,
+            unwatch_directory,
             read_cached_listing,
             write_cached_listing,
             materialize_archive_paths,
@@ -37,25 +42,44 @@ pub fn run() {
             prewarm_psd_preview,
             get_file_icon,
             check_duplicate_items,
+            check_transfer_conflicts,
             analyze_folder_merge,
             merge_folders,
             copy_items,
             copy_items_with_progress,
             transfer_items_with_progress,
+            cancel_transfer,
+            set_transfer_paused,
+            list_pending_transfers,
+            resume_transfer,
+            discard_pending_transfer,
             duplicate_items,
             move_items,
             delete_items,
             delete_items_elevated,
             restore_trash_items,
+            list_operation_history,
+            undo_last_operation,
+            redo_operation,
             create_directory,
             create_text_file,
             write_text_file,
             rename_item,
+            bulk_rename_preview,
+            bulk_rename_apply,
             quick_look,
             is_directory,
             ensure_thumbnails_batch,
             get_video_thumbnail,
             get_video_thumbnail_path,
+            get_audio_thumbnail_path,
+            get_audio_metadata,
+            get_audio_waveform,
+            probe_media,
+            convert_audio,
+            normalize_audio_loudness,
+            trim_audio,
+            cancel_media_job,
             compress_to_zip,
             extract_zip,
             open_with_app,
@@ -67,13 +91,22 @@ pub fn run() {
             invalidate_thumbnail_cache,
             get_recent_files,
             search_files,
+            cancel_file_search,
+            set_index_roots,
+            index_status,
+            search_file_contents,
+            cancel_content_search,
             find_duplicate_files,
+            find_duplicate_files_stream,
+            cancel_duplicate_scan,
             check_ffmpeg,
             download_ffmpeg,
             install_ffmpeg,
             compress_video,
+            compress_videos_batch,
             trim_video,
             cut_video,
+            list_video_keyframes,
             concat_videos,
             video_to_gif,
             gif_to_mp4,
@@ -118,6 +151,11 @@ pub fn run() {
             std::thread::spawn(move || {
                 crate::modules::image_ops::migrate_psd_local_cache_once(&handle);
             });
+            // 앱 파일명 인덱스 로드 후 증분 갱신 (Spotlight/Windows Search 미사용 환경 검색용)
+            let handle = app.handle().clone();
+            std::thread::spawn(move || {
+                crate::modules::system_ops::load_search_index(&handle);
+            });
             Ok(())
         })
         .run(tauri::generate_context!())
@@ -9,10 +9,10 @@ mod materialize;
 mod path;
 mod records;
 
-pub use listing::list_archive_directory;
+pub use listing::{list_archive_directory, list_archive_tree};
 pub use materialize::{materialize_archive_path_in_cache, materialize_archive_paths};
 pub use path::{
-    build_archive_root_virtual_path, is_browsable_archive_path,
+    build_archive_root_virtual_path, is_browsable_archive_path, is_zip_archive_path,
     resolve_archive_virtual_path_with_app,
 };
 
@@ -24,7 +24,7 @@ use listing::list_archive_directory_resolved;
 use path::{resolve_archive_virtual_path, resolve_archive_virtual_path_with_loader};
 use std::path::PathBuf;
 
-const BROWSABLE_ARCHIVE_SUFFIXES: &[&str] = &[
+pub(crate) const BROWSABLE_ARCHIVE_SUFFIXES: &[&str] = &[
     ".zip", ".rar", ".7z", ".tar", ".tgz", ".tar.gz", ".tbz2", ".tar.bz2", ".txz", ".tar.xz",
 ];
 
@@ -41,6 +41,8 @@ pub(super) struct ArchiveEntryRecord {
     normalized_path: String,
     is_dir: bool,
     size: u64,
+    /// 항목 수정 시각 (ms, zip만 기록됨)
+    modified: Option<u64>,
 }
 
 #[cfg(test)]
@@ -260,6 +262,34 @@ mod tests {
         cleanup_test_dir(&test_dir);
     }
 
+    #[test]
+    fn test_list_archive_tree_returns_all_entries_as_virtual_paths() {
+        let test_dir = setup_test_dir("list_tree");
+        let archive = test_dir.join("delivery.zip");
+        create_test_zip(&archive);
+
+        let entries = list_archive_tree(&archive).unwrap();
+        let sep = std::path::MAIN_SEPARATOR;
+        let file = entries
+            .iter()
+            .find(|entry| entry.name == "file.txt")
+            .unwrap();
+        assert_eq!(
+            file.path,
+            format!("{}{}folder{}file.txt", archive.display(), sep, sep)
+        );
+        assert!(!file.is_dir && file.size > 0 && file.modified > 0);
+        assert!(entries
+            .iter()
+            .any(|entry| entry.name == "folder" && entry.is_dir));
+        assert_eq!(entries.len(), 3);
+
+        let resolved = resolve_archive_virtual_path(&file.path).unwrap();
+        assert_eq!(resolved.inner_path.as_deref(), Some("folder/file.txt"));
+
+        cleanup_test_dir(&test_dir);
+    }
+
     #[test]
     fn test_list_zip_cp949_directory_name() {
         let test_dir = setup_test_dir("list_zip_cp949");
@@ -4,8 +4,15 @@ use super::{
     ArchiveVirtualPath,
 };
 use crate::modules::error::{AppError, Result};
-use crate::modules::types::{classify_file, FileEntry, FileType};
-use std::collections::BTreeMap;
+use crate::modules::types::{classify_file, FileDetails, FileEntry, FileType};
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// 전체 항목 목록 캐시에 보관할 최대 항목 수 합계 (넘으면 비움, 이보다 큰 압축 파일은 보관 안 함)
+const ARCHIVE_TREE_CACHE_MAX_ENTRIES: usize = 500_000;
+
+type ArchiveTreeCache = HashMap<String, (String, Arc<Vec<FileEntry>>)>;
 
 #[derive(Debug, Clone, Copy)]
 struct ArchiveChildRecord {
@@ -80,6 +87,8 @@ pub(super) fn list_archive_directory_resolved(
                 classify_file(&name)
             },
             name,
+            is_symlink: false,
+            details: FileDetails::default(),
         })
         .collect())
 }
@@ -93,3 +102,87 @@ pub fn list_archive_directory<R: tauri::Runtime>(
     })?;
     list_archive_directory_resolved(&resolved)
 }
+
+/// 압축 파일 안의 모든 항목 (파일 검색용). 경로는 압축 파일 루트 기준 가상 경로이고,
+/// 항목 수정 시각이 없는 형식(tar 등)은 압축 파일 수정 시각을 쓴다.
+/// 같은 크기·수정 시각이면 이전 목록을 재사용한다.
+pub fn list_archive_tree(archive_path: &Path) -> Result<Arc<Vec<FileEntry>>> {
+    static CACHE: OnceLock<Mutex<ArchiveTreeCache>> = OnceLock::new();
+
+    let meta = std::fs::metadata(archive_path)?;
+    let archive_modified = meta
+        .modified()
+        .ok()
+        .and_then(|value| value.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|value| value.as_millis() as u64)
+        .unwrap_or(0);
+    let key = archive_path.to_string_lossy().to_string();
+    let stamp = format!("{}:{}", meta.len(), archive_modified);
+    let cache = CACHE.get_or_init(Default::default);
+    if let Some((cached_stamp, entries)) = cache.lock().unwrap_or_else(|e| e.into_inner()).get(&key)
+    {
+        if *cached_stamp == stamp {
+            return Ok(Arc::clone(entries));
+        }
+    }
+
+    let resolved = ArchiveVirtualPath {
+        archive_path: archive_path.to_path_buf(),
+        logical_archive_path: key.clone(),
+        inner_path: None,
+        separator: std::path::MAIN_SEPARATOR,
+    };
+    // 상위 폴더 항목이 따로 없는 압축 파일도 있어 경로에서 폴더를 채운다
+    let mut items: BTreeMap<String, (bool, u64, Option<u64>)> = BTreeMap::new();
+    for record in list_archive_records(archive_path)? {
+        let mut parent = record.normalized_path.as_str();
+        while let Some((dir, _)) = parent.rsplit_once('/') {
+            items.entry(dir.to_string()).or_insert((true, 0, None));
+            parent = dir;
+        }
+        let item = items
+            .entry(record.normalized_path.clone())
+            .or_insert((record.is_dir, 0, None));
+        item.0 |= record.is_dir;
+        if !item.0 {
+            item.1 = record.size;
+            item.2 = record.modified;
+        }
+    }
+
+    let entries = Arc::new(
+        items
+            .into_iter()
+            .map(|(inner, (is_dir, size, modified))| {
+                let name = inner.rsplit('/').next().unwrap_or(&inner).to_string();
+                FileEntry {
+                    path: build_archive_virtual_path(&resolved, &inner),
+                    is_dir,
+                    size,
+                    modified: modified.unwrap_or(archive_modified),
+                    identity: format!("archive:{}:{}", resolved.archive_path.display(), inner),
+                    file_type: if is_dir {
+                        FileType::Directory
+                    } else {
+                        classify_file(&name)
+                    },
+                    name,
+                    is_symlink: false,
+                    details: FileDetails::default(),
+                }
+            })
+            .collect::<Vec<_>>(),
+    );
+
+    if entries.len() > ARCHIVE_TREE_CACHE_MAX_ENTRIES {
+        return Ok(entries);
+    }
+    let mut cache = cache.lock().unwrap_or_else(|e| e.into_inner());
+    cache.remove(&key);
+    let cached: usize = cache.values().map(|(_, cached)| cached.len()).sum();
+    if cached + entries.len() > ARCHIVE_TREE_CACHE_MAX_ENTRIES {
+        cache.clear();
+    }
+    cache.insert(key, (stamp, Arc::clone(&entries)));
+    Ok(entries)
+}
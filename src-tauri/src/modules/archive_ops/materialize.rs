@@ -4,7 +4,7 @@ use super::{
 };
 use crate::helpers::{get_copy_destination, stable_cache_key};
 use crate::modules::error::{AppError, Result};
-use crate::modules::file_ops::copy_dir_recursive;
+use crate::modules::file_ops::{copy_dir_recursive, SymlinkPolicy};
 use std::path::{Path, PathBuf};
 
 fn archive_cache_root<R: tauri::Runtime>(
@@ -74,7 +74,7 @@ fn copy_materialized_entry_to_batch(src: &Path, batch_root: &Path) -> Result<Pat
 
     if src.is_dir() {
         let dest = get_copy_destination(batch_root, &file_name, "", true);
-        copy_dir_recursive(src, &dest)?;
+        copy_dir_recursive(src, &dest, SymlinkPolicy::default())?;
         return Ok(dest);
     }
 
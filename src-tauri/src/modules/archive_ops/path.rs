@@ -17,7 +17,7 @@ pub fn is_browsable_archive_path(path: &str) -> bool {
         .any(|suffix| lower.ends_with(suffix))
 }
 
-pub(super) fn is_zip_archive_path(path: &Path) -> bool {
+pub fn is_zip_archive_path(path: &Path) -> bool {
     path.to_string_lossy().to_lowercase().ends_with(".zip")
 }
 
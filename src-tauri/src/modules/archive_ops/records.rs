@@ -35,9 +35,27 @@ pub(super) fn normalize_archive_entry_name(raw: &str, size: u64) -> Option<Archi
         normalized_path,
         is_dir,
         size,
+        modified: None,
     })
 }
 
+/// zip 항목 시각(압축한 PC의 로컬 시간) → epoch ms
+fn zip_time_to_ms(time: zip::DateTime) -> Option<u64> {
+    let local = chrono::NaiveDate::from_ymd_opt(
+        time.year() as i32,
+        time.month() as u32,
+        time.day() as u32,
+    )?
+    .and_hms_opt(
+        time.hour() as u32,
+        time.minute() as u32,
+        time.second() as u32,
+    )?
+    .and_local_timezone(chrono::Local)
+    .earliest()?;
+    u64::try_from(local.timestamp_millis()).ok()
+}
+
 fn contains_hangul(value: &str) -> bool {
     value
         .chars()
@@ -123,7 +141,8 @@ fn list_zip_records(archive_path: &Path) -> Result<Vec<ArchiveEntryRecord>> {
     for index in 0..archive.len() {
         let file = archive.by_index(index)?;
         let name = decode_zip_entry_name(file.name_raw(), file.name());
-        if let Some(record) = normalize_archive_entry_name(&name, file.size()) {
+        if let Some(mut record) = normalize_archive_entry_name(&name, file.size()) {
+            record.modified = file.last_modified().and_then(zip_time_to_ms);
             records.push(record);
         }
     }
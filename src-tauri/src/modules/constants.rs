@@ -11,6 +11,30 @@ pub const MAX_HEAVY_OPS: usize = 3;
 /// 파일 검색 최대 깊이 (재귀 폴더 탐색)
 pub const SEARCH_MAX_DEPTH: usize = 10;
 
+/// 파일 검색 정렬 전 모으는 최소 후보 수 (정렬 후 요청 개수만큼 자른다)
+pub const SEARCH_SORT_CANDIDATES: usize = 2_000;
+
+/// 압축 파일 내부 검색 시 열어 볼 최대 압축 파일 수
+pub const ARCHIVE_SEARCH_MAX_ARCHIVES: usize = 500;
+
+/// 압축 파일 내부 검색 시 zip이 아닌 압축 파일을 열어 볼 최대 크기 (tar 계열은 목록에도 전체 해제가 필요)
+pub const ARCHIVE_SEARCH_MAX_STREAM_SIZE: u64 = 256 * 1024 * 1024;
+
+/// 압축 파일 내부 검색 전체 시간 예산 (밀리초, 넘으면 남은 압축 파일은 건너뜀)
+pub const ARCHIVE_SEARCH_BUDGET_MS: u64 = 10_000;
+
+/// 최근 파일 목록에 넣을 압축 파일당 최대 항목 수
+pub const RECENT_ARCHIVE_MAX_ENTRIES: usize = 100;
+
+/// 앱 파일명 인덱스의 루트당 최대 항목 수 (넘으면 일부만 색인하고 검색에 쓰지 않음)
+pub const SEARCH_INDEX_MAX_ENTRIES: usize = 3_000_000;
+
+/// 앱 파일명 인덱스 증분 갱신 주기 (초)
+pub const SEARCH_INDEX_REFRESH_SECS: u64 = 300;
+
+/// 앱 파일명 인덱스 전체 재색인 주기 (초) — 폴더 수정 시각을 믿을 수 없는 SMB/FAT 보정
+pub const SEARCH_INDEX_FULL_RESCAN_SECS: u64 = 6 * 60 * 60;
+
 /// 중복 파일 탐색 최대 깊이 (재귀 폴더 탐색)
 pub const DUPLICATE_SCAN_MAX_DEPTH: usize = 20;
 
@@ -2,23 +2,43 @@
 //! 파일 조회, 캐시, 변경, 전송, 압축 command를 하위 모듈로 분리한다.
 
 mod archive;
+mod bulk_rename;
 mod cache;
+mod detect;
+mod history;
 mod listing;
+mod listing_stream;
 mod mutation;
+mod symlink;
 mod transfer;
+mod watcher;
 
 pub use archive::*;
+pub use bulk_rename::*;
 pub use cache::*;
+pub use detect::*;
+pub use history::*;
 pub use listing::*;
+pub use listing_stream::*;
 pub use mutation::*;
+pub use symlink::*;
 pub use transfer::*;
+pub use watcher::*;
 
 #[cfg(test)]
 use crate::modules::error::AppError;
 #[cfg(test)]
-use mutation::{delete_items_impl, is_cloud_path, read_text_file_impl, rename_item_impl};
+use listing::{parse_mountinfo, parse_network_mounts, read_directory_entries};
 #[cfg(test)]
-use transfer::{copy_items_impl, count_files_to_copy, merge_folders_recursive, move_items_impl};
+use mutation::{
+    create_directory_impl, create_text_file_impl, delete_items_impl, is_cloud_path,
+    read_text_file_impl, rename_item_impl,
+};
+#[cfg(test)]
+use transfer::{
+    contents_match, copy_file_cancellable, copy_file_verified, copy_items_impl,
+    count_files_to_copy, duplicate_items_impl, merge_folders_recursive, move_items_impl,
+};
 
 // ===== 테스트 =====
 
@@ -39,6 +59,78 @@ mod tests {
         let _ = fs::remove_dir_all(path);
     }
 
+    #[test]
+    fn test_parse_mountinfo_skips_pseudo_and_system_mounts() {
+        let mountinfo = "\
+22 1 8:1 / / rw,relatime shared:1 - ext4 /dev/sda1 rw
+23 22 0:5 / /proc rw,nosuid - proc proc rw
+24 22 0:21 / /run rw - tmpfs tmpfs rw
+25 22 8:17 / /mnt/data rw shared:2 - ext4 /dev/sdb1 rw
+26 22 8:33 / /run/media/me/USB\\0401 rw - vfat /dev/sdc1 rw
+27 22 8:17 /photos /home/me/photos rw - ext4 /dev/sdb1 rw
+28 22 7:0 / /snap/core/1 ro - squashfs /dev/loop0 ro
+29 22 8:2 / /boot/efi rw - vfat /dev/sda2 rw
+30 22 8:17 / /mnt/data rw - ext4 /dev/sdb1 rw
+";
+        assert_eq!(
+            parse_mountinfo(mountinfo),
+            ["/", "/mnt/data", "/run/media/me/USB 1"]
+        );
+    }
+
+    #[test]
+    fn test_parse_network_mounts_picks_smb_and_nfs() {
+        let mountinfo = "\
+22 1 8:1 / / rw,relatime shared:1 - ext4 /dev/sda1 rw
+40 22 0:50 / /mnt/nas rw - cifs //nas/share rw
+41 22 0:51 / /mnt/build\\040farm rw - nfs4 server:/export rw
+42 22 8:17 / /mnt/data rw - ext4 /dev/sdb1 rw
+";
+        assert_eq!(
+            parse_network_mounts(mountinfo),
+            [
+                std::path::PathBuf::from("/mnt/nas"),
+                std::path::PathBuf::from("/mnt/build farm")
+            ]
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_read_directory_entries_hidden_and_details() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let test_dir = setup_test_dir("listing_details");
+        fs::write(test_dir.join(".env"), "x").unwrap();
+        fs::write(test_dir.join("desktop.ini"), "x").unwrap();
+        fs::write(test_dir.join("run.sh"), "#!/bin/sh").unwrap();
+        fs::set_permissions(test_dir.join("run.sh"), fs::Permissions::from_mode(0o750)).unwrap();
+        std::os::unix::fs::symlink("run.sh", test_dir.join("link")).unwrap();
+        let dir = test_dir.to_string_lossy().to_string();
+
+        let mut visible: Vec<String> = read_directory_entries(&dir, false)
+            .unwrap()
+            .into_iter()
+            .map(|e| e.name)
+            .collect();
+        visible.sort();
+        assert_eq!(visible, ["link", "run.sh"]);
+
+        let all = read_directory_entries(&dir, true).unwrap();
+        let find = |name: &str| all.iter().find(|e| e.name == name).unwrap();
+        assert_eq!(all.len(), 4);
+        assert!(find(".env").details.is_hidden && find("desktop.ini").details.is_hidden);
+        let script = &find("run.sh").details;
+        assert_eq!(script.mode, Some(0o750));
+        assert!(script.executable && !script.readonly && !script.is_hidden);
+        assert!(script.owner.is_some() && script.group.is_some());
+        let link = find("link");
+        assert!(link.is_symlink);
+        assert_eq!(link.details.link_target.as_deref(), Some("run.sh"));
+
+        cleanup_test_dir(&test_dir);
+    }
+
     #[test]
     fn test_is_directory() {
         let test_dir = setup_test_dir("is_directory");
@@ -74,7 +166,7 @@ mod tests {
         let new_dir = test_dir.join("new_folder");
 
         tauri::async_runtime::block_on(async {
-            let result = create_directory(new_dir.to_string_lossy().to_string()).await;
+            let result = create_directory_impl(&new_dir.to_string_lossy()).await;
             assert!(result.is_ok());
             assert!(new_dir.exists());
             assert!(new_dir.is_dir());
@@ -90,13 +182,13 @@ mod tests {
 
         tauri::async_runtime::block_on(async {
             // 새 파일 생성
-            let result = create_text_file(file_path.to_string_lossy().to_string()).await;
+            let result = create_text_file_impl(&file_path.to_string_lossy()).await;
             assert!(result.is_ok());
             assert!(file_path.exists());
             assert!(file_path.is_file());
 
             // 이미 존재하는 파일 — AlreadyExists 에러
-            let result2 = create_text_file(file_path.to_string_lossy().to_string()).await;
+            let result2 = create_text_file_impl(&file_path.to_string_lossy()).await;
             assert!(result2.is_err());
             assert!(matches!(result2.unwrap_err(), AppError::AlreadyExists(_)));
         });
@@ -189,7 +281,7 @@ mod tests {
         fs::write(src_dir.join("subdir/file2.txt"), "content2").unwrap();
 
         // 재귀 복사
-        let result = copy_dir_recursive(&src_dir, &dest_dir);
+        let result = copy_dir_recursive(&src_dir, &dest_dir, SymlinkPolicy::default());
         assert!(result.is_ok());
         assert!(dest_dir.exists());
         assert!(dest_dir.join("file1.txt").exists());
@@ -202,6 +294,90 @@ mod tests {
         cleanup_test_dir(&test_dir);
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn test_copy_dir_recursive_symlink_policies() {
+        let test_dir = setup_test_dir("copy_dir_symlink_policies");
+        let src_dir = test_dir.join("source");
+        fs::create_dir_all(src_dir.join("subdir")).unwrap();
+        fs::write(src_dir.join("file.txt"), "content").unwrap();
+        std::os::unix::fs::symlink("file.txt", src_dir.join("link.txt")).unwrap();
+        // 상위 폴더를 가리키는 순환 링크
+        std::os::unix::fs::symlink("..", src_dir.join("subdir/loop")).unwrap();
+
+        let copy_link = test_dir.join("copy_link");
+        copy_dir_recursive(&src_dir, &copy_link, SymlinkPolicy::CopyLink).unwrap();
+        assert!(is_symlink(&copy_link.join("link.txt")));
+        assert_eq!(
+            fs::read_link(copy_link.join("link.txt")).unwrap(),
+            std::path::PathBuf::from("file.txt")
+        );
+        assert!(is_symlink(&copy_link.join("subdir/loop")));
+
+        let skip = test_dir.join("skip");
+        copy_dir_recursive(&src_dir, &skip, SymlinkPolicy::Skip).unwrap();
+        assert!(skip.join("file.txt").exists());
+        assert!(fs::symlink_metadata(skip.join("link.txt")).is_err());
+        assert!(fs::symlink_metadata(skip.join("subdir/loop")).is_err());
+
+        // Follow: 링크 대상은 실제 파일로 복사, 순환 링크는 무한 재귀 없이 건너뜀
+        let follow = test_dir.join("follow");
+        copy_dir_recursive(&src_dir, &follow, SymlinkPolicy::Follow).unwrap();
+        assert!(!is_symlink(&follow.join("link.txt")));
+        assert_eq!(
+            fs::read_to_string(follow.join("link.txt")).unwrap(),
+            "content"
+        );
+        assert!(!follow.join("subdir/loop").exists());
+
+        cleanup_test_dir(&test_dir);
+    }
+
+    #[test]
+    fn test_copy_dir_recursive_preserves_metadata() {
+        use filetime::FileTime;
+
+        let test_dir = setup_test_dir("copy_dir_preserves_metadata");
+        let src_dir = test_dir.join("source");
+        let dest_dir = test_dir.join("destination");
+        fs::create_dir_all(src_dir.join("subdir")).unwrap();
+        fs::write(src_dir.join("subdir/file.txt"), "content").unwrap();
+
+        let file_time = FileTime::from_unix_time(1_600_000_000, 0);
+        let dir_time = FileTime::from_unix_time(1_500_000_000, 0);
+        filetime::set_file_times(src_dir.join("subdir/file.txt"), file_time, file_time).unwrap();
+        filetime::set_file_times(src_dir.join("subdir"), dir_time, dir_time).unwrap();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(
+                src_dir.join("subdir/file.txt"),
+                fs::Permissions::from_mode(0o640),
+            )
+            .unwrap();
+        }
+
+        copy_dir_recursive(&src_dir, &dest_dir, SymlinkPolicy::default()).unwrap();
+
+        let mtime = |p: std::path::PathBuf| {
+            FileTime::from_last_modification_time(&fs::metadata(p).unwrap())
+        };
+        assert_eq!(mtime(dest_dir.join("subdir/file.txt")), file_time);
+        // 하위 파일 생성 후에도 폴더 mtime이 원본 값으로 복원됨
+        assert_eq!(mtime(dest_dir.join("subdir")), dir_time);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(dest_dir.join("subdir/file.txt"))
+                .unwrap()
+                .permissions()
+                .mode();
+            assert_eq!(mode & 0o777, 0o640);
+        }
+
+        cleanup_test_dir(&test_dir);
+    }
+
     #[test]
     fn test_duplicate_items() {
         let test_dir = setup_test_dir("duplicate_items");
@@ -210,7 +386,7 @@ mod tests {
 
         tauri::async_runtime::block_on(async {
             // 복제
-            let result = duplicate_items(vec![file1.to_string_lossy().to_string()]).await;
+            let result = duplicate_items_impl(vec![file1.to_string_lossy().to_string()]).await;
             assert!(result.is_ok());
 
             let new_paths = result.unwrap();
@@ -222,7 +398,7 @@ mod tests {
             assert_eq!(fs::read_to_string(duplicated).unwrap(), "content");
 
             // 두 번째 복제 — "(복사 2)" 접미사
-            let result2 = duplicate_items(vec![file1.to_string_lossy().to_string()]).await;
+            let result2 = duplicate_items_impl(vec![file1.to_string_lossy().to_string()]).await;
             assert!(result2.is_ok());
             let new_paths2 = result2.unwrap();
             assert!(new_paths2[0].contains("(복사 2)"));
@@ -345,6 +521,7 @@ mod tests {
                 &dest_folder,
                 transfer::FolderMergeConflictMode::Skip,
                 None,
+                SymlinkPolicy::default(),
             )
             .unwrap();
         });
@@ -633,6 +810,64 @@ mod tests {
         cleanup_test_dir(&test_dir);
     }
 
+    #[test]
+    fn test_copy_file_cancellable() {
+        let test_dir = setup_test_dir("copy_file_cancellable");
+        let src = test_dir.join("big.bin");
+        let dest = test_dir.join("big_copy.bin");
+        fs::write(&src, vec![7u8; 3 * 1024 * 1024 + 17]).unwrap();
+
+        // 정상 복사: 내용·크기 일치
+        let token = crate::modules::jobs::CancelToken::new();
+        let mut chunks = Vec::new();
+        let written = copy_file_cancellable(&src, &dest, &token, &mut |n| chunks.push(n)).unwrap();
+        assert_eq!(written, 3 * 1024 * 1024 + 17);
+        // 청크 콜백: 1MB 단위 + 나머지
+        assert_eq!(chunks.len(), 4);
+        assert_eq!(chunks.iter().sum::<u64>(), written);
+        assert_eq!(fs::read(&dest).unwrap(), fs::read(&src).unwrap());
+        fs::remove_file(&dest).unwrap();
+
+        // 취소된 토큰: Cancelled 반환 + 쓰다 만 대상 파일 정리
+        token.cancel();
+        let result = copy_file_cancellable(&src, &dest, &token, &mut |_| {});
+        assert!(matches!(result, Err(AppError::Cancelled(_))));
+        assert!(!dest.exists());
+        assert!(src.exists());
+
+        cleanup_test_dir(&test_dir);
+    }
+
+    #[test]
+    fn test_copy_file_verified() {
+        let test_dir = setup_test_dir("copy_file_verified");
+        let src = test_dir.join("data.bin");
+        let dest = test_dir.join("data_copy.bin");
+        fs::write(
+            &src,
+            (0..=255u8)
+                .cycle()
+                .take(2 * 1024 * 1024 + 5)
+                .collect::<Vec<_>>(),
+        )
+        .unwrap();
+
+        let token = crate::modules::jobs::CancelToken::new();
+        let mut last = 0u64;
+        let written = copy_file_verified(&src, &dest, &token, true, &mut |n| last = n).unwrap();
+        assert_eq!(written, 2 * 1024 * 1024 + 5);
+        assert_eq!(last, written);
+        assert!(contents_match(&src, &dest, &token).unwrap());
+
+        // 같은 크기·다른 내용은 불일치
+        let mut tampered = fs::read(&dest).unwrap();
+        tampered[1024] ^= 0xff;
+        fs::write(&dest, tampered).unwrap();
+        assert!(!contents_match(&src, &dest, &token).unwrap());
+
+        cleanup_test_dir(&test_dir);
+    }
+
     #[test]
     fn test_count_files_to_copy() {
         let test_dir = setup_test_dir("count_files");
@@ -0,0 +1,748 @@
+// ===== 일괄 이름 변경 =====
+// 규칙 목록(찾아 바꾸기·번호·대소문자·날짜·확장자)을 순서대로 적용해 미리보기를 만들고,
+// 적용 시 충돌을 다시 검사한 뒤 연쇄(a→b, b→c)·순환(a→b, b→a) 이름 변경을 임시 이름으로 풀어서 실행한다.
+
+mod exif;
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use crate::modules::error::{AppError, Result};
+use crate::modules::image_ops::{migrate_thumbnail_cache_for_rename, thumbnail_cache_root};
+use crate::modules::types::file_identity;
+
+use super::history::{path_pairs, record_operation, FileOperation};
+
+/// 번호·날짜를 붙일 위치
+#[derive(serde::Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RenameInsertPosition {
+    Prefix,
+    #[default]
+    Suffix,
+}
+
+#[derive(serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RenameCase {
+    Lower,
+    Upper,
+    /// 단어(공백·`_`·`-` 구분) 첫 글자만 대문자
+    Title,
+}
+
+/// 날짜 규칙의 기준 (EXIF가 없는 파일은 수정 시각으로 대체)
+#[derive(serde::Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RenameDateSource {
+    #[default]
+    Modified,
+    Exif,
+}
+
+fn default_number_start() -> i64 {
+    1
+}
+
+fn default_number_step() -> i64 {
+    1
+}
+
+fn default_date_format() -> String {
+    "%Y-%m-%d".to_string()
+}
+
+/// 이름 변경 규칙 (확장자 규칙 외에는 확장자를 뺀 이름에만 적용)
+#[derive(serde::Deserialize, Clone, Debug)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RenameRule {
+    Replace {
+        find: String,
+        #[serde(default)]
+        replace: String,
+        /// true면 `find`를 정규식으로 해석 (`$1` 등 캡처 참조 가능)
+        #[serde(default)]
+        regex: bool,
+        #[serde(default = "default_true")]
+        case_sensitive: bool,
+    },
+    Insert {
+        text: String,
+        #[serde(default)]
+        position: RenameInsertPosition,
+    },
+    /// 입력 순서대로 번호 부여 (`padding` 자리까지 0으로 채움)
+    Number {
+        #[serde(default = "default_number_start")]
+        start: i64,
+        #[serde(default = "default_number_step")]
+        step: i64,
+        #[serde(default)]
+        padding: usize,
+        #[serde(default)]
+        position: RenameInsertPosition,
+        #[serde(default)]
+        separator: String,
+    },
+    Case {
+        case: RenameCase,
+    },
+    /// strftime 형식(`%Y%m%d` 등)의 날짜 삽입
+    Date {
+        #[serde(default)]
+        source: RenameDateSource,
+        #[serde(default = "default_date_format")]
+        format: String,
+        #[serde(default)]
+        position: RenameInsertPosition,
+        #[serde(default)]
+        separator: String,
+    },
+    /// 확장자 교체 (빈 문자열이면 확장자 제거, 폴더는 무시)
+    Extension {
+        extension: String,
+    },
+}
+
+fn default_true() -> bool {
+    true
+}
+
+#[derive(serde::Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkRenameStatus {
+    Ok,
+    /// 이름이 그대로여서 건너뜀
+    Unchanged,
+    /// 빈 이름·경로 구분자·금지 문자 포함
+    Invalid,
+    /// 다른 항목과 같은 이름이 됨
+    Duplicate,
+    /// 이름 변경 대상이 아닌 기존 항목과 겹침
+    Exists,
+}
+
+#[derive(serde::Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkRenamePreview {
+    pub old_path: String,
+    pub new_path: String,
+    pub new_name: String,
+    pub status: BulkRenameStatus,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkRenameItem {
+    pub old_path: String,
+    pub new_path: String,
+}
+
+/// 미리 컴파일한 규칙 (정규식·날짜 형식 오류는 여기서 한 번만 보고)
+enum CompiledRule<'a> {
+    Replace {
+        pattern: regex::Regex,
+        replace: &'a str,
+        /// 일반 문자열 치환에서는 `$`를 캡처 참조로 해석하지 않음
+        literal: bool,
+    },
+    Rule(&'a RenameRule),
+}
+
+fn compile_rules(rules: &[RenameRule]) -> Result<Vec<CompiledRule<'_>>> {
+    rules
+        .iter()
+        .map(|rule| match rule {
+            RenameRule::Replace {
+                find,
+                replace,
+                regex,
+                case_sensitive,
+            } => {
+                if find.is_empty() {
+                    return Err(AppError::InvalidInput(
+                        "찾을 문자열이 비어 있습니다.".into(),
+                    ));
+                }
+                let pattern = if *regex {
+                    find.clone()
+                } else {
+                    regex::escape(find)
+                };
+                let pattern = regex::RegexBuilder::new(&pattern)
+                    .case_insensitive(!case_sensitive)
+                    .build()
+                    .map_err(|e| AppError::InvalidInput(format!("잘못된 정규식: {}", e)))?;
+                Ok(CompiledRule::Replace {
+                    pattern,
+                    replace,
+                    literal: !regex,
+                })
+            }
+            RenameRule::Date { format, .. } => {
+                let invalid = chrono::format::StrftimeItems::new(format)
+                    .any(|item| matches!(item, chrono::format::Item::Error));
+                if invalid {
+                    return Err(AppError::InvalidInput(format!(
+                        "잘못된 날짜 형식: {}",
+                        format
+                    )));
+                }
+                Ok(CompiledRule::Rule(rule))
+            }
+            _ => Ok(CompiledRule::Rule(rule)),
+        })
+        .collect()
+}
+
+/// 확장자를 뺀 이름과 확장자(점 제외)로 분리 (폴더·숨김 파일은 확장자 없음)
+fn split_name(name: &str, is_dir: bool) -> (String, Option<String>) {
+    if is_dir {
+        return (name.to_string(), None);
+    }
+    match name.rfind('.') {
+        Some(idx) if idx > 0 && idx + 1 < name.len() => {
+            (name[..idx].to_string(), Some(name[idx + 1..].to_string()))
+        }
+        _ => (name.to_string(), None),
+    }
+}
+
+fn insert(base: &mut String, text: &str, separator: &str, position: RenameInsertPosition) {
+    *base = match position {
+        RenameInsertPosition::Prefix => format!("{}{}{}", text, separator, base),
+        RenameInsertPosition::Suffix => format!("{}{}{}", base, separator, text),
+    };
+}
+
+fn title_case(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut word_start = true;
+    for c in text.chars() {
+        if word_start {
+            out.extend(c.to_uppercase());
+        } else {
+            out.extend(c.to_lowercase());
+        }
+        word_start = c.is_whitespace() || c == '_' || c == '-';
+    }
+    out
+}
+
+fn file_date(path: &Path, source: RenameDateSource) -> Option<chrono::NaiveDateTime> {
+    let exif = match source {
+        RenameDateSource::Exif => exif::exif_date_time(path),
+        RenameDateSource::Modified => None,
+    };
+    exif.or_else(|| {
+        let modified = std::fs::metadata(path).ok()?.modified().ok()?;
+        Some(chrono::DateTime::<chrono::Local>::from(modified).naive_local())
+    })
+}
+
+/// 한 항목에 규칙을 순서대로 적용한 새 이름
+fn apply_rules(path: &Path, index: usize, rules: &[CompiledRule<'_>]) -> String {
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let (mut base, mut ext) = split_name(&name, path.is_dir());
+    for rule in rules {
+        match rule {
+            CompiledRule::Replace {
+                pattern,
+                replace,
+                literal: true,
+            } => {
+                base = pattern
+                    .replace_all(&base, regex::NoExpand(replace))
+                    .into_owned();
+            }
+            CompiledRule::Replace {
+                pattern,
+                replace,
+                literal: false,
+            } => {
+                base = pattern.replace_all(&base, *replace).into_owned();
+            }
+            CompiledRule::Rule(RenameRule::Insert { text, position }) => {
+                insert(&mut base, text, "", *position);
+            }
+            CompiledRule::Rule(RenameRule::Number {
+                start,
+                step,
+                padding,
+                position,
+                separator,
+            }) => {
+                let number = start.saturating_add(step.saturating_mul(index as i64));
+                let text = if number < 0 {
+                    format!("-{:0width$}", number.unsigned_abs(), width = *padding)
+                } else {
+                    format!("{:0width$}", number, width = *padding)
+                };
+                insert(&mut base, &text, separator, *position);
+            }
+            CompiledRule::Rule(RenameRule::Case { case }) => {
+                base = match case {
+                    RenameCase::Lower => base.to_lowercase(),
+                    RenameCase::Upper => base.to_uppercase(),
+                    RenameCase::Title => title_case(&base),
+                };
+            }
+            CompiledRule::Rule(RenameRule::Date {
+                source,
+                format,
+                position,
+                separator,
+            }) => {
+                if let Some(date) = file_date(path, *source) {
+                    let text = date.format(format).to_string();
+                    insert(&mut base, &text, separator, *position);
+                }
+            }
+            CompiledRule::Rule(RenameRule::Extension { extension }) => {
+                if !path.is_dir() {
+                    let extension = extension.trim_start_matches('.');
+                    ext = (!extension.is_empty()).then(|| extension.to_string());
+                }
+            }
+            CompiledRule::Rule(RenameRule::Replace { .. }) => {}
+        }
+    }
+    match ext {
+        Some(ext) => format!("{}.{}", base, ext),
+        None => base,
+    }
+}
+
+fn is_invalid_name(name: &str) -> bool {
+    const FORBIDDEN: &[char] = if cfg!(windows) {
+        &['/', '\\', ':', '*', '?', '"', '<', '>', '|'][..]
+    } else {
+        &['/', '\0'][..]
+    };
+    name.is_empty()
+        || name == "."
+        || name == ".."
+        || name.contains(FORBIDDEN)
+        || (cfg!(windows) && name.ends_with(['.', ' ']))
+}
+
+/// 대소문자를 구분하지 않는 파일 시스템에서는 대소문자만 다른 이름도 같은 이름으로 취급
+fn name_key(path: &Path) -> String {
+    let text = path.to_string_lossy();
+    if cfg!(any(windows, target_os = "macos")) {
+        text.to_lowercase()
+    } else {
+        text.to_string()
+    }
+}
+
+/// 대소문자만 바꾸는 경우 (대소문자 비구분 파일 시스템에서는 대상이 자기 자신으로 보임)
+fn is_case_only_rename(a: &Path, b: &Path) -> bool {
+    if a.to_string_lossy().to_lowercase() != b.to_string_lossy().to_lowercase() {
+        return false;
+    }
+    match (std::fs::symlink_metadata(a), std::fs::symlink_metadata(b)) {
+        (Ok(a), Ok(b)) => file_identity(&a) == file_identity(&b),
+        _ => false,
+    }
+}
+
+/// 항목별 상태 (적용 전 충돌 검사)
+fn check_renames(pairs: &[(PathBuf, PathBuf)]) -> Vec<BulkRenameStatus> {
+    let changing: Vec<bool> = pairs.iter().map(|(old, new)| old != new).collect();
+    // 이름이 바뀌는 항목의 원래 자리는 실행 중 비워지므로 다른 항목이 써도 된다
+    let vacated: HashSet<String> = pairs
+        .iter()
+        .zip(&changing)
+        .filter(|(_, changing)| **changing)
+        .map(|((old, _), _)| name_key(old))
+        .collect();
+    let mut target_counts: HashMap<String, usize> = HashMap::new();
+    for (_, new) in pairs {
+        *target_counts.entry(name_key(new)).or_default() += 1;
+    }
+
+    pairs
+        .iter()
+        .zip(&changing)
+        .map(|((old, new), &changing)| {
+            let name = new
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_default();
+            if !changing {
+                BulkRenameStatus::Unchanged
+            } else if is_invalid_name(&name) || new.parent() != old.parent() {
+                BulkRenameStatus::Invalid
+            } else if target_counts[&name_key(new)] > 1 {
+                BulkRenameStatus::Duplicate
+            } else if std::fs::symlink_metadata(new).is_ok()
+                && !vacated.contains(&name_key(new))
+                && !is_case_only_rename(old, new)
+            {
+                BulkRenameStatus::Exists
+            } else {
+                BulkRenameStatus::Ok
+            }
+        })
+        .collect()
+}
+
+fn temp_name(target: &Path, index: usize) -> PathBuf {
+    let dir = target.parent().unwrap_or_else(|| Path::new(""));
+    let mut attempt = 0usize;
+    loop {
+        let candidate = dir.join(format!(
+            ".bulk-rename-{}-{}-{}.tmp",
+            std::process::id(),
+            index,
+            attempt
+        ));
+        if std::fs::symlink_metadata(&candidate).is_err() {
+            return candidate;
+        }
+        attempt += 1;
+    }
+}
+
+/// 검사를 통과한 이름 변경 실행 상태
+struct RenameRun<'a> {
+    pairs: &'a [(PathBuf, PathBuf)],
+    app_cache: Option<&'a Path>,
+    /// 항목별 현재 위치 (원래 경로 → 임시 이름 → 최종 경로)
+    current: Vec<PathBuf>,
+    /// (항목, 옮기기 전 위치, 옮긴 위치) — 롤백용
+    done: Vec<(usize, PathBuf, PathBuf)>,
+}
+
+impl RenameRun<'_> {
+    fn rename(&mut self, i: usize, to: PathBuf) -> Result<()> {
+        let (old, new) = &self.pairs[i];
+        let from = std::mem::replace(&mut self.current[i], to.clone());
+        if let Err(e) = std::fs::rename(&from, &to) {
+            self.current[i] = from;
+            return Err(e.into());
+        }
+        // 최종 경로에 도착한 뒤에만 썸네일 캐시 키를 이관 (실패한 이름 변경은 캐시를 건드리지 않음)
+        if let (Some(app_cache), true) = (self.app_cache, &to == new) {
+            migrate_thumbnail_cache_for_rename(
+                app_cache,
+                &old.to_string_lossy(),
+                &new.to_string_lossy(),
+            );
+        }
+        self.done.push((i, from, to));
+        Ok(())
+    }
+
+    /// 다른 항목이 아직 차지한 이름은 그 항목이 옮겨질 때까지 미루고,
+    /// 서로 기다리는 순환만 남으면 한 항목을 임시 이름으로 빼서 푼다.
+    fn run(&mut self) -> Result<()> {
+        let mut pending: Vec<usize> = (0..self.pairs.len()).collect();
+        while !pending.is_empty() {
+            let occupied: HashMap<String, usize> = pending
+                .iter()
+                .map(|&i| (name_key(&self.current[i]), i))
+                .collect();
+            let (ready, blocked): (Vec<usize>, Vec<usize>) =
+                pending.iter().copied().partition(|&i| {
+                    occupied
+                        .get(&name_key(&self.pairs[i].1))
+                        .map_or(true, |&owner| owner == i)
+                });
+            if ready.is_empty() {
+                let i = blocked[0];
+                self.rename(i, temp_name(&self.pairs[i].1, i))?;
+                continue;
+            }
+            for i in ready {
+                self.rename(i, self.pairs[i].1.clone())?;
+            }
+            pending = blocked;
+        }
+        Ok(())
+    }
+
+    fn rollback(self) {
+        for (i, from, to) in self.done.into_iter().rev() {
+            let (old, new) = &self.pairs[i];
+            if let Err(e) = std::fs::rename(&to, &from) {
+                log::warn!(
+                    "일괄 이름 변경 롤백 실패 {} → {}: {}",
+                    to.display(),
+                    from.display(),
+                    e
+                );
+                continue;
+            }
+            if let (Some(app_cache), true) = (self.app_cache, &to == new) {
+                migrate_thumbnail_cache_for_rename(
+                    app_cache,
+                    &new.to_string_lossy(),
+                    &old.to_string_lossy(),
+                );
+            }
+        }
+    }
+}
+
+/// 검사를 통과한 이름 변경 실행 (연쇄·순환 포함). 실패하면 이미 바꾼 이름을 되돌린다.
+pub(super) fn execute_renames(
+    pairs: &[(PathBuf, PathBuf)],
+    app_cache: Option<&Path>,
+) -> Result<()> {
+    let mut run = RenameRun {
+        pairs,
+        app_cache,
+        current: pairs.iter().map(|(old, _)| old.clone()).collect(),
+        done: Vec::new(),
+    };
+    if let Err(e) = run.run() {
+        run.rollback();
+        return Err(e);
+    }
+    Ok(())
+}
+
+/// 규칙 적용 결과 미리보기 (파일 시스템은 변경하지 않음)
+#[tauri::command]
+pub async fn bulk_rename_preview(
+    paths: Vec<String>,
+    rules: Vec<RenameRule>,
+) -> Result<Vec<BulkRenamePreview>> {
+    tauri::async_runtime::spawn_blocking(move || -> Result<Vec<BulkRenamePreview>> {
+        let compiled = compile_rules(&rules)?;
+        let pairs: Vec<(PathBuf, PathBuf)> = paths
+            .iter()
+            .enumerate()
+            .map(|(index, path)| {
+                let old = PathBuf::from(path);
+                let new_name = apply_rules(&old, index, &compiled);
+                let new = old.with_file_name(&new_name);
+                (old, new)
+            })
+            .collect();
+        let statuses = check_renames(&pairs);
+        Ok(pairs
+            .into_iter()
+            .zip(statuses)
+            .map(|((old, new), status)| BulkRenamePreview {
+                old_path: old.to_string_lossy().to_string(),
+                new_name: new
+                    .file_name()
+                    .map(|n| n.to_string_lossy().to_string())
+                    .unwrap_or_default(),
+                new_path: new.to_string_lossy().to_string(),
+                status,
+            })
+            .collect())
+    })
+    .await
+    .map_err(|e| AppError::Internal(format!("일괄 이름 변경 미리보기 실패: {}", e)))?
+}
+
+/// 원본이 모두 있고 충돌이 없으면 실제로 바뀌는 쌍만, 하나라도 문제가 있으면 오류
+/// (`execute_renames`에 넘기기 전 검사 — 실행 취소·다시 실행도 같은 검사를 거친다)
+pub(super) fn checked_renames(pairs: Vec<(PathBuf, PathBuf)>) -> Result<Vec<(PathBuf, PathBuf)>> {
+    if let Some((old, _)) = pairs
+        .iter()
+        .find(|(old, _)| std::fs::symlink_metadata(old).is_err())
+    {
+        return Err(AppError::NotFound(format!(
+            "항목을 찾을 수 없습니다: {}",
+            old.display()
+        )));
+    }
+    let statuses = check_renames(&pairs);
+    for ((_, new), status) in pairs.iter().zip(&statuses) {
+        let name = new.display();
+        match status {
+            BulkRenameStatus::Invalid => {
+                return Err(AppError::InvalidInput(format!("잘못된 이름: {}", name)))
+            }
+            BulkRenameStatus::Duplicate => {
+                return Err(AppError::AlreadyExists(format!(
+                    "여러 항목이 같은 이름이 됩니다: {}",
+                    name
+                )))
+            }
+            BulkRenameStatus::Exists => {
+                return Err(AppError::AlreadyExists(format!(
+                    "동일한 이름의 항목이 존재합니다: {}",
+                    name
+                )))
+            }
+            BulkRenameStatus::Ok | BulkRenameStatus::Unchanged => {}
+        }
+    }
+    Ok(pairs
+        .into_iter()
+        .zip(statuses)
+        .filter(|(_, status)| *status == BulkRenameStatus::Ok)
+        .map(|(pair, _)| pair)
+        .collect())
+}
+
+pub(super) async fn bulk_rename_apply_impl(
+    items: Vec<BulkRenameItem>,
+    app_cache: Option<PathBuf>,
+) -> Result<Vec<(PathBuf, PathBuf)>> {
+    tauri::async_runtime::spawn_blocking(move || -> Result<Vec<(PathBuf, PathBuf)>> {
+        let pairs: Vec<(PathBuf, PathBuf)> = items
+            .into_iter()
+            .map(|item| (PathBuf::from(item.old_path), PathBuf::from(item.new_path)))
+            .collect();
+        let changed = checked_renames(pairs)?;
+        execute_renames(&changed, app_cache.as_deref())?;
+        Ok(changed)
+    })
+    .await
+    .map_err(|e| AppError::Internal(format!("일괄 이름 변경 작업 실패: {}", e)))?
+}
+
+/// 일괄 이름 변경 적용 (충돌이 하나라도 있으면 아무것도 바꾸지 않음, 실제로 바뀐 항목 반환)
+#[tauri::command]
+pub async fn bulk_rename_apply<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    items: Vec<BulkRenameItem>,
+) -> Result<Vec<BulkRenameItem>> {
+    let app_cache = thumbnail_cache_root(&app)?;
+    let changed = bulk_rename_apply_impl(items, Some(app_cache)).await?;
+    let items = path_pairs(&changed);
+    record_operation(
+        &app,
+        FileOperation::BulkRename {
+            items: items.clone(),
+        },
+    );
+    Ok(items
+        .into_iter()
+        .map(|(old_path, new_path)| BulkRenameItem { old_path, new_path })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup(name: &str, files: &[&str]) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("file_ops_test_bulk_rename_{}", name));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        for file in files {
+            std::fs::write(dir.join(file), file).unwrap();
+        }
+        dir
+    }
+
+    fn item(dir: &Path, old: &str, new: &str) -> BulkRenameItem {
+        BulkRenameItem {
+            old_path: dir.join(old).to_string_lossy().to_string(),
+            new_path: dir.join(new).to_string_lossy().to_string(),
+        }
+    }
+
+    #[test]
+    fn preview_applies_rules_in_order() {
+        let dir = setup("preview", &["IMG_001.JPG", "IMG_002.JPG"]);
+        let paths = ["IMG_001.JPG", "IMG_002.JPG"]
+            .iter()
+            .map(|n| dir.join(n).to_string_lossy().to_string())
+            .collect();
+        let rules = vec![
+            RenameRule::Replace {
+                find: r"IMG_(\d+)".into(),
+                replace: "photo $1".into(),
+                regex: true,
+                case_sensitive: true,
+            },
+            RenameRule::Case {
+                case: RenameCase::Title,
+            },
+            RenameRule::Number {
+                start: 9,
+                step: 1,
+                padding: 3,
+                position: RenameInsertPosition::Prefix,
+                separator: "_".into(),
+            },
+            RenameRule::Extension {
+                extension: ".jpeg".into(),
+            },
+        ];
+        let preview = tauri::async_runtime::block_on(bulk_rename_preview(paths, rules)).unwrap();
+        let names: Vec<&str> = preview.iter().map(|p| p.new_name.as_str()).collect();
+        assert_eq!(names, ["009_Photo 001.jpeg", "010_Photo 002.jpeg"]);
+        assert!(preview.iter().all(|p| p.status == BulkRenameStatus::Ok));
+
+        // 일반 치환은 `$`를 그대로 쓰고, 겹치는 결과는 중복으로 표시
+        let paths = vec![
+            dir.join("IMG_001.JPG").to_string_lossy().to_string(),
+            dir.join("IMG_002.JPG").to_string_lossy().to_string(),
+        ];
+        let rules = vec![RenameRule::Replace {
+            find: r"_00\d".into(),
+            replace: "$".into(),
+            regex: false,
+            case_sensitive: true,
+        }];
+        let preview =
+            tauri::async_runtime::block_on(bulk_rename_preview(paths.clone(), rules)).unwrap();
+        assert!(preview
+            .iter()
+            .all(|p| p.status == BulkRenameStatus::Unchanged));
+        let rules = vec![RenameRule::Replace {
+            find: r"_00\d".into(),
+            replace: String::new(),
+            regex: true,
+            case_sensitive: true,
+        }];
+        let preview = tauri::async_runtime::block_on(bulk_rename_preview(paths, rules)).unwrap();
+        assert_eq!(preview[0].new_name, "IMG.JPG");
+        assert!(preview
+            .iter()
+            .all(|p| p.status == BulkRenameStatus::Duplicate));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn apply_resolves_swaps_and_rejects_collisions() {
+        let dir = setup("apply", &["a.txt", "b.txt", "c.txt", "keep.txt"]);
+
+        // a↔b 순환 + c→a 연쇄를 한 번에 적용
+        let items = vec![
+            item(&dir, "a.txt", "b.txt"),
+            item(&dir, "b.txt", "c.txt"),
+            item(&dir, "c.txt", "a.txt"),
+        ];
+        tauri::async_runtime::block_on(bulk_rename_apply_impl(items, None)).unwrap();
+        let read = |n: &str| std::fs::read_to_string(dir.join(n)).unwrap();
+        assert_eq!(
+            (read("a.txt"), read("b.txt"), read("c.txt")),
+            ("c.txt".into(), "a.txt".into(), "b.txt".into())
+        );
+
+        // 이름 변경 대상이 아닌 기존 파일과 겹치면 아무것도 바꾸지 않음
+        let items = vec![
+            item(&dir, "a.txt", "x.txt"),
+            item(&dir, "b.txt", "keep.txt"),
+        ];
+        let err = tauri::async_runtime::block_on(bulk_rename_apply_impl(items, None));
+        assert!(matches!(err, Err(AppError::AlreadyExists(_))));
+        assert!(dir.join("a.txt").exists());
+        assert!(!dir.join("x.txt").exists());
+
+        let leftovers = std::fs::read_dir(&dir)
+            .unwrap()
+            .flatten()
+            .filter(|e| e.file_name().to_string_lossy().ends_with(".tmp"))
+            .count();
+        assert_eq!(leftovers, 0);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,171 @@
+// ===== EXIF 촬영 일시 읽기 =====
+// JPEG APP1(Exif) 세그먼트 또는 TIFF 기반 파일(DNG·CR2·NEF 등)의 IFD에서
+// DateTimeOriginal → DateTimeDigitized → DateTime 순으로 찾는다.
+
+use std::io::Read;
+
+/// EXIF를 찾을 파일 앞부분 크기 (APP1은 보통 파일 맨 앞에 위치)
+const EXIF_SCAN_BYTES: u64 = 256 * 1024;
+
+const TAG_DATE_TIME: u16 = 0x0132;
+const TAG_EXIF_IFD: u16 = 0x8769;
+const TAG_DATE_TIME_ORIGINAL: u16 = 0x9003;
+const TAG_DATE_TIME_DIGITIZED: u16 = 0x9004;
+
+/// 촬영 일시 (EXIF가 없거나 읽을 수 없으면 None)
+pub(super) fn exif_date_time(path: &std::path::Path) -> Option<chrono::NaiveDateTime> {
+    let mut data = Vec::new();
+    std::fs::File::open(path)
+        .ok()?
+        .take(EXIF_SCAN_BYTES)
+        .read_to_end(&mut data)
+        .ok()?;
+    let tiff = if data.starts_with(b"II*\0") || data.starts_with(b"MM\0*") {
+        &data[..]
+    } else {
+        find_jpeg_exif(&data)?
+    };
+    parse_tiff_date(tiff)
+}
+
+/// JPEG 세그먼트를 따라가며 Exif APP1 본문(TIFF 헤더부터) 반환
+fn find_jpeg_exif(data: &[u8]) -> Option<&[u8]> {
+    if !data.starts_with(&[0xFF, 0xD8]) {
+        return None;
+    }
+    let mut pos = 2;
+    while pos + 4 <= data.len() {
+        if data[pos] != 0xFF {
+            return None;
+        }
+        let marker = data[pos + 1];
+        // 영상 데이터 시작(SOS) 이후에는 메타데이터가 없다
+        if marker == 0xDA {
+            return None;
+        }
+        let len = u16::from_be_bytes([data[pos + 2], data[pos + 3]]) as usize;
+        let end = (pos + 2 + len).min(data.len());
+        let body = data.get(pos + 4..end)?;
+        if marker == 0xE1 && body.starts_with(b"Exif\0\0") {
+            return Some(&body[6..]);
+        }
+        pos += 2 + len;
+    }
+    None
+}
+
+struct Tiff<'a> {
+    data: &'a [u8],
+    little_endian: bool,
+}
+
+impl Tiff<'_> {
+    fn u16_at(&self, offset: usize) -> Option<u16> {
+        let bytes: [u8; 2] = self.data.get(offset..offset + 2)?.try_into().ok()?;
+        Some(if self.little_endian {
+            u16::from_le_bytes(bytes)
+        } else {
+            u16::from_be_bytes(bytes)
+        })
+    }
+
+    fn u32_at(&self, offset: usize) -> Option<u32> {
+        let bytes: [u8; 4] = self.data.get(offset..offset + 4)?.try_into().ok()?;
+        Some(if self.little_endian {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    }
+
+    /// IFD 항목 중 `tag`의 값 오프셋 필드 위치 (항목 시작 + 8)
+    fn find_entry(&self, ifd: usize, tag: u16) -> Option<usize> {
+        let count = self.u16_at(ifd)? as usize;
+        (0..count)
+            .map(|i| ifd + 2 + i * 12)
+            .find(|&entry| self.u16_at(entry) == Some(tag))
+            .map(|entry| entry + 8)
+    }
+
+    /// "YYYY:MM:DD HH:MM:SS" 형식의 ASCII 값 (20바이트라 항상 오프셋으로 저장됨)
+    fn date_at(&self, ifd: usize, tag: u16) -> Option<chrono::NaiveDateTime> {
+        let offset = self.u32_at(self.find_entry(ifd, tag)?)? as usize;
+        let raw = self.data.get(offset..offset + 19)?;
+        let text = std::str::from_utf8(raw).ok()?;
+        chrono::NaiveDateTime::parse_from_str(text, "%Y:%m:%d %H:%M:%S").ok()
+    }
+}
+
+fn parse_tiff_date(data: &[u8]) -> Option<chrono::NaiveDateTime> {
+    let little_endian = match data.get(0..2)? {
+        b"II" => true,
+        b"MM" => false,
+        _ => return None,
+    };
+    let tiff = Tiff {
+        data,
+        little_endian,
+    };
+    if tiff.u16_at(2)? != 42 {
+        return None;
+    }
+    let ifd0 = tiff.u32_at(4)? as usize;
+    let exif_ifd = tiff
+        .find_entry(ifd0, TAG_EXIF_IFD)
+        .and_then(|value| tiff.u32_at(value))
+        .map(|offset| offset as usize);
+    exif_ifd
+        .and_then(|ifd| {
+            tiff.date_at(ifd, TAG_DATE_TIME_ORIGINAL)
+                .or_else(|| tiff.date_at(ifd, TAG_DATE_TIME_DIGITIZED))
+        })
+        .or_else(|| tiff.date_at(ifd0, TAG_DATE_TIME))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// IFD0(Exif 포인터) → Exif IFD(DateTimeOriginal) 구조의 최소 리틀 엔디언 TIFF
+    fn sample_tiff(date: &str) -> Vec<u8> {
+        let mut t = b"II\x2a\x00\x08\x00\x00\x00".to_vec();
+        // IFD0: 항목 1개 (ExifIFD → 26)
+        t.extend_from_slice(&1u16.to_le_bytes());
+        t.extend_from_slice(&TAG_EXIF_IFD.to_le_bytes());
+        t.extend_from_slice(&4u16.to_le_bytes());
+        t.extend_from_slice(&1u32.to_le_bytes());
+        t.extend_from_slice(&26u32.to_le_bytes());
+        t.extend_from_slice(&0u32.to_le_bytes());
+        // Exif IFD: 항목 1개 (DateTimeOriginal → 44)
+        t.extend_from_slice(&1u16.to_le_bytes());
+        t.extend_from_slice(&TAG_DATE_TIME_ORIGINAL.to_le_bytes());
+        t.extend_from_slice(&2u16.to_le_bytes());
+        t.extend_from_slice(&20u32.to_le_bytes());
+        t.extend_from_slice(&44u32.to_le_bytes());
+        t.extend_from_slice(&0u32.to_le_bytes());
+        t.extend_from_slice(date.as_bytes());
+        t.push(0);
+        t
+    }
+
+    #[test]
+    fn reads_date_time_original_from_jpeg_app1() {
+        let tiff = sample_tiff("2024:05:06 07:08:09");
+        let mut jpeg = vec![0xFF, 0xD8, 0xFF, 0xE1];
+        jpeg.extend_from_slice(&((tiff.len() + 8) as u16).to_be_bytes());
+        jpeg.extend_from_slice(b"Exif\0\0");
+        jpeg.extend_from_slice(&tiff);
+        jpeg.extend_from_slice(&[0xFF, 0xDA, 0x00, 0x02]);
+
+        let path = std::env::temp_dir().join("file_ops_test_exif_date.jpg");
+        std::fs::write(&path, &jpeg).unwrap();
+        let date = exif_date_time(&path).unwrap();
+        assert_eq!(
+            date.format("%Y-%m-%d %H:%M:%S").to_string(),
+            "2024-05-06 07:08:09"
+        );
+        let _ = std::fs::remove_file(&path);
+
+        assert!(parse_tiff_date(b"II\x2a\x00").is_none());
+    }
+}
@@ -47,6 +47,24 @@ fn read_cached_listing_file(file: &std::path::Path, path: &str) -> Result<Option
     }
 }
 
+fn write_cached_listing_file(file: &std::path::Path, path: String, entries: Vec<FileEntry>) {
+    let cached = CachedListing { path, entries };
+    if let Ok(data) = serde_json::to_vec(&cached) {
+        std::fs::write(file, data).ok();
+    }
+}
+
+/// 백엔드에서 새로 읽은 목록으로 디스크 캐시 갱신 (디렉토리 감시 등)
+pub(super) fn update_cached_listing<R: tauri::Runtime>(
+    app: &tauri::AppHandle<R>,
+    path: &str,
+    entries: Vec<FileEntry>,
+) -> Result<()> {
+    let (file, _) = dir_listing_cache_files(app, path)?;
+    write_cached_listing_file(&file, path.to_string(), entries);
+    Ok(())
+}
+
 // 디스크에 저장된 디렉토리 목록 조회 (없으면 None). 빠른 로컬 읽기.
 #[tauri::command]
 pub async fn read_cached_listing<R: tauri::Runtime>(
@@ -85,10 +103,7 @@ pub async fn write_cached_listing<R: tauri::Runtime>(
 ) -> Result<()> {
     let (file, _) = dir_listing_cache_files(&app, &path)?;
     tauri::async_runtime::spawn_blocking(move || -> Result<()> {
-        let cached = CachedListing { path, entries };
-        if let Ok(data) = serde_json::to_vec(&cached) {
-            std::fs::write(&file, data).ok();
-        }
+        write_cached_listing_file(&file, path, entries);
         Ok(())
     })
     .await
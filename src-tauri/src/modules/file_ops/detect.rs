@@ -0,0 +1,323 @@
+// ===== 내용 기반 파일 종류 판별 =====
+// 확장자가 없거나(렌더 출력 등) 잘못 붙은 파일(`.dat` PNG, `.bin` ZIP)을 파일 앞부분의
+// 시그니처로 판별한다. 목록에서는 확장자로 `Other`가 나온 파일에만 쓰고 결과를 캐시한다.
+
+use std::collections::HashMap;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::Path;
+use std::sync::{Mutex, OnceLock};
+
+use crate::modules::error::{AppError, Result};
+use crate::modules::types::{classify_file, mime_from_extension, FileType};
+
+/// 읽는 앞부분 크기 (tar의 `ustar` 표식이 257바이트 위치)
+const HEADER_LEN: usize = 512;
+/// 목록 판별 캐시 상한 (넘으면 비움)
+const SNIFF_CACHE_LIMIT: usize = 20_000;
+
+const OLE_MAGIC: &[u8] = b"\xD0\xCF\x11\xE0\xA1\xB1\x1A\xE1";
+
+/// 판별 결과 (파일 종류, MIME)
+pub type Sniffed = (FileType, &'static str);
+
+#[derive(serde::Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DetectedFileType {
+    pub file_type: FileType,
+    pub mime: Option<String>,
+    /// 내용 시그니처로 판별했는지 (false면 확장자 기준)
+    pub from_content: bool,
+}
+
+/// 파일 앞부분 바이트로 판별 (OLE 복합 문서는 일반 OLE로만 구분)
+pub fn sniff_header(h: &[u8]) -> Option<Sniffed> {
+    let at = |offset: usize, magic: &[u8]| h.get(offset..offset + magic.len()) == Some(magic);
+
+    let sniffed = if at(0, b"\x89PNG\r\n\x1a\n") {
+        (FileType::Image, "image/png")
+    } else if at(0, b"\xFF\xD8\xFF") {
+        (FileType::Image, "image/jpeg")
+    } else if at(0, b"GIF87a") || at(0, b"GIF89a") {
+        (FileType::Image, "image/gif")
+    } else if at(0, b"RIFF") && at(8, b"WEBP") {
+        (FileType::Image, "image/webp")
+    } else if at(0, b"RIFF") && at(8, b"WAVE") {
+        (FileType::Audio, "audio/wav")
+    } else if at(0, b"RIFF") && at(8, b"AVI ") {
+        (FileType::Video, "video/x-msvideo")
+    } else if at(0, b"8BPS") {
+        (FileType::Image, "image/vnd.adobe.photoshop")
+    } else if at(0, b"icns") {
+        (FileType::Image, "image/icns")
+    } else if at(0, b"BM") && matches!(h.get(14).copied(), Some(12 | 40 | 52 | 56 | 108 | 124)) {
+        (FileType::Image, "image/bmp")
+    } else if at(0, b"\x00\x00\x01\x00") && h.get(4..6).is_some_and(|n| n != [0, 0]) {
+        (FileType::Image, "image/x-icon")
+    } else if at(0, b"%PDF-") {
+        (FileType::Document, "application/pdf")
+    } else if at(0, b"PK\x03\x04") || at(0, b"PK\x05\x06") {
+        // ODF·EPUB·HWPX는 압축되지 않은 첫 항목 `mimetype`에 종류를 적는다
+        if at(30, b"mimetypeapplication/hwp+zip") {
+            (FileType::Document, "application/hwp+zip")
+        } else if at(30, b"mimetypeapplication/epub+zip") {
+            (FileType::Document, "application/epub+zip")
+        } else {
+            (FileType::Archive, "application/zip")
+        }
+    } else if at(0, b"Rar!\x1A\x07") {
+        (FileType::Archive, "application/vnd.rar")
+    } else if at(0, b"7z\xBC\xAF\x27\x1C") {
+        (FileType::Archive, "application/x-7z-compressed")
+    } else if at(0, b"\x1F\x8B") {
+        (FileType::Archive, "application/gzip")
+    } else if at(257, b"ustar") {
+        (FileType::Archive, "application/x-tar")
+    } else if at(4, b"ftyp") {
+        match h.get(8..12)? {
+            b"qt  " => (FileType::Video, "video/quicktime"),
+            b"M4A " | b"M4B " => (FileType::Audio, "audio/mp4"),
+            b"avif" | b"avis" => (FileType::Image, "image/avif"),
+            b"heic" | b"heix" | b"mif1" | b"msf1" => (FileType::Other, "image/heic"),
+            _ => (FileType::Video, "video/mp4"),
+        }
+    } else if at(0, b"\x1A\x45\xDF\xA3") {
+        // EBML 헤더의 DocType
+        if h.windows(4).any(|w| w == b"webm") {
+            (FileType::Video, "video/webm")
+        } else {
+            (FileType::Video, "video/x-matroska")
+        }
+    } else if at(0, b"OggS") {
+        (FileType::Audio, "audio/ogg")
+    } else if at(0, b"fLaC") {
+        (FileType::Audio, "audio/flac")
+    } else if at(0, b"ID3") {
+        (FileType::Audio, "audio/mpeg")
+    } else if h.len() >= 2 && h[0] == 0xFF && h[1] & 0xF6 == 0xF0 {
+        // ADTS 프레임 동기 (layer 비트 00)
+        (FileType::Audio, "audio/aac")
+    } else if h.len() >= 2 && h[0] == 0xFF && h[1] & 0xE0 == 0xE0 && h[1] & 0x06 != 0 {
+        // MPEG 오디오 프레임 동기
+        (FileType::Audio, "audio/mpeg")
+    } else if (at(0, b"\x00\x01\x00\x00") || at(0, b"true")) && is_sfnt_header(h) {
+        (FileType::Font, "font/ttf")
+    } else if at(0, b"OTTO") && is_sfnt_header(h) {
+        (FileType::Font, "font/otf")
+    } else if at(0, b"ttcf") {
+        (FileType::Font, "font/collection")
+    } else if at(0, b"wOFF") {
+        (FileType::Font, "font/woff")
+    } else if at(0, b"wOF2") {
+        (FileType::Font, "font/woff2")
+    } else if at(0, OLE_MAGIC) {
+        (FileType::Other, "application/x-ole-storage")
+    } else if at(0, b"SQLite format 3\0") {
+        (FileType::Other, "application/vnd.sqlite3")
+    } else if at(0, b"\x7FELF") {
+        (FileType::Other, "application/x-elf")
+    } else if at(0, b"MZ") {
+        (
+            FileType::Other,
+            "application/vnd.microsoft.portable-executable",
+        )
+    } else {
+        return None;
+    };
+    Some(sniffed)
+}
+
+/// sfnt(TrueType/OpenType) 테이블 디렉토리 검사 — `true`로 시작하는 텍스트 등을 글꼴로 오인하지 않도록
+/// 테이블 수와 이진 탐색 필드가 맞고, 앞부분에 든 테이블 레코드의 태그·오프셋이 정상인지 본다.
+fn is_sfnt_header(h: &[u8]) -> bool {
+    let u16_at = |offset: usize| {
+        h.get(offset..offset + 2)
+            .map(|b| u16::from_be_bytes([b[0], b[1]]))
+    };
+    let (Some(num_tables), Some(search_range), Some(entry_selector), Some(range_shift)) =
+        (u16_at(4), u16_at(6), u16_at(8), u16_at(10))
+    else {
+        return false;
+    };
+    if !(1..=64).contains(&num_tables) {
+        return false;
+    }
+    let selector = 15 - num_tables.leading_zeros() as u16;
+    if entry_selector != selector
+        || search_range != (1 << selector) * 16
+        || range_shift != num_tables * 16 - search_range
+    {
+        return false;
+    }
+    let directory_end = 12 + num_tables as u32 * 16;
+    let records = h[12..].chunks_exact(16).take(num_tables as usize);
+    let mut checked = 0;
+    for record in records {
+        let tag_ok = record[..4].iter().all(|c| (0x20..=0x7E).contains(c));
+        let offset = u32::from_be_bytes([record[8], record[9], record[10], record[11]]);
+        if !tag_ok || offset < directory_end {
+            return false;
+        }
+        checked += 1;
+    }
+    checked > 0
+}
+
+/// OLE 복합 문서의 첫 디렉토리 섹터에서 스트림 이름을 읽어 HWP·Office 문서 구분
+fn sniff_ole(file: &mut std::fs::File, header: &[u8]) -> Option<Sniffed> {
+    let sector_shift = u16::from_le_bytes(header.get(0x1E..0x20)?.try_into().ok()?);
+    let first_dir = u32::from_le_bytes(header.get(0x30..0x34)?.try_into().ok()?);
+    if !(9..=12).contains(&sector_shift) {
+        return None;
+    }
+    let sector_size = 1u64 << sector_shift;
+    file.seek(SeekFrom::Start((first_dir as u64 + 1) * sector_size))
+        .ok()?;
+    let mut dir = Vec::new();
+    file.take(sector_size).read_to_end(&mut dir).ok()?;
+
+    let names: Vec<String> = dir
+        .chunks_exact(128)
+        .filter_map(|entry| {
+            let len = u16::from_le_bytes([entry[0x40], entry[0x41]]) as usize;
+            let units: Vec<u16> = entry
+                .get(..len.checked_sub(2)?)?
+                .chunks_exact(2)
+                .map(|c| u16::from_le_bytes([c[0], c[1]]))
+                .collect();
+            Some(String::from_utf16_lossy(&units))
+        })
+        .collect();
+    let has = |name: &str| names.iter().any(|n| n == name);
+
+    if has("FileHeader") && (has("DocInfo") || has("BodyText") || has("\u{5}HwpSummaryInformation"))
+    {
+        Some((FileType::Document, "application/x-hwp"))
+    } else if has("WordDocument") {
+        Some((FileType::Document, "application/msword"))
+    } else if has("Workbook") || has("Book") {
+        Some((FileType::Document, "application/vnd.ms-excel"))
+    } else if has("PowerPoint Document") {
+        Some((FileType::Document, "application/vnd.ms-powerpoint"))
+    } else {
+        None
+    }
+}
+
+/// 파일 앞부분을 읽어 판별 (읽을 수 없거나 알 수 없는 형식이면 None)
+pub fn sniff_file(path: &Path) -> Option<Sniffed> {
+    let mut file = std::fs::File::open(path).ok()?;
+    let mut header = Vec::with_capacity(HEADER_LEN);
+    (&mut file)
+        .take(HEADER_LEN as u64)
+        .read_to_end(&mut header)
+        .ok()?;
+    let sniffed = sniff_header(&header)?;
+    if header.starts_with(OLE_MAGIC) {
+        return Some(sniff_ole(&mut file, &header).unwrap_or(sniffed));
+    }
+    Some(sniffed)
+}
+
+/// 목록용 판별. 같은 파일(정체성 문자열이 같음)은 다시 읽지 않는다.
+pub(super) fn sniff_file_cached(identity: &str, path: &Path) -> Option<Sniffed> {
+    static CACHE: OnceLock<Mutex<HashMap<String, Option<Sniffed>>>> = OnceLock::new();
+    let cache = CACHE.get_or_init(Default::default);
+    if let Some(hit) = cache
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(identity)
+    {
+        return *hit;
+    }
+    let sniffed = sniff_file(path);
+    let mut cache = cache.lock().unwrap_or_else(|e| e.into_inner());
+    if cache.len() >= SNIFF_CACHE_LIMIT {
+        cache.clear();
+    }
+    cache.insert(identity.to_string(), sniffed);
+    sniffed
+}
+
+/// 파일 하나의 종류·MIME 판별. 내용 시그니처를 우선하고, 알 수 없으면 확장자 기준.
+#[tauri::command]
+pub async fn detect_file_type(path: String) -> Result<DetectedFileType> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let target = Path::new(&path);
+        let meta = std::fs::metadata(target)?;
+        if meta.is_dir() {
+            return Ok(DetectedFileType {
+                file_type: FileType::Directory,
+                mime: Some("inode/directory".to_string()),
+                from_content: false,
+            });
+        }
+        if let Some((file_type, mime)) = sniff_file(target) {
+            return Ok(DetectedFileType {
+                file_type,
+                mime: Some(mime.to_string()),
+                from_content: true,
+            });
+        }
+        let name = target
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        Ok(DetectedFileType {
+            file_type: classify_file(&name),
+            mime: mime_from_extension(&name).map(str::to_string),
+            from_content: false,
+        })
+    })
+    .await
+    .map_err(|e| AppError::Internal(format!("파일 종류 판별 태스크 실패: {}", e)))?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sniffs_common_signatures_and_ole_streams() {
+        let mime = |bytes: &[u8]| sniff_header(bytes).map(|(_, mime)| mime);
+        assert_eq!(mime(b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR"), Some("image/png"));
+        assert_eq!(mime(b"RIFF\0\0\0\0WEBPVP8 "), Some("image/webp"));
+        assert_eq!(mime(b"\0\0\0\x18ftypqt  \0\0\0\0"), Some("video/quicktime"));
+        assert_eq!(mime(b"\0\0\0\x18ftypisom\0\0\0\0"), Some("video/mp4"));
+        assert_eq!(mime(b"ID3\x04\0\0"), Some("audio/mpeg"));
+        // 테이블 1개 (`CFF `, 디렉토리 바로 뒤 오프셋 28)
+        let otf = b"OTTO\0\x01\0\x10\0\0\0\0CFF \0\0\0\0\0\0\0\x1c\0\0\0\x04";
+        assert_eq!(mime(otf), Some("font/otf"));
+        assert_eq!(mime(b"OTTO\0\x0a"), None);
+        assert_eq!(mime(b"true story about fonts and other things"), None);
+        assert_eq!(mime(b"plain text"), None);
+        let mut hwpx = b"PK\x03\x04".to_vec();
+        hwpx.resize(30, 0);
+        hwpx.extend_from_slice(b"mimetypeapplication/hwp+zip");
+        assert_eq!(mime(&hwpx), Some("application/hwp+zip"));
+        assert!(matches!(
+            sniff_header(b"PK\x03\x04\x14\0"),
+            Some((FileType::Archive, _))
+        ));
+
+        // 512바이트 섹터, 디렉토리 섹터 0 (파일 오프셋 512)에 FileHeader·BodyText 스트림
+        let mut ole = OLE_MAGIC.to_vec();
+        ole.resize(512, 0);
+        ole[0x1E] = 9;
+        for name in ["Root Entry", "FileHeader", "BodyText"] {
+            let mut entry = [0u8; 128];
+            let units: Vec<u16> = name.encode_utf16().collect();
+            for (i, unit) in units.iter().enumerate() {
+                entry[i * 2..i * 2 + 2].copy_from_slice(&unit.to_le_bytes());
+            }
+            entry[0x40..0x42].copy_from_slice(&((units.len() as u16 + 1) * 2).to_le_bytes());
+            ole.extend_from_slice(&entry);
+        }
+        let path = std::env::temp_dir().join("file_ops_test_sniff_hwp");
+        std::fs::write(&path, &ole).unwrap();
+        assert!(matches!(
+            sniff_file(&path),
+            Some((FileType::Document, "application/x-hwp"))
+        ));
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,662 @@
+// ===== 작업 기록 (여러 단계 실행 취소/다시 실행) =====
+// 이름 변경·이동·복사·생성·휴지통 삭제·폴더 병합을 앱 데이터 폴더에 기록하고,
+// 기존 이동/이름 변경/휴지통 복원 경로로 역연산을 실행한다.
+// 덮어쓰기로 사라진 기존 파일과 직접 삭제(휴지통 미사용)는 되돌릴 수 없으므로 기록하지 않는다.
+
+use std::sync::{Mutex, OnceLock};
+
+use crate::modules::error::{AppError, Result};
+use crate::modules::image_ops::thumbnail_cache_root;
+use crate::modules::jobs::new_job_id;
+
+use super::bulk_rename::{checked_renames, execute_renames};
+use super::mutation::{delete_items_impl, rename_item_impl, restore_single_item};
+use super::symlink::SymlinkPolicy;
+use super::transfer::{copy_entry, move_items_impl};
+
+const HISTORY_FILE: &str = "operation_history.json";
+/// 실행 취소 스택 최대 길이 (오래된 것부터 버림)
+const MAX_HISTORY: usize = 50;
+
+/// 기록되는 파일 작업 (경로 쌍은 `(원래 경로, 결과 경로)`)
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum FileOperation {
+    Rename {
+        from: String,
+        to: String,
+    },
+    /// 일괄 이름 변경 (연쇄·순환 포함이라 한 번에 되돌림)
+    BulkRename {
+        items: Vec<(String, String)>,
+    },
+    Move {
+        items: Vec<(String, String)>,
+    },
+    Copy {
+        items: Vec<(String, String)>,
+    },
+    Create {
+        path: String,
+        directory: bool,
+    },
+    /// 휴지통으로 보낸 항목의 원래 경로
+    Trash {
+        paths: Vec<String>,
+    },
+    /// 병합으로 새로 생긴 항목만 (덮어쓴 파일은 제외)
+    Merge {
+        items: Vec<(String, String)>,
+        moved: bool,
+    },
+}
+
+impl FileOperation {
+    fn is_empty(&self) -> bool {
+        match self {
+            Self::Move { items }
+            | Self::Copy { items }
+            | Self::BulkRename { items }
+            | Self::Merge { items, .. } => items.is_empty(),
+            Self::Trash { paths } => paths.is_empty(),
+            Self::Rename { .. } | Self::Create { .. } => false,
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct OperationRecord {
+    pub id: String,
+    pub created_at: u64,
+    pub operation: FileOperation,
+}
+
+/// `list_operation_history` 응답 (각 목록은 최신 작업이 앞)
+#[derive(serde::Serialize, serde::Deserialize, Default, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct OperationHistory {
+    pub undo: Vec<OperationRecord>,
+    pub redo: Vec<OperationRecord>,
+}
+
+#[derive(Clone, Copy)]
+enum Direction {
+    Undo,
+    Redo,
+}
+
+/// 기록 파일 읽기·쓰기 직렬화 (command 간 동시 갱신 방지)
+fn history_lock() -> std::sync::MutexGuard<'static, ()> {
+    static LOCK: OnceLock<Mutex<()>> = OnceLock::new();
+    LOCK.get_or_init(|| Mutex::new(()))
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+}
+
+/// 실행 취소·다시 실행 직렬화 — 맨 앞 기록 읽기부터 적용·스택 이동까지 한 번에 하나만
+/// (await 중에도 잡고 있어야 하므로 비동기 뮤텍스)
+fn step_lock() -> &'static tauri::async_runtime::Mutex<()> {
+    static LOCK: OnceLock<tauri::async_runtime::Mutex<()>> = OnceLock::new();
+    LOCK.get_or_init(|| tauri::async_runtime::Mutex::new(()))
+}
+
+fn history_path<R: tauri::Runtime>(app: &tauri::AppHandle<R>) -> Result<std::path::PathBuf> {
+    use tauri::Manager;
+    app.path()
+        .app_data_dir()
+        .map(|dir| dir.join(HISTORY_FILE))
+        .map_err(|e: tauri::Error| AppError::Internal(e.to_string()))
+}
+
+/// 파일이 없거나 깨졌으면 빈 기록으로 시작
+fn load_history(path: &std::path::Path) -> OperationHistory {
+    let Ok(data) = std::fs::read(path) else {
+        return OperationHistory::default();
+    };
+    serde_json::from_slice(&data).unwrap_or_else(|e| {
+        log::warn!("작업 기록 파일 손상, 새로 시작: {}", e);
+        OperationHistory::default()
+    })
+}
+
+/// 임시 파일 + rename으로 저장 (중간 종료 시에도 깨지지 않게)
+fn save_history(path: &std::path::Path, history: &OperationHistory) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let data = serde_json::to_vec(history)
+        .map_err(|e| AppError::Internal(format!("작업 기록 직렬화 실패: {}", e)))?;
+    let tmp = path.with_extension("json.tmp");
+    std::fs::write(&tmp, data)?;
+    std::fs::rename(&tmp, path)?;
+    Ok(())
+}
+
+fn push_record(path: &std::path::Path, operation: FileOperation) -> Result<()> {
+    let _guard = history_lock();
+    let mut history = load_history(path);
+    history.undo.insert(
+        0,
+        OperationRecord {
+            id: new_job_id("op"),
+            created_at: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_millis() as u64)
+                .unwrap_or(0),
+            operation,
+        },
+    );
+    history.undo.truncate(MAX_HISTORY);
+    // 새 작업이 생기면 다시 실행할 작업은 의미가 없어진다
+    history.redo.clear();
+    save_history(path, &history)
+}
+
+/// 성공한 작업 기록 (기록 실패는 작업 자체를 실패시키지 않음)
+pub(crate) fn record_operation<R: tauri::Runtime>(
+    app: &tauri::AppHandle<R>,
+    operation: FileOperation,
+) {
+    if operation.is_empty() {
+        return;
+    }
+    let result = history_path(app).and_then(|path| push_record(&path, operation));
+    if let Err(e) = result {
+        log::warn!("작업 기록 실패: {}", e);
+    }
+}
+
+/// 경로 쌍 목록 (PathBuf → String)
+pub(crate) fn path_pairs(
+    pairs: &[(std::path::PathBuf, std::path::PathBuf)],
+) -> Vec<(String, String)> {
+    pairs
+        .iter()
+        .map(|(a, b)| {
+            (
+                a.to_string_lossy().to_string(),
+                b.to_string_lossy().to_string(),
+            )
+        })
+        .collect()
+}
+
+// 여러 항목 작업은 모든 쌍을 먼저 검사하고, 적용 중 실패하면 이미 적용한 항목을 되돌린다
+// (일부만 되돌려진 채 기록이 남으면 다음 실행 취소가 첫 항목부터 실패하므로)
+
+/// 결과 자리가 비어 있어야 함
+fn ensure_vacant(path: &str) -> Result<()> {
+    if std::fs::symlink_metadata(path).is_ok() {
+        return Err(AppError::AlreadyExists(format!(
+            "같은 이름의 항목이 있어 되돌릴 수 없습니다: {}",
+            path
+        )));
+    }
+    Ok(())
+}
+
+fn ensure_exists(path: &str) -> Result<()> {
+    if std::fs::symlink_metadata(path).is_err() {
+        return Err(AppError::NotFound(format!(
+            "항목을 찾을 수 없습니다: {}",
+            path
+        )));
+    }
+    Ok(())
+}
+
+/// 가장 가까운 기존 상위 경로가 폴더여야 상위 폴더를 만들 수 있다
+fn ensure_parent_creatable(path: &str) -> Result<()> {
+    let parent = std::path::Path::new(path)
+        .parent()
+        .ok_or_else(|| AppError::InvalidInput(format!("잘못된 경로: {}", path)))?;
+    match parent.ancestors().find_map(|a| std::fs::metadata(a).ok()) {
+        Some(meta) if !meta.is_dir() => Err(AppError::InvalidInput(format!(
+            "상위 폴더를 만들 수 없습니다: {}",
+            path
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// 복사로 만든 항목 삭제 (다시 복사 실패 시 정리)
+fn remove_created(path: &std::path::Path) -> std::io::Result<()> {
+    match std::fs::symlink_metadata(path) {
+        Ok(meta) if meta.is_dir() => std::fs::remove_dir_all(path),
+        Ok(_) => std::fs::remove_file(path),
+        Err(_) => Ok(()),
+    }
+}
+
+/// `from`에 있는 항목 하나를 `to`로 옮김 (이름이 다르면 옮긴 뒤 이름 변경)
+async fn relocate_one(from: &str, to: &str, app_cache: &std::path::Path) -> Result<()> {
+    let to_path = std::path::Path::new(to);
+    let parent = to_path
+        .parent()
+        .ok_or_else(|| AppError::InvalidInput(format!("잘못된 경로: {}", to)))?;
+    std::fs::create_dir_all(parent)?;
+    let from_path = std::path::Path::new(from);
+    let from_name = from_path
+        .file_name()
+        .ok_or_else(|| AppError::InvalidInput(format!("잘못된 경로: {}", from)))?;
+    move_items_impl(
+        vec![from.to_string()],
+        parent.to_string_lossy().to_string(),
+        Some(false),
+        Some(app_cache),
+    )
+    .await?;
+    let moved = parent.join(from_name);
+    if moved != to_path {
+        let renamed = rename_item_impl(
+            moved.to_string_lossy().to_string(),
+            to.to_string(),
+            Some(app_cache.to_path_buf()),
+        )
+        .await;
+        if let Err(e) = renamed {
+            // 이름 변경에 실패하면 옮긴 항목을 원래 폴더로 되돌린다
+            if let Some(from_parent) = from_path.parent() {
+                let back = move_items_impl(
+                    vec![moved.to_string_lossy().to_string()],
+                    from_parent.to_string_lossy().to_string(),
+                    Some(false),
+                    Some(app_cache),
+                )
+                .await;
+                if let Err(back_err) = back {
+                    log::warn!("이동 되돌리기 실패 {}: {}", moved.display(), back_err);
+                }
+            }
+            return Err(e);
+        }
+    }
+    Ok(())
+}
+
+/// 모든 항목을 `from`에서 `to`로 옮김. 중간에 실패하면 옮긴 항목을 제자리로 돌린다.
+async fn relocate(pairs: Vec<(String, String)>, app_cache: &std::path::Path) -> Result<()> {
+    for (from, to) in &pairs {
+        ensure_vacant(to)?;
+        ensure_exists(from)?;
+        ensure_parent_creatable(to)?;
+    }
+    for (index, (from, to)) in pairs.iter().enumerate() {
+        if let Err(e) = relocate_one(from, to, app_cache).await {
+            for (from, to) in pairs[..index].iter().rev() {
+                if let Err(back_err) = relocate_one(to, from, app_cache).await {
+                    log::warn!("이동 되돌리기 실패 {} → {}: {}", to, from, back_err);
+                }
+            }
+            return Err(e);
+        }
+    }
+    Ok(())
+}
+
+/// 같은 경로로 다시 복사 (대상이 하나라도 있으면 실패). 중간에 실패하면 만든 사본을 지운다.
+async fn copy_again(pairs: Vec<(String, String)>) -> Result<()> {
+    tauri::async_runtime::spawn_blocking(move || -> Result<()> {
+        for (src, dest) in &pairs {
+            ensure_vacant(dest)?;
+            ensure_exists(src)?;
+            ensure_parent_creatable(dest)?;
+        }
+        for (index, (src, dest)) in pairs.iter().enumerate() {
+            let dest = std::path::Path::new(dest);
+            let copied = dest
+                .parent()
+                .map_or(Ok(()), std::fs::create_dir_all)
+                .map_err(AppError::from)
+                .and_then(|_| copy_entry(std::path::Path::new(src), dest, SymlinkPolicy::CopyLink));
+            if let Err(e) = copied {
+                // 실패한 항목의 일부 사본까지 포함해 지운다 (검사에서 비어 있던 자리)
+                for (_, created) in pairs[..=index].iter().rev() {
+                    if let Err(rm_err) = remove_created(std::path::Path::new(created)) {
+                        log::warn!("사본 정리 실패 {}: {}", created, rm_err);
+                    }
+                }
+                return Err(e);
+            }
+        }
+        Ok(())
+    })
+    .await
+    .map_err(|e| AppError::Internal(format!("다시 복사 작업 실패: {}", e)))?
+}
+
+/// 휴지통에서 원래 자리로 복원. 중간에 실패하면 복원한 항목을 다시 휴지통으로 보낸다.
+async fn restore_from_trash(paths: Vec<String>, app_cache: std::path::PathBuf) -> Result<()> {
+    let (restored, result) = tauri::async_runtime::spawn_blocking(move || {
+        let mut restored = Vec::new();
+        for path in &paths {
+            if let Err(e) = ensure_vacant(path) {
+                return (restored, Err(e));
+            }
+        }
+        for path in paths {
+            if let Err(e) = restore_single_item(&path) {
+                return (restored, Err(e));
+            }
+            restored.push(path);
+        }
+        (restored, Ok(()))
+    })
+    .await
+    .map_err(|e| AppError::Internal(format!("복원 작업 실패: {}", e)))?;
+    if result.is_err() && !restored.is_empty() {
+        if let Err(e) = delete_items_impl(restored, true, Some(app_cache)).await {
+            log::warn!("복원 되돌리기 실패: {}", e);
+        }
+    }
+    result
+}
+
+/// 일괄 이름 변경 다시 적용. 기록 이후 생긴 항목이 대상 이름을 쓰고 있으면 덮어쓰지 않고 실패
+async fn rename_again(pairs: Vec<(String, String)>, app_cache: std::path::PathBuf) -> Result<()> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let pairs = checked_renames(
+            pairs
+                .into_iter()
+                .map(|(from, to)| (from.into(), to.into()))
+                .collect(),
+        )?;
+        execute_renames(&pairs, Some(&app_cache))
+    })
+    .await
+    .map_err(|e| AppError::Internal(format!("일괄 이름 변경 작업 실패: {}", e)))?
+}
+
+fn swap_pairs(items: &[(String, String)]) -> Vec<(String, String)> {
+    // 되돌릴 때는 나중에 옮긴 것부터 (하위 항목이 상위보다 먼저 제자리로)
+    items
+        .iter()
+        .rev()
+        .map(|(from, to)| (to.clone(), from.clone()))
+        .collect()
+}
+
+fn results(items: &[(String, String)]) -> Vec<String> {
+    items.iter().map(|(_, to)| to.clone()).collect()
+}
+
+/// 작업 하나를 되돌리거나 다시 실행. 복사·생성의 결과물은 휴지통으로 보낸다.
+async fn apply(
+    operation: &FileOperation,
+    direction: Direction,
+    app_cache: std::path::PathBuf,
+) -> Result<()> {
+    use Direction::{Redo, Undo};
+    match (operation, direction) {
+        (FileOperation::Rename { from, to }, Undo) => {
+            rename_item_impl(to.clone(), from.clone(), Some(app_cache)).await
+        }
+        (FileOperation::Rename { from, to }, Redo) => {
+            rename_item_impl(from.clone(), to.clone(), Some(app_cache)).await
+        }
+        (FileOperation::BulkRename { items }, Undo) => {
+            rename_again(swap_pairs(items), app_cache).await
+        }
+        (FileOperation::BulkRename { items }, Redo) => rename_again(items.clone(), app_cache).await,
+        (FileOperation::Move { items }, Undo)
+        | (FileOperation::Merge { items, moved: true }, Undo) => {
+            relocate(swap_pairs(items), &app_cache).await
+        }
+        (FileOperation::Move { items }, Redo)
+        | (FileOperation::Merge { items, moved: true }, Redo) => {
+            relocate(items.clone(), &app_cache).await
+        }
+        (FileOperation::Copy { items }, Undo)
+        | (
+            FileOperation::Merge {
+                items,
+                moved: false,
+            },
+            Undo,
+        ) => delete_items_impl(results(items), true, Some(app_cache)).await,
+        (FileOperation::Copy { items }, Redo)
+        | (
+            FileOperation::Merge {
+                items,
+                moved: false,
+            },
+            Redo,
+        ) => copy_again(items.clone()).await,
+        (FileOperation::Create { path, .. }, Undo) => {
+            delete_items_impl(vec![path.clone()], true, Some(app_cache)).await
+        }
+        (FileOperation::Create { path, directory }, Redo) => {
+            if std::path::Path::new(path).exists() {
+                return Err(AppError::AlreadyExists(format!(
+                    "이미 존재하는 항목입니다: {}",
+                    path
+                )));
+            }
+            if *directory {
+                std::fs::create_dir_all(path)?;
+            } else {
+                std::fs::write(path, "")?;
+            }
+            Ok(())
+        }
+        (FileOperation::Trash { paths }, Undo) => {
+            restore_from_trash(paths.clone(), app_cache).await
+        }
+        (FileOperation::Trash { paths }, Redo) => {
+            delete_items_impl(paths.clone(), true, Some(app_cache)).await
+        }
+    }
+}
+
+/// 스택 맨 앞 작업을 실행하고, 성공하면 반대쪽 스택으로 옮긴다 (실패 시 기록 유지)
+async fn step<R: tauri::Runtime>(
+    app: &tauri::AppHandle<R>,
+    direction: Direction,
+) -> Result<Option<OperationRecord>> {
+    let path = history_path(app)?;
+    let app_cache = thumbnail_cache_root(app)?;
+    // 동시에 들어온 실행 취소가 같은 기록을 두 번 적용하지 않도록 pop까지 잡고 있는다
+    let _step = step_lock().lock().await;
+    let top = {
+        let _guard = history_lock();
+        let history = load_history(&path);
+        match direction {
+            Direction::Undo => history.undo.first().cloned(),
+            Direction::Redo => history.redo.first().cloned(),
+        }
+    };
+    let Some(record) = top else {
+        return Ok(None);
+    };
+
+    apply(&record.operation, direction, app_cache).await?;
+
+    let _guard = history_lock();
+    let mut history = load_history(&path);
+    let (from, to) = match direction {
+        Direction::Undo => (&mut history.undo, &mut history.redo),
+        Direction::Redo => (&mut history.redo, &mut history.undo),
+    };
+    from.retain(|r| r.id != record.id);
+    to.insert(0, record.clone());
+    to.truncate(MAX_HISTORY);
+    save_history(&path, &history)?;
+    Ok(Some(record))
+}
+
+/// 실행 취소·다시 실행 목록 (최신 작업이 앞)
+#[tauri::command]
+pub async fn list_operation_history(app: tauri::AppHandle) -> Result<OperationHistory> {
+    let path = history_path(&app)?;
+    let _guard = history_lock();
+    Ok(load_history(&path))
+}
+
+/// 마지막 작업 되돌리기 (되돌린 작업을 반환, 기록이 없으면 None)
+#[tauri::command]
+pub async fn undo_last_operation(app: tauri::AppHandle) -> Result<Option<OperationRecord>> {
+    step(&app, Direction::Undo).await
+}
+
+/// 마지막으로 되돌린 작업 다시 실행 (다시 실행한 작업을 반환, 없으면 None)
+#[tauri::command]
+pub async fn redo_operation(app: tauri::AppHandle) -> Result<Option<OperationRecord>> {
+    step(&app, Direction::Redo).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn undo_and_redo_move_and_rename() {
+        let root = std::env::temp_dir().join("file_ops_test_operation_history");
+        let _ = std::fs::remove_dir_all(&root);
+        let (src_dir, dest_dir, cache) = (root.join("src"), root.join("dest"), root.join("cache"));
+        std::fs::create_dir_all(&src_dir).unwrap();
+        std::fs::create_dir_all(&dest_dir).unwrap();
+        std::fs::write(dest_dir.join("a.txt"), "a").unwrap();
+        let original = src_dir.join("a.txt").to_string_lossy().to_string();
+        let moved = dest_dir.join("b.txt").to_string_lossy().to_string();
+
+        // 충돌로 이름이 바뀐 채 이동된 항목 (a.txt → dest/b.txt)
+        let operation = FileOperation::Move {
+            items: vec![(original.clone(), moved.clone())],
+        };
+        std::fs::rename(dest_dir.join("a.txt"), &moved).unwrap();
+        tauri::async_runtime::block_on(async {
+            apply(&operation, Direction::Undo, cache.clone())
+                .await
+                .unwrap();
+            assert_eq!(std::fs::read_to_string(&original).unwrap(), "a");
+            assert!(!std::path::Path::new(&moved).exists());
+
+            apply(&operation, Direction::Redo, cache.clone())
+                .await
+                .unwrap();
+            assert_eq!(std::fs::read_to_string(&moved).unwrap(), "a");
+
+            let rename = FileOperation::Rename {
+                from: moved.clone(),
+                to: dest_dir.join("c.txt").to_string_lossy().to_string(),
+            };
+            apply(&rename, Direction::Redo, cache.clone())
+                .await
+                .unwrap();
+            apply(&rename, Direction::Undo, cache.clone())
+                .await
+                .unwrap();
+            assert!(std::path::Path::new(&moved).exists());
+        });
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn multi_item_undo_checks_every_pair_before_changing_anything() {
+        let root = std::env::temp_dir().join("file_ops_test_operation_history_partial");
+        let _ = std::fs::remove_dir_all(&root);
+        let (src_dir, dest_dir, cache) = (root.join("src"), root.join("dest"), root.join("cache"));
+        std::fs::create_dir_all(&src_dir).unwrap();
+        std::fs::create_dir_all(&dest_dir).unwrap();
+        let path = |dir: &std::path::Path, name: &str| dir.join(name).to_string_lossy().to_string();
+        std::fs::write(dest_dir.join("a.txt"), "a").unwrap();
+        std::fs::write(dest_dir.join("b.txt"), "b").unwrap();
+        // 되돌릴 때 두 번째로 처리되는 a.txt 자리를 다른 파일이 차지
+        std::fs::write(src_dir.join("a.txt"), "new").unwrap();
+        let operation = FileOperation::Move {
+            items: vec![
+                (path(&src_dir, "a.txt"), path(&dest_dir, "a.txt")),
+                (path(&src_dir, "b.txt"), path(&dest_dir, "b.txt")),
+            ],
+        };
+
+        tauri::async_runtime::block_on(async {
+            let result = apply(&operation, Direction::Undo, cache.clone()).await;
+            assert!(matches!(result, Err(AppError::AlreadyExists(_))));
+            // 첫 항목도 옮기지 않아 기록 그대로 다시 시도할 수 있다
+            assert!(dest_dir.join("b.txt").exists());
+            assert!(!src_dir.join("b.txt").exists());
+
+            std::fs::remove_file(src_dir.join("a.txt")).unwrap();
+            apply(&operation, Direction::Undo, cache.clone())
+                .await
+                .unwrap();
+            assert_eq!(std::fs::read_to_string(src_dir.join("a.txt")).unwrap(), "a");
+            assert_eq!(std::fs::read_to_string(src_dir.join("b.txt")).unwrap(), "b");
+
+            // 다시 복사도 두 번째 대상이 차 있으면 첫 사본을 만들지 않는다
+            let copy = FileOperation::Copy {
+                items: vec![
+                    (path(&src_dir, "a.txt"), path(&dest_dir, "a.txt")),
+                    (path(&src_dir, "b.txt"), path(&dest_dir, "b.txt")),
+                ],
+            };
+            std::fs::write(dest_dir.join("b.txt"), "other").unwrap();
+            let result = apply(&copy, Direction::Redo, cache.clone()).await;
+            assert!(matches!(result, Err(AppError::AlreadyExists(_))));
+            assert!(!dest_dir.join("a.txt").exists());
+        });
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn bulk_rename_undo_keeps_item_created_after_rename() {
+        let root = std::env::temp_dir().join("file_ops_test_operation_history_bulk_rename");
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(&root).unwrap();
+        let (a, b) = (root.join("a.txt"), root.join("b.txt"));
+        std::fs::write(&b, "renamed").unwrap();
+        // a.txt → b.txt 이후 기록과 무관하게 새로 만든 a.txt
+        std::fs::write(&a, "new").unwrap();
+        let operation = FileOperation::BulkRename {
+            items: vec![(
+                a.to_string_lossy().to_string(),
+                b.to_string_lossy().to_string(),
+            )],
+        };
+
+        let result =
+            tauri::async_runtime::block_on(apply(&operation, Direction::Undo, root.join("cache")));
+        assert!(matches!(result, Err(AppError::AlreadyExists(_))));
+        assert_eq!(std::fs::read_to_string(&a).unwrap(), "new");
+        assert_eq!(std::fs::read_to_string(&b).unwrap(), "renamed");
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn new_record_clears_redo_and_caps_history() {
+        let root = std::env::temp_dir().join("file_ops_test_operation_history_stack");
+        let _ = std::fs::remove_dir_all(&root);
+        let path = root.join(HISTORY_FILE);
+        let create = |i: usize| FileOperation::Create {
+            path: format!("/tmp/{}", i),
+            directory: true,
+        };
+
+        let mut history = OperationHistory::default();
+        history.redo.push(OperationRecord {
+            id: "old".to_string(),
+            created_at: 0,
+            operation: create(0),
+        });
+        save_history(&path, &history).unwrap();
+        for i in 0..MAX_HISTORY + 5 {
+            push_record(&path, create(i)).unwrap();
+        }
+
+        let history = load_history(&path);
+        assert!(history.redo.is_empty());
+        assert_eq!(history.undo.len(), MAX_HISTORY);
+        // 최신 작업이 앞
+        assert!(matches!(
+            &history.undo[0].operation,
+            FileOperation::Create { path, .. } if path == &format!("/tmp/{}", MAX_HISTORY + 4)
+        ));
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+}
@@ -1,6 +1,9 @@
 use crate::modules::archive_ops::{list_archive_directory, resolve_archive_virtual_path_with_app};
 use crate::modules::error::{AppError, Result};
-use crate::modules::types::{classify_file, file_identity, FileEntry, FileType};
+use crate::modules::types::{classify_file, file_identity, FileDetails, FileEntry, FileType};
+
+use super::detect::sniff_file_cached;
+use super::mutation::is_cloud_path;
 
 fn virtual_dir_entry(name: String, path: String) -> FileEntry {
     let identity = format!("virtual:{}", path);
@@ -12,16 +15,19 @@ fn virtual_dir_entry(name: String, path: String) -> FileEntry {
         modified: 0,
         identity,
         file_type: FileType::Directory,
+        is_symlink: false,
+        details: FileDetails::default(),
     }
 }
 
 // ===== 디렉토리 목록 조회 =====
 
-// 디렉토리 목록 조회
+// 디렉토리 목록 조회 (show_hidden: 숨김·시스템 파일 포함, 기본 제외)
 #[tauri::command]
 pub async fn list_directory<R: tauri::Runtime>(
     app: tauri::AppHandle<R>,
     path: String,
+    show_hidden: Option<bool>,
 ) -> Result<Vec<FileEntry>> {
     // spawn_blocking: 네트워크 파일시스템(Google Drive 등) I/O가 tokio 워커를 차단하지 않도록 분리
     tauri::async_runtime::spawn_blocking(move || -> Result<Vec<FileEntry>> {
@@ -29,62 +35,85 @@ pub async fn list_directory<R: tauri::Runtime>(
             return list_archive_directory(&app, &path);
         }
 
-        let entries = std::fs::read_dir(&path)?;
-        let mut result = vec![];
-        for entry in entries.flatten() {
-            let meta = match entry.metadata() {
-                Ok(m) => m,
-                Err(_) => continue,
-            };
-            let modified = meta
-                .modified()
-                .ok()
-                .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
-                .map(|d| d.as_millis() as u64)
-                .unwrap_or(0);
-            // Windows: 숨김(HIDDEN) 또는 시스템(SYSTEM) 속성 파일 제외
-            #[cfg(target_os = "windows")]
-            {
-                use std::os::windows::fs::MetadataExt;
-                // FILE_ATTRIBUTE_HIDDEN(0x2) | FILE_ATTRIBUTE_SYSTEM(0x4)
-                if meta.file_attributes() & 0x6 != 0 {
-                    continue;
-                }
-            }
+        read_directory_entries(&path, show_hidden.unwrap_or(false))
+    })
+    .await
+    .map_err(|e| AppError::Internal(format!("디렉토리 읽기 태스크 실패: {}", e)))?
+}
 
-            let name = entry.file_name().to_string_lossy().to_string();
-            // 숨김 파일 제외 (점으로 시작하는 파일)
-            if name.starts_with('.') {
-                continue;
-            }
-            // Windows 시스템 파일 이름으로 필터링 (대소문자 무관)
-            let name_lower = name.to_lowercase();
-            if name_lower == "desktop.ini"
-                || name_lower == "thumbs.db"
-                || name_lower == "ntuser.dat"
-            {
-                continue;
-            }
+/// 폴더 직속 항목 (링크는 대상 기준 메타데이터)
+pub(super) fn read_directory_entries(path: &str, show_hidden: bool) -> Result<Vec<FileEntry>> {
+    Ok(std::fs::read_dir(path)?
+        .flatten()
+        .filter_map(|entry| listing_entry(&entry, show_hidden))
+        .collect())
+}
 
-            let file_type = if meta.is_dir() {
-                FileType::Directory
-            } else {
-                classify_file(&name)
-            };
-            result.push(FileEntry {
-                path: entry.path().to_string_lossy().to_string(),
-                is_dir: meta.is_dir(),
-                size: if meta.is_dir() { 0 } else { meta.len() },
-                modified,
-                identity: file_identity(&meta),
-                file_type,
-                name,
-            });
+/// 목록에 표시할 항목으로 변환 (메타데이터를 읽을 수 없거나, 숨김 항목인데 `show_hidden`이 아니면 None)
+pub(super) fn listing_entry(entry: &std::fs::DirEntry, show_hidden: bool) -> Option<FileEntry> {
+    let meta = entry.metadata().ok()?;
+    // DirEntry::file_type은 링크를 따라가지 않음
+    let is_symlink = entry.file_type().is_ok_and(|t| t.is_symlink());
+    entry_from_metadata(entry.path(), &meta, is_symlink, show_hidden)
+}
+
+/// 경로 하나를 목록 항목으로 변환 (폴더 감시가 바뀐 항목만 다시 읽을 때). 규칙은 `listing_entry`와 같다.
+pub(super) fn listing_entry_at(path: &std::path::Path, show_hidden: bool) -> Option<FileEntry> {
+    let meta = std::fs::symlink_metadata(path).ok()?;
+    let is_symlink = meta.file_type().is_symlink();
+    entry_from_metadata(path.to_path_buf(), &meta, is_symlink, show_hidden)
+}
+
+fn entry_from_metadata(
+    path: std::path::PathBuf,
+    meta: &std::fs::Metadata,
+    is_symlink: bool,
+    show_hidden: bool,
+) -> Option<FileEntry> {
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let mut details = FileDetails::read(&name, &path, meta, is_symlink);
+    if details.is_hidden && !show_hidden {
+        return None;
+    }
+    let modified = meta
+        .modified()
+        .ok()
+        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0);
+
+    let identity = file_identity(meta);
+    let mut file_type = if meta.is_dir() {
+        FileType::Directory
+    } else {
+        classify_file(&name)
+    };
+    // 확장자로 알 수 없는 파일은 앞부분 시그니처로 판별
+    // (클라우드 파일은 내려받게 되고 네트워크 마운트는 항목마다 원격으로 읽으므로 제외)
+    if file_type == FileType::Other
+        && meta.len() > 0
+        && !is_cloud_path(&path.to_string_lossy())
+        && !is_network_path(&path)
+    {
+        if let Some((sniffed, mime)) = sniff_file_cached(&identity, &path) {
+            file_type = sniffed;
+            details.mime = Some(mime.to_string());
         }
-        Ok(result)
+    }
+    Some(FileEntry {
+        path: path.to_string_lossy().to_string(),
+        is_dir: meta.is_dir(),
+        size: if meta.is_dir() { 0 } else { meta.len() },
+        modified,
+        identity,
+        file_type,
+        name,
+        is_symlink,
+        details,
     })
-    .await
-    .map_err(|e| AppError::Internal(format!("디렉토리 읽기 태스크 실패: {}", e)))?
 }
 
 // ===== 가상 시스템 루트 목록 =====
@@ -133,12 +162,254 @@ pub async fn list_system_roots() -> Result<Vec<FileEntry>> {
         return Ok(result);
     }
 
-    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    #[cfg(target_os = "linux")]
+    {
+        let mountinfo = std::fs::read_to_string("/proc/self/mountinfo")?;
+        let result = parse_mountinfo(&mountinfo)
+            .into_iter()
+            .filter(|mount_point| std::path::Path::new(mount_point).is_dir())
+            .map(|mount_point| {
+                let name = if mount_point == "/" {
+                    "파일 시스템".to_string()
+                } else {
+                    mount_point
+                        .rsplit('/')
+                        .next()
+                        .unwrap_or_default()
+                        .to_string()
+                };
+                virtual_dir_entry(name, mount_point)
+            })
+            .collect();
+        Ok(result)
+    }
+
+    #[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
     {
         Ok(vec![])
     }
 }
 
+/// 드라이브로 보여줄 필요가 없는 가상·시스템 파일 시스템
+#[cfg(any(target_os = "linux", test))]
+const PSEUDO_FILESYSTEMS: &[&str] = &[
+    "autofs",
+    "binfmt_misc",
+    "bpf",
+    "cgroup",
+    "cgroup2",
+    "configfs",
+    "debugfs",
+    "devpts",
+    "devtmpfs",
+    "efivarfs",
+    "fusectl",
+    "hugetlbfs",
+    "mqueue",
+    "nsfs",
+    "proc",
+    "pstore",
+    "ramfs",
+    "rpc_pipefs",
+    "securityfs",
+    "selinuxfs",
+    "squashfs",
+    "sysfs",
+    "tmpfs",
+    "tracefs",
+    "fuse.gvfsd-fuse",
+    "fuse.portal",
+];
+
+/// 시스템 내부용 마운트 위치 (이동식 미디어는 /run/media 아래라 허용)
+#[cfg(any(target_os = "linux", test))]
+fn is_system_mount_point(mount_point: &str) -> bool {
+    const SYSTEM_PREFIXES: &[&str] = &[
+        "/proc", "/sys", "/dev", "/run", "/boot", "/snap", "/var/lib",
+    ];
+    if mount_point.starts_with("/run/media/") {
+        return false;
+    }
+    SYSTEM_PREFIXES.iter().any(|prefix| {
+        mount_point == *prefix
+            || mount_point
+                .strip_prefix(prefix)
+                .is_some_and(|rest| rest.starts_with('/'))
+    })
+}
+
+/// mountinfo 경로의 8진수 이스케이프(`\040` 등) 해제
+#[cfg(any(target_os = "linux", test))]
+fn unescape_mount_path(raw: &str) -> String {
+    let bytes = raw.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let octal = bytes
+            .get(i + 1..i + 4)
+            .filter(|d| bytes[i] == b'\\' && d.iter().all(|c| (b'0'..=b'7').contains(c)));
+        match octal.and_then(|d| u8::from_str_radix(std::str::from_utf8(d).ok()?, 8).ok()) {
+            Some(byte) => {
+                out.push(byte);
+                i += 4;
+            }
+            None => {
+                out.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+/// `/proc/self/mountinfo`에서 사용자에게 보여줄 마운트 위치 목록 (루트가 맨 앞)
+/// 형식: `ID 부모ID 장치 루트 마운트위치 옵션 [선택 필드...] - 파일시스템 소스 슈퍼옵션`
+#[cfg(any(target_os = "linux", test))]
+pub(super) fn parse_mountinfo(mountinfo: &str) -> Vec<String> {
+    let mut mounts: Vec<String> = Vec::new();
+    for line in mountinfo.lines() {
+        let Some((fields, fs_fields)) = line.split_once(" - ") else {
+            continue;
+        };
+        let fields: Vec<&str> = fields.split(' ').collect();
+        let fs_type = fs_fields.split(' ').next().unwrap_or_default();
+        let (Some(root), Some(mount_point)) = (fields.get(3), fields.get(4)) else {
+            continue;
+        };
+        // 하위 폴더 bind 마운트는 원본 폴더와 중복
+        if *root != "/" || PSEUDO_FILESYSTEMS.contains(&fs_type) {
+            continue;
+        }
+        let mount_point = unescape_mount_path(mount_point);
+        if is_system_mount_point(&mount_point) || mounts.contains(&mount_point) {
+            continue;
+        }
+        mounts.push(mount_point);
+    }
+    mounts.sort_by(|a, b| (a != "/").cmp(&(b != "/")).then_with(|| a.cmp(b)));
+    mounts
+}
+
+// ===== 네트워크 마운트 판별 =====
+
+/// 원격 파일 시스템 — 목록에서 파일 내용을 읽는 시그니처 판별을 건너뛴다 (항목마다 원격 왕복)
+#[cfg(any(target_os = "linux", target_os = "macos", test))]
+const NETWORK_FILESYSTEMS: &[&str] = &[
+    "cifs",
+    "smb3",
+    "smbfs",
+    "nfs",
+    "nfs4",
+    "afpfs",
+    "webdav",
+    "davfs",
+    "9p",
+    "ceph",
+    "glusterfs",
+    "fuse.sshfs",
+    "fuse.rclone",
+];
+/// 폴더별 판별 결과 유지 시간 (마운트가 바뀌어도 이 시간 뒤엔 다시 확인)
+const NETWORK_DIR_CACHE_TTL: std::time::Duration = std::time::Duration::from_secs(60);
+const NETWORK_DIR_CACHE_LIMIT: usize = 1024;
+
+/// `/proc/self/mountinfo`에서 네트워크 파일 시스템 마운트 위치 목록
+#[cfg(any(target_os = "linux", test))]
+pub(super) fn parse_network_mounts(mountinfo: &str) -> Vec<std::path::PathBuf> {
+    mountinfo
+        .lines()
+        .filter_map(|line| {
+            let (fields, fs_fields) = line.split_once(" - ")?;
+            let fs_type = fs_fields.split(' ').next()?;
+            if !NETWORK_FILESYSTEMS.contains(&fs_type) {
+                return None;
+            }
+            let mount_point = fields.split(' ').nth(4)?;
+            Some(std::path::PathBuf::from(unescape_mount_path(mount_point)))
+        })
+        .collect()
+}
+
+#[cfg(target_os = "linux")]
+fn detect_network_dir(dir: &std::path::Path) -> bool {
+    std::fs::read_to_string("/proc/self/mountinfo").is_ok_and(|mountinfo| {
+        parse_network_mounts(&mountinfo)
+            .iter()
+            .any(|mount| dir.starts_with(mount))
+    })
+}
+
+#[cfg(target_os = "macos")]
+fn detect_network_dir(dir: &std::path::Path) -> bool {
+    use std::os::unix::ffi::OsStrExt;
+    let Ok(c_path) = std::ffi::CString::new(dir.as_os_str().as_bytes()) else {
+        return false;
+    };
+    let mut stat: libc::statfs = unsafe { std::mem::zeroed() };
+    if unsafe { libc::statfs(c_path.as_ptr(), &mut stat) } != 0 {
+        return false;
+    }
+    let fs_type = unsafe { std::ffi::CStr::from_ptr(stat.f_fstypename.as_ptr()) };
+    NETWORK_FILESYSTEMS.contains(&fs_type.to_string_lossy().as_ref())
+}
+
+/// UNC 경로(`\\server\share`)이거나 네트워크 드라이브로 연결된 드라이브 문자
+#[cfg(target_os = "windows")]
+fn detect_network_dir(dir: &std::path::Path) -> bool {
+    use std::os::windows::ffi::OsStrExt;
+    use std::path::{Component, Prefix};
+    let root = match dir.components().next() {
+        Some(Component::Prefix(prefix)) => match prefix.kind() {
+            Prefix::UNC(..) | Prefix::VerbatimUNC(..) => return true,
+            Prefix::Disk(letter) | Prefix::VerbatimDisk(letter) => {
+                format!("{}:\\", letter as char)
+            }
+            _ => return false,
+        },
+        _ => return false,
+    };
+    let wide: Vec<u16> = std::ffi::OsStr::new(&root)
+        .encode_wide()
+        .chain(std::iter::once(0))
+        .collect();
+    unsafe {
+        winapi::um::fileapi::GetDriveTypeW(wide.as_ptr()) == winapi::um::winbase::DRIVE_REMOTE
+    }
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
+fn detect_network_dir(_dir: &std::path::Path) -> bool {
+    false
+}
+
+/// SMB/NFS 등 네트워크 마운트 아래 경로인지 (상위 폴더 단위로 캐시)
+pub(super) fn is_network_path(path: &std::path::Path) -> bool {
+    is_network_dir(path.parent().unwrap_or(path))
+}
+
+/// 폴더가 SMB/NFS 등 네트워크 마운트 아래인지 (폴더 단위로 캐시)
+pub(super) fn is_network_dir(dir: &std::path::Path) -> bool {
+    use std::collections::HashMap;
+    use std::sync::{Mutex, OnceLock};
+    use std::time::Instant;
+
+    static CACHE: OnceLock<Mutex<HashMap<std::path::PathBuf, (bool, Instant)>>> = OnceLock::new();
+    let dir = dir.to_path_buf();
+    let cache = CACHE.get_or_init(Default::default);
+    if let Some((network, checked)) = cache.lock().unwrap_or_else(|e| e.into_inner()).get(&dir) {
+        if checked.elapsed() < NETWORK_DIR_CACHE_TTL {
+            return *network;
+        }
+    }
+    let network = detect_network_dir(&dir);
+    let mut cache = cache.lock().unwrap_or_else(|e| e.into_inner());
+    if cache.len() >= NETWORK_DIR_CACHE_LIMIT {
+        cache.clear();
+    }
+    cache.insert(dir, (network, Instant::now()));
+    network
+}
+
 // ===== 경로 확인 =====
 
 // 경로가 디렉토리인지 확인
@@ -155,6 +426,7 @@ pub struct FolderSizeChildInfo {
     pub bytes: String,
     pub file_count: u64,
     pub folder_count: u64,
+    pub symlink_count: u64,
 }
 
 #[derive(Debug, serde::Serialize)]
@@ -162,6 +434,8 @@ pub struct FolderSizeInfo {
     pub bytes: String,
     pub file_count: u64,
     pub folder_count: u64,
+    /// 따라가지 않고 개수만 센 심볼릭 링크 (용량에는 포함하지 않음)
+    pub symlink_count: u64,
     pub children: Vec<FolderSizeChildInfo>,
 }
 
@@ -170,6 +444,7 @@ struct FolderSizeAccumulator {
     bytes: u64,
     file_count: u64,
     folder_count: u64,
+    symlink_count: u64,
 }
 
 // 폴더 내부 파일 크기 합계를 계산한다.
@@ -193,6 +468,7 @@ fn calculate_folder_size_impl(path: &str) -> Result<FolderSizeInfo> {
         bytes: acc.bytes.to_string(),
         file_count: acc.file_count,
         folder_count: acc.folder_count,
+        symlink_count: acc.symlink_count,
         children,
     })
 }
@@ -214,6 +490,7 @@ fn collect_folder_size_children(
             Err(_) => continue,
         };
         if metadata.file_type().is_symlink() {
+            total.symlink_count = total.symlink_count.saturating_add(1);
             continue;
         }
 
@@ -224,6 +501,7 @@ fn collect_folder_size_children(
             collect_folder_size(&entry_path, &mut child_acc)?;
             total.bytes = total.bytes.saturating_add(child_acc.bytes);
             total.file_count = total.file_count.saturating_add(child_acc.file_count);
+            total.symlink_count = total.symlink_count.saturating_add(child_acc.symlink_count);
             total.folder_count = total
                 .folder_count
                 .saturating_add(child_acc.folder_count.saturating_add(1));
@@ -237,6 +515,7 @@ fn collect_folder_size_children(
                     bytes: child_acc.bytes.to_string(),
                     file_count: child_acc.file_count,
                     folder_count: child_acc.folder_count,
+                    symlink_count: child_acc.symlink_count,
                 },
             ));
         } else if metadata.is_file() {
@@ -253,6 +532,7 @@ fn collect_folder_size_children(
                     bytes: bytes.to_string(),
                     file_count: 1,
                     folder_count: 0,
+                    symlink_count: 0,
                 },
             ));
         }
@@ -274,6 +554,7 @@ fn collect_folder_size(path: &std::path::Path, acc: &mut FolderSizeAccumulator)
             Err(_) => continue,
         };
         if metadata.file_type().is_symlink() {
+            acc.symlink_count = acc.symlink_count.saturating_add(1);
             continue;
         }
         if metadata.is_dir() {
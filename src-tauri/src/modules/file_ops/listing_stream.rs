@@ -0,0 +1,355 @@
+// ===== 스트리밍 디렉토리 목록 =====
+// 항목이 수십만 개인 폴더(렌더 프레임 등)를 한 번의 IPC 응답으로 보내면 웹뷰가 멈추므로,
+// read_dir 진행 중에 묶음 단위로 Channel에 보낸다. 정렬·필터를 백엔드에서 적용하면
+// 요청한 구간(offset/limit)만 보내고 전체 개수는 응답으로 돌려준다.
+
+use std::cmp::Ordering;
+
+use tauri::ipc::Channel;
+
+use crate::helpers::{glob_match, natural_cmp};
+use crate::modules::error::{AppError, Result};
+use crate::modules::jobs::{cancel_job, new_job_id, register_job, CancelToken};
+use crate::modules::types::{FileEntry, FileType};
+
+use super::listing::listing_entry;
+
+const DEFAULT_BATCH_SIZE: usize = 500;
+
+/// 정렬 기준 (프런트 `EntrySortBy`와 동일, 폴더가 항상 먼저)
+#[derive(serde::Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ListingSortBy {
+    #[default]
+    Name,
+    Size,
+    Modified,
+    /// 확장자별 묶음 후 이름순
+    Type,
+}
+
+#[derive(serde::Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ListingSort {
+    #[serde(default)]
+    pub by: ListingSortBy,
+    #[serde(default)]
+    pub descending: bool,
+}
+
+/// 항목 필터 (모든 조건을 만족해야 통과, 크기 조건은 파일에만 적용)
+#[derive(serde::Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ListingFilter {
+    pub file_types: Option<Vec<FileType>>,
+    /// 이름 와일드카드 (`*.exr`, `shot_??_*` — 대소문자 무시)
+    pub name_glob: Option<String>,
+    pub min_size: Option<u64>,
+    pub max_size: Option<u64>,
+}
+
+impl ListingFilter {
+    fn matches(&self, entry: &FileEntry) -> bool {
+        if let Some(types) = &self.file_types {
+            if !types.contains(&entry.file_type) {
+                return false;
+            }
+        }
+        if let Some(glob) = self.name_glob.as_deref().filter(|g| !g.is_empty()) {
+            if !glob_match(glob, &entry.name) {
+                return false;
+            }
+        }
+        if entry.is_dir {
+            return true;
+        }
+        self.min_size.map_or(true, |min| entry.size >= min)
+            && self.max_size.map_or(true, |max| entry.size <= max)
+    }
+}
+
+#[derive(serde::Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ListDirectoryOptions {
+    /// 취소용 작업 ID (없으면 발급). 다른 폴더로 이동하면 `cancel_directory_listing`으로 중단.
+    pub job_id: Option<String>,
+    pub batch_size: Option<usize>,
+    /// 지정하면 전체를 읽어 정렬한 뒤 구간을 보낸다 (없으면 읽는 순서대로 즉시 전송)
+    pub sort: Option<ListingSort>,
+    #[serde(default)]
+    pub filter: ListingFilter,
+    /// 숨김·시스템 파일 포함 (기본 제외)
+    #[serde(default)]
+    pub show_hidden: bool,
+    /// 필터(와 정렬)를 적용한 결과에서 보낼 구간
+    #[serde(default)]
+    pub offset: usize,
+    pub limit: Option<usize>,
+}
+
+/// Channel로 보내는 항목 묶음
+#[derive(serde::Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DirectoryBatch {
+    pub job_id: String,
+    /// 묶음 첫 항목의 결과 내 위치
+    pub offset: usize,
+    pub entries: Vec<FileEntry>,
+}
+
+/// `list_directory_stream` 응답 (묶음 전송이 모두 끝난 뒤 반환)
+#[derive(serde::Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DirectoryListingSummary {
+    pub job_id: String,
+    /// 필터를 통과한 전체 항목 수 (가상 스크롤 높이용)
+    pub total: usize,
+    /// 실제로 보낸 항목 수
+    pub sent: usize,
+}
+
+fn extension(name: &str) -> String {
+    name.rsplit_once('.')
+        .map(|(_, ext)| ext.to_lowercase())
+        .unwrap_or_default()
+}
+
+fn compare_entries(a: &FileEntry, b: &FileEntry, sort: &ListingSort) -> Ordering {
+    // 폴더 우선은 정렬 방향과 무관
+    if a.is_dir != b.is_dir {
+        return if a.is_dir {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        };
+    }
+    let ord = match sort.by {
+        ListingSortBy::Name => natural_cmp(&a.name, &b.name),
+        ListingSortBy::Size => a.size.cmp(&b.size),
+        ListingSortBy::Modified => a.modified.cmp(&b.modified),
+        ListingSortBy::Type => extension(&a.name)
+            .cmp(&extension(&b.name))
+            .then_with(|| natural_cmp(&a.name, &b.name)),
+    };
+    if sort.descending {
+        ord.reverse()
+    } else {
+        ord
+    }
+}
+
+/// 결과 구간만 골라 묶음으로 보내는 송신기
+struct BatchSender<'a, F: FnMut(DirectoryBatch) -> Result<()>> {
+    job_id: &'a str,
+    start: usize,
+    end: usize,
+    batch_size: usize,
+    /// 필터를 통과한 항목 수 (다음 항목의 결과 내 위치)
+    total: usize,
+    sent: usize,
+    pending: Vec<FileEntry>,
+    send: F,
+}
+
+impl<F: FnMut(DirectoryBatch) -> Result<()>> BatchSender<'_, F> {
+    fn push(&mut self, entry: FileEntry) -> Result<()> {
+        let index = self.total;
+        self.total += 1;
+        if index < self.start || index >= self.end {
+            return Ok(());
+        }
+        self.pending.push(entry);
+        if self.pending.len() >= self.batch_size {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        if self.pending.is_empty() {
+            return Ok(());
+        }
+        let entries = std::mem::take(&mut self.pending);
+        let offset = self.start + self.sent;
+        self.sent += entries.len();
+        (self.send)(DirectoryBatch {
+            job_id: self.job_id.to_string(),
+            offset,
+            entries,
+        })
+    }
+}
+
+/// 폴더를 읽으며 필터·정렬·구간을 적용해 `send`로 묶음 전달
+fn stream_directory(
+    path: &str,
+    options: &ListDirectoryOptions,
+    job_id: &str,
+    token: &CancelToken,
+    send: impl FnMut(DirectoryBatch) -> Result<()>,
+) -> Result<DirectoryListingSummary> {
+    let read_dir = std::fs::read_dir(path)?;
+    let mut sender = BatchSender {
+        job_id,
+        start: options.offset,
+        end: options
+            .limit
+            .map_or(usize::MAX, |limit| options.offset.saturating_add(limit)),
+        batch_size: options.batch_size.unwrap_or(DEFAULT_BATCH_SIZE).max(1),
+        total: 0,
+        sent: 0,
+        pending: Vec::new(),
+        send,
+    };
+    let entries = read_dir
+        .flatten()
+        .filter_map(|entry| listing_entry(&entry, options.show_hidden))
+        .filter(|entry| options.filter.matches(entry));
+
+    match &options.sort {
+        None => {
+            for entry in entries {
+                token.check()?;
+                sender.push(entry)?;
+            }
+        }
+        Some(sort) => {
+            let mut all = Vec::new();
+            for entry in entries {
+                token.check()?;
+                all.push(entry);
+            }
+            all.sort_by(|a, b| compare_entries(a, b, sort));
+            for entry in all {
+                token.check()?;
+                sender.push(entry)?;
+            }
+        }
+    }
+    sender.flush()?;
+    Ok(DirectoryListingSummary {
+        job_id: job_id.to_string(),
+        total: sender.total,
+        sent: sender.sent,
+    })
+}
+
+/// 대용량 폴더용 스트리밍 목록. 항목은 `on_batch`로 도착하고, 끝나면 요약을 반환한다.
+#[tauri::command]
+pub async fn list_directory_stream(
+    path: String,
+    options: Option<ListDirectoryOptions>,
+    on_batch: Channel<DirectoryBatch>,
+) -> Result<DirectoryListingSummary> {
+    let options = options.unwrap_or_default();
+    let job_id = options
+        .job_id
+        .clone()
+        .unwrap_or_else(|| new_job_id("listing"));
+    let handle = register_job(job_id)?;
+    tauri::async_runtime::spawn_blocking(move || {
+        stream_directory(&path, &options, handle.id(), handle.token(), |batch| {
+            on_batch
+                .send(batch)
+                .map_err(|e| AppError::Internal(format!("목록 전송 실패: {}", e)))
+        })
+    })
+    .await
+    .map_err(|e| AppError::Internal(format!("디렉토리 읽기 태스크 실패: {}", e)))?
+}
+
+/// 진행 중인 스트리밍 목록 중단 (이미 끝났으면 false)
+#[tauri::command]
+pub fn cancel_directory_listing(job_id: String) -> bool {
+    cancel_job(&job_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn collect(
+        path: &std::path::Path,
+        options: ListDirectoryOptions,
+    ) -> (Vec<DirectoryBatch>, DirectoryListingSummary) {
+        let mut batches = Vec::new();
+        let summary = stream_directory(
+            &path.to_string_lossy(),
+            &options,
+            "test",
+            &CancelToken::new(),
+            |batch| {
+                batches.push(batch);
+                Ok(())
+            },
+        )
+        .unwrap();
+        (batches, summary)
+    }
+
+    #[test]
+    fn streams_sorted_filtered_slice_in_batches() {
+        let dir = std::env::temp_dir().join("file_ops_test_listing_stream");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join("sub")).unwrap();
+        for i in 1..=12 {
+            std::fs::write(dir.join(format!("frame{}.png", i)), vec![0u8; i]).unwrap();
+        }
+        std::fs::write(dir.join("notes.txt"), "x").unwrap();
+
+        let (batches, summary) = collect(
+            &dir,
+            ListDirectoryOptions {
+                batch_size: Some(2),
+                sort: Some(ListingSort::default()),
+                filter: ListingFilter {
+                    name_glob: Some("FRAME*".into()),
+                    min_size: Some(2),
+                    ..Default::default()
+                },
+                offset: 1,
+                limit: Some(3),
+                ..Default::default()
+            },
+        );
+        assert_eq!((summary.total, summary.sent), (11, 3));
+        assert_eq!(batches.iter().map(|b| b.offset).collect::<Vec<_>>(), [1, 3]);
+        let names: Vec<&str> = batches
+            .iter()
+            .flat_map(|b| b.entries.iter().map(|e| e.name.as_str()))
+            .collect();
+        assert_eq!(names, ["frame3.png", "frame4.png", "frame5.png"]);
+
+        // 폴더는 크기 조건과 무관하게 앞에 오고, 내림차순에서도 폴더 우선
+        let (batches, summary) = collect(
+            &dir,
+            ListDirectoryOptions {
+                sort: Some(ListingSort {
+                    by: ListingSortBy::Size,
+                    descending: true,
+                }),
+                filter: ListingFilter {
+                    min_size: Some(12),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
+        assert_eq!(summary.total, 2);
+        let names: Vec<&str> = batches[0].entries.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, ["sub", "frame12.png"]);
+
+        let token = CancelToken::new();
+        token.cancel();
+        let result = stream_directory(
+            &dir.to_string_lossy(),
+            &ListDirectoryOptions::default(),
+            "test",
+            &token,
+            |_| Ok(()),
+        );
+        assert!(matches!(result, Err(AppError::Cancelled(_))));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -5,25 +5,55 @@ use crate::modules::image_ops::{
     thumbnail_cache_root,
 };
 
+use super::history::{record_operation, FileOperation};
+use super::symlink::{is_symlink, remove_symlink};
+
 // ===== 파일/디렉토리 생성 =====
 
+pub(super) async fn create_directory_impl(path: &str) -> Result<()> {
+    std::fs::create_dir_all(path)?;
+    Ok(())
+}
+
 // 새 폴더 생성
 #[tauri::command]
-pub async fn create_directory(path: String) -> Result<()> {
-    std::fs::create_dir_all(&path)?;
+pub async fn create_directory(app: tauri::AppHandle, path: String) -> Result<()> {
+    let existed = std::path::Path::new(&path).exists();
+    create_directory_impl(&path).await?;
+    if !existed {
+        record_operation(
+            &app,
+            FileOperation::Create {
+                path,
+                directory: true,
+            },
+        );
+    }
     Ok(())
 }
 
-// 빈 텍스트 파일 생성
-#[tauri::command]
-pub async fn create_text_file(path: String) -> Result<()> {
-    let p = std::path::Path::new(&path);
+pub(super) async fn create_text_file_impl(path: &str) -> Result<()> {
+    let p = std::path::Path::new(path);
     if p.exists() {
         return Err(AppError::AlreadyExists(
             "이미 존재하는 파일입니다".to_string(),
         ));
     }
-    std::fs::write(&path, "")?;
+    std::fs::write(path, "")?;
+    Ok(())
+}
+
+// 빈 텍스트 파일 생성
+#[tauri::command]
+pub async fn create_text_file(app: tauri::AppHandle, path: String) -> Result<()> {
+    create_text_file_impl(&path).await?;
+    record_operation(
+        &app,
+        FileOperation::Create {
+            path,
+            directory: false,
+        },
+    );
     Ok(())
 }
 
@@ -74,11 +104,12 @@ pub(super) async fn rename_item_impl(
                 "동일한 이름의 파일이 존재합니다.".to_string(),
             ));
         }
+        std::fs::rename(&old_path, &new_path)?;
         // 삭제 대신 키 이관 — 이름만 바뀐 파일의 썸네일을 재생성 없이 재사용한다
+        // (rename이 실패하면 캐시는 옛 키 그대로 남는다)
         if let Some(app_cache) = app_cache {
             migrate_thumbnail_cache_for_rename(&app_cache, &old_path, &new_path);
         }
-        std::fs::rename(&old_path, &new_path)?;
         Ok(())
     })
     .await
@@ -93,7 +124,17 @@ pub async fn rename_item<R: tauri::Runtime>(
     new_path: String,
 ) -> Result<()> {
     let app_cache = thumbnail_cache_root(&app)?;
-    rename_item_impl(old_path, new_path, Some(app_cache)).await
+    rename_item_impl(old_path.clone(), new_path.clone(), Some(app_cache)).await?;
+    if old_path != new_path {
+        record_operation(
+            &app,
+            FileOperation::Rename {
+                from: old_path,
+                to: new_path,
+            },
+        );
+    }
+    Ok(())
 }
 
 // ===== 삭제 =====
@@ -138,6 +179,10 @@ fn is_windows_google_drive_virtual(norm: &str) -> bool {
 
 /// 경로에 따라 직접 삭제 수행 (디렉토리/파일 구분)
 fn remove_directly(p: &std::path::Path, _path: &str) -> Result<()> {
+    // 링크는 가리키는 대상이 아니라 링크 자체만 삭제
+    if is_symlink(p) {
+        return remove_symlink(p);
+    }
     if p.is_dir() {
         std::fs::remove_dir_all(p)?;
     } else {
@@ -191,7 +236,13 @@ pub async fn delete_items<R: tauri::Runtime>(
     use_trash: bool,
 ) -> Result<()> {
     let app_cache = thumbnail_cache_root(&app)?;
-    delete_items_impl(paths, use_trash, Some(app_cache)).await
+    delete_items_impl(paths.clone(), use_trash, Some(app_cache)).await?;
+    // 휴지통으로 보낸 항목만 되돌릴 수 있다 (클라우드 경로는 직접 삭제됨)
+    if use_trash {
+        let paths = paths.into_iter().filter(|p| !is_cloud_path(p)).collect();
+        record_operation(&app, FileOperation::Trash { paths });
+    }
+    Ok(())
 }
 
 // Windows 관리자 권한으로 파일/폴더 삭제
@@ -283,7 +334,7 @@ pub async fn restore_trash_items(original_paths: Vec<String>) -> Result<()> {
 }
 
 #[cfg(target_os = "macos")]
-fn restore_single_item(orig_path: &str) -> Result<()> {
+pub(super) fn restore_single_item(orig_path: &str) -> Result<()> {
     let home =
         std::env::var("HOME").map_err(|_| AppError::Internal("HOME 환경변수 없음".to_string()))?;
     let trash_dir = std::path::Path::new(&home).join(".Trash");
@@ -339,8 +390,10 @@ fn restore_single_item(orig_path: &str) -> Result<()> {
     }
 }
 
-#[cfg(target_os = "windows")]
-fn restore_single_item(orig_path: &str) -> Result<()> {
+// Windows 휴지통과 FreeDesktop 휴지통(~/.local/share/Trash, 마운트별 .Trash-UID)은
+// trash::os_limited가 원래 경로 정보를 제공한다
+#[cfg(any(target_os = "windows", target_os = "linux"))]
+pub(super) fn restore_single_item(orig_path: &str) -> Result<()> {
     let items =
         trash::os_limited::list().map_err(|e| AppError::Io(format!("휴지통 조회 실패: {}", e)))?;
     let orig = std::path::Path::new(orig_path);
@@ -365,8 +418,8 @@ fn restore_single_item(orig_path: &str) -> Result<()> {
     }
 }
 
-#[cfg(not(any(target_os = "macos", target_os = "windows")))]
-fn restore_single_item(orig_path: &str) -> Result<()> {
+#[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
+pub(super) fn restore_single_item(orig_path: &str) -> Result<()> {
     Err(AppError::UnsupportedPlatform(format!(
         "이 플랫폼에서는 휴지통 복원이 지원되지 않습니다: {}",
         orig_path
@@ -0,0 +1,76 @@
+// ===== 심볼릭 링크 처리 정책 =====
+// 복사·이동·병합이 같은 정책을 공유한다. 링크를 따라갈 때는 상위 폴더를 가리키는
+// 순환 링크를 감지해 건너뛴다.
+
+use crate::modules::error::Result;
+
+/// 복사 시 심볼릭 링크 처리 방식
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SymlinkPolicy {
+    /// 링크를 링크로 재생성 (가리키는 대상은 복사하지 않음)
+    #[default]
+    CopyLink,
+    /// 링크를 따라가 대상 내용을 복사
+    Follow,
+    /// 링크 건너뜀
+    Skip,
+}
+
+/// 경로 자체가 심볼릭 링크인지 (링크를 따라가지 않음)
+pub fn is_symlink(path: &std::path::Path) -> bool {
+    std::fs::symlink_metadata(path)
+        .map(|m| m.file_type().is_symlink())
+        .unwrap_or(false)
+}
+
+/// 링크 자체 복사 — 링크 대상 경로를 그대로 새 링크로 만든다
+pub(crate) fn copy_symlink(src: &std::path::Path, dest: &std::path::Path) -> Result<()> {
+    let target = std::fs::read_link(src)?;
+    #[cfg(unix)]
+    std::os::unix::fs::symlink(&target, dest)?;
+    #[cfg(windows)]
+    {
+        // Windows는 파일/폴더 링크를 구분해 생성해야 함
+        if src.is_dir() {
+            std::os::windows::fs::symlink_dir(&target, dest)?;
+        } else {
+            std::os::windows::fs::symlink_file(&target, dest)?;
+        }
+    }
+    Ok(())
+}
+
+/// 링크 자체 삭제 (Windows 폴더 링크는 remove_dir로만 지워짐)
+pub(crate) fn remove_symlink(path: &std::path::Path) -> Result<()> {
+    match std::fs::remove_file(path) {
+        Ok(()) => Ok(()),
+        #[cfg(windows)]
+        Err(_) => Ok(std::fs::remove_dir(path)?),
+        #[cfg(not(windows))]
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// 링크를 따라 재귀 복사할 때의 순환 감지 (현재 경로의 조상 폴더 canonical 경로 스택)
+#[derive(Default)]
+pub(crate) struct LoopGuard(Vec<std::path::PathBuf>);
+
+impl LoopGuard {
+    /// 폴더 진입. 이미 조상 경로에 있으면(순환) false를 반환하고 진입하지 않는다.
+    pub(crate) fn enter(&mut self, dir: &std::path::Path) -> bool {
+        let Ok(canonical) = dir.canonicalize() else {
+            return false;
+        };
+        if self.0.contains(&canonical) {
+            log::warn!("순환 심볼릭 링크 건너뜀: {}", dir.display());
+            return false;
+        }
+        self.0.push(canonical);
+        true
+    }
+
+    pub(crate) fn leave(&mut self) {
+        self.0.pop();
+    }
+}
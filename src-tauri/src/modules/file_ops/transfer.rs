@@ -1,49 +1,128 @@
+mod conflict;
 mod duplicate;
 mod folder_merge;
+mod journal;
+mod metadata;
 mod progress;
+mod verify;
 
+pub use conflict::{ConflictPolicy, FolderMergeConflictMode, TransferConflict};
+#[cfg(test)]
+pub(super) use duplicate::duplicate_items_impl;
 pub use duplicate::{check_duplicate_items, duplicate_items};
 #[cfg(test)]
 pub(super) use folder_merge::merge_folders_recursive;
 pub use folder_merge::{
     analyze_folder_merge, merge_folders, FolderMergeAnalysis, FolderMergeConflictFile,
-    FolderMergeConflictMode,
 };
-pub use progress::{CopyProgress, TransferFileItem, TransferQueueProgress};
+pub use journal::PendingTransfer;
+#[cfg(test)]
+pub(super) use progress::copy_file_cancellable;
+pub use progress::{CopyProgress, TransferFileItem, TransferOptions, TransferQueueProgress};
+#[cfg(test)]
+pub(super) use verify::{contents_match, copy_file_verified};
 
 use crate::helpers::get_copy_destination;
 use crate::modules::error::{AppError, Result};
 use crate::modules::image_ops::{invalidate_thumbnail_cache_paths_in_root, thumbnail_cache_root};
+use crate::modules::jobs::{cancel_job, is_job_running, new_job_id, pause_job, register_job};
+
+use super::history::{path_pairs, record_operation, FileOperation};
+use conflict::prepare_top_level_dest;
+use metadata::{copy_file_preserving, preserve_metadata};
 
-pub fn copy_dir_recursive(src: &std::path::Path, dest: &std::path::Path) -> Result<()> {
+use super::symlink::{copy_symlink, is_symlink, remove_symlink, LoopGuard, SymlinkPolicy};
+
+/// 폴더 재귀 복사 (파일·폴더의 시각/권한/확장 속성 보존, 링크는 `policy`대로 처리)
+pub fn copy_dir_recursive(
+    src: &std::path::Path,
+    dest: &std::path::Path,
+    policy: SymlinkPolicy,
+) -> Result<()> {
+    copy_dir_guarded(src, dest, policy, &mut LoopGuard::default())
+}
+
+fn copy_dir_guarded(
+    src: &std::path::Path,
+    dest: &std::path::Path,
+    policy: SymlinkPolicy,
+    guard: &mut LoopGuard,
+) -> Result<()> {
+    if !guard.enter(src) {
+        return Ok(());
+    }
     std::fs::create_dir_all(dest)?;
     for entry in std::fs::read_dir(src)?.flatten() {
+        let path = entry.path();
         let dest_child = dest.join(entry.file_name());
-        if entry.path().is_dir() {
-            copy_dir_recursive(&entry.path(), &dest_child)?;
+        if entry.file_type().is_ok_and(|t| t.is_symlink()) {
+            match policy {
+                SymlinkPolicy::CopyLink => {
+                    copy_symlink(&path, &dest_child)?;
+                    continue;
+                }
+                SymlinkPolicy::Skip => continue,
+                SymlinkPolicy::Follow => {}
+            }
+        }
+        if path.is_dir() {
+            copy_dir_guarded(&path, &dest_child, policy, guard)?;
+        } else if path.is_file() {
+            copy_file_preserving(&path, &dest_child)?;
         } else {
-            std::fs::copy(entry.path(), &dest_child)?;
+            log::warn!("대상이 없는 심볼릭 링크 건너뜀: {}", path.display());
         }
     }
+    guard.leave();
+    // 하위 항목 생성으로 바뀐 폴더 시각은 마지막에 복원
+    preserve_metadata(src, dest);
     Ok(())
 }
 
+/// 파일·폴더·링크 하나를 정책에 따라 복사 (`Skip` 정책의 링크는 아무것도 하지 않음)
+pub fn copy_entry(
+    src: &std::path::Path,
+    dest: &std::path::Path,
+    policy: SymlinkPolicy,
+) -> Result<()> {
+    if is_symlink(src) {
+        match policy {
+            SymlinkPolicy::CopyLink => return copy_symlink(src, dest),
+            SymlinkPolicy::Skip => return Ok(()),
+            SymlinkPolicy::Follow => {}
+        }
+    }
+    if src.is_dir() {
+        copy_dir_recursive(src, dest, policy)
+    } else {
+        copy_file_preserving(src, dest).map(|_| ())
+    }
+}
+
 /// copy_items와 동일한 대상 경로 결정 (실제로 복사할 (소스, 대상) 쌍만 수집)
+/// 겹치는 최상위 항목은 `conflict` 정책대로 처리하며, 폴더끼리 겹치면 대상 폴더를 그대로 두고
+/// 하위 파일 충돌은 전송 계획에서 결정한다. `SymlinkPolicy::Skip`이면 링크인 소스는 제외한다.
 pub(super) fn collect_copy_jobs(
     sources: &[String],
     dest: &std::path::Path,
-    overwrite: bool,
+    conflict: ConflictPolicy,
     app_cache: Option<&std::path::Path>,
+    symlinks: SymlinkPolicy,
 ) -> Result<Vec<(std::path::PathBuf, std::path::PathBuf)>> {
     let mut jobs = Vec::new();
     for source in sources {
         let src_path = std::path::Path::new(source);
+        if symlinks == SymlinkPolicy::Skip && is_symlink(src_path) {
+            continue;
+        }
         let file_name = src_path
             .file_name()
             .ok_or_else(|| AppError::InvalidInput(format!("잘못된 경로: {}", source)))?;
-        let mut dest_path = dest.join(file_name);
+        let dest_path = dest.join(file_name);
 
-        if dest_path.exists() && dest_path.canonicalize().ok() == src_path.canonicalize().ok() {
+        let dest_path = if dest_path.exists()
+            && dest_path.canonicalize().ok() == src_path.canonicalize().ok()
+        {
             let stem = src_path
                 .file_stem()
                 .unwrap_or_default()
@@ -54,33 +133,26 @@ pub(super) fn collect_copy_jobs(
                 .map(|e| format!(".{}", e.to_string_lossy()))
                 .unwrap_or_default();
             let is_dir = src_path.is_dir();
-            dest_path = get_copy_destination(dest, &stem, &ext, is_dir);
-        } else if dest_path.exists() && overwrite {
-            if let Some(app_cache) = app_cache {
-                invalidate_thumbnail_cache_paths_in_root(
-                    app_cache,
-                    &[dest_path.to_string_lossy().to_string()],
-                );
-            }
-            if dest_path.is_dir() {
-                std::fs::remove_dir_all(&dest_path)?;
-            } else {
-                std::fs::remove_file(&dest_path)?;
+            get_copy_destination(dest, &stem, &ext, is_dir)
+        } else {
+            match prepare_top_level_dest(src_path, &dest_path, conflict, symlinks, app_cache)? {
+                Some(dest_path) => dest_path,
+                None => continue,
             }
-        } else if dest_path.exists() {
-            continue;
-        }
+        };
         jobs.push((src_path.to_path_buf(), dest_path));
     }
     Ok(jobs)
 }
 
-/// move_items와 동일한 대상 경로 결정 (실제 이동은 하지 않음)
+/// move_items와 동일한 대상 경로 결정 (실제 이동은 하지 않음, 충돌 처리는 `collect_copy_jobs`와 같음)
+/// 이미 대상 폴더에 있는 소스는 제외한다.
 pub(super) fn collect_move_jobs(
     sources: &[String],
     dest: &std::path::Path,
-    overwrite: bool,
+    conflict: ConflictPolicy,
     app_cache: Option<&std::path::Path>,
+    symlinks: SymlinkPolicy,
 ) -> Result<Vec<(std::path::PathBuf, std::path::PathBuf)>> {
     let mut jobs = Vec::new();
     for source in sources {
@@ -90,23 +162,14 @@ pub(super) fn collect_move_jobs(
             .ok_or_else(|| AppError::InvalidInput(format!("잘못된 경로: {}", source)))?;
         let dest_path = dest.join(file_name);
 
-        if dest_path.exists() && dest_path.canonicalize().ok() != src_path.canonicalize().ok() {
-            if overwrite {
-                if let Some(app_cache) = app_cache {
-                    invalidate_thumbnail_cache_paths_in_root(
-                        app_cache,
-                        &[dest_path.to_string_lossy().to_string()],
-                    );
-                }
-                if dest_path.is_dir() {
-                    std::fs::remove_dir_all(&dest_path)?;
-                } else {
-                    std::fs::remove_file(&dest_path)?;
-                }
-            } else {
-                continue;
-            }
+        if dest_path.exists() && dest_path.canonicalize().ok() == src_path.canonicalize().ok() {
+            continue;
         }
+        let Some(dest_path) =
+            prepare_top_level_dest(src_path, &dest_path, conflict, symlinks, app_cache)?
+        else {
+            continue;
+        };
 
         jobs.push((src_path.to_path_buf(), dest_path));
     }
@@ -130,16 +193,33 @@ pub(super) fn count_files_to_copy(path: &std::path::Path) -> Result<u64> {
     Ok(n)
 }
 
+/// 복사할 파일 크기 합계 (바이트 진행률용, 읽을 수 없는 항목은 0으로 계산)
+pub(super) fn count_bytes_to_copy(path: &std::path::Path) -> u64 {
+    use walkdir::WalkDir;
+    if path.is_file() {
+        return std::fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+    }
+    WalkDir::new(path)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| e.metadata().ok())
+        .map(|m| m.len())
+        .sum()
+}
+
 // ===== 복사 =====
 
 // 파일/폴더 복사 (재귀 지원, overwrite=true면 기존 파일 덮어쓰기)
+// 실제로 복사한 (원본, 사본) 경로 쌍을 반환한다.
 pub(super) async fn copy_items_impl(
     sources: Vec<String>,
     dest: String,
     overwrite: Option<bool>,
     app_cache: Option<&std::path::Path>,
-) -> Result<()> {
+) -> Result<Vec<(String, String)>> {
     let overwrite = overwrite.unwrap_or(false);
+    let mut copied = Vec::new();
     for source in &sources {
         let src_path = std::path::Path::new(source);
         let file_name = src_path
@@ -178,13 +258,10 @@ pub(super) async fn copy_items_impl(
             continue;
         }
 
-        if src_path.is_dir() {
-            copy_dir_recursive(src_path, &dest_path)?;
-        } else {
-            std::fs::copy(src_path, &dest_path)?;
-        }
+        copy_entry(src_path, &dest_path, SymlinkPolicy::default())?;
+        copied.push((source.clone(), dest_path.to_string_lossy().to_string()));
     }
-    Ok(())
+    Ok(copied)
 }
 
 // 파일/폴더 복사 (재귀 지원, overwrite=true면 기존 파일 덮어쓰기)
@@ -196,31 +273,61 @@ pub async fn copy_items(
     overwrite: Option<bool>,
 ) -> Result<()> {
     let app_cache = thumbnail_cache_root(&app)?;
-    copy_items_impl(sources, dest, overwrite, Some(&app_cache)).await
+    let items = copy_items_impl(sources, dest, overwrite, Some(&app_cache)).await?;
+    record_operation(&app, FileOperation::Copy { items });
+    Ok(())
 }
 
-/// 파일 단위 진행률(0~100%)을 Channel로 전송하는 복사 (클라우드 드라이브 등 대용량 복사용)
+/// 진행률을 Channel로 전송하는 복사 (클라우드 드라이브 등 대용량 복사용)
+/// 퍼센트는 복사한 바이트 기준(합계를 모르면 파일 수 기준)이고, 완료 파일 수와 바이트 수를 함께 보낸다.
+/// 큰 파일도 청크마다(200ms 간격) 갱신된다. 처리 속도·남은 시간은 `transfer_items_with_progress`가 보낸다.
+///
+/// `options.verify`면 파일마다 원본·사본을 xxh3로 비교하고, 불일치 시 한 번 다시 복사한다.
+/// 충돌은 `options.conflict`(생략 시 `overwrite`에 따라 덮어쓰기/건너뛰기)로 항목마다 처리한다.
 #[tauri::command]
 pub async fn copy_items_with_progress(
     app: tauri::AppHandle,
     sources: Vec<String>,
     dest: String,
     overwrite: Option<bool>,
+    options: Option<TransferOptions>,
     on_progress: tauri::ipc::Channel<CopyProgress>,
 ) -> Result<()> {
     let overwrite = overwrite.unwrap_or(false);
+    let options = options.unwrap_or_default();
     let dest_path = std::path::PathBuf::from(dest);
     let app_cache = thumbnail_cache_root(&app)?;
     let on_progress = on_progress.clone();
 
-    tauri::async_runtime::spawn_blocking(move || {
-        progress::run_copy_with_progress(sources, dest_path, overwrite, app_cache, on_progress)
+    let created = tauri::async_runtime::spawn_blocking(move || {
+        progress::run_copy_with_progress(
+            sources,
+            dest_path,
+            overwrite,
+            app_cache,
+            options,
+            on_progress,
+        )
     })
     .await
-    .map_err(|e| AppError::Internal(format!("복사 작업 실패: {}", e)))?
+    .map_err(|e| AppError::Internal(format!("복사 작업 실패: {}", e)))??;
+    record_operation(
+        &app,
+        FileOperation::Copy {
+            items: path_pairs(&created),
+        },
+    );
+    Ok(())
 }
 
 /// 작업 큐 패널용 복사/이동 (파일별 진행률 + 전체 카운트)
+///
+/// `options.job_id`를 생략하면 새로 발급하며, 모든 진행률 메시지에 실려 `cancel_transfer`에 쓸 수 있다.
+/// 완료 시 작업 ID를 반환하고, 취소되면 `cancelled` 단계 전송 후 `AppError::Cancelled`를 반환한다.
+/// `options.verify`면 복사 단계마다 xxh3 검증(불일치 시 1회 재복사)하며, cross-volume 이동의
+/// 원본은 모든 파일이 검증된 뒤에만 삭제된다.
+/// 충돌은 `options.conflict`(생략 시 `overwrite`에 따라 덮어쓰기/건너뛰기)로 항목마다 처리하며,
+/// 건너뛴 파일은 이동 후에도 원본 위치에 남는다.
 #[tauri::command]
 pub async fn transfer_items_with_progress(
     app: tauri::AppHandle,
@@ -228,38 +335,162 @@ pub async fn transfer_items_with_progress(
     sources: Vec<String>,
     dest: String,
     overwrite: Option<bool>,
+    options: Option<TransferOptions>,
     on_progress: tauri::ipc::Channel<TransferQueueProgress>,
-) -> Result<()> {
+) -> Result<String> {
     let overwrite = overwrite.unwrap_or(false);
+    let options = options.unwrap_or_default();
     let dest_path = std::path::PathBuf::from(dest);
     let app_cache = thumbnail_cache_root(&app)?;
     let on_progress = on_progress.clone();
-    let op = operation.clone();
+    let job_id = options
+        .job_id
+        .clone()
+        .unwrap_or_else(|| new_job_id("transfer"));
+    let job = register_job(job_id)?;
 
-    tauri::async_runtime::spawn_blocking(move || {
-        progress::run_transfer_with_queue(
-            &op,
+    let (job_id, operation, created) = tauri::async_runtime::spawn_blocking(move || {
+        let reporter = progress::QueueReporter {
+            channel: &on_progress,
+            operation: &operation,
+            job_id: job.id(),
+        };
+        let created = progress::run_transfer_with_queue(
             &sources,
             &dest_path,
             overwrite,
             Some(&app_cache),
-            &on_progress,
+            &reporter,
+            job.token(),
+            &options,
+        )?;
+        Ok::<_, AppError>((job.id().to_string(), operation, created))
+    })
+    .await
+    .map_err(|e| AppError::Internal(format!("전송 작업 실패: {}", e)))??;
+    record_transfer(&app, &operation, &created);
+    Ok(job_id)
+}
+
+/// 완료된 전송을 작업 기록에 추가 (`created`: 전송 계획의 (원본, 결과) 쌍)
+fn record_transfer(
+    app: &tauri::AppHandle,
+    operation: &str,
+    created: &[(std::path::PathBuf, std::path::PathBuf)],
+) {
+    let items = path_pairs(created);
+    let operation = if operation == "move" {
+        FileOperation::Move { items }
+    } else {
+        FileOperation::Copy { items }
+    };
+    record_operation(app, operation);
+}
+
+/// 전송 전 충돌 미리보기 (디스크 변경 없음) — 양쪽 크기·수정 시각 포함.
+/// 폴더끼리 겹치면 폴더 안에서 겹치는 파일을 하나씩 돌려준다 (`overwrite` 정책은 폴더째 교체).
+#[tauri::command]
+pub async fn check_transfer_conflicts(
+    sources: Vec<String>,
+    dest: String,
+    symlinks: Option<SymlinkPolicy>,
+) -> Result<Vec<TransferConflict>> {
+    tauri::async_runtime::spawn_blocking(move || {
+        conflict::find_conflicts(
+            &sources,
+            std::path::Path::new(&dest),
+            symlinks.unwrap_or_default(),
         )
     })
     .await
-    .map_err(|e| AppError::Internal(format!("전송 작업 실패: {}", e)))?
+    .map_err(|e| AppError::Internal(format!("전송 충돌 확인 실패: {}", e)))?
+}
+
+/// 진행 중인 전송 작업 취소 요청 (이미 끝난 작업이면 false)
+#[tauri::command]
+pub async fn cancel_transfer(job_id: String) -> Result<bool> {
+    Ok(cancel_job(&job_id))
+}
+
+/// 진행 중인 전송 일시정지/재개 (이미 끝난 작업이면 false)
+///
+/// 일시정지는 현재 청크에서 멈추며, 그 상태로 앱이 종료되면 저널이 남아 다음 실행에서 재개할 수 있다.
+#[tauri::command]
+pub async fn set_transfer_paused(job_id: String, paused: bool) -> Result<bool> {
+    Ok(pause_job(&job_id, paused))
+}
+
+/// 앱 종료·오류로 중단되어 이어서 진행할 수 있는 전송 목록
+#[tauri::command]
+pub async fn list_pending_transfers(app: tauri::AppHandle) -> Result<Vec<PendingTransfer>> {
+    let app_cache = thumbnail_cache_root(&app)?;
+    tauri::async_runtime::spawn_blocking(move || journal::list_pending(&app_cache))
+        .await
+        .map_err(|e| AppError::Internal(format!("전송 저널 조회 실패: {}", e)))
+}
+
+/// 저널에 남은 전송을 첫 미완료 단계부터 이어서 진행 (진행률 형식은 `transfer_items_with_progress`와 동일)
+#[tauri::command]
+pub async fn resume_transfer(
+    app: tauri::AppHandle,
+    job_id: String,
+    on_progress: tauri::ipc::Channel<TransferQueueProgress>,
+) -> Result<String> {
+    let app_cache = thumbnail_cache_root(&app)?;
+    let on_progress = on_progress.clone();
+    let job = register_job(job_id)?;
+
+    let (job_id, journal) = tauri::async_runtime::spawn_blocking(move || {
+        let (file, journal, start) = journal::load_journal(&app_cache, job.id())?;
+        let reporter = progress::QueueReporter {
+            channel: &on_progress,
+            operation: &journal.operation,
+            job_id: job.id(),
+        };
+        progress::execute_transfer_steps(
+            &journal.plan,
+            start,
+            Some(&app_cache),
+            &reporter,
+            job.token(),
+            Some(&file),
+            &journal.options,
+        )?;
+        Ok::<_, AppError>((job.id().to_string(), journal))
+    })
+    .await
+    .map_err(|e| AppError::Internal(format!("전송 재개 실패: {}", e)))??;
+    record_transfer(&app, &journal.operation, &journal.plan.created);
+    Ok(job_id)
+}
+
+/// 재개하지 않을 전송 저널 삭제 (이미 복사된 파일은 그대로 둔다)
+#[tauri::command]
+pub async fn discard_pending_transfer(app: tauri::AppHandle, job_id: String) -> Result<()> {
+    if is_job_running(&job_id) {
+        return Err(AppError::InvalidInput(format!(
+            "실행 중인 전송은 삭제할 수 없습니다: {}",
+            job_id
+        )));
+    }
+    let app_cache = thumbnail_cache_root(&app)?;
+    tauri::async_runtime::spawn_blocking(move || journal::discard_journal(&app_cache, &job_id))
+        .await
+        .map_err(|e| AppError::Internal(format!("전송 저널 삭제 실패: {}", e)))
 }
 
 // ===== 이동 =====
 
 // 파일/폴더 이동 (overwrite=true면 기존 파일 덮어쓰기)
+// 실제로 옮긴 (원래 경로, 새 경로) 쌍을 반환한다.
 pub(super) async fn move_items_impl(
     sources: Vec<String>,
     dest: String,
     overwrite: Option<bool>,
     app_cache: Option<&std::path::Path>,
-) -> Result<()> {
+) -> Result<Vec<(String, String)>> {
     let overwrite = overwrite.unwrap_or(false);
+    let mut moved = Vec::new();
     for source in &sources {
         let src_path = std::path::Path::new(source);
         let file_name = src_path
@@ -291,17 +522,22 @@ pub(super) async fn move_items_impl(
         }
 
         // 같은 볼륨이면 rename, 다른 볼륨이면 복사 후 삭제
+        // (링크는 링크 자체를 옮긴다)
         if std::fs::rename(src_path, &dest_path).is_err() {
-            if src_path.is_dir() {
-                copy_dir_recursive(src_path, &dest_path)?;
+            copy_entry(src_path, &dest_path, SymlinkPolicy::CopyLink)?;
+            if is_symlink(src_path) {
+                remove_symlink(src_path)?;
+            } else if src_path.is_dir() {
                 std::fs::remove_dir_all(src_path)?;
             } else {
-                std::fs::copy(src_path, &dest_path)?;
                 std::fs::remove_file(src_path)?;
             }
         }
+        if dest_path != src_path {
+            moved.push((source.clone(), dest_path.to_string_lossy().to_string()));
+        }
     }
-    Ok(())
+    Ok(moved)
 }
 
 // 파일/폴더 이동 (overwrite=true면 기존 파일 덮어쓰기)
@@ -313,5 +549,7 @@ pub async fn move_items(
     overwrite: Option<bool>,
 ) -> Result<()> {
     let app_cache = thumbnail_cache_root(&app)?;
-    move_items_impl(sources, dest, overwrite, Some(&app_cache)).await
+    let items = move_items_impl(sources, dest, overwrite, Some(&app_cache)).await?;
+    record_operation(&app, FileOperation::Move { items });
+    Ok(())
 }
@@ -0,0 +1,390 @@
+// ===== 전송 충돌 처리 =====
+// 대상에 같은 이름이 있을 때의 처리를 최상위 항목과 폴더 안 파일마다 같은 정책으로 결정한다.
+// 폴더끼리 겹치면 폴더는 합치고(`Overwrite`는 대상 폴더를 통째로 교체), 그 안에서 겹치는 파일마다 정책을 적용한다.
+
+use crate::helpers::get_numbered_destination;
+use crate::modules::error::{AppError, Result};
+use crate::modules::file_ops::symlink::{is_symlink, remove_symlink, SymlinkPolicy};
+use crate::modules::image_ops::invalidate_thumbnail_cache_paths_in_root;
+
+/// 대상에 같은 이름이 있을 때의 처리 방식
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictPolicy {
+    /// 번호를 붙인 새 이름으로 저장 (`이름 (1).ext`)
+    Rename,
+    /// 원본이 더 최신일 때만 덮어씀
+    OverwriteNewer,
+    Skip,
+    /// 같은 이름 항목을 통째로 교체 (폴더도 기존 폴더를 지우고 새로 씀)
+    Overwrite,
+    /// 폴더는 합치고, 폴더 안에서 겹치는 파일은 덮어씀
+    Merge,
+}
+
+/// 폴더 병합 충돌 처리 (전송 충돌 정책과 동일)
+pub type FolderMergeConflictMode = ConflictPolicy;
+
+impl ConflictPolicy {
+    /// 기존 `overwrite` 인자 호환 (true=덮어쓰기, false=건너뛰기)
+    pub(super) fn from_overwrite(overwrite: bool) -> Self {
+        if overwrite {
+            Self::Overwrite
+        } else {
+            Self::Skip
+        }
+    }
+}
+
+/// 전송 전 미리보기용 충돌 항목
+#[derive(serde::Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferConflict {
+    pub source_path: String,
+    pub dest_path: String,
+    /// 대상 폴더 기준 상대 경로 (`/` 구분)
+    pub relative_path: String,
+    pub source_is_dir: bool,
+    pub dest_is_dir: bool,
+    /// 폴더는 0
+    pub source_size: u64,
+    pub dest_size: u64,
+    pub source_modified: u64,
+    pub dest_modified: u64,
+}
+
+pub(super) fn modified_ms(path: &std::path::Path) -> u64 {
+    path.metadata()
+        .ok()
+        .and_then(|m| m.modified().ok())
+        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+fn exists_no_follow(path: &std::path::Path) -> bool {
+    std::fs::symlink_metadata(path).is_ok()
+}
+
+/// 링크는 `Follow`일 때만 가리키는 폴더로 취급
+pub(super) fn is_dir_with(path: &std::path::Path, symlinks: SymlinkPolicy) -> bool {
+    if is_symlink(path) {
+        symlinks == SymlinkPolicy::Follow && path.is_dir()
+    } else {
+        path.is_dir()
+    }
+}
+
+/// 소스 폴더를 이미 있는 대상 폴더에 합쳐야 하는지
+pub(super) fn is_merge_target(
+    src: &std::path::Path,
+    dest: &std::path::Path,
+    symlinks: SymlinkPolicy,
+) -> bool {
+    is_dir_with(src, symlinks) && std::fs::symlink_metadata(dest).is_ok_and(|m| m.is_dir())
+}
+
+/// 충돌 시 실제로 쓸 대상 경로 (None이면 건너뜀). 대상이 없으면 그대로 반환한다.
+pub(super) fn resolve_dest(
+    src: &std::path::Path,
+    dest: &std::path::Path,
+    policy: ConflictPolicy,
+) -> Option<std::path::PathBuf> {
+    if !exists_no_follow(dest) {
+        return Some(dest.to_path_buf());
+    }
+    match policy {
+        ConflictPolicy::Skip => None,
+        ConflictPolicy::Overwrite | ConflictPolicy::Merge => Some(dest.to_path_buf()),
+        ConflictPolicy::OverwriteNewer => {
+            (modified_ms(src) > modified_ms(dest)).then(|| dest.to_path_buf())
+        }
+        ConflictPolicy::Rename => {
+            let stem = src
+                .file_stem()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .to_string();
+            let ext = src
+                .extension()
+                .map(|e| format!(".{}", e.to_string_lossy()))
+                .unwrap_or_default();
+            let parent = dest.parent()?;
+            Some(get_numbered_destination(parent, &stem, &ext, src.is_dir()))
+        }
+    }
+}
+
+/// 폴더 병합 중 하위 항목의 대상 경로 (None이면 건너뜀).
+/// 폴더끼리는 합치고, 폴더↔파일 타입이 다르면 건너뛴다.
+pub(super) fn resolve_nested(
+    src: &std::path::Path,
+    src_is_dir: bool,
+    dest: &std::path::Path,
+    policy: ConflictPolicy,
+) -> Option<std::path::PathBuf> {
+    let Ok(meta) = std::fs::symlink_metadata(dest) else {
+        return Some(dest.to_path_buf());
+    };
+    if meta.is_dir() != src_is_dir {
+        log::warn!("폴더·파일 타입이 달라 건너뜀: {}", dest.display());
+        return None;
+    }
+    if src_is_dir {
+        return Some(dest.to_path_buf());
+    }
+    resolve_dest(src, dest, policy)
+}
+
+/// 덮어쓸 기존 항목 삭제 (링크는 링크만)
+fn remove_existing(path: &std::path::Path) -> Result<()> {
+    if is_symlink(path) {
+        remove_symlink(path)
+    } else if path.is_dir() {
+        Ok(std::fs::remove_dir_all(path)?)
+    } else {
+        Ok(std::fs::remove_file(path)?)
+    }
+}
+
+/// 최상위 항목의 대상 경로 결정 (None이면 건너뜀). 통째로 덮어쓸 기존 항목은 여기서 지운다.
+/// 폴더끼리 겹치면 `Overwrite`가 아닌 한 대상 폴더를 그대로 두고, 하위 파일마다 정책을 적용하도록 맡긴다.
+pub(super) fn prepare_top_level_dest(
+    src: &std::path::Path,
+    dest: &std::path::Path,
+    policy: ConflictPolicy,
+    symlinks: SymlinkPolicy,
+    app_cache: Option<&std::path::Path>,
+) -> Result<Option<std::path::PathBuf>> {
+    if !exists_no_follow(dest)
+        || (policy != ConflictPolicy::Overwrite && is_merge_target(src, dest, symlinks))
+    {
+        return Ok(Some(dest.to_path_buf()));
+    }
+    let Some(target) = resolve_dest(src, dest, policy) else {
+        return Ok(None);
+    };
+    if target == dest {
+        if let Some(app_cache) = app_cache {
+            invalidate_thumbnail_cache_paths_in_root(
+                app_cache,
+                &[dest.to_string_lossy().to_string()],
+            );
+        }
+        remove_existing(dest)?;
+    }
+    Ok(Some(target))
+}
+
+fn conflict_entry(
+    src: &std::path::Path,
+    dest: &std::path::Path,
+    relative_path: String,
+) -> TransferConflict {
+    let src_is_dir = src.is_dir();
+    let dest_is_dir = dest.is_dir();
+    let size = |path: &std::path::Path, is_dir: bool| {
+        if is_dir {
+            0
+        } else {
+            std::fs::metadata(path).map(|m| m.len()).unwrap_or(0)
+        }
+    };
+    TransferConflict {
+        source_path: src.to_string_lossy().to_string(),
+        dest_path: dest.to_string_lossy().to_string(),
+        relative_path,
+        source_is_dir: src_is_dir,
+        dest_is_dir,
+        source_size: size(src, src_is_dir),
+        dest_size: size(dest, dest_is_dir),
+        source_modified: modified_ms(src),
+        dest_modified: modified_ms(dest),
+    }
+}
+
+/// 전송 시 겹치는 항목 수집 (디스크 변경 없음).
+/// 같은 위치로의 전송은 충돌로 보지 않는다 (복사는 "(복사)" 이름, 이동은 무시).
+pub(super) fn find_conflicts(
+    sources: &[String],
+    dest: &std::path::Path,
+    symlinks: SymlinkPolicy,
+) -> Result<Vec<TransferConflict>> {
+    use walkdir::WalkDir;
+    let mut conflicts = Vec::new();
+    for source in sources {
+        let src_path = std::path::Path::new(source);
+        if symlinks == SymlinkPolicy::Skip && is_symlink(src_path) {
+            continue;
+        }
+        let file_name = src_path
+            .file_name()
+            .ok_or_else(|| AppError::InvalidInput(format!("잘못된 경로: {}", source)))?;
+        let dest_path = dest.join(file_name);
+        if !exists_no_follow(&dest_path)
+            || dest_path.canonicalize().ok() == src_path.canonicalize().ok()
+        {
+            continue;
+        }
+        let top_name = file_name.to_string_lossy().to_string();
+        if !is_merge_target(src_path, &dest_path, symlinks) {
+            conflicts.push(conflict_entry(src_path, &dest_path, top_name));
+            continue;
+        }
+
+        let walker = WalkDir::new(src_path)
+            .min_depth(1)
+            .follow_links(symlinks == SymlinkPolicy::Follow);
+        for entry in walker.into_iter().filter_map(|e| e.ok()) {
+            if entry.path_is_symlink() && symlinks == SymlinkPolicy::Skip {
+                continue;
+            }
+            let rel = entry.path().strip_prefix(src_path).unwrap_or(entry.path());
+            let target = dest_path.join(rel);
+            let Ok(meta) = std::fs::symlink_metadata(&target) else {
+                continue;
+            };
+            // 폴더끼리는 합쳐지므로 충돌이 아님
+            if meta.is_dir() && entry.file_type().is_dir() {
+                continue;
+            }
+            let relative_path =
+                format!("{}/{}", top_name, rel.to_string_lossy().replace('\\', "/"));
+            conflicts.push(conflict_entry(entry.path(), &target, relative_path));
+        }
+    }
+    conflicts.sort_by(|a, b| a.relative_path.cmp(&b.relative_path));
+    Ok(conflicts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::progress::{run_transfer_with_queue, QueueReporter, TransferOptions};
+    use super::*;
+    use crate::modules::jobs::CancelToken;
+
+    #[test]
+    fn move_merge_applies_policy_per_nested_file() {
+        let root = std::env::temp_dir().join("file_ops_test_transfer_conflict");
+        let _ = std::fs::remove_dir_all(&root);
+        let (src, dest) = (root.join("src").join("Project"), root.join("dest"));
+        std::fs::create_dir_all(src.join("sub")).unwrap();
+        std::fs::create_dir_all(dest.join("Project").join("sub")).unwrap();
+        std::fs::write(src.join("new.txt"), "new").unwrap();
+        std::fs::write(src.join("sub").join("same.txt"), "source").unwrap();
+        std::fs::write(dest.join("Project").join("sub").join("same.txt"), "dest").unwrap();
+        let sources = vec![src.to_string_lossy().to_string()];
+
+        let conflicts = find_conflicts(&sources, &dest, SymlinkPolicy::default()).unwrap();
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].relative_path, "Project/sub/same.txt");
+        assert_eq!((conflicts[0].source_size, conflicts[0].dest_size), (6, 4));
+
+        let channel = tauri::ipc::Channel::new(|_| Ok(()));
+        let reporter = QueueReporter {
+            channel: &channel,
+            operation: "move",
+            job_id: "conflict-test",
+        };
+        let options = TransferOptions {
+            conflict: Some(ConflictPolicy::Skip),
+            ..Default::default()
+        };
+        run_transfer_with_queue(
+            &sources,
+            &dest,
+            false,
+            None,
+            &reporter,
+            &CancelToken::new(),
+            &options,
+        )
+        .unwrap();
+
+        let merged = dest.join("Project");
+        assert_eq!(
+            std::fs::read_to_string(merged.join("new.txt")).unwrap(),
+            "new"
+        );
+        assert_eq!(
+            std::fs::read_to_string(merged.join("sub").join("same.txt")).unwrap(),
+            "dest"
+        );
+        // 건너뛴 파일은 원본에 남고, 옮긴 파일만 사라진다
+        assert!(!src.join("new.txt").exists());
+        assert_eq!(
+            std::fs::read_to_string(src.join("sub").join("same.txt")).unwrap(),
+            "source"
+        );
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn overwrite_replaces_folder_and_merge_keeps_it() {
+        let root = std::env::temp_dir().join("file_ops_test_transfer_conflict_overwrite");
+        let _ = std::fs::remove_dir_all(&root);
+        let (src, dest) = (
+            root.join("src").join("Project"),
+            root.join("dest").join("Project"),
+        );
+        std::fs::create_dir_all(&src).unwrap();
+        std::fs::create_dir_all(&dest).unwrap();
+        std::fs::write(dest.join("old.txt"), "old").unwrap();
+
+        let merged = prepare_top_level_dest(
+            &src,
+            &dest,
+            ConflictPolicy::Merge,
+            SymlinkPolicy::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(merged, Some(dest.clone()));
+        assert!(dest.join("old.txt").exists());
+
+        let replaced = prepare_top_level_dest(
+            &src,
+            &dest,
+            ConflictPolicy::Overwrite,
+            SymlinkPolicy::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(replaced, Some(dest.clone()));
+        assert!(!dest.exists());
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn rename_policy_numbers_conflicting_file() {
+        let root = std::env::temp_dir().join("file_ops_test_transfer_conflict_rename");
+        let _ = std::fs::remove_dir_all(&root);
+        let dest = root.join("dest");
+        std::fs::create_dir_all(&dest).unwrap();
+        std::fs::write(root.join("a.txt"), "source").unwrap();
+        std::fs::write(dest.join("a.txt"), "dest").unwrap();
+
+        let target = prepare_top_level_dest(
+            &root.join("a.txt"),
+            &dest.join("a.txt"),
+            ConflictPolicy::Rename,
+            SymlinkPolicy::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(target, Some(dest.join("a (1).txt")));
+        assert!(prepare_top_level_dest(
+            &root.join("a.txt"),
+            &dest.join("a.txt"),
+            ConflictPolicy::Skip,
+            SymlinkPolicy::default(),
+            None,
+        )
+        .unwrap()
+        .is_none());
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+}
@@ -1,7 +1,9 @@
 use crate::helpers::get_copy_destination;
 use crate::modules::error::{AppError, Result};
+use crate::modules::file_ops::history::{record_operation, FileOperation};
+use crate::modules::file_ops::symlink::SymlinkPolicy;
 
-use super::copy_dir_recursive;
+use super::copy_entry;
 
 // 대상 디렉토리에서 중복되는 파일명 확인
 #[tauri::command]
@@ -22,8 +24,9 @@ pub async fn check_duplicate_items(sources: Vec<String>, dest: String) -> Result
 }
 
 // 파일/폴더 복제 (같은 디렉토리에 " (복사)" 접미사)
-#[tauri::command]
-pub async fn duplicate_items(paths: Vec<String>) -> Result<Vec<String>> {
+pub(in crate::modules::file_ops) async fn duplicate_items_impl(
+    paths: Vec<String>,
+) -> Result<Vec<String>> {
     let mut new_paths = vec![];
     for source in &paths {
         let src = std::path::Path::new(source);
@@ -44,12 +47,16 @@ pub async fn duplicate_items(paths: Vec<String>) -> Result<Vec<String>> {
         // 충돌 방지: " (복사)", " (복사 2)", " (복사 3)" ...
         let dest_path = get_copy_destination(parent, &stem, &ext, is_dir);
 
-        if is_dir {
-            copy_dir_recursive(src, &dest_path)?;
-        } else {
-            std::fs::copy(src, &dest_path)?;
-        }
+        copy_entry(src, &dest_path, SymlinkPolicy::default())?;
         new_paths.push(dest_path.to_string_lossy().to_string());
     }
     Ok(new_paths)
 }
+
+#[tauri::command]
+pub async fn duplicate_items(app: tauri::AppHandle, paths: Vec<String>) -> Result<Vec<String>> {
+    let new_paths = duplicate_items_impl(paths.clone()).await?;
+    let items = paths.into_iter().zip(new_paths.iter().cloned()).collect();
+    record_operation(&app, FileOperation::Copy { items });
+    Ok(new_paths)
+}
@@ -1,8 +1,11 @@
-use crate::helpers::get_numbered_destination;
 use crate::modules::error::{AppError, Result};
+use crate::modules::file_ops::history::{path_pairs, record_operation, FileOperation};
+use crate::modules::file_ops::symlink::{copy_symlink, LoopGuard, SymlinkPolicy};
 use crate::modules::image_ops::{invalidate_thumbnail_cache_paths_in_root, thumbnail_cache_root};
 
+use super::conflict::{modified_ms, resolve_dest, FolderMergeConflictMode};
 use super::copy_dir_recursive;
+use super::metadata::copy_file_preserving;
 
 #[derive(serde::Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -25,23 +28,6 @@ pub struct FolderMergeAnalysis {
     pub only_dest: Vec<String>,
 }
 
-#[derive(serde::Deserialize, Clone, Copy)]
-#[serde(rename_all = "snake_case")]
-pub enum FolderMergeConflictMode {
-    Rename,
-    OverwriteNewer,
-    Skip,
-}
-
-fn file_modified_ms(path: &std::path::Path) -> u64 {
-    path.metadata()
-        .ok()
-        .and_then(|m| m.modified().ok())
-        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
-        .map(|d| d.as_millis() as u64)
-        .unwrap_or(0)
-}
-
 /// 디렉토리 내 모든 파일의 상대 경로·메타 수집 (키: `/` 구분 상대 경로)
 fn collect_relative_files(
     dir: &std::path::Path,
@@ -58,7 +44,7 @@ fn collect_relative_files(
                 .map_err(|_| AppError::Internal("상대 경로 계산 실패".into()))?;
             let key = rel.to_string_lossy().replace('\\', "/");
             let meta = path.metadata()?;
-            out.insert(key, (meta.len(), file_modified_ms(&path)));
+            out.insert(key, (meta.len(), modified_ms(&path)));
         }
     }
     Ok(())
@@ -141,78 +127,104 @@ pub async fn analyze_folder_merge(
     .map_err(|e| AppError::Internal(format!("폴더 병합 분석 실패: {}", e)))?
 }
 
+/// 충돌 정책대로 파일 하나 복사. 새로 만든 경로를 반환한다 (덮어쓰기·건너뜀은 None).
 fn merge_copy_file(
     src: &std::path::Path,
     dest: &std::path::Path,
     mode: FolderMergeConflictMode,
     app_cache: Option<&std::path::Path>,
-) -> Result<()> {
-    if dest.exists() {
-        match mode {
-            FolderMergeConflictMode::Skip => return Ok(()),
-            FolderMergeConflictMode::OverwriteNewer => {
-                if file_modified_ms(src) <= file_modified_ms(dest) {
-                    return Ok(());
-                }
-                if let Some(app_cache) = app_cache {
-                    invalidate_thumbnail_cache_paths_in_root(
-                        app_cache,
-                        &[dest.to_string_lossy().to_string()],
-                    );
-                }
-                std::fs::copy(src, dest)?;
-            }
-            FolderMergeConflictMode::Rename => {
-                let stem = src
-                    .file_stem()
-                    .unwrap_or_default()
-                    .to_string_lossy()
-                    .to_string();
-                let ext = src
-                    .extension()
-                    .map(|e| format!(".{}", e.to_string_lossy()))
-                    .unwrap_or_default();
-                let parent = dest
-                    .parent()
-                    .ok_or_else(|| AppError::Internal("대상 파일의 부모 경로 없음".into()))?;
-                let numbered = get_numbered_destination(parent, &stem, &ext, false);
-                std::fs::copy(src, &numbered)?;
-            }
-        }
-    } else {
-        if let Some(parent) = dest.parent() {
-            std::fs::create_dir_all(parent)?;
+) -> Result<Option<std::path::PathBuf>> {
+    let Some(target) = resolve_dest(src, dest, mode) else {
+        return Ok(None);
+    };
+    let existed = target.exists();
+    if existed {
+        if let Some(app_cache) = app_cache {
+            invalidate_thumbnail_cache_paths_in_root(
+                app_cache,
+                &[target.to_string_lossy().to_string()],
+            );
         }
-        std::fs::copy(src, dest)?;
+    } else if let Some(parent) = target.parent() {
+        std::fs::create_dir_all(parent)?;
     }
-    Ok(())
+    copy_file_preserving(src, &target)?;
+    Ok((!existed).then_some(target))
 }
 
+/// 폴더 병합. 새로 생긴 (원본, 대상) 항목 목록을 반환한다 (작업 기록용).
 pub(in crate::modules::file_ops) fn merge_folders_recursive(
     source: &std::path::Path,
     dest: &std::path::Path,
     mode: FolderMergeConflictMode,
     app_cache: Option<&std::path::Path>,
-) -> Result<()> {
-    std::fs::create_dir_all(dest)?;
-    for entry in std::fs::read_dir(source)?.flatten() {
-        let src_child = entry.path();
-        let dest_child = dest.join(entry.file_name());
-        if src_child.is_dir() {
-            if dest_child.exists() && dest_child.is_dir() {
-                merge_folders_recursive(&src_child, &dest_child, mode, app_cache)?;
-            } else if !dest_child.exists() {
-                copy_dir_recursive(&src_child, &dest_child)?;
+    symlinks: SymlinkPolicy,
+) -> Result<Vec<(std::path::PathBuf, std::path::PathBuf)>> {
+    let mut merge = MergeRun {
+        mode,
+        app_cache,
+        symlinks,
+        guard: LoopGuard::default(),
+        created: Vec::new(),
+    };
+    merge.merge_dir(source, dest)?;
+    Ok(merge.created)
+}
+
+struct MergeRun<'a> {
+    mode: FolderMergeConflictMode,
+    app_cache: Option<&'a std::path::Path>,
+    symlinks: SymlinkPolicy,
+    guard: LoopGuard,
+    created: Vec<(std::path::PathBuf, std::path::PathBuf)>,
+}
+
+impl MergeRun<'_> {
+    fn merge_dir(&mut self, source: &std::path::Path, dest: &std::path::Path) -> Result<()> {
+        if !self.guard.enter(source) {
+            return Ok(());
+        }
+        std::fs::create_dir_all(dest)?;
+        for entry in std::fs::read_dir(source)?.flatten() {
+            let src_child = entry.path();
+            let dest_child = dest.join(entry.file_name());
+            if entry.file_type().is_ok_and(|t| t.is_symlink()) {
+                match self.symlinks {
+                    // 대상에 같은 이름이 있으면 링크는 건너뜀 (병합 대상 덮어쓰기 방지)
+                    SymlinkPolicy::CopyLink => {
+                        if std::fs::symlink_metadata(&dest_child).is_err() {
+                            copy_symlink(&src_child, &dest_child)?;
+                            self.created.push((src_child, dest_child));
+                        }
+                        continue;
+                    }
+                    SymlinkPolicy::Skip => continue,
+                    SymlinkPolicy::Follow => {}
+                }
+            }
+            if src_child.is_dir() {
+                if dest_child.exists() && dest_child.is_dir() {
+                    self.merge_dir(&src_child, &dest_child)?;
+                } else if !dest_child.exists() {
+                    copy_dir_recursive(&src_child, &dest_child, self.symlinks)?;
+                    self.created.push((src_child, dest_child));
+                }
+                // 파일·폴더 타입 불일치 시 소스 하위는 건너뜀
+            } else if src_child.is_file() {
+                if let Some(target) =
+                    merge_copy_file(&src_child, &dest_child, self.mode, self.app_cache)?
+                {
+                    self.created.push((src_child, target));
+                }
             }
-            // 파일·폴더 타입 불일치 시 소스 하위는 건너뜀
-        } else {
-            merge_copy_file(&src_child, &dest_child, mode, app_cache)?;
         }
+        self.guard.leave();
+        Ok(())
     }
-    Ok(())
 }
 
 /// 스마트 폴더 병합 실행 (is_move=true면 병합 후 소스 폴더 삭제)
+/// 작업 기록에는 새로 생긴 항목만 남으며, 덮어쓴 파일과 이동 시 남은 소스 파일은 되돌릴 수 없다.
 #[tauri::command]
 pub async fn merge_folders(
     app: tauri::AppHandle,
@@ -220,9 +232,10 @@ pub async fn merge_folders(
     dest_parent: String,
     conflict_mode: FolderMergeConflictMode,
     is_move: bool,
+    symlinks: Option<SymlinkPolicy>,
 ) -> Result<()> {
     let app_cache = thumbnail_cache_root(&app)?;
-    tauri::async_runtime::spawn_blocking(move || {
+    let created = tauri::async_runtime::spawn_blocking(move || {
         let src_path = std::path::Path::new(&source);
         if !src_path.is_dir() {
             return Err(AppError::InvalidInput("소스가 폴더가 아닙니다".into()));
@@ -244,7 +257,13 @@ pub async fn merge_folders(
             );
         }
 
-        merge_folders_recursive(src_path, &dest_path, conflict_mode, Some(&app_cache))?;
+        let created = merge_folders_recursive(
+            src_path,
+            &dest_path,
+            conflict_mode,
+            Some(&app_cache),
+            symlinks.unwrap_or_default(),
+        )?;
 
         if is_move {
             if let Some(app_cache) = Some(&app_cache) {
@@ -253,8 +272,16 @@ pub async fn merge_folders(
             std::fs::remove_dir_all(src_path)?;
         }
 
-        Ok(())
+        Ok(created)
     })
     .await
-    .map_err(|e| AppError::Internal(format!("폴더 병합 실패: {}", e)))?
+    .map_err(|e| AppError::Internal(format!("폴더 병합 실패: {}", e)))??;
+    record_operation(
+        &app,
+        FileOperation::Merge {
+            items: path_pairs(&created),
+            moved: is_move,
+        },
+    );
+    Ok(())
 }
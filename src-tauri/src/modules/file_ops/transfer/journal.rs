@@ -0,0 +1,217 @@
+// ===== 전송 저널 (앱 재시작 후 이어서 전송) =====
+// 전송 계획을 캐시 폴더에 JSON으로 기록하고, 단계마다 완료 개수를 별도 파일에 갱신한다.
+// 정상 완료·취소 시 삭제되며, 앱 종료·오류로 남은 저널은 다음 실행에서 재개 대상이 된다.
+
+use crate::helpers::stable_cache_key;
+use crate::modules::error::{AppError, Result};
+use crate::modules::jobs::is_job_running;
+
+use super::progress::{TransferOptions, TransferPlan};
+
+const JOURNAL_DIR: &str = "transfer_journal";
+
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct TransferJournal {
+    pub(super) job_id: String,
+    pub(super) operation: String,
+    pub(super) dest: String,
+    pub(super) created_at: u64,
+    /// 재개 시에도 같은 옵션(검증 등) 유지
+    #[serde(default)]
+    pub(super) options: TransferOptions,
+    pub(super) plan: TransferPlan,
+}
+
+/// 재개 가능한 전송 요약 (`list_pending_transfers` 응답)
+#[derive(Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PendingTransfer {
+    pub job_id: String,
+    pub operation: String,
+    pub dest: String,
+    pub created_at: u64,
+    pub done_steps: u64,
+    pub total_steps: u64,
+}
+
+/// 작업 하나의 저널 파일 쌍 (계획 + 완료 개수)
+pub(super) struct JournalFile {
+    plan_path: std::path::PathBuf,
+    done_path: std::path::PathBuf,
+}
+
+fn journal_dir(app_cache: &std::path::Path) -> std::path::PathBuf {
+    app_cache.join(JOURNAL_DIR)
+}
+
+impl JournalFile {
+    // 작업 ID는 프런트에서 올 수 있으므로 파일명에는 해시만 사용
+    fn for_job(app_cache: &std::path::Path, job_id: &str) -> Self {
+        let key = stable_cache_key(&[b"transfer-journal-v1", job_id.as_bytes()]);
+        let dir = journal_dir(app_cache);
+        Self {
+            plan_path: dir.join(format!("{}.json", key)),
+            done_path: dir.join(format!("{}.done", key)),
+        }
+    }
+
+    pub(super) fn create(app_cache: &std::path::Path, journal: &TransferJournal) -> Result<Self> {
+        std::fs::create_dir_all(journal_dir(app_cache))?;
+        let file = Self::for_job(app_cache, &journal.job_id);
+        let data = serde_json::to_vec(journal)
+            .map_err(|e| AppError::Internal(format!("전송 저널 직렬화 실패: {}", e)))?;
+        std::fs::write(&file.plan_path, data)?;
+        file.record_done(0);
+        Ok(file)
+    }
+
+    /// 완료 단계 수 기록 (임시 파일 + rename으로 중간 종료 시에도 깨지지 않게)
+    pub(super) fn record_done(&self, done: u64) {
+        let tmp = self.done_path.with_extension("done.tmp");
+        if std::fs::write(&tmp, done.to_string()).is_ok() {
+            let _ = std::fs::rename(&tmp, &self.done_path);
+        }
+    }
+
+    fn read_done(&self) -> u64 {
+        std::fs::read_to_string(&self.done_path)
+            .ok()
+            .and_then(|s| s.trim().parse().ok())
+            .unwrap_or(0)
+    }
+
+    pub(super) fn remove(&self) {
+        let _ = std::fs::remove_file(&self.plan_path);
+        let _ = std::fs::remove_file(&self.done_path);
+    }
+}
+
+fn read_journal(plan_path: &std::path::Path) -> Option<TransferJournal> {
+    let data = std::fs::read(plan_path).ok()?;
+    serde_json::from_slice(&data).ok()
+}
+
+/// 재개할 저널 로드: (저널 파일, 계획, 첫 미완료 단계 인덱스)
+pub(super) fn load_journal(
+    app_cache: &std::path::Path,
+    job_id: &str,
+) -> Result<(JournalFile, TransferJournal, usize)> {
+    let file = JournalFile::for_job(app_cache, job_id);
+    let journal = read_journal(&file.plan_path)
+        .filter(|j| j.job_id == job_id)
+        .ok_or_else(|| AppError::NotFound(format!("재개할 전송이 없습니다: {}", job_id)))?;
+    let start = (file.read_done() as usize).min(journal.plan.steps.len());
+    Ok((file, journal, start))
+}
+
+/// 남아 있는 저널 목록 (현재 실행 중인 작업은 제외, 오래된 순)
+pub(super) fn list_pending(app_cache: &std::path::Path) -> Vec<PendingTransfer> {
+    let Ok(entries) = std::fs::read_dir(journal_dir(app_cache)) else {
+        return Vec::new();
+    };
+    let mut pending: Vec<PendingTransfer> = entries
+        .flatten()
+        .filter(|e| e.path().extension().is_some_and(|ext| ext == "json"))
+        .filter_map(|e| read_journal(&e.path()))
+        .filter(|j| !is_job_running(&j.job_id))
+        .map(|j| {
+            let done = JournalFile::for_job(app_cache, &j.job_id).read_done();
+            let total = j.plan.steps.len() as u64;
+            PendingTransfer {
+                job_id: j.job_id,
+                operation: j.operation,
+                dest: j.dest,
+                created_at: j.created_at,
+                done_steps: done.min(total),
+                total_steps: total,
+            }
+        })
+        .collect();
+    pending.sort_by_key(|p| p.created_at);
+    pending
+}
+
+/// 재개하지 않을 저널 삭제
+pub(super) fn discard_journal(app_cache: &std::path::Path, job_id: &str) {
+    JournalFile::for_job(app_cache, job_id).remove();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::progress::{execute_transfer_steps, QueueReporter, TransferStep};
+    use super::*;
+    use crate::modules::jobs::CancelToken;
+
+    #[test]
+    fn resume_continues_from_first_unfinished_step() {
+        let root = std::env::temp_dir().join("file_ops_test_transfer_journal");
+        let _ = std::fs::remove_dir_all(&root);
+        let (cache, src, dest) = (root.join("cache"), root.join("src"), root.join("dest"));
+        std::fs::create_dir_all(&src).unwrap();
+        std::fs::create_dir_all(&dest).unwrap();
+        std::fs::write(src.join("a.txt"), "a").unwrap();
+        std::fs::write(src.join("b.txt"), "b").unwrap();
+
+        let steps = ["a.txt", "b.txt"]
+            .iter()
+            .map(|name| TransferStep::CopyFile {
+                src: src.join(name),
+                dest: dest.join(name),
+                name: name.to_string(),
+            })
+            .collect();
+        let journal = TransferJournal {
+            job_id: "journal-test".to_string(),
+            operation: "copy".to_string(),
+            dest: dest.to_string_lossy().to_string(),
+            created_at: 1,
+            options: TransferOptions {
+                verify: true,
+                ..Default::default()
+            },
+            plan: TransferPlan {
+                steps,
+                move_cleanup_roots: Vec::new(),
+                merge_cleanup_roots: Vec::new(),
+                dirs: Vec::new(),
+                created: Vec::new(),
+            },
+        };
+        // 첫 단계까지 끝난 뒤 앱이 종료된 상황
+        JournalFile::create(&cache, &journal)
+            .unwrap()
+            .record_done(1);
+
+        let pending = list_pending(&cache);
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].job_id, "journal-test");
+        assert_eq!((pending[0].done_steps, pending[0].total_steps), (1, 2));
+
+        let (file, journal, start) = load_journal(&cache, "journal-test").unwrap();
+        assert_eq!(start, 1);
+        let channel = tauri::ipc::Channel::new(|_| Ok(()));
+        let reporter = QueueReporter {
+            channel: &channel,
+            operation: &journal.operation,
+            job_id: &journal.job_id,
+        };
+        execute_transfer_steps(
+            &journal.plan,
+            start,
+            None,
+            &reporter,
+            &CancelToken::new(),
+            Some(&file),
+            &journal.options,
+        )
+        .unwrap();
+
+        assert!(!dest.join("a.txt").exists());
+        assert_eq!(std::fs::read_to_string(dest.join("b.txt")).unwrap(), "b");
+        assert!(list_pending(&cache).is_empty());
+        assert!(load_journal(&cache, "journal-test").is_err());
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+}
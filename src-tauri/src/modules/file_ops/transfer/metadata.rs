@@ -0,0 +1,74 @@
+// ===== 메타데이터 보존 (복사·cross-volume 이동) =====
+// 수정/접근 시각, 권한 비트, (macOS) 확장 속성(Finder 태그 등)을 사본에 옮긴다.
+// 대상 파일시스템이 지원하지 않는 항목이 있어도 복사 자체는 실패시키지 않는다 (best-effort).
+
+use crate::modules::error::Result;
+
+/// 복사 전에 캡처한 원본 메타데이터 (복사 중 읽기로 atime이 바뀌기 전 값)
+pub(super) struct PreservedMetadata {
+    permissions: std::fs::Permissions,
+    atime: filetime::FileTime,
+    mtime: filetime::FileTime,
+    #[cfg(target_os = "macos")]
+    xattrs: Vec<(std::ffi::OsString, Vec<u8>)>,
+}
+
+impl PreservedMetadata {
+    pub(super) fn capture(src: &std::path::Path) -> Option<Self> {
+        let meta = std::fs::metadata(src).ok()?;
+        Some(Self {
+            permissions: meta.permissions(),
+            atime: filetime::FileTime::from_last_access_time(&meta),
+            mtime: filetime::FileTime::from_last_modification_time(&meta),
+            #[cfg(target_os = "macos")]
+            xattrs: xattr::list(src)
+                .map(|names| {
+                    names
+                        .filter_map(|name| {
+                            let value = xattr::get(src, &name).ok()??;
+                            Some((name, value))
+                        })
+                        .collect()
+                })
+                .unwrap_or_default(),
+        })
+    }
+
+    /// 확장 속성 → 권한 → 시각 순으로 적용 (시각은 마지막이어야 덮어써지지 않음)
+    pub(super) fn apply(&self, dest: &std::path::Path) {
+        #[cfg(target_os = "macos")]
+        for (name, value) in &self.xattrs {
+            if let Err(e) = xattr::set(dest, name, value) {
+                log::warn!(
+                    "확장 속성 복사 실패 ({:?}): {} — {}",
+                    name,
+                    dest.display(),
+                    e
+                );
+            }
+        }
+        if let Err(e) = std::fs::set_permissions(dest, self.permissions.clone()) {
+            log::warn!("권한 복사 실패: {} — {}", dest.display(), e);
+        }
+        if let Err(e) = filetime::set_file_times(dest, self.atime, self.mtime) {
+            log::warn!("시각 복사 실패: {} — {}", dest.display(), e);
+        }
+    }
+}
+
+/// 원본 메타데이터를 그대로 대상에 적용 (폴더는 하위 항목을 모두 만든 뒤 호출해야 함)
+pub(super) fn preserve_metadata(src: &std::path::Path, dest: &std::path::Path) {
+    if let Some(meta) = PreservedMetadata::capture(src) {
+        meta.apply(dest);
+    }
+}
+
+/// `std::fs::copy` + 메타데이터 보존
+pub(super) fn copy_file_preserving(src: &std::path::Path, dest: &std::path::Path) -> Result<u64> {
+    let meta = PreservedMetadata::capture(src);
+    let copied = std::fs::copy(src, dest)?;
+    if let Some(meta) = meta {
+        meta.apply(dest);
+    }
+    Ok(copied)
+}
@@ -1,7 +1,15 @@
 use crate::modules::error::{AppError, Result};
+use crate::modules::file_ops::symlink::{
+    copy_symlink, is_symlink, remove_symlink, LoopGuard, SymlinkPolicy,
+};
 use crate::modules::image_ops::invalidate_thumbnail_cache_paths_in_root;
+use crate::modules::jobs::CancelToken;
 
-use super::{collect_copy_jobs, collect_move_jobs, count_files_to_copy};
+use super::conflict::{is_dir_with, is_merge_target, resolve_nested, ConflictPolicy};
+use super::journal::{JournalFile, TransferJournal};
+use super::metadata::{preserve_metadata, PreservedMetadata};
+use super::verify::copy_file_verified;
+use super::{collect_copy_jobs, collect_move_jobs, count_bytes_to_copy, count_files_to_copy};
 
 #[derive(Clone, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -10,6 +18,8 @@ pub struct CopyProgress {
     pub done_files: u64,
     pub total_files: u64,
     pub current_name: String,
+    pub bytes_done: u64,
+    pub bytes_total: u64,
 }
 
 /// 작업 큐 패널용 개별 파일 항목
@@ -26,6 +36,7 @@ pub struct TransferFileItem {
 #[derive(Clone, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TransferQueueProgress {
+    pub job_id: String,
     pub phase: String,
     pub operation: String,
     pub done_files: u64,
@@ -34,10 +45,43 @@ pub struct TransferQueueProgress {
     pub percent: f32,
     pub active_id: Option<u32>,
     pub files: Option<Vec<TransferFileItem>>,
+    /// `cancelled` 단계에서만 채움: 이미 완료된 대상 경로 목록
+    pub completed: Option<Vec<String>>,
+    /// 복사 단계 기준 바이트 진행률 (같은 볼륨 rename은 제외)
+    pub bytes_done: u64,
+    pub bytes_total: u64,
+    /// 최근 처리 속도 (바이트/초, 지수 이동 평균)
+    pub bytes_per_sec: f64,
+    pub eta_secs: Option<u64>,
+    /// 현재 복사 중인 파일의 진행률
+    pub active_percent: Option<f32>,
 }
 
-/// 전송 단위: 원자적 이동(rename) 또는 파일 복사
-enum TransferStep {
+/// 전송 command 공통 옵션 (생략한 필드는 기본값)
+#[derive(Clone, Default, serde::Serialize, serde::Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct TransferOptions {
+    /// 작업 ID (생략 시 새로 발급) — 진행률 메시지에 실려 취소·일시정지에 사용
+    pub job_id: Option<String>,
+    /// 복사 후 원본·사본 xxh3 비교 (불일치 시 1회 재복사)
+    pub verify: bool,
+    /// 심볼릭 링크 처리 (기본: 링크로 복사)
+    pub symlinks: SymlinkPolicy,
+    /// 충돌 처리 (생략 시 command의 `overwrite`로 결정)
+    pub conflict: Option<ConflictPolicy>,
+}
+
+impl TransferOptions {
+    fn conflict_policy(&self, overwrite: bool) -> ConflictPolicy {
+        self.conflict
+            .unwrap_or_else(|| ConflictPolicy::from_overwrite(overwrite))
+    }
+}
+
+/// 전송 단위: 원자적 이동(rename), 파일 복사 또는 링크 재생성
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub(super) enum TransferStep {
     AtomicMove {
         src: std::path::PathBuf,
         dest: std::path::PathBuf,
@@ -48,12 +92,29 @@ enum TransferStep {
         dest: std::path::PathBuf,
         name: String,
     },
+    CopySymlink {
+        src: std::path::PathBuf,
+        dest: std::path::PathBuf,
+        name: String,
+    },
 }
 
-struct TransferPlan {
-    steps: Vec<TransferStep>,
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct TransferPlan {
+    pub(super) steps: Vec<TransferStep>,
     /// cross-volume 이동 후 삭제할 최상위 소스 경로
-    move_cleanup_roots: Vec<std::path::PathBuf>,
+    pub(super) move_cleanup_roots: Vec<std::path::PathBuf>,
+    /// 복사되는 폴더 (원본, 대상) — 파일 복사가 끝난 뒤 빈 폴더 생성 + 메타데이터 복원
+    #[serde(default)]
+    pub(super) dirs: Vec<(std::path::PathBuf, std::path::PathBuf)>,
+    /// 기존 폴더에 합쳐 이동했거나 일부 항목(건너뛴 링크·충돌)을 건너뛴 최상위 소스 —
+    /// 옮긴 항목만 지우고 빈 폴더를 정리 (건너뛴 항목은 남김)
+    #[serde(default)]
+    pub(super) merge_cleanup_roots: Vec<std::path::PathBuf>,
+    /// 새로 생기는 (원본, 대상) 항목 — 작업 기록(실행 취소)용. 병합 시 기존 항목을 덮어쓰는 파일은 제외
+    #[serde(default)]
+    pub(super) created: Vec<(std::path::PathBuf, std::path::PathBuf)>,
 }
 
 /// 같은 볼륨이면 rename으로 이동 가능 (부작용 없이 판별)
@@ -82,48 +143,232 @@ fn is_same_volume(a: &std::path::Path, b: &std::path::Path) -> bool {
     }
 }
 
-fn send_queue_progress(
-    channel: &tauri::ipc::Channel<TransferQueueProgress>,
-    phase: &str,
-    operation: &str,
+/// 작업 큐 진행률 전송기 (채널 + 작업 식별 정보 묶음)
+pub(super) struct QueueReporter<'a> {
+    pub(super) channel: &'a tauri::ipc::Channel<TransferQueueProgress>,
+    pub(super) operation: &'a str,
+    pub(super) job_id: &'a str,
+}
+
+impl QueueReporter<'_> {
+    fn progress(
+        &self,
+        phase: &str,
+        done: u64,
+        total: u64,
+        current_name: &str,
+    ) -> TransferQueueProgress {
+        let percent = if total > 0 {
+            (done as f32 / total as f32) * 100.0
+        } else {
+            0.0
+        };
+        TransferQueueProgress {
+            job_id: self.job_id.to_string(),
+            phase: phase.to_string(),
+            operation: self.operation.to_string(),
+            done_files: done,
+            total_files: total,
+            current_name: current_name.to_string(),
+            percent: percent.min(100.0),
+            active_id: None,
+            files: None,
+            completed: None,
+            bytes_done: 0,
+            bytes_total: 0,
+            bytes_per_sec: 0.0,
+            eta_secs: None,
+            active_percent: None,
+        }
+    }
+
+    /// 바이트 진행률 포함 전송 — 바이트 합계가 있으면 전체 퍼센트도 바이트 기준
+    /// `active`: 현재 파일 (인덱스, 파일 진행률)
+    fn send_bytes(
+        &self,
+        phase: &str,
+        done: u64,
+        total: u64,
+        current_name: &str,
+        active: Option<(u32, f32)>,
+        meter: &ByteMeter,
+    ) {
+        let base = self.progress(phase, done, total, current_name);
+        let _ = self.channel.send(TransferQueueProgress {
+            percent: meter.percent().unwrap_or(base.percent),
+            active_id: active.map(|(id, _)| id),
+            active_percent: active.map(|(_, percent)| percent),
+            bytes_done: meter.done,
+            bytes_total: meter.total,
+            bytes_per_sec: meter.rate,
+            eta_secs: meter.eta_secs(),
+            ..base
+        });
+    }
+
+    fn send(
+        &self,
+        phase: &str,
+        done: u64,
+        total: u64,
+        current_name: &str,
+        active_id: Option<u32>,
+        files: Option<Vec<TransferFileItem>>,
+    ) {
+        let _ = self.channel.send(TransferQueueProgress {
+            active_id,
+            files,
+            ..self.progress(phase, done, total, current_name)
+        });
+    }
+}
+
+const COPY_CHUNK_SIZE: usize = 1024 * 1024;
+/// 청크 단위 진행률 전송 최소 간격 (Channel 폭주 방지)
+const PROGRESS_EMIT_INTERVAL: std::time::Duration = std::time::Duration::from_millis(200);
+/// 처리 속도 지수 이동 평균 가중치 (최근 구간 비중)
+const RATE_SMOOTHING: f64 = 0.3;
+
+/// 바이트 진행률 + 처리 속도 측정기
+pub(super) struct ByteMeter {
     done: u64,
     total: u64,
-    current_name: &str,
-    active_id: Option<u32>,
-    files: Option<Vec<TransferFileItem>>,
-) {
-    let percent = if total > 0 {
-        (done as f32 / total as f32) * 100.0
-    } else {
-        0.0
-    };
-    let _ = channel.send(TransferQueueProgress {
-        phase: phase.to_string(),
-        operation: operation.to_string(),
-        done_files: done,
-        total_files: total,
-        current_name: current_name.to_string(),
-        percent: percent.min(100.0),
-        active_id,
-        files,
-    });
+    rate: f64,
+    last_sample: std::time::Instant,
+    last_sample_bytes: u64,
+}
+
+impl ByteMeter {
+    pub(super) fn new(done: u64, total: u64) -> Self {
+        Self {
+            done,
+            total,
+            rate: 0.0,
+            last_sample: std::time::Instant::now(),
+            last_sample_bytes: done,
+        }
+    }
+
+    pub(super) fn done(&self) -> u64 {
+        self.done
+    }
+
+    /// 누적 바이트 갱신 (재시도·실패로 줄어들 수도 있음)
+    pub(super) fn set_done(&mut self, done: u64) {
+        self.done = done;
+        self.last_sample_bytes = self.last_sample_bytes.min(done);
+    }
+
+    /// 전송 간격이 지났으면 속도를 갱신하고 true (호출 측은 이때만 진행률 전송)
+    pub(super) fn tick(&mut self) -> bool {
+        let elapsed = self.last_sample.elapsed();
+        if elapsed < PROGRESS_EMIT_INTERVAL {
+            return false;
+        }
+        let instant_rate = (self.done - self.last_sample_bytes) as f64 / elapsed.as_secs_f64();
+        self.rate = if self.rate > 0.0 {
+            RATE_SMOOTHING * instant_rate + (1.0 - RATE_SMOOTHING) * self.rate
+        } else {
+            instant_rate
+        };
+        self.last_sample = std::time::Instant::now();
+        self.last_sample_bytes = self.done;
+        true
+    }
+
+    pub(super) fn eta_secs(&self) -> Option<u64> {
+        if self.rate <= 0.0 {
+            return None;
+        }
+        let remaining = self.total.saturating_sub(self.done) as f64;
+        Some((remaining / self.rate).ceil() as u64)
+    }
+
+    fn percent(&self) -> Option<f32> {
+        (self.total > 0).then(|| ((self.done as f64 / self.total as f64) * 100.0).min(100.0) as f32)
+    }
+}
+
+/// 청크 단위 파일 복사 — 청크마다 취소 여부를 확인하고 `on_chunk`에 쓴 바이트 수를 알린다.
+/// 취소·실패 시 쓰다 만 대상 파일을 지운다.
+pub(in crate::modules::file_ops) fn copy_file_cancellable(
+    src: &std::path::Path,
+    dest: &std::path::Path,
+    token: &CancelToken,
+    on_chunk: &mut dyn FnMut(u64),
+) -> Result<u64> {
+    use std::io::{Read, Write};
+
+    let mut reader = std::fs::File::open(src)?;
+    let permissions = reader.metadata()?.permissions();
+    let mut writer = std::fs::File::create(dest)?;
+    let result = (|| -> Result<u64> {
+        let mut buf = vec![0u8; COPY_CHUNK_SIZE];
+        let mut written = 0u64;
+        loop {
+            token.check()?;
+            let n = reader.read(&mut buf)?;
+            if n == 0 {
+                break;
+            }
+            writer.write_all(&buf[..n])?;
+            written += n as u64;
+            on_chunk(n as u64);
+        }
+        writer.flush()?;
+        Ok(written)
+    })();
+    drop(writer);
+
+    match result {
+        Ok(written) => {
+            std::fs::set_permissions(dest, permissions)?;
+            Ok(written)
+        }
+        Err(e) => {
+            let _ = std::fs::remove_file(dest);
+            Err(e)
+        }
+    }
 }
 
 /// 복사/이동 작업을 전송 단위 목록으로 펼침 (디스크 변경 없음)
+/// 기존 폴더에 합치는 작업은 하위 항목마다 `conflict` 정책으로 대상 경로를 정한다.
 fn build_transfer_plan(
     jobs: &[(std::path::PathBuf, std::path::PathBuf)],
     operation: &str,
+    symlinks: SymlinkPolicy,
+    conflict: ConflictPolicy,
+) -> Result<TransferPlan> {
+    plan_transfer(jobs, operation, symlinks, conflict, is_same_volume)
+}
+
+/// `build_transfer_plan` 본체 — 볼륨 판별을 주입받아 테스트에서 cross-volume 이동을 흉내 낸다
+fn plan_transfer(
+    jobs: &[(std::path::PathBuf, std::path::PathBuf)],
+    operation: &str,
+    symlinks: SymlinkPolicy,
+    conflict: ConflictPolicy,
+    is_same_volume: fn(&std::path::Path, &std::path::Path) -> bool,
 ) -> Result<TransferPlan> {
     use walkdir::WalkDir;
     let mut steps = Vec::new();
     let mut move_cleanup_roots = Vec::new();
+    let mut merge_cleanup_roots = Vec::new();
+    let mut dirs = Vec::new();
+    let mut created = Vec::new();
     for (src, dest) in jobs {
         let top_name = src
             .file_name()
             .map(|s| s.to_string_lossy().to_string())
             .unwrap_or_default();
+        let merge = is_merge_target(src, dest, symlinks);
+        let same_volume = operation == "move" && is_same_volume(src, dest);
+        if !merge {
+            created.push((src.clone(), dest.clone()));
+        }
 
-        if operation == "move" && is_same_volume(src, dest) {
+        if same_volume && !merge {
             steps.push(TransferStep::AtomicMove {
                 src: src.clone(),
                 dest: dest.clone(),
@@ -132,10 +377,22 @@ fn build_transfer_plan(
             continue;
         }
 
-        if operation == "move" {
-            move_cleanup_roots.push(src.clone());
+        if is_symlink(src) && symlinks != SymlinkPolicy::Follow {
+            if symlinks == SymlinkPolicy::CopyLink {
+                steps.push(TransferStep::CopySymlink {
+                    src: src.clone(),
+                    dest: dest.clone(),
+                    name: top_name,
+                });
+                if operation == "move" {
+                    move_cleanup_roots.push(src.clone());
+                }
+            }
+            continue;
         }
 
+        // 계획에 들어간 소스만 이동 후 정리 대상 — 건너뛴 링크는 원본에 그대로 남는다
+        let mut selective_cleanup = merge;
         if src.is_file() {
             steps.push(TransferStep::CopyFile {
                 src: src.clone(),
@@ -143,89 +400,212 @@ fn build_transfer_plan(
                 name: top_name,
             });
         } else if src.is_dir() {
-            for entry in WalkDir::new(src).into_iter().filter_map(|e| e.ok()) {
-                if entry.file_type().is_file() {
-                    let rel = entry.path().strip_prefix(src).unwrap_or(entry.path());
-                    let dest_path = dest.join(rel);
-                    let name = entry.file_name().to_string_lossy().to_string();
+            // 같은 볼륨 병합 이동은 파일 단위 rename (링크를 따라간 하위 항목은 원본을 옮기지 않도록 복사)
+            let atomic = same_volume && symlinks != SymlinkPolicy::Follow;
+            // 병합 중 대상에 없던 폴더 (하위 항목은 폴더 단위로 기록)
+            let mut new_dirs: Vec<std::path::PathBuf> = Vec::new();
+            // Follow면 walkdir가 조상 폴더를 가리키는 순환 링크를 오류로 돌려준다
+            let mut walker = WalkDir::new(src)
+                .follow_links(symlinks == SymlinkPolicy::Follow)
+                .into_iter();
+            while let Some(entry) = walker.next() {
+                let entry = match entry {
+                    Ok(entry) => entry,
+                    Err(e) => {
+                        if e.loop_ancestor().is_some() {
+                            log::warn!("순환 심볼릭 링크 건너뜀: {}", e);
+                        }
+                        selective_cleanup = true;
+                        continue;
+                    }
+                };
+                let rel = entry.path().strip_prefix(src).unwrap_or(entry.path());
+                let is_link = entry.path_is_symlink() && symlinks != SymlinkPolicy::Follow;
+                if is_link && symlinks == SymlinkPolicy::Skip {
+                    selective_cleanup = true;
+                    continue;
+                }
+                let is_dir = !is_link && entry.file_type().is_dir();
+                let Some(dest_path) =
+                    resolve_nested(entry.path(), is_dir, &dest.join(rel), conflict)
+                else {
+                    if is_dir {
+                        walker.skip_current_dir();
+                    }
+                    selective_cleanup = true;
+                    continue;
+                };
+                let src_path = entry.path().to_path_buf();
+                let name = entry.file_name().to_string_lossy().to_string();
+                if merge
+                    && std::fs::symlink_metadata(&dest_path).is_err()
+                    && !new_dirs.iter().any(|dir| dest_path.starts_with(dir))
+                {
+                    if is_dir {
+                        new_dirs.push(dest_path.clone());
+                    }
+                    created.push((src_path.clone(), dest_path.clone()));
+                }
+                if is_dir {
+                    dirs.push((src_path, dest_path));
+                } else if atomic {
+                    steps.push(TransferStep::AtomicMove {
+                        src: src_path,
+                        dest: dest_path,
+                        name,
+                    });
+                } else if is_link {
+                    steps.push(TransferStep::CopySymlink {
+                        src: src_path,
+                        dest: dest_path,
+                        name,
+                    });
+                } else if entry.file_type().is_file() {
                     steps.push(TransferStep::CopyFile {
-                        src: entry.path().to_path_buf(),
+                        src: src_path,
                         dest: dest_path,
                         name,
                     });
                 }
             }
+        } else {
+            continue;
+        }
+
+        if operation == "move" {
+            if selective_cleanup {
+                merge_cleanup_roots.push(src.clone());
+            } else {
+                move_cleanup_roots.push(src.clone());
+            }
         }
     }
     Ok(TransferPlan {
         steps,
         move_cleanup_roots,
+        dirs,
+        merge_cleanup_roots,
+        created,
     })
 }
 
-fn execute_transfer_steps(
+fn step_name(step: &TransferStep) -> &str {
+    match step {
+        TransferStep::AtomicMove { name, .. }
+        | TransferStep::CopyFile { name, .. }
+        | TransferStep::CopySymlink { name, .. } => name,
+    }
+}
+
+fn step_dest(step: &TransferStep) -> &std::path::Path {
+    match step {
+        TransferStep::AtomicMove { dest, .. }
+        | TransferStep::CopyFile { dest, .. }
+        | TransferStep::CopySymlink { dest, .. } => dest,
+    }
+}
+
+/// 전송 단위 실행. `start` 이전 단계는 완료된 것으로 간주한다 (저널 재개용).
+/// 정상 완료·취소 시 저널을 지우고, 오류로 중단되면 재개할 수 있도록 남겨 둔다.
+/// `options.verify`면 복사 단계마다 xxh3로 검증하며, 검증 실패도 오류로 중단된다.
+pub(super) fn execute_transfer_steps(
     plan: &TransferPlan,
-    operation: &str,
+    start: usize,
     app_cache: Option<&std::path::Path>,
-    channel: &tauri::ipc::Channel<TransferQueueProgress>,
+    reporter: &QueueReporter,
+    token: &CancelToken,
+    journal: Option<&JournalFile>,
+    options: &TransferOptions,
 ) -> Result<()> {
     let steps = &plan.steps;
     let total = steps.len() as u64;
+    let start = start.min(steps.len());
     let file_items: Vec<TransferFileItem> = steps
         .iter()
         .enumerate()
-        .map(|(i, step)| TransferFileItem {
-            id: i as u32,
-            name: match step {
-                TransferStep::AtomicMove { name, .. } | TransferStep::CopyFile { name, .. } => {
-                    name.clone()
-                }
-            },
-            status: "pending".to_string(),
-            percent: 0.0,
+        .map(|(i, step)| {
+            let finished = i < start;
+            TransferFileItem {
+                id: i as u32,
+                name: step_name(step).to_string(),
+                status: if finished { "completed" } else { "pending" }.to_string(),
+                percent: if finished { 100.0 } else { 0.0 },
+            }
         })
         .collect();
 
-    send_queue_progress(
-        channel,
-        "transferring",
-        operation,
-        0,
-        total,
-        "",
-        None,
-        Some(file_items),
-    );
-
-    let mut done = 0u64;
-
-    for (idx, step) in steps.iter().enumerate() {
-        let name = match step {
-            TransferStep::AtomicMove { name, .. } | TransferStep::CopyFile { name, .. } => {
-                name.clone()
+    // 바이트 진행률은 실제 복사 단계만 대상 (rename은 즉시 끝나므로 제외)
+    let step_bytes: Vec<u64> = steps
+        .iter()
+        .map(|step| match step {
+            TransferStep::CopyFile { src, .. } => {
+                std::fs::metadata(src).map(|m| m.len()).unwrap_or(0)
             }
-        };
+            TransferStep::AtomicMove { .. } | TransferStep::CopySymlink { .. } => 0,
+        })
+        .collect();
+    let mut meter = ByteMeter::new(step_bytes[..start].iter().sum(), step_bytes.iter().sum());
+
+    let mut done = start as u64;
+    let mut completed: Vec<String> = steps[..start]
+        .iter()
+        .map(|step| step_dest(step).to_string_lossy().to_string())
+        .collect();
+
+    reporter.send("transferring", done, total, "", None, Some(file_items));
+
+    let cancel = |done: u64, completed: Vec<String>| {
+        if let Some(journal) = journal {
+            journal.remove();
+        }
+        report_cancelled(reporter, done, total, completed)
+    };
 
-        send_queue_progress(
-            channel,
+    for (idx, step) in steps.iter().enumerate().skip(start) {
+        let name = step_name(step).to_string();
+
+        if token.is_paused() {
+            reporter.send_bytes("paused", done, total, &name, None, &meter);
+            token.wait_while_paused();
+        }
+        if token.is_cancelled() {
+            return cancel(done, completed);
+        }
+
+        reporter.send_bytes(
             "transferring",
-            operation,
             done,
             total,
             &name,
-            Some(idx as u32),
-            None,
+            Some((idx as u32, 0.0)),
+            &meter,
         );
 
-        match step {
+        let step_result = match step {
+            // 재개 시: 종료 직전에 이미 옮겨진 항목
+            TransferStep::AtomicMove { src, dest, .. } if !src.exists() && dest.exists() => Ok(()),
             TransferStep::AtomicMove { src, dest, .. } => {
+                // 병합 이동은 대상에 아직 없는 하위 폴더로 옮길 수 있음
+                if let Some(parent) = dest.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
                 if let Some(app_cache) = app_cache {
                     invalidate_thumbnail_cache_paths_in_root(
                         app_cache,
                         &[src.to_string_lossy().to_string()],
                     );
                 }
-                std::fs::rename(src, dest)?;
+                std::fs::rename(src, dest).map_err(AppError::from)
+            }
+            TransferStep::CopySymlink { src, dest, .. } => {
+                if let Some(parent) = dest.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+                // 재개 시 이미 만들어 둔 링크나 덮어쓰기로 정한 파일은 지우고 다시 만든다
+                if std::fs::symlink_metadata(dest).is_ok_and(|m| !m.is_dir()) {
+                    remove_symlink(dest)?;
+                }
+                copy_symlink(src, dest)
             }
             TransferStep::CopyFile { src, dest, .. } => {
                 if let Some(parent) = dest.parent() {
@@ -239,24 +619,73 @@ fn execute_transfer_steps(
                         );
                     }
                 }
-                std::fs::copy(src, dest)?;
+                let preserved = PreservedMetadata::capture(src);
+                let file_total = step_bytes[idx];
+                let file_base = meter.done();
+                let mut paused_sent = false;
+                let result =
+                    copy_file_verified(src, dest, token, options.verify, &mut |file_done| {
+                        meter.set_done(file_base + file_done);
+                        // 청크 사이 일시정지: 다음 청크의 check()가 대기하기 전에 알린다
+                        if token.is_paused() {
+                            if !paused_sent {
+                                reporter.send_bytes("paused", done, total, &name, None, &meter);
+                                paused_sent = true;
+                            }
+                            return;
+                        }
+                        if meter.tick() || paused_sent {
+                            paused_sent = false;
+                            let file_percent = if file_total > 0 {
+                                (file_done as f32 / file_total as f32 * 100.0).min(100.0)
+                            } else {
+                                0.0
+                            };
+                            reporter.send_bytes(
+                                "transferring",
+                                done,
+                                total,
+                                &name,
+                                Some((idx as u32, file_percent)),
+                                &meter,
+                            );
+                        }
+                    });
+                match (&result, &preserved) {
+                    (Ok(_), Some(preserved)) => preserved.apply(dest),
+                    (Err(_), _) => meter.set_done(file_base),
+                    _ => {}
+                }
+                result.map(|_| ())
             }
+        };
+
+        match step_result {
+            Ok(()) => {}
+            // 청크 복사·검증 중 취소 — 쓰다 만(검증 전) 파일은 이미 정리됨
+            Err(AppError::Cancelled(_)) => return cancel(done, completed),
+            Err(e) => return Err(e),
         }
 
         done += 1;
-        send_queue_progress(
-            channel,
-            "transferring",
-            operation,
-            done,
-            total,
-            &name,
-            None,
-            None,
-        );
+        if let Some(journal) = journal {
+            journal.record_done(done);
+        }
+        completed.push(step_dest(step).to_string_lossy().to_string());
+        reporter.send_bytes("transferring", done, total, &name, None, &meter);
     }
 
-    if operation == "move" {
+    // 빈 폴더까지 만든 뒤 폴더 시각·권한 복원 (하위 파일 생성으로 바뀐 mtime 되돌림)
+    for (_, dest_dir) in &plan.dirs {
+        std::fs::create_dir_all(dest_dir)?;
+    }
+    for (src_dir, dest_dir) in plan.dirs.iter().rev() {
+        preserve_metadata(src_dir, dest_dir);
+    }
+
+    // 모든 단계가 끝나야(verify 모드면 검증까지 통과해야) 여기 도달하므로
+    // cross-volume 이동 소스는 하위 파일이 전부 안전하게 복사된 뒤에만 삭제된다
+    if reporter.operation == "move" {
         for root in &plan.move_cleanup_roots {
             if root.exists() {
                 if root.is_dir() {
@@ -266,82 +695,229 @@ fn execute_transfer_steps(
                 }
             }
         }
+        for root in &plan.merge_cleanup_roots {
+            cleanup_merged_source(root, steps);
+        }
     }
 
-    send_queue_progress(channel, "done", operation, done, total, "", None, None);
+    if let Some(journal) = journal {
+        journal.remove();
+    }
+    reporter.send_bytes("done", done, total, "", None, &meter);
     Ok(())
 }
 
+/// 기존 폴더에 합쳐 이동한 소스 정리: 복사된 항목만 지우고 빈 폴더를 아래에서부터 삭제한다.
+/// 충돌로 건너뛴 파일과 그 상위 폴더는 남고, 따라간 링크 안의 원본(링크 대상)은 건드리지 않는다.
+fn cleanup_merged_source(root: &std::path::Path, steps: &[TransferStep]) {
+    for step in steps {
+        let src = match step {
+            TransferStep::CopyFile { src, .. } | TransferStep::CopySymlink { src, .. } => src,
+            TransferStep::AtomicMove { .. } => continue,
+        };
+        let inside_link = src
+            .ancestors()
+            .skip(1)
+            .take_while(|dir| *dir != root)
+            .any(is_symlink);
+        if src.starts_with(root) && !inside_link {
+            let _ = remove_symlink(src);
+        }
+    }
+    for entry in walkdir::WalkDir::new(root)
+        .contents_first(true)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_dir())
+    {
+        // 비어 있지 않으면 실패 — 남은 파일이 있는 폴더는 유지
+        let _ = std::fs::remove_dir(entry.path());
+    }
+}
+
+/// 취소 종료: 완료된 파일 목록과 함께 `cancelled` 단계를 알리고 `AppError::Cancelled` 반환.
+/// cross-volume 이동의 소스는 삭제하지 않는다 (원본 보존).
+fn report_cancelled(
+    reporter: &QueueReporter,
+    done: u64,
+    total: u64,
+    completed: Vec<String>,
+) -> Result<()> {
+    let _ = reporter.channel.send(TransferQueueProgress {
+        completed: Some(completed),
+        ..reporter.progress("cancelled", done, total, "")
+    });
+    Err(AppError::Cancelled(format!(
+        "전송이 취소되었습니다 ({}/{} 완료)",
+        done, total
+    )))
+}
+
 pub(super) fn run_transfer_with_queue(
-    operation: &str,
     sources: &[String],
     dest: &std::path::Path,
     overwrite: bool,
     app_cache: Option<&std::path::Path>,
-    channel: &tauri::ipc::Channel<TransferQueueProgress>,
-) -> Result<()> {
-    send_queue_progress(channel, "scanning", operation, 0, 0, "", None, None);
+    reporter: &QueueReporter,
+    token: &CancelToken,
+    options: &TransferOptions,
+) -> Result<Vec<(std::path::PathBuf, std::path::PathBuf)>> {
+    let operation = reporter.operation;
+    reporter.send("scanning", 0, 0, "", None, None);
 
+    let conflict = options.conflict_policy(overwrite);
     let jobs = if operation == "move" {
-        collect_move_jobs(sources, dest, overwrite, app_cache)?
+        collect_move_jobs(sources, dest, conflict, app_cache, options.symlinks)?
     } else {
-        collect_copy_jobs(sources, dest, overwrite, app_cache)?
+        collect_copy_jobs(sources, dest, conflict, app_cache, options.symlinks)?
     };
 
-    // 빈 폴더도 대상에 생성 (파일이 없으면 steps에 포함되지 않음)
-    for (src, dest) in &jobs {
-        if src.is_dir() {
-            std::fs::create_dir_all(dest)?;
-        }
+    if token.is_cancelled() {
+        return report_cancelled(reporter, 0, 0, Vec::new());
     }
 
-    let plan = build_transfer_plan(&jobs, operation)?;
-    let total = plan.steps.len() as u64;
-
-    if total == 0 {
-        send_queue_progress(channel, "done", operation, 0, 0, "", None, None);
-        return Ok(());
+    // 빈 폴더는 steps 없이 plan.dirs에만 담겨 실행 마지막에 생성된다
+    let plan = build_transfer_plan(&jobs, operation, options.symlinks, conflict)?;
+    if plan.steps.is_empty() && plan.dirs.is_empty() {
+        reporter.send("done", 0, 0, "", None, None);
+        return Ok(Vec::new());
     }
 
-    execute_transfer_steps(&plan, operation, app_cache, channel)
+    let journal = TransferJournal {
+        job_id: reporter.job_id.to_string(),
+        operation: operation.to_string(),
+        dest: dest.to_string_lossy().to_string(),
+        created_at: std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or(0),
+        options: options.clone(),
+        plan,
+    };
+    // 저널 기록 실패는 전송 자체를 막지 않는다 (재개만 불가)
+    let journal_file = app_cache.and_then(|cache| match JournalFile::create(cache, &journal) {
+        Ok(file) => Some(file),
+        Err(e) => {
+            log::warn!("전송 저널 기록 실패 ({}): {}", journal.job_id, e);
+            None
+        }
+    });
+
+    execute_transfer_steps(
+        &journal.plan,
+        0,
+        app_cache,
+        reporter,
+        token,
+        journal_file.as_ref(),
+        &journal.options,
+    )?;
+    Ok(journal.plan.created)
 }
 
-fn copy_dir_recursive_with_progress(
-    src: &std::path::Path,
-    dest: &std::path::Path,
+/// `copy_items_with_progress` 진행 상태 (파일 개수 + 바이트)
+struct CopyRun<'a> {
     total_files: u64,
-    done: &mut u64,
-    on_progress: &tauri::ipc::Channel<CopyProgress>,
-) -> Result<()> {
-    std::fs::create_dir_all(dest)?;
-    for entry in std::fs::read_dir(src)?.flatten() {
-        let dest_child = dest.join(entry.file_name());
-        if entry.path().is_dir() {
-            copy_dir_recursive_with_progress(
-                &entry.path(),
-                &dest_child,
-                total_files,
-                done,
-                on_progress,
-            )?;
+    done: u64,
+    meter: ByteMeter,
+    verify: bool,
+    symlinks: SymlinkPolicy,
+    conflict: ConflictPolicy,
+    guard: LoopGuard,
+    on_progress: &'a tauri::ipc::Channel<CopyProgress>,
+}
+
+impl CopyRun<'_> {
+    fn send(&self, current_name: &str) {
+        let by_files = if self.total_files > 0 {
+            (self.done as f32 / self.total_files as f32) * 100.0
         } else {
-            std::fs::copy(entry.path(), &dest_child)?;
-            *done += 1;
-            let name = entry.file_name().to_string_lossy().to_string();
-            let pct = if total_files > 0 {
-                (*done as f32 / total_files as f32) * 100.0
-            } else {
-                100.0
+            100.0
+        };
+        let _ = self.on_progress.send(CopyProgress {
+            percent: self.meter.percent().unwrap_or(by_files).min(100.0),
+            done_files: self.done,
+            total_files: self.total_files,
+            current_name: current_name.to_string(),
+            bytes_done: self.meter.done,
+            bytes_total: self.meter.total,
+        });
+    }
+
+    fn copy_file(&mut self, src: &std::path::Path, dest: &std::path::Path) -> Result<()> {
+        let name = src
+            .file_name()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_default();
+        // 이 경로는 취소 command가 없으므로 취소되지 않는 토큰 사용
+        let token = CancelToken::new();
+        let preserved = PreservedMetadata::capture(src);
+        let file_base = self.meter.done();
+        copy_file_verified(src, dest, &token, self.verify, &mut |file_done| {
+            self.meter.set_done(file_base + file_done);
+            if self.meter.tick() {
+                self.send(&name);
+            }
+        })?;
+        if let Some(preserved) = preserved {
+            preserved.apply(dest);
+        }
+        self.done += 1;
+        self.send(&name);
+        Ok(())
+    }
+
+    /// 파일·폴더·링크 하나 복사 (링크는 `symlinks` 정책대로)
+    fn copy_entry(&mut self, src: &std::path::Path, dest: &std::path::Path) -> Result<()> {
+        if is_symlink(src) {
+            match self.symlinks {
+                SymlinkPolicy::CopyLink => {
+                    // 병합 중 덮어쓰기로 정한 기존 파일·링크
+                    if std::fs::symlink_metadata(dest).is_ok_and(|m| !m.is_dir()) {
+                        remove_symlink(dest)?;
+                    }
+                    return copy_symlink(src, dest);
+                }
+                SymlinkPolicy::Skip => return Ok(()),
+                SymlinkPolicy::Follow => {}
+            }
+        }
+        if src.is_dir() {
+            self.copy_dir(src, dest)
+        } else if src.is_file() {
+            self.copy_file(src, dest)
+        } else {
+            log::warn!("대상이 없는 심볼릭 링크 건너뜀: {}", src.display());
+            Ok(())
+        }
+    }
+
+    fn copy_dir(&mut self, src: &std::path::Path, dest: &std::path::Path) -> Result<()> {
+        if !self.guard.enter(src) {
+            return Ok(());
+        }
+        std::fs::create_dir_all(dest)?;
+        for entry in std::fs::read_dir(src)?.flatten() {
+            let src_child = entry.path();
+            if is_symlink(&src_child) && self.symlinks == SymlinkPolicy::Skip {
+                continue;
+            }
+            // 기존 폴더에 합치는 중이면 겹치는 하위 항목마다 충돌 정책 적용
+            let is_dir = is_dir_with(&src_child, self.symlinks);
+            let Some(dest_child) = resolve_nested(
+                &src_child,
+                is_dir,
+                &dest.join(entry.file_name()),
+                self.conflict,
+            ) else {
+                continue;
             };
-            let _ = on_progress.send(CopyProgress {
-                percent: pct.min(100.0),
-                done_files: *done,
-                total_files,
-                current_name: name,
-            });
+            self.copy_entry(&src_child, &dest_child)?;
         }
+        self.guard.leave();
+        preserve_metadata(src, dest);
+        Ok(())
     }
-    Ok(())
 }
 
 pub(super) fn run_copy_with_progress(
@@ -349,58 +925,117 @@ pub(super) fn run_copy_with_progress(
     dest_path: std::path::PathBuf,
     overwrite: bool,
     app_cache: std::path::PathBuf,
+    options: TransferOptions,
     on_progress: tauri::ipc::Channel<CopyProgress>,
-) -> Result<()> {
-    let jobs = collect_copy_jobs(&sources, &dest_path, overwrite, Some(&app_cache))?;
+) -> Result<Vec<(std::path::PathBuf, std::path::PathBuf)>> {
+    let conflict = options.conflict_policy(overwrite);
+    let jobs = collect_copy_jobs(
+        &sources,
+        &dest_path,
+        conflict,
+        Some(&app_cache),
+        options.symlinks,
+    )?;
     let mut total_files = 0u64;
+    let mut total_bytes = 0u64;
     for (src, _) in &jobs {
         total_files += count_files_to_copy(src)?;
+        total_bytes += count_bytes_to_copy(src);
     }
 
+    let mut run = CopyRun {
+        total_files,
+        done: 0,
+        meter: ByteMeter::new(0, total_bytes),
+        verify: options.verify,
+        symlinks: options.symlinks,
+        conflict,
+        guard: LoopGuard::default(),
+        on_progress: &on_progress,
+    };
     let _ = on_progress.send(CopyProgress {
         percent: 0.0,
         done_files: 0,
         total_files,
         current_name: String::new(),
+        bytes_done: 0,
+        bytes_total: total_bytes,
     });
 
-    let mut done = 0u64;
+    // 기존 폴더에 합친 작업은 작업 기록에서 제외 (되돌리면 기존 파일까지 지워지므로)
+    let mut created = Vec::new();
     for (src, dest_one) in jobs {
-        if src.is_dir() {
-            copy_dir_recursive_with_progress(
-                &src,
-                &dest_one,
-                total_files,
-                &mut done,
-                &on_progress,
-            )?;
-        } else {
-            std::fs::copy(&src, &dest_one)?;
-            done += 1;
-            let pct = if total_files > 0 {
-                (done as f32 / total_files as f32) * 100.0
-            } else {
-                100.0
-            };
-            let name = src
-                .file_name()
-                .map(|s| s.to_string_lossy().to_string())
-                .unwrap_or_default();
-            let _ = on_progress.send(CopyProgress {
-                percent: pct.min(100.0),
-                done_files: done,
-                total_files,
-                current_name: name,
-            });
+        let merge = is_merge_target(&src, &dest_one, options.symlinks);
+        run.copy_entry(&src, &dest_one)?;
+        if !merge {
+            created.push((src, dest_one));
         }
     }
 
     let _ = on_progress.send(CopyProgress {
         percent: 100.0,
-        done_files: done,
+        done_files: run.done,
         total_files,
         current_name: String::new(),
+        bytes_done: run.meter.done,
+        bytes_total: total_bytes,
     });
 
-    Ok::<(), AppError>(())
+    Ok(created)
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cross_volume_move_keeps_skipped_symlinks_in_source() {
+        let root = std::env::temp_dir().join("file_ops_test_transfer_skip_links");
+        let _ = std::fs::remove_dir_all(&root);
+        let (src, dest) = (root.join("src").join("Project"), root.join("dest"));
+        std::fs::create_dir_all(&src).unwrap();
+        std::fs::create_dir_all(&dest).unwrap();
+        std::fs::write(root.join("target.txt"), "target").unwrap();
+        std::fs::write(src.join("a.txt"), "a").unwrap();
+        std::os::unix::fs::symlink(root.join("target.txt"), src.join("link.txt")).unwrap();
+
+        let jobs = vec![(src.clone(), dest.join("Project"))];
+        let plan = plan_transfer(
+            &jobs,
+            "move",
+            SymlinkPolicy::Skip,
+            ConflictPolicy::Overwrite,
+            |_, _| false,
+        )
+        .unwrap();
+        assert!(plan.move_cleanup_roots.is_empty());
+
+        let channel = tauri::ipc::Channel::new(|_| Ok(()));
+        let reporter = QueueReporter {
+            channel: &channel,
+            operation: "move",
+            job_id: "skip-link-test",
+        };
+        execute_transfer_steps(
+            &plan,
+            0,
+            None,
+            &reporter,
+            &CancelToken::new(),
+            None,
+            &TransferOptions::default(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(dest.join("Project").join("a.txt")).unwrap(),
+            "a"
+        );
+        assert!(!dest.join("Project").join("link.txt").exists());
+        // 복사하지 않은 링크는 원본에 남고, 옮긴 파일만 사라진다
+        assert!(is_symlink(&src.join("link.txt")));
+        assert!(!src.join("a.txt").exists());
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
 }
@@ -0,0 +1,80 @@
+// ===== 복사 검증 (xxh3) =====
+// 네트워크 드라이브 등에서 조용히 깨지는 사본을 잡기 위해 복사 직후 원본·사본 해시를 비교한다.
+
+use crate::modules::error::{AppError, Result};
+use crate::modules::jobs::CancelToken;
+
+use super::progress::copy_file_cancellable;
+
+const HASH_CHUNK_SIZE: usize = 1024 * 1024;
+/// 검증 불일치 시 재복사 횟수
+const VERIFY_RETRIES: usize = 1;
+
+fn hash_file(path: &std::path::Path, token: &CancelToken) -> Result<u64> {
+    use std::io::Read;
+    use xxhash_rust::xxh3::Xxh3;
+
+    let mut file = std::fs::File::open(path)?;
+    let mut hasher = Xxh3::new();
+    let mut buf = vec![0u8; HASH_CHUNK_SIZE];
+    loop {
+        token.check()?;
+        let n = file.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buf[..n]);
+    }
+    Ok(hasher.digest())
+}
+
+/// 크기가 다르면 해시 없이 바로 불일치
+pub(in crate::modules::file_ops) fn contents_match(
+    src: &std::path::Path,
+    dest: &std::path::Path,
+    token: &CancelToken,
+) -> Result<bool> {
+    if std::fs::metadata(src)?.len() != std::fs::metadata(dest)?.len() {
+        return Ok(false);
+    }
+    Ok(hash_file(src, token)? == hash_file(dest, token)?)
+}
+
+/// 청크 복사 + (`verify`면) 해시 검증. 불일치 시 한 번 다시 복사하고,
+/// 그래도 다르면 사본을 지우고 `AppError::Io`를 반환한다.
+/// `on_progress`에는 현재 시도에서 쓴 누적 바이트를 알린다 (재시도 시 0부터 다시).
+pub(in crate::modules::file_ops) fn copy_file_verified(
+    src: &std::path::Path,
+    dest: &std::path::Path,
+    token: &CancelToken,
+    verify: bool,
+    on_progress: &mut dyn FnMut(u64),
+) -> Result<u64> {
+    for attempt in 0..=VERIFY_RETRIES {
+        let mut written = 0u64;
+        let copied = copy_file_cancellable(src, dest, token, &mut |n| {
+            written += n;
+            on_progress(written);
+        })?;
+        if !verify {
+            return Ok(copied);
+        }
+        match contents_match(src, dest, token) {
+            Ok(true) => return Ok(copied),
+            Ok(false) => {
+                log::warn!("복사 검증 불일치 ({}회차): {}", attempt + 1, src.display());
+                on_progress(0);
+            }
+            // 검증 도중 취소·오류: 검증되지 않은 사본은 남기지 않는다
+            Err(e) => {
+                let _ = std::fs::remove_file(dest);
+                return Err(e);
+            }
+        }
+    }
+    let _ = std::fs::remove_file(dest);
+    Err(AppError::Io(format!(
+        "복사 검증 실패 (원본과 사본 내용이 다릅니다): {}",
+        src.display()
+    )))
+}
@@ -0,0 +1,490 @@
+// ===== 디렉토리 변경 감시 =====
+// 로컬 폴더는 OS 변경 알림(inotify·FSEvents·ReadDirectoryChangesW)이 오면, 네트워크 마운트나
+// 알림을 쓸 수 없는 폴더는 주기적으로 stat(이름·크기·수정 시각·정체성)을 다시 읽어 직전 목록과
+// 비교하고, 바뀐 항목만 전체 메타데이터를 읽는다. 주기 확인은 큰 폴더·네트워크 폴더일수록 간격을 늘린다.
+// 연속된 변경은 목록이 잠잠해질 때까지 모아 한 번에 보내고, 디스크 목록 캐시와 바뀐 파일의
+// 썸네일 캐시도 함께 갱신한다.
+
+use std::collections::HashMap;
+use std::sync::mpsc::{Receiver, RecvTimeoutError, Sender};
+use std::time::{Duration, Instant};
+
+use tauri::ipc::Channel;
+
+use crate::modules::error::{AppError, Result};
+use crate::modules::image_ops::{invalidate_thumbnail_cache_paths_in_root, thumbnail_cache_root};
+use crate::modules::jobs::{cancel_job, new_job_id, register_job, CancelToken};
+use crate::modules::types::{file_identity, is_hidden_entry, FileEntry};
+
+use super::cache::update_cached_listing;
+use super::listing::{is_network_dir, listing_entry_at, read_directory_entries};
+
+/// 목록 재확인 주기 (OS 알림을 쓸 수 없는 로컬 폴더)
+const POLL_INTERVAL: Duration = Duration::from_millis(1000);
+/// 네트워크 마운트 목록 재확인 최소 주기
+const NETWORK_POLL_INTERVAL: Duration = Duration::from_secs(5);
+/// 큰 폴더는 이 항목 수마다 `POLL_INTERVAL`만큼 재확인 주기를 늘린다
+const POLL_ENTRIES_PER_INTERVAL: usize = 5_000;
+const MAX_POLL_INTERVAL: Duration = Duration::from_secs(30);
+/// OS 알림을 쓰는 동안 놓친 알림 보정용 재확인 주기
+const NOTIFY_RESCAN_INTERVAL: Duration = Duration::from_secs(60);
+/// 대기 중 취소 확인 간격
+const CANCEL_CHECK_INTERVAL: Duration = Duration::from_millis(250);
+/// 변경을 감지한 뒤 목록이 안정될 때까지 다시 읽는 간격
+const SETTLE_INTERVAL: Duration = Duration::from_millis(250);
+/// 계속 바뀌는 폴더(대용량 복사 중 등)도 이 횟수 안에는 한 번 보고
+const MAX_SETTLE_ROUNDS: usize = 8;
+
+#[derive(serde::Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DirectoryChangeKind {
+    Created,
+    Modified,
+    Renamed,
+    Removed,
+}
+
+#[derive(serde::Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DirectoryChange {
+    pub kind: DirectoryChangeKind,
+    pub path: String,
+    /// 이름 변경 전 경로 (`renamed`만)
+    pub old_path: Option<String>,
+    /// 변경 후 항목 (`removed`는 None)
+    pub entry: Option<FileEntry>,
+}
+
+/// Channel로 보내는 변경 묶음
+#[derive(serde::Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DirectoryChangeBatch {
+    pub watch_id: String,
+    pub path: String,
+    pub changes: Vec<DirectoryChange>,
+    /// 감시 폴더 자체가 사라짐 (이후 감시 종료)
+    pub root_removed: bool,
+}
+
+/// 변경 감지용 가벼운 항목 정보 (소유자 조회·시그니처 판별 없이 stat만)
+#[derive(Clone, PartialEq, Eq)]
+struct EntryStat {
+    is_dir: bool,
+    size: u64,
+    modified: u64,
+    identity: String,
+}
+
+impl EntryStat {
+    fn of(entry: &FileEntry) -> Self {
+        Self {
+            is_dir: entry.is_dir,
+            size: entry.size,
+            modified: entry.modified,
+            identity: entry.identity.clone(),
+        }
+    }
+}
+
+/// 경로 → stat. 주기마다 이것만 다시 읽고, 전체 `FileEntry`는 바뀐 항목만 만든다.
+type Snapshot = HashMap<String, EntryStat>;
+
+/// 폴더 직속 항목의 stat 목록 (숨김 규칙은 `read_directory_entries`와 같음)
+fn stat_snapshot(path: &str, show_hidden: bool) -> Result<Snapshot> {
+    let mut snapshot = Snapshot::new();
+    for entry in std::fs::read_dir(path)?.flatten() {
+        let Ok(meta) = entry.metadata() else {
+            continue;
+        };
+        let name = entry.file_name().to_string_lossy().to_string();
+        if !show_hidden && is_hidden_entry(&name, &meta) {
+            continue;
+        }
+        let modified = meta
+            .modified()
+            .ok()
+            .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or(0);
+        let stat = EntryStat {
+            is_dir: meta.is_dir(),
+            size: if meta.is_dir() { 0 } else { meta.len() },
+            modified,
+            identity: file_identity(&meta),
+        };
+        snapshot.insert(entry.path().to_string_lossy().to_string(), stat);
+    }
+    Ok(snapshot)
+}
+
+/// 두 목록의 차이 (`entry`는 비워 둠). 사라진 항목과 새 항목의 파일 정체성이 같으면 이름 변경으로 합친다.
+fn diff_snapshots(before: &Snapshot, after: &Snapshot) -> Vec<DirectoryChange> {
+    let mut removed: Vec<(&String, &EntryStat)> = before
+        .iter()
+        .filter(|(path, _)| !after.contains_key(*path))
+        .collect();
+    let mut changes = Vec::new();
+    let mut created = Vec::new();
+    for (path, stat) in after {
+        match before.get(path) {
+            Some(old) if old != stat => changes.push(DirectoryChange {
+                kind: DirectoryChangeKind::Modified,
+                path: path.clone(),
+                old_path: None,
+                entry: None,
+            }),
+            Some(_) => {}
+            None => created.push((path, stat)),
+        }
+    }
+
+    for (path, stat) in created {
+        let renamed_from = removed
+            .iter()
+            .position(|(_, old)| !old.identity.is_empty() && old.identity == stat.identity);
+        let (kind, old_path) = match renamed_from {
+            Some(index) => (
+                DirectoryChangeKind::Renamed,
+                Some(removed.swap_remove(index).0.clone()),
+            ),
+            None => (DirectoryChangeKind::Created, None),
+        };
+        changes.push(DirectoryChange {
+            kind,
+            path: path.clone(),
+            old_path,
+            entry: None,
+        });
+    }
+    changes.extend(removed.into_iter().map(|(path, _)| DirectoryChange {
+        kind: DirectoryChangeKind::Removed,
+        path: path.clone(),
+        old_path: None,
+        entry: None,
+    }));
+    changes.sort_by(|a, b| a.path.cmp(&b.path));
+    changes
+}
+
+enum PollResult {
+    Unchanged,
+    Changed(Vec<DirectoryChange>),
+    RootRemoved,
+}
+
+/// 폴더 하나의 감시 상태 (마지막으로 보고한 목록)
+struct DirectoryWatch {
+    path: String,
+    show_hidden: bool,
+    last: Snapshot,
+    /// 목록 캐시·변경 항목에 쓰는 전체 항목 (바뀐 항목만 다시 만든다)
+    entries: HashMap<String, FileEntry>,
+}
+
+impl DirectoryWatch {
+    fn new(path: String, show_hidden: bool) -> Result<Self> {
+        let entries: HashMap<String, FileEntry> = read_directory_entries(&path, show_hidden)?
+            .into_iter()
+            .map(|entry| (entry.path.clone(), entry))
+            .collect();
+        let last = entries
+            .iter()
+            .map(|(path, entry)| (path.clone(), EntryStat::of(entry)))
+            .collect();
+        Ok(Self {
+            path,
+            show_hidden,
+            last,
+            entries,
+        })
+    }
+
+    /// stat만 다시 읽어 비교. 바뀌었으면 `settle` 간격으로 안정될 때까지 다시 읽은 뒤 한 번에 보고한다.
+    fn poll(&mut self, settle: Duration, token: &CancelToken) -> PollResult {
+        let mut current = match stat_snapshot(&self.path, self.show_hidden) {
+            Ok(snapshot) => snapshot,
+            Err(_) if !std::path::Path::new(&self.path).is_dir() => return PollResult::RootRemoved,
+            // 네트워크 드라이브 일시 오류 등은 다음 주기에 재시도
+            Err(_) => return PollResult::Unchanged,
+        };
+        if current == self.last {
+            return PollResult::Unchanged;
+        }
+        for _ in 0..MAX_SETTLE_ROUNDS {
+            std::thread::sleep(settle);
+            if token.is_cancelled() {
+                break;
+            }
+            let Ok(next) = stat_snapshot(&self.path, self.show_hidden) else {
+                break;
+            };
+            let stable = next == current;
+            current = next;
+            if stable {
+                break;
+            }
+        }
+        let changes = self.resolve_changes(diff_snapshots(&self.last, &current));
+        self.last = current;
+        if changes.is_empty() {
+            PollResult::Unchanged
+        } else {
+            PollResult::Changed(changes)
+        }
+    }
+
+    /// 바뀐 항목만 전체 `FileEntry`로 다시 읽어 변경에 채우고 보관 목록을 갱신한다.
+    /// 그 사이 다시 사라진 항목은 다음 주기에 삭제로 보고된다.
+    fn resolve_changes(&mut self, changes: Vec<DirectoryChange>) -> Vec<DirectoryChange> {
+        let mut resolved = Vec::with_capacity(changes.len());
+        for mut change in changes {
+            if let Some(old_path) = &change.old_path {
+                self.entries.remove(old_path);
+            }
+            if change.kind == DirectoryChangeKind::Removed {
+                self.entries.remove(&change.path);
+                resolved.push(change);
+                continue;
+            }
+            let Some(entry) =
+                listing_entry_at(std::path::Path::new(&change.path), self.show_hidden)
+            else {
+                self.entries.remove(&change.path);
+                continue;
+            };
+            self.entries.insert(change.path.clone(), entry.clone());
+            change.entry = Some(entry);
+            resolved.push(change);
+        }
+        resolved
+    }
+
+    fn entries(&self) -> Vec<FileEntry> {
+        self.entries.values().cloned().collect()
+    }
+}
+
+/// OS 알림 없이 목록을 비교할 때의 재확인 주기 (네트워크·큰 폴더일수록 길게)
+fn poll_interval(entry_count: usize, network: bool) -> Duration {
+    let base = if network {
+        NETWORK_POLL_INTERVAL
+    } else {
+        POLL_INTERVAL
+    };
+    let scaled = POLL_INTERVAL * (entry_count / POLL_ENTRIES_PER_INTERVAL) as u32;
+    base.max(scaled).min(MAX_POLL_INTERVAL)
+}
+
+/// OS 변경 알림 구독 (실패하면 None — 목록 비교 주기로 대체)
+fn start_notifier(path: &str, events: Sender<()>) -> Option<notify::RecommendedWatcher> {
+    use notify::Watcher;
+
+    let mut watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+        // 열기·읽기는 목록을 바꾸지 않고 감시 스레드 자신의 조회로도 생긴다.
+        // 알림 오류(놓친 이벤트 등)는 다시 확인하도록 그대로 깨운다.
+        if !res.is_ok_and(|event| event.kind.is_access()) {
+            let _ = events.send(());
+        }
+    })
+    .map_err(|e| log::warn!("폴더 변경 알림 사용 불가 {}: {}", path, e))
+    .ok()?;
+    watcher
+        .watch(
+            std::path::Path::new(path),
+            notify::RecursiveMode::NonRecursive,
+        )
+        .map_err(|e| log::warn!("폴더 변경 알림 등록 실패 {}: {}", path, e))
+        .ok()?;
+    Some(watcher)
+}
+
+/// 알림이 오거나 `wait`가 지날 때까지 대기 (취소는 짧은 간격으로 확인, 몰려온 알림은 한 번으로 합침)
+fn wait_for_change(events: &Receiver<()>, wait: Duration, token: &CancelToken) {
+    let deadline = Instant::now() + wait;
+    while !token.is_cancelled() {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return;
+        }
+        match events.recv_timeout(remaining.min(CANCEL_CHECK_INTERVAL)) {
+            Ok(()) => {
+                while events.try_recv().is_ok() {}
+                return;
+            }
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => {
+                std::thread::sleep(remaining.min(CANCEL_CHECK_INTERVAL))
+            }
+        }
+    }
+}
+
+/// 바뀌거나 사라진 파일의 썸네일은 다시 만들어야 한다
+fn stale_thumbnail_paths(changes: &[DirectoryChange]) -> Vec<String> {
+    changes
+        .iter()
+        .filter(|change| match change.kind {
+            DirectoryChangeKind::Modified => change.entry.as_ref().is_some_and(|e| !e.is_dir),
+            DirectoryChangeKind::Removed => true,
+            DirectoryChangeKind::Created | DirectoryChangeKind::Renamed => false,
+        })
+        .map(|change| change.path.clone())
+        .collect()
+}
+
+/// 폴더 감시 시작. 변경 묶음을 `on_event`로 보내며, 반환한 감시 ID로 `unwatch_directory` 호출.
+/// `show_hidden`은 목록 조회와 같게 맞춰야 숨김 파일 변경도 보고된다.
+#[tauri::command]
+pub async fn watch_directory<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    path: String,
+    show_hidden: Option<bool>,
+    on_event: Channel<DirectoryChangeBatch>,
+) -> Result<String> {
+    let app_cache = thumbnail_cache_root(&app)?;
+    let show_hidden = show_hidden.unwrap_or(false);
+    let mut watch =
+        tauri::async_runtime::spawn_blocking(move || DirectoryWatch::new(path, show_hidden))
+            .await
+            .map_err(|e| AppError::Internal(format!("폴더 감시 시작 실패: {}", e)))??;
+    let handle = register_job(new_job_id("watch"))?;
+    let watch_id = handle.id().to_string();
+
+    std::thread::spawn(move || {
+        let token = handle.token().clone();
+        // 네트워크 마운트는 다른 기기의 변경이 OS 알림으로 오지 않아 목록 비교만 쓴다
+        let network = is_network_dir(std::path::Path::new(&watch.path));
+        let (events, changed) = std::sync::mpsc::channel();
+        let notifier = if network {
+            None
+        } else {
+            start_notifier(&watch.path, events.clone())
+        };
+        while !token.is_cancelled() {
+            let wait = if notifier.is_some() {
+                NOTIFY_RESCAN_INTERVAL
+            } else {
+                poll_interval(watch.last.len(), network)
+            };
+            wait_for_change(&changed, wait, &token);
+            if token.is_cancelled() {
+                break;
+            }
+            let (changes, root_removed) = match watch.poll(SETTLE_INTERVAL, &token) {
+                PollResult::Unchanged => continue,
+                PollResult::Changed(changes) => (changes, false),
+                PollResult::RootRemoved => (Vec::new(), true),
+            };
+            if !root_removed {
+                // 디스크 목록 캐시는 숨김 항목을 뺀 기본 목록 — 숨김 포함 감시는 캐시에 쓰지 않는다
+                if !watch.show_hidden {
+                    if let Err(e) = update_cached_listing(&app, &watch.path, watch.entries()) {
+                        log::warn!("폴더 목록 캐시 갱신 실패 {}: {}", watch.path, e);
+                    }
+                }
+                let stale = stale_thumbnail_paths(&changes);
+                if !stale.is_empty() {
+                    invalidate_thumbnail_cache_paths_in_root(&app_cache, &stale);
+                }
+            }
+            let batch = DirectoryChangeBatch {
+                watch_id: handle.id().to_string(),
+                path: watch.path.clone(),
+                changes,
+                root_removed,
+            };
+            // 프런트 창이 닫혀 전달할 곳이 없으면 감시 종료
+            if on_event.send(batch).is_err() || root_removed {
+                break;
+            }
+        }
+        drop(notifier);
+        drop(events);
+        drop(handle);
+    });
+
+    Ok(watch_id)
+}
+
+/// 폴더 감시 중지 (이미 끝난 감시면 false)
+#[tauri::command]
+pub fn unwatch_directory(watch_id: String) -> bool {
+    cancel_job(&watch_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(changes: &[DirectoryChange]) -> Vec<(DirectoryChangeKind, String)> {
+        changes
+            .iter()
+            .map(|c| {
+                let name = std::path::Path::new(&c.path)
+                    .file_name()
+                    .unwrap()
+                    .to_string_lossy()
+                    .to_string();
+                (c.kind, name)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn poll_interval_backs_off_for_large_and_network_folders() {
+        assert_eq!(poll_interval(100, false), POLL_INTERVAL);
+        assert_eq!(poll_interval(100, true), NETWORK_POLL_INTERVAL);
+        assert_eq!(poll_interval(20_000, false), POLL_INTERVAL * 4);
+        assert_eq!(poll_interval(20_000, true), NETWORK_POLL_INTERVAL);
+        assert_eq!(poll_interval(10_000_000, false), MAX_POLL_INTERVAL);
+    }
+
+    #[test]
+    fn poll_reports_create_modify_rename_and_remove_once() {
+        let dir = std::env::temp_dir().join("file_ops_test_directory_watch");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("keep.txt"), "a").unwrap();
+        std::fs::write(dir.join("old.txt"), "b").unwrap();
+        std::fs::write(dir.join("gone.txt"), "c").unwrap();
+
+        let token = CancelToken::new();
+        let mut watch = DirectoryWatch::new(dir.to_string_lossy().to_string(), false).unwrap();
+        assert!(matches!(
+            watch.poll(Duration::ZERO, &token),
+            PollResult::Unchanged
+        ));
+
+        std::fs::write(dir.join("keep.txt"), "changed").unwrap();
+        std::fs::rename(dir.join("old.txt"), dir.join("new.txt")).unwrap();
+        std::fs::remove_file(dir.join("gone.txt")).unwrap();
+        std::fs::write(dir.join("added.txt"), "d").unwrap();
+
+        let PollResult::Changed(changes) = watch.poll(Duration::ZERO, &token) else {
+            panic!("변경이 감지되어야 함");
+        };
+        assert_eq!(
+            kinds(&changes),
+            [
+                (DirectoryChangeKind::Created, "added.txt".to_string()),
+                (DirectoryChangeKind::Removed, "gone.txt".to_string()),
+                (DirectoryChangeKind::Modified, "keep.txt".to_string()),
+                (DirectoryChangeKind::Renamed, "new.txt".to_string()),
+            ]
+        );
+        assert!(changes[3].old_path.as_deref().unwrap().ends_with("old.txt"));
+        assert_eq!(stale_thumbnail_paths(&changes).len(), 2);
+
+        // 보고한 변경은 다시 보내지 않음
+        assert!(matches!(
+            watch.poll(Duration::ZERO, &token),
+            PollResult::Unchanged
+        ));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(matches!(
+            watch.poll(Duration::ZERO, &token),
+            PollResult::RootRemoved
+        ));
+    }
+}
@@ -10,10 +10,11 @@ use std::sync::atomic::{AtomicU64, Ordering};
 
 pub(crate) const THUMBNAIL_CACHE_MAX_BYTES: u64 = 10 * 1024 * 1024 * 1024;
 pub(crate) const THUMBNAIL_CACHE_SIZES: [u32; 10] = [40, 60, 80, 100, 120, 160, 200, 240, 280, 320];
-pub(crate) const THUMBNAIL_CACHE_DIR_NAMES: [&str; 4] = [
+pub(crate) const THUMBNAIL_CACHE_DIR_NAMES: [&str; 5] = [
     "img_thumbnails",
     "psd_thumbnails",
     "video_thumbnails",
+    "audio_thumbnails",
     "drive_thumbnails",
 ];
 const THUMBNAIL_CACHE_PRUNE_INTERVAL_MS: u64 = 60_000;
@@ -221,11 +222,12 @@ fn collect_thumbnail_source_paths(path: &Path, out: &mut Vec<String>) {
 }
 
 // 이름 변경 시 디스크 썸네일 캐시를 새 경로 키로 이관한다 — 내용이 같은데 재생성하는 낭비와
-// 스피너 복귀를 없앤다. identity는 ctime을 포함하지 않아(rename 시 불변) rename 전에 계산해도 유효하다.
+// 스피너 복귀를 없앤다. identity는 ctime을 포함하지 않아(rename 시 불변) 옛 경로·새 경로 어느 쪽에서
+// 계산해도 같다 — rename이 성공한 뒤(파일이 새 경로에 있을 때) 호출한다.
 // 디렉토리는 하위 파일 키가 전부 경로를 포함해 일괄 이관 비용이 크므로 재생성에 맡긴다
 // (옛 키 파일은 10GB 프루닝이 정리). Google Drive의 fileId 기반 캐시는 경로 무관이라 이관 불필요.
 pub(crate) fn migrate_thumbnail_cache_for_rename(app_cache: &Path, old_path: &str, new_path: &str) {
-    let Ok(meta) = std::fs::metadata(old_path) else {
+    let Ok(meta) = std::fs::metadata(new_path).or_else(|_| std::fs::metadata(old_path)) else {
         return;
     };
     if meta.is_dir() {
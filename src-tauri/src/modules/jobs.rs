@@ -0,0 +1,188 @@
+//! 장시간 작업 취소/일시정지 토큰 레지스트리
+//!
+//! command가 작업 ID를 발급해 토큰을 등록하고, 별도의 취소·일시정지 command가 같은 ID로
+//! 토큰을 찾아 플래그를 세운다. 작업 루프는 단계 사이(및 청크 단위)에서 플래그를 확인한다.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+
+use crate::modules::error::{AppError, Result};
+
+static NEXT_JOB_SEQ: AtomicU64 = AtomicU64::new(1);
+
+const PAUSE_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(100);
+
+/// 작업 간 공유되는 취소·일시정지 플래그
+#[derive(Clone, Default)]
+pub struct CancelToken {
+    cancelled: Arc<AtomicBool>,
+    paused: Arc<AtomicBool>,
+}
+
+impl CancelToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+
+    pub fn set_paused(&self, paused: bool) {
+        self.paused.store(paused, Ordering::SeqCst);
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::SeqCst)
+    }
+
+    /// 일시정지 중이면 재개되거나 취소될 때까지 대기
+    pub fn wait_while_paused(&self) {
+        while self.is_paused() && !self.is_cancelled() {
+            std::thread::sleep(PAUSE_POLL_INTERVAL);
+        }
+    }
+
+    /// 일시정지 중이면 대기한 뒤, 취소되었으면 `AppError::Cancelled` 반환 (`?`로 루프 탈출용)
+    pub fn check(&self) -> Result<()> {
+        self.wait_while_paused();
+        if self.is_cancelled() {
+            Err(AppError::Cancelled(
+                "사용자가 작업을 취소했습니다".to_string(),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+fn job_registry() -> &'static Mutex<HashMap<String, CancelToken>> {
+    static JOBS: OnceLock<Mutex<HashMap<String, CancelToken>>> = OnceLock::new();
+    JOBS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// 새 작업 ID 발급 (예: `transfer-1760000000000-3`)
+pub fn new_job_id(prefix: &str) -> String {
+    let millis = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis())
+        .unwrap_or(0);
+    let seq = NEXT_JOB_SEQ.fetch_add(1, Ordering::Relaxed);
+    format!("{}-{}-{}", prefix, millis, seq)
+}
+
+/// RAII 핸들: 생성 시 레지스트리에 등록, 드롭 시 등록 해제
+pub struct JobHandle {
+    id: String,
+    token: CancelToken,
+}
+
+impl JobHandle {
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn token(&self) -> &CancelToken {
+        &self.token
+    }
+}
+
+impl Drop for JobHandle {
+    fn drop(&mut self) {
+        let mut jobs = job_registry().lock().unwrap_or_else(|e| e.into_inner());
+        jobs.remove(&self.id);
+    }
+}
+
+/// 작업 등록. 같은 ID가 이미 실행 중이면 `AlreadyExists`.
+pub fn register_job(id: String) -> Result<JobHandle> {
+    let mut jobs = job_registry().lock().unwrap_or_else(|e| e.into_inner());
+    if jobs.contains_key(&id) {
+        return Err(AppError::AlreadyExists(format!(
+            "이미 실행 중인 작업입니다: {}",
+            id
+        )));
+    }
+    let token = CancelToken::new();
+    jobs.insert(id.clone(), token.clone());
+    Ok(JobHandle { id, token })
+}
+
+/// 실행 중인 작업에 취소 요청. 해당 ID가 없으면(이미 끝남) false.
+pub fn cancel_job(id: &str) -> bool {
+    let jobs = job_registry().lock().unwrap_or_else(|e| e.into_inner());
+    match jobs.get(id) {
+        Some(token) => {
+            token.cancel();
+            true
+        }
+        None => false,
+    }
+}
+
+/// 실행 중인 작업 일시정지/재개. 해당 ID가 없으면 false.
+pub fn pause_job(id: &str, paused: bool) -> bool {
+    let jobs = job_registry().lock().unwrap_or_else(|e| e.into_inner());
+    match jobs.get(id) {
+        Some(token) => {
+            token.set_paused(paused);
+            true
+        }
+        None => false,
+    }
+}
+
+/// 현재 프로세스에서 실행 중인 작업인지 여부
+pub fn is_job_running(id: &str) -> bool {
+    let jobs = job_registry().lock().unwrap_or_else(|e| e.into_inner());
+    jobs.contains_key(id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cancel_job_flags_registered_token_until_handle_dropped() {
+        let handle = register_job(new_job_id("test")).unwrap();
+        let id = handle.id().to_string();
+        assert!(!handle.token().is_cancelled());
+        assert!(register_job(id.clone()).is_err());
+
+        assert!(cancel_job(&id));
+        assert!(handle.token().is_cancelled());
+        assert!(matches!(
+            handle.token().check(),
+            Err(AppError::Cancelled(_))
+        ));
+
+        drop(handle);
+        assert!(!cancel_job(&id));
+    }
+
+    #[test]
+    fn paused_token_blocks_check_until_resumed() {
+        let handle = register_job(new_job_id("test")).unwrap();
+        let id = handle.id().to_string();
+        assert!(is_job_running(&id));
+        assert!(pause_job(&id, true));
+        assert!(handle.token().is_paused());
+
+        let token = handle.token().clone();
+        let waiter = std::thread::spawn(move || token.check().is_ok());
+        std::thread::sleep(PAUSE_POLL_INTERVAL * 2);
+        assert!(!waiter.is_finished());
+
+        assert!(pause_job(&id, false));
+        assert!(waiter.join().unwrap());
+
+        drop(handle);
+        assert!(!is_job_running(&id));
+        assert!(!pause_job(&id, true));
+    }
+}
@@ -1,17 +1,28 @@
 //! 미디어 처리 모듈 (비디오/오디오 변환, 썸네일, 압축)
 
+mod audio;
 mod gif;
 mod pdf;
+mod probe;
+mod process;
 mod thumbnail;
 mod video;
 
+pub use audio::{
+    convert_audio, get_audio_metadata, get_audio_thumbnail_path, get_audio_waveform,
+    normalize_audio_loudness, trim_audio, AudioMetadata, AudioTags, AudioWaveform,
+};
 pub use gif::{compress_gif, gif_to_mp4};
 pub use pdf::compress_pdf;
+pub use probe::{probe_media, MediaProbe, MediaStream, MediaStreamKind};
+pub use process::cancel_media_job;
 pub(crate) use thumbnail::get_os_thumbnail;
 pub use thumbnail::{
     ensure_thumbnails_batch, get_video_thumbnail, get_video_thumbnail_path,
     invalidate_thumbnail_cache, ThumbnailBatchItem, ThumbnailBatchResult,
 };
 pub use video::{
-    compress_video, concat_videos, cut_video, trim_video, video_to_gif, VideoProgress,
+    compress_video, compress_videos_batch, concat_videos, cut_video, list_video_keyframes,
+    trim_video, video_to_gif, VideoBatchItem, VideoBatchOptions, VideoBatchProgress, VideoEditMode,
+    VideoProgress,
 };
@@ -0,0 +1,221 @@
+//! 오디오 메타데이터·앨범 아트 썸네일·파형·변환 모듈
+
+mod convert;
+mod metadata;
+mod waveform;
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::modules::archive_ops::materialize_archive_path_in_cache;
+use crate::modules::error::{AppError, Result};
+use crate::modules::image_ops::ensure_cached_thumbnail;
+use crate::modules::media_ops::process::MediaJob;
+use crate::modules::media_ops::VideoProgress;
+
+pub use waveform::AudioWaveform;
+
+/// 주요 태그 (ID3·Vorbis comment·MP4 ilst 공통 이름으로 정규화)
+#[derive(serde::Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AudioTags {
+    pub title: Option<String>,
+    pub artist: Option<String>,
+    pub album: Option<String>,
+    pub album_artist: Option<String>,
+    pub year: Option<String>,
+    /// "3" 또는 "3/12"
+    pub track: Option<String>,
+    pub genre: Option<String>,
+    pub comment: Option<String>,
+    /// 그 밖의 태그 (작곡가·디스크·BPM 등, 소문자 키)
+    pub other: BTreeMap<String, String>,
+}
+
+#[derive(serde::Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AudioMetadata {
+    /// 컨테이너 (mp3, wav, flac, ogg, opus, m4a / 해석하지 않는 aac, aiff, wma)
+    pub format: String,
+    pub codec: Option<String>,
+    pub duration_sec: Option<f64>,
+    pub sample_rate: Option<u32>,
+    pub channels: Option<u16>,
+    pub bits_per_sample: Option<u16>,
+    /// 헤더에 없으면 파일 크기와 길이로 계산한 평균값
+    pub bitrate_kbps: Option<u32>,
+    pub tags: AudioTags,
+    /// 내장 앨범 아트 여부
+    pub has_cover: bool,
+    /// 헤더를 해석한 형식인지 (false면 aac·aiff·wma처럼 `format`만 채워지고 나머지는 비어 있음)
+    pub supported: bool,
+}
+
+/// 내장 앨범 아트 원본 (ID3 APIC, FLAC PICTURE, MP4 covr)
+struct CoverArt {
+    mime: String,
+    data: Vec<u8>,
+}
+
+fn read_audio(path: &Path) -> Result<metadata::Parsed> {
+    let ext = path
+        .extension()
+        .map(|e| e.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    let mut file = std::fs::File::open(path)?;
+    metadata::parse_audio_file(&mut file, &ext).ok_or_else(|| {
+        AppError::InvalidInput(format!(
+            "지원하지 않는 오디오 형식입니다: {}",
+            path.display()
+        ))
+    })
+}
+
+/// 앨범 아트 → PNG 썸네일 (아트가 없거나 디코딩할 수 없는 형식이면 None)
+fn cover_thumbnail_bytes(path: &str, size: u32) -> Result<Option<Vec<u8>>> {
+    let Ok(parsed) = read_audio(Path::new(path)) else {
+        return Ok(None);
+    };
+    let Some(cover) = parsed.cover else {
+        return Ok(None);
+    };
+    let img = match image::load_from_memory(&cover.data) {
+        Ok(img) => img,
+        Err(e) => {
+            log::debug!("앨범 아트 디코딩 실패 ({}) {}: {}", cover.mime, path, e);
+            return Ok(None);
+        }
+    };
+    let thumb = img.thumbnail(size, size);
+    let mut buf = vec![];
+    thumb.write_to(&mut std::io::Cursor::new(&mut buf), image::ImageFormat::Png)?;
+    Ok(Some(buf))
+}
+
+/// 길이·샘플레이트·채널·비트레이트와 태그 조회
+#[tauri::command]
+pub async fn get_audio_metadata(app: tauri::AppHandle, path: String) -> Result<AudioMetadata> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let resolved_path = materialize_archive_path_in_cache(&app, &path)?
+            .unwrap_or_else(|| std::path::PathBuf::from(&path));
+        read_audio(&resolved_path).map(|parsed| parsed.meta)
+    })
+    .await
+    .map_err(|e| AppError::Internal(format!("오디오 정보 조회 태스크 실패: {}", e)))?
+}
+
+/// 앨범 아트 썸네일 캐시 PNG 경로 (asset 프로토콜용, 아트가 없으면 None)
+#[tauri::command]
+pub async fn get_audio_thumbnail_path(
+    app: tauri::AppHandle,
+    path: String,
+    size: u32,
+) -> Result<Option<String>> {
+    use tauri::Manager;
+
+    let app_cache = app
+        .path()
+        .app_cache_dir()
+        .map_err(|e: tauri::Error| AppError::Internal(e.to_string()))?;
+    let cache_dir = app_cache.join("audio_thumbnails");
+
+    tauri::async_runtime::spawn_blocking(move || -> Result<Option<String>> {
+        let resolved_path = materialize_archive_path_in_cache(&app, &path)?
+            .unwrap_or_else(|| std::path::PathBuf::from(&path));
+        let resolved_path_str = resolved_path.to_string_lossy().to_string();
+        // 클라우드 파일은 태그를 읽으려면 전체를 내려받아야 하므로 아이콘으로 둔다
+        if crate::helpers::is_cloud_path(&resolved_path_str) {
+            return Ok(None);
+        }
+        let cache_path =
+            ensure_cached_thumbnail(&cache_dir, &resolved_path_str, size, false, false, || {
+                cover_thumbnail_bytes(&resolved_path_str, size)
+            })?;
+        Ok(cache_path.map(|p| p.to_string_lossy().to_string()))
+    })
+    .await
+    .map_err(|e| AppError::Internal(format!("앨범 아트 썸네일 태스크 실패: {}", e)))?
+}
+
+/// 파형 미리보기: 너비만큼의 구간별 최소/최대값과 같은 모양의 PNG
+#[tauri::command]
+pub async fn get_audio_waveform(
+    app: tauri::AppHandle,
+    path: String,
+    width: u32,
+    height: u32,
+) -> Result<AudioWaveform> {
+    use tauri::Manager;
+
+    let app_cache = app
+        .path()
+        .app_cache_dir()
+        .map_err(|e: tauri::Error| AppError::Internal(e.to_string()))?;
+    let cache_dir = app_cache.join("audio_waveforms");
+    let width = width.clamp(16, waveform::MAX_BUCKETS);
+    let height = height.clamp(8, 1024);
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let resolved_path = materialize_archive_path_in_cache(&app, &path)?
+            .unwrap_or_else(|| std::path::PathBuf::from(&path));
+        waveform::load_or_render_waveform(
+            &cache_dir,
+            &resolved_path.to_string_lossy(),
+            width,
+            height,
+        )
+    })
+    .await
+    .map_err(|e| AppError::Internal(format!("파형 생성 태스크 실패: {}", e)))?
+}
+
+/// 포맷 변환 (`format`: wav/mp3/ogg/flac/m4a, `quality`: low/medium/high 비트레이트 프리셋)
+#[tauri::command]
+pub async fn convert_audio(
+    input: String,
+    format: String,
+    quality: String,
+    job_id: Option<String>,
+    on_progress: tauri::ipc::Channel<VideoProgress>,
+) -> Result<String> {
+    let job = MediaJob::start(job_id)?;
+    tauri::async_runtime::spawn_blocking(move || {
+        convert::convert_audio(&input, &format, &quality, &on_progress, &job)
+    })
+    .await
+    .map_err(|e| AppError::Internal(format!("오디오 변환 태스크 실패: {}", e)))?
+}
+
+/// EBU R128 라우드니스 정규화 (기본 -23 LUFS, 트루 피크 -1 dBTP)
+#[tauri::command]
+pub async fn normalize_audio_loudness(
+    input: String,
+    target_lufs: Option<f64>,
+    job_id: Option<String>,
+    on_progress: tauri::ipc::Channel<VideoProgress>,
+) -> Result<String> {
+    let target_lufs = target_lufs.unwrap_or(convert::DEFAULT_TARGET_LUFS);
+    let job = MediaJob::start(job_id)?;
+    tauri::async_runtime::spawn_blocking(move || {
+        convert::normalize_audio_loudness(&input, target_lufs, &on_progress, &job)
+    })
+    .await
+    .map_err(|e| AppError::Internal(format!("라우드니스 정규화 태스크 실패: {}", e)))?
+}
+
+/// 구간 자르기 (원본 형식 유지)
+#[tauri::command]
+pub async fn trim_audio(
+    input: String,
+    start_sec: f64,
+    end_sec: f64,
+    job_id: Option<String>,
+    on_progress: tauri::ipc::Channel<VideoProgress>,
+) -> Result<String> {
+    let job = MediaJob::start(job_id)?;
+    tauri::async_runtime::spawn_blocking(move || {
+        convert::trim_audio(&input, start_sec, end_sec, &on_progress, &job)
+    })
+    .await
+    .map_err(|e| AppError::Internal(format!("오디오 자르기 태스크 실패: {}", e)))?
+}
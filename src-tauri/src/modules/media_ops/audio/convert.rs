@@ -0,0 +1,449 @@
+//! 오디오 포맷 변환·라우드니스 정규화·구간 자르기 (FFmpeg)
+
+use std::path::Path;
+
+use super::super::video::{parse_ffmpeg_time, run_edit_encode_attempt};
+use crate::helpers::find_unique_path;
+use crate::modules::error::{AppError, Result};
+use crate::modules::media_ops::process::MediaJob;
+use crate::modules::media_ops::VideoProgress;
+use crate::modules::tool_ops::find_ffmpeg_path;
+
+/// EBU R128 기본 목표 (방송 기준 -23 LUFS, 트루 피크 -1 dBTP)
+pub(super) const DEFAULT_TARGET_LUFS: f64 = -23.0;
+const TARGET_TRUE_PEAK: f64 = -1.0;
+const TARGET_LRA: f64 = 7.0;
+
+/// 인코더 후보 (라벨은 에러 메시지용)
+struct AudioEncoderAttempt {
+    label: &'static str,
+    audio_args: Vec<String>,
+}
+
+fn args(list: &[&str]) -> Vec<String> {
+    list.iter().map(|s| s.to_string()).collect()
+}
+
+/// 출력 포맷 → 확장자 (지원하지 않으면 None)
+fn output_extension(format: &str) -> Option<&'static str> {
+    match format {
+        "wav" => Some(".wav"),
+        "mp3" => Some(".mp3"),
+        "ogg" => Some(".ogg"),
+        "flac" => Some(".flac"),
+        "m4a" => Some(".m4a"),
+        _ => None,
+    }
+}
+
+/// 원본 확장자로 재인코딩할 포맷 결정 (인코더가 없는 형식은 무손실 FLAC)
+fn same_format_for(ext: &str) -> &'static str {
+    match ext {
+        "wav" => "wav",
+        "mp3" => "mp3",
+        "ogg" | "oga" => "ogg",
+        "m4a" | "aac" => "m4a",
+        _ => "flac",
+    }
+}
+
+/// 포맷·품질별 인코더 후보 체인. 품질은 low/medium/high 비트레이트 프리셋.
+fn encoder_candidates(format: &str, quality: &str) -> Vec<AudioEncoderAttempt> {
+    let pick = |low: &'static str, medium: &'static str, high: &'static str| match quality {
+        "low" => low,
+        "high" => high,
+        _ => medium,
+    };
+    match format {
+        "wav" => vec![AudioEncoderAttempt {
+            label: "pcm",
+            audio_args: args(&["-c:a", pick("pcm_s16le", "pcm_s16le", "pcm_s24le")]),
+        }],
+        "mp3" => vec![AudioEncoderAttempt {
+            label: "libmp3lame",
+            audio_args: args(&["-c:a", "libmp3lame", "-b:a", pick("128k", "192k", "320k")]),
+        }],
+        "ogg" => vec![
+            AudioEncoderAttempt {
+                label: "libvorbis",
+                audio_args: args(&["-c:a", "libvorbis", "-q:a", pick("3", "5", "8")]),
+            },
+            // libvorbis가 없는 빌드용 내장 인코더 (실험적, 스테레오만 지원)
+            AudioEncoderAttempt {
+                label: "vorbis",
+                audio_args: args(&[
+                    "-c:a",
+                    "vorbis",
+                    "-strict",
+                    "-2",
+                    "-ac",
+                    "2",
+                    "-q:a",
+                    pick("3", "5", "8"),
+                ]),
+            },
+        ],
+        "flac" => vec![AudioEncoderAttempt {
+            label: "flac",
+            audio_args: args(&["-c:a", "flac", "-compression_level", "5"]),
+        }],
+        "m4a" => vec![AudioEncoderAttempt {
+            label: "aac",
+            audio_args: args(&[
+                "-c:a",
+                "aac",
+                "-b:a",
+                pick("128k", "192k", "256k"),
+                "-movflags",
+                "+faststart",
+            ]),
+        }],
+        _ => Vec::new(),
+    }
+}
+
+/// 오디오 스트림 하나만 재인코딩하는 ffmpeg 인자 (태그 유지, 앨범 아트·영상 제외)
+fn build_audio_args(
+    input: &str,
+    output: &str,
+    filter: Option<&str>,
+    extra_args: &[String],
+    audio_args: &[String],
+) -> Vec<String> {
+    let mut list = args(&["-y", "-i", input, "-map", "0:a:0", "-map_metadata", "0"]);
+    if let Some(filter) = filter {
+        list.extend(["-af".to_string(), filter.to_string()]);
+    }
+    list.extend(audio_args.iter().cloned());
+    list.extend(extra_args.iter().cloned());
+    list.extend(args(&["-progress", "pipe:1", output]));
+    list
+}
+
+fn format_seconds(sec: f64) -> String {
+    format!("{:.3}", sec.max(0.0))
+}
+
+fn trim_filter(start_sec: f64, end_sec: f64) -> String {
+    format!(
+        "atrim=start={}:end={},asetpts=PTS-STARTPTS",
+        format_seconds(start_sec),
+        format_seconds(end_sec)
+    )
+}
+
+fn ffmpeg_command(ffmpeg_path: &Path) -> std::process::Command {
+    #[allow(unused_mut)]
+    let mut cmd = std::process::Command::new(ffmpeg_path);
+    // Windows: 콘솔 창 숨기기
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt;
+        cmd.creation_flags(0x08000000);
+    }
+    cmd
+}
+
+/// 진행률 기준 길이. 헤더로 알 수 없으면 ffmpeg 배너의 `Duration:`을 읽는다.
+fn probe_duration(ffmpeg_path: &Path, input: &str) -> Option<f64> {
+    if let Some(duration) = super::read_audio(Path::new(input))
+        .ok()
+        .and_then(|parsed| parsed.meta.duration_sec)
+    {
+        return Some(duration);
+    }
+    let output = ffmpeg_command(ffmpeg_path)
+        .args(["-hide_banner", "-i", input])
+        .output()
+        .ok()?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let time = stderr
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("Duration:"))?
+        .split(',')
+        .next()?
+        .trim();
+    let secs = parse_ffmpeg_time(time) as f64;
+    (secs > 0.0).then_some(secs)
+}
+
+/// 1차 측정 결과 (loudnorm print_format=json)
+#[derive(Debug, PartialEq)]
+struct LoudnessMeasurement {
+    input_i: String,
+    input_tp: String,
+    input_lra: String,
+    input_thresh: String,
+    target_offset: String,
+}
+
+/// ffmpeg stderr 끝의 loudnorm JSON 블록 파싱
+fn parse_loudnorm_output(stderr: &str) -> Option<LoudnessMeasurement> {
+    let start = stderr.rfind('{')?;
+    let end = stderr[start..].find('}')? + start;
+    let json: serde_json::Value = serde_json::from_str(&stderr[start..=end]).ok()?;
+    let field = |name: &str| -> Option<String> {
+        let value = json.get(name)?.as_str()?.trim();
+        // 무음 파일은 -inf가 나와 2차 패스 인자로 쓸 수 없다
+        value
+            .parse::<f64>()
+            .ok()
+            .filter(|v| v.is_finite())
+            .map(|_| value.to_string())
+    };
+    Some(LoudnessMeasurement {
+        input_i: field("input_i")?,
+        input_tp: field("input_tp")?,
+        input_lra: field("input_lra")?,
+        input_thresh: field("input_thresh")?,
+        target_offset: field("target_offset")?,
+    })
+}
+
+fn loudnorm_targets(target_lufs: f64) -> String {
+    format!(
+        "I={}:TP={}:LRA={}",
+        target_lufs, TARGET_TRUE_PEAK, TARGET_LRA
+    )
+}
+
+/// 2차 패스 필터: 측정값을 넣어 선형 보정 (다이내믹 모드보다 음색 변화가 적다)
+fn loudnorm_apply_filter(target_lufs: f64, measured: &LoudnessMeasurement) -> String {
+    format!(
+        "loudnorm={}:measured_I={}:measured_TP={}:measured_LRA={}:measured_thresh={}:offset={}:linear=true",
+        loudnorm_targets(target_lufs),
+        measured.input_i,
+        measured.input_tp,
+        measured.input_lra,
+        measured.input_thresh,
+        measured.target_offset
+    )
+}
+
+fn measure_loudness(
+    ffmpeg_path: &Path,
+    input: &str,
+    target_lufs: f64,
+    job: &MediaJob,
+) -> Result<LoudnessMeasurement> {
+    let filter = format!(
+        "loudnorm={}:print_format=json",
+        loudnorm_targets(target_lufs)
+    );
+    let output = job.output(
+        ffmpeg_command(ffmpeg_path)
+            .args([
+                "-hide_banner",
+                "-nostats",
+                "-i",
+                input,
+                "-map",
+                "0:a:0",
+                "-af",
+            ])
+            .arg(&filter)
+            .args(["-f", "null", "-"]),
+    );
+    job.check()?;
+    let output = output?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    parse_loudnorm_output(&stderr).ok_or_else(|| {
+        AppError::VideoProcessing(format!(
+            "라우드니스 측정 실패: {}",
+            stderr.lines().last().unwrap_or("(출력 없음)")
+        ))
+    })
+}
+
+/// 입력 옆에 출력 경로 결정 (`{이름}{suffix}.{포맷}`, 충돌 시 번호)
+fn output_path_for(input: &str, suffix: &str, ext: &str) -> std::path::PathBuf {
+    let input_path = Path::new(input);
+    let stem = input_path.file_stem().unwrap_or_default().to_string_lossy();
+    let parent = input_path.parent().unwrap_or(Path::new("."));
+    find_unique_path(parent, &stem, suffix, ext)
+}
+
+fn input_extension(input: &str) -> String {
+    Path::new(input)
+        .extension()
+        .map(|ext| ext.to_string_lossy().to_lowercase())
+        .unwrap_or_default()
+}
+
+/// 인코더 후보를 순서대로 시도해 첫 성공 출력 경로 반환 (취소되면 부분 출력 삭제).
+/// `range`(시작, 끝 초)를 주면 진행률 기준을 입력 전체가 아닌 출력 구간 길이로 잡는다.
+#[allow(clippy::too_many_arguments)]
+fn encode_with_fallback(
+    input: &str,
+    output_path: &Path,
+    candidates: Vec<AudioEncoderAttempt>,
+    filter: Option<&str>,
+    extra_args: &[String],
+    range: Option<(f64, f64)>,
+    on_progress: &tauri::ipc::Channel<VideoProgress>,
+    job: &MediaJob,
+) -> Result<String> {
+    let ffmpeg_path = find_ffmpeg_path().ok_or_else(|| AppError::ToolNotFound {
+        tool: "FFmpeg".to_string(),
+    })?;
+    // 길이를 모르면 진행률은 0에서 머물다 완료로 넘어간다
+    let input_duration = probe_duration(&ffmpeg_path, input);
+    let duration = match (range, input_duration) {
+        (Some((start, end)), Some(total)) => (end.min(total) - start.max(0.0)).max(0.0),
+        (Some((start, end)), None) => end - start.max(0.0),
+        (None, Some(total)) => total,
+        (None, None) => f64::INFINITY,
+    } as f32;
+    let output_str = output_path.to_string_lossy().to_string();
+
+    let mut errors: Vec<String> = Vec::new();
+    for attempt in candidates {
+        let list = build_audio_args(input, &output_str, filter, extra_args, &attempt.audio_args);
+        match run_edit_encode_attempt(&ffmpeg_path, &list, duration, output_path, on_progress, job)
+        {
+            Ok(()) => return Ok(output_str),
+            Err(e) => {
+                job.check_and_discard(output_path)?;
+                log::warn!("오디오 인코더 {} 실패: {}", attempt.label, e);
+                errors.push(format!("[{}] {e}", attempt.label));
+            }
+        }
+    }
+    Err(AppError::VideoProcessing(errors.join(" / ")))
+}
+
+/// wav/mp3/ogg/flac/m4a 간 변환
+pub(super) fn convert_audio(
+    input: &str,
+    format: &str,
+    quality: &str,
+    on_progress: &tauri::ipc::Channel<VideoProgress>,
+    job: &MediaJob,
+) -> Result<String> {
+    let ext = output_extension(format).ok_or_else(|| {
+        AppError::InvalidInput(format!("지원하지 않는 오디오 출력 형식입니다: {}", format))
+    })?;
+    let output_path = output_path_for(input, "", ext);
+    encode_with_fallback(
+        input,
+        &output_path,
+        encoder_candidates(format, quality),
+        None,
+        &[],
+        None,
+        on_progress,
+        job,
+    )
+}
+
+/// EBU R128 2패스 라우드니스 정규화 (`{이름}_norm`, 원본 형식 유지)
+pub(super) fn normalize_audio_loudness(
+    input: &str,
+    target_lufs: f64,
+    on_progress: &tauri::ipc::Channel<VideoProgress>,
+    job: &MediaJob,
+) -> Result<String> {
+    if !(-70.0..=-5.0).contains(&target_lufs) {
+        return Err(AppError::InvalidInput(format!(
+            "목표 라우드니스는 -70 ~ -5 LUFS 사이여야 합니다: {}",
+            target_lufs
+        )));
+    }
+    let ffmpeg_path = find_ffmpeg_path().ok_or_else(|| AppError::ToolNotFound {
+        tool: "FFmpeg".to_string(),
+    })?;
+    let measured = measure_loudness(&ffmpeg_path, input, target_lufs, job)?;
+    let filter = loudnorm_apply_filter(target_lufs, &measured);
+
+    // loudnorm은 내부적으로 192kHz로 올려 처리하므로 원래 샘플레이트로 되돌린다
+    let sample_rate = super::read_audio(Path::new(input))
+        .ok()
+        .and_then(|parsed| parsed.meta.sample_rate)
+        .unwrap_or(48_000);
+    let format = same_format_for(&input_extension(input));
+    let ext = output_extension(format).unwrap_or(".flac");
+    let output_path = output_path_for(input, "_norm", ext);
+    encode_with_fallback(
+        input,
+        &output_path,
+        encoder_candidates(format, "high"),
+        Some(&filter),
+        &["-ar".to_string(), sample_rate.to_string()],
+        None,
+        on_progress,
+        job,
+    )
+}
+
+/// 구간 자르기 (`{이름}_trim`, 원본 형식으로 재인코딩해 샘플 단위로 정확히 자른다)
+pub(super) fn trim_audio(
+    input: &str,
+    start_sec: f64,
+    end_sec: f64,
+    on_progress: &tauri::ipc::Channel<VideoProgress>,
+    job: &MediaJob,
+) -> Result<String> {
+    if !(start_sec.is_finite() && end_sec.is_finite()) || end_sec <= start_sec.max(0.0) {
+        return Err(AppError::InvalidInput(format!(
+            "잘못된 구간입니다: {} ~ {}",
+            start_sec, end_sec
+        )));
+    }
+    let format = same_format_for(&input_extension(input));
+    let ext = output_extension(format).unwrap_or(".flac");
+    let output_path = output_path_for(input, "_trim", ext);
+    encode_with_fallback(
+        input,
+        &output_path,
+        encoder_candidates(format, "high"),
+        Some(&trim_filter(start_sec, end_sec)),
+        &[],
+        Some((start_sec, end_sec)),
+        on_progress,
+        job,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_encoder_args_and_parses_loudnorm_measurement() {
+        let attempts = encoder_candidates("mp3", "high");
+        let list = build_audio_args(
+            "in.wav",
+            "out.mp3",
+            Some(&trim_filter(1.5, 4.0)),
+            &[],
+            &attempts[0].audio_args,
+        );
+        assert!(list.windows(2).any(|p| p == ["-b:a", "320k"]));
+        assert!(list.windows(2).any(|p| p == ["-map", "0:a:0"]));
+        assert!(list
+            .windows(2)
+            .any(|p| p[0] == "-af" && p[1] == "atrim=start=1.500:end=4.000,asetpts=PTS-STARTPTS"));
+        assert_eq!(list.last().map(String::as_str), Some("out.mp3"));
+        assert_eq!(encoder_candidates("ogg", "low").len(), 2);
+        assert!(encoder_candidates("opus", "medium").is_empty());
+        assert_eq!(same_format_for("aiff"), "flac");
+
+        let stderr = r#"[Parsed_loudnorm_0 @ 0x7f]
+{
+	"input_i" : "-16.52",
+	"input_tp" : "-0.31",
+	"input_lra" : "5.40",
+	"input_thresh" : "-26.78",
+	"output_i" : "-23.01",
+	"target_offset" : "0.01"
+}"#;
+        let measured = parse_loudnorm_output(stderr).unwrap();
+        assert_eq!(measured.input_i, "-16.52");
+        let filter = loudnorm_apply_filter(DEFAULT_TARGET_LUFS, &measured);
+        assert!(filter.starts_with("loudnorm=I=-23:TP=-1:LRA=7:measured_I=-16.52"));
+        assert!(filter.ends_with("offset=0.01:linear=true"));
+
+        // 무음 파일(-inf)은 측정 실패로 처리
+        assert!(parse_loudnorm_output(&stderr.replace("-16.52", "-inf")).is_none());
+    }
+}
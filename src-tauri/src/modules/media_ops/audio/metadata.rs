@@ -0,0 +1,1079 @@
+//! 오디오 컨테이너별 스트림 정보·태그·앨범 아트 파서
+//!
+//! FFmpeg 없이 헤더만 읽는다: MP3(ID3v1/v2 + MPEG 프레임/Xing), WAV(fmt/LIST INFO/id3),
+//! FLAC(STREAMINFO/VORBIS_COMMENT/PICTURE), Ogg Vorbis·Opus, MP4/M4A(mvhd/stsd/ilst).
+
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+
+use super::{AudioMetadata, AudioTags, CoverArt};
+
+/// 태그·앨범 아트 블록을 읽는 상한 (이보다 큰 블록은 손상으로 보고 건너뜀)
+const MAX_BLOCK_BYTES: u64 = 32 * 1024 * 1024;
+/// Ogg 마지막 페이지(전체 길이)를 찾을 꼬리 크기
+const OGG_TAIL_BYTES: u64 = 64 * 1024;
+
+/// 파싱 결과 (스트림 정보 + 태그 + 첫 앨범 아트)
+#[derive(Default)]
+pub(super) struct Parsed {
+    pub meta: AudioMetadata,
+    pub cover: Option<CoverArt>,
+}
+
+fn read_exact_at(file: &mut File, offset: u64, len: usize) -> Option<Vec<u8>> {
+    file.seek(SeekFrom::Start(offset)).ok()?;
+    let mut buf = vec![0u8; len];
+    file.read_exact(&mut buf).ok()?;
+    Some(buf)
+}
+
+fn read_block(file: &mut File, offset: u64, len: u64) -> Option<Vec<u8>> {
+    if len > MAX_BLOCK_BYTES {
+        return None;
+    }
+    read_exact_at(file, offset, len as usize)
+}
+
+fn be_u32(b: &[u8], at: usize) -> Option<u32> {
+    Some(u32::from_be_bytes(b.get(at..at + 4)?.try_into().ok()?))
+}
+
+fn le_u32(b: &[u8], at: usize) -> Option<u32> {
+    Some(u32::from_le_bytes(b.get(at..at + 4)?.try_into().ok()?))
+}
+
+fn be_u16(b: &[u8], at: usize) -> Option<u16> {
+    Some(u16::from_be_bytes(b.get(at..at + 2)?.try_into().ok()?))
+}
+
+fn le_u16(b: &[u8], at: usize) -> Option<u16> {
+    Some(u16::from_le_bytes(b.get(at..at + 2)?.try_into().ok()?))
+}
+
+/// 앨범 아트 후보 중 앞표지(picture type 3)를 우선
+fn keep_cover(slot: &mut Option<CoverArt>, cover: CoverArt, picture_type: u32) {
+    if cover.data.is_empty() {
+        return;
+    }
+    if slot.is_none() || picture_type == 3 {
+        *slot = Some(cover);
+    }
+}
+
+/// 오디오로 분류되지만 헤더를 해석하지 않는 형식 (ADTS AAC, AIFF, ASF/WMA)
+fn unparsed_audio_format(head: &[u8], ext: &str) -> Option<&'static str> {
+    const ASF_GUID: &[u8] = b"\x30\x26\xB2\x75\x8E\x66\xCF\x11";
+    if head.len() >= 2 && head[0] == 0xFF && head[1] & 0xF6 == 0xF0 {
+        Some("aac")
+    } else if head.starts_with(b"FORM") && matches!(head.get(8..12), Some(b"AIFF" | b"AIFC")) {
+        Some("aiff")
+    } else if head.starts_with(ASF_GUID) {
+        Some("wma")
+    } else {
+        match ext {
+            "aac" => Some("aac"),
+            "aif" | "aiff" => Some("aiff"),
+            "wma" => Some("wma"),
+            _ => None,
+        }
+    }
+}
+
+/// 파일 앞부분 시그니처로 컨테이너를 골라 파싱 (오디오가 아니면 None).
+/// AAC·AIFF·WMA는 형식만 채우고 `supported: false`로 돌려준다.
+pub(super) fn parse_audio_file(file: &mut File, ext: &str) -> Option<Parsed> {
+    let file_size = file.metadata().ok()?.len();
+    let head = {
+        let mut buf = Vec::new();
+        file.seek(SeekFrom::Start(0)).ok()?;
+        (&mut *file).take(64).read_to_end(&mut buf).ok()?;
+        buf
+    };
+    let mut parsed = if head.starts_with(b"RIFF") && head.get(8..12) == Some(&b"WAVE"[..]) {
+        parse_wav(file, file_size)?
+    } else if head.starts_with(b"fLaC") {
+        parse_flac(file, 0)?
+    } else if head.starts_with(b"OggS") {
+        parse_ogg(file, file_size)?
+    } else if head.get(4..8) == Some(&b"ftyp"[..]) {
+        parse_mp4(file, file_size)?
+    } else if let Some(format) = unparsed_audio_format(&head, ext) {
+        // MPEG 프레임 동기로 오인해 엉뚱한 값을 채우지 않도록 ID3·mp3 판별보다 먼저 확인
+        let mut parsed = Parsed::default();
+        parsed.meta.format = format.to_string();
+        return Some(parsed);
+    } else if head.starts_with(b"ID3") {
+        // ID3 뒤에 FLAC이 오는 경우도 있다
+        let tag_len = id3_total_len(&head)?;
+        match read_exact_at(file, tag_len, 4) {
+            Some(magic) if magic == b"fLaC" => {
+                let mut parsed = parse_flac(file, tag_len)?;
+                if let Some(tag) = read_block(file, 0, tag_len) {
+                    parse_id3v2(&tag, &mut parsed);
+                }
+                parsed
+            }
+            _ => parse_mp3(file, file_size)?,
+        }
+    } else if ext == "mp3" || (head.len() >= 2 && head[0] == 0xFF && head[1] & 0xE0 == 0xE0) {
+        parse_mp3(file, file_size)?
+    } else {
+        return None;
+    };
+
+    let meta = &mut parsed.meta;
+    if meta.bitrate_kbps.is_none() {
+        if let Some(duration) = meta.duration_sec.filter(|d| *d > 0.0) {
+            meta.bitrate_kbps = Some((file_size as f64 * 8.0 / duration / 1000.0).round() as u32);
+        }
+    }
+    meta.has_cover = parsed.cover.is_some();
+    meta.supported = true;
+    Some(parsed)
+}
+
+// ===== ID3 =====
+
+/// ID3v2 태그 전체 길이 (헤더 10바이트 + 본문 + 푸터)
+fn id3_total_len(head: &[u8]) -> Option<u64> {
+    if !head.starts_with(b"ID3") || head.len() < 10 {
+        return None;
+    }
+    let size = syncsafe(&head[6..10]) as u64;
+    let footer = if head[5] & 0x10 != 0 { 10 } else { 0 };
+    Some(10 + size + footer)
+}
+
+fn syncsafe(b: &[u8]) -> u32 {
+    b.iter()
+        .fold(0u32, |acc, byte| (acc << 7) | (*byte as u32 & 0x7F))
+}
+
+/// ID3 인코딩 바이트에 따라 문자열 해석 (0: Latin-1, 1: BOM UTF-16, 2: UTF-16BE, 3: UTF-8)
+fn decode_id3_text(encoding: u8, bytes: &[u8]) -> String {
+    let text = match encoding {
+        1 | 2 => {
+            let (big_endian, body) = match bytes {
+                [0xFE, 0xFF, rest @ ..] => (true, rest),
+                [0xFF, 0xFE, rest @ ..] => (false, rest),
+                _ => (encoding == 2, bytes),
+            };
+            let units: Vec<u16> = body
+                .chunks_exact(2)
+                .map(|c| {
+                    if big_endian {
+                        u16::from_be_bytes([c[0], c[1]])
+                    } else {
+                        u16::from_le_bytes([c[0], c[1]])
+                    }
+                })
+                .collect();
+            String::from_utf16_lossy(&units)
+        }
+        3 => String::from_utf8_lossy(bytes).to_string(),
+        _ => bytes.iter().map(|b| *b as char).collect(),
+    };
+    text.trim_end_matches('\0').trim().to_string()
+}
+
+/// 인코딩별 널 종료 위치 (UTF-16은 2바이트 정렬 00 00)
+fn id3_terminator(encoding: u8, bytes: &[u8]) -> Option<(usize, usize)> {
+    if matches!(encoding, 1 | 2) {
+        (0..bytes.len().saturating_sub(1))
+            .step_by(2)
+            .find(|&i| bytes[i] == 0 && bytes[i + 1] == 0)
+            .map(|i| (i, i + 2))
+    } else {
+        bytes.iter().position(|b| *b == 0).map(|i| (i, i + 1))
+    }
+}
+
+fn set_tag(tags: &mut AudioTags, key: &str, value: String) {
+    if value.is_empty() {
+        return;
+    }
+    let slot = match key {
+        "title" => &mut tags.title,
+        "artist" => &mut tags.artist,
+        "album" => &mut tags.album,
+        "album_artist" => &mut tags.album_artist,
+        "year" => &mut tags.year,
+        "track" => &mut tags.track,
+        "genre" => &mut tags.genre,
+        "comment" => &mut tags.comment,
+        _ => {
+            tags.other.entry(key.to_string()).or_insert(value);
+            return;
+        }
+    };
+    if slot.is_none() {
+        *slot = Some(value);
+    }
+}
+
+fn id3_frame_key(id: &str) -> Option<&'static str> {
+    Some(match id {
+        "TIT2" | "TT2" => "title",
+        "TPE1" | "TP1" => "artist",
+        "TALB" | "TAL" => "album",
+        "TPE2" | "TP2" => "album_artist",
+        "TYER" | "TDRC" | "TYE" => "year",
+        "TRCK" | "TRK" => "track",
+        "TCON" | "TCO" => "genre",
+        "COMM" | "COM" => "comment",
+        "TCOM" | "TCM" => "composer",
+        "TPOS" | "TPA" => "disc",
+        "TBPM" | "TBP" => "bpm",
+        "TKEY" | "TKE" => "key",
+        _ => return None,
+    })
+}
+
+/// ID3v2.2~2.4 태그 본문 파싱 (`tag`는 헤더 포함 전체)
+fn parse_id3v2(tag: &[u8], parsed: &mut Parsed) {
+    if tag.len() < 10 || !tag.starts_with(b"ID3") {
+        return;
+    }
+    let version = tag[3];
+    let flags = tag[5];
+    let end = (10 + syncsafe(&tag[6..10]) as usize).min(tag.len());
+    let mut pos = 10;
+    // 확장 헤더 건너뛰기
+    if flags & 0x40 != 0 && version >= 3 {
+        let Some(size) = be_u32(tag, 10) else { return };
+        pos += if version == 4 {
+            syncsafe(&tag[10..14]) as usize
+        } else {
+            size as usize + 4
+        };
+    }
+    let (id_len, header_len) = if version == 2 { (3, 6) } else { (4, 10) };
+
+    while pos + header_len <= end {
+        let id = &tag[pos..pos + id_len];
+        if id[0] == 0 {
+            break;
+        }
+        let size = match version {
+            2 => u32::from_be_bytes([0, tag[pos + 3], tag[pos + 4], tag[pos + 5]]) as usize,
+            4 => syncsafe(&tag[pos + 4..pos + 8]) as usize,
+            _ => be_u32(tag, pos + 4).unwrap_or(0) as usize,
+        };
+        let body_start = pos + header_len;
+        let Some(body) = tag.get(body_start..body_start + size) else {
+            break;
+        };
+        pos = body_start + size;
+        let id = String::from_utf8_lossy(id).to_string();
+        let Some((&encoding, rest)) = body.split_first() else {
+            continue;
+        };
+
+        if id == "APIC" || id == "PIC" {
+            let (mime, rest) = if id == "PIC" {
+                let format =
+                    String::from_utf8_lossy(rest.get(..3).unwrap_or_default()).to_lowercase();
+                (
+                    format!("image/{}", format),
+                    rest.get(3..).unwrap_or_default(),
+                )
+            } else {
+                let Some(nul) = rest.iter().position(|b| *b == 0) else {
+                    continue;
+                };
+                (
+                    String::from_utf8_lossy(&rest[..nul]).to_string(),
+                    &rest[nul + 1..],
+                )
+            };
+            let Some((&picture_type, rest)) = rest.split_first() else {
+                continue;
+            };
+            let Some((_, data_start)) = id3_terminator(encoding, rest) else {
+                continue;
+            };
+            let cover = CoverArt {
+                mime,
+                data: rest[data_start..].to_vec(),
+            };
+            keep_cover(&mut parsed.cover, cover, picture_type as u32);
+        } else if id == "COMM" || id == "COM" {
+            // 언어 3바이트 + 설명(널 종료) + 본문
+            let Some(text) = rest.get(3..) else { continue };
+            let Some((_, start)) = id3_terminator(encoding, text) else {
+                continue;
+            };
+            set_tag(
+                &mut parsed.meta.tags,
+                "comment",
+                decode_id3_text(encoding, &text[start..]),
+            );
+        } else if let Some(key) = id3_frame_key(&id) {
+            set_tag(&mut parsed.meta.tags, key, decode_id3_text(encoding, rest));
+        }
+    }
+}
+
+/// 파일 끝 128바이트 ID3v1 (v2 태그가 없는 오래된 MP3)
+fn parse_id3v1(file: &mut File, file_size: u64, tags: &mut AudioTags) -> bool {
+    if file_size < 128 {
+        return false;
+    }
+    let Some(tag) = read_exact_at(file, file_size - 128, 128) else {
+        return false;
+    };
+    if !tag.starts_with(b"TAG") {
+        return false;
+    }
+    let field = |range: std::ops::Range<usize>| decode_id3_text(0, &tag[range]);
+    set_tag(tags, "title", field(3..33));
+    set_tag(tags, "artist", field(33..63));
+    set_tag(tags, "album", field(63..93));
+    set_tag(tags, "year", field(93..97));
+    if tag[125] == 0 && tag[126] != 0 {
+        set_tag(tags, "track", tag[126].to_string());
+        set_tag(tags, "comment", field(97..125));
+    } else {
+        set_tag(tags, "comment", field(97..127));
+    }
+    true
+}
+
+// ===== MP3 =====
+
+struct MpegFrame {
+    version: u8, // 1, 2, 25 (MPEG 2.5)
+    layer: u8,
+    bitrate_kbps: u32,
+    sample_rate: u32,
+    channels: u16,
+}
+
+fn parse_mpeg_header(h: &[u8]) -> Option<MpegFrame> {
+    if h.len() < 4 || h[0] != 0xFF || h[1] & 0xE0 != 0xE0 {
+        return None;
+    }
+    let version = match (h[1] >> 3) & 0x03 {
+        0 => 25,
+        2 => 2,
+        3 => 1,
+        _ => return None,
+    };
+    let layer = match (h[1] >> 1) & 0x03 {
+        1 => 3,
+        2 => 2,
+        3 => 1,
+        _ => return None,
+    };
+    let bitrate_index = (h[2] >> 4) as usize;
+    let rate_index = ((h[2] >> 2) & 0x03) as usize;
+    if bitrate_index == 0 || bitrate_index == 15 || rate_index == 3 {
+        return None;
+    }
+    const V1_L1: [u32; 15] = [
+        0, 32, 64, 96, 128, 160, 192, 224, 256, 288, 320, 352, 384, 416, 448,
+    ];
+    const V1_L2: [u32; 15] = [
+        0, 32, 48, 56, 64, 80, 96, 112, 128, 160, 192, 224, 256, 320, 384,
+    ];
+    const V1_L3: [u32; 15] = [
+        0, 32, 40, 48, 56, 64, 80, 96, 112, 128, 160, 192, 224, 256, 320,
+    ];
+    const V2_L1: [u32; 15] = [
+        0, 32, 48, 56, 64, 80, 96, 112, 128, 144, 160, 176, 192, 224, 256,
+    ];
+    const V2_L23: [u32; 15] = [0, 8, 16, 24, 32, 40, 48, 56, 64, 80, 96, 112, 128, 144, 160];
+    let table = match (version, layer) {
+        (1, 1) => &V1_L1,
+        (1, 2) => &V1_L2,
+        (1, _) => &V1_L3,
+        (_, 1) => &V2_L1,
+        _ => &V2_L23,
+    };
+    let base_rate = [44100, 48000, 32000][rate_index];
+    Some(MpegFrame {
+        version,
+        layer,
+        bitrate_kbps: table[bitrate_index],
+        sample_rate: match version {
+            1 => base_rate,
+            2 => base_rate / 2,
+            _ => base_rate / 4,
+        },
+        channels: if h[3] >> 6 == 3 { 1 } else { 2 },
+    })
+}
+
+fn parse_mp3(file: &mut File, file_size: u64) -> Option<Parsed> {
+    let mut parsed = Parsed::default();
+    parsed.meta.format = "mp3".to_string();
+    parsed.meta.codec = Some("mp3".to_string());
+    let head = read_exact_at(file, 0, 10.min(file_size as usize))?;
+    let mut audio_start = 0;
+    if let Some(tag_len) = id3_total_len(&head) {
+        if let Some(tag) = read_block(file, 0, tag_len.min(file_size)) {
+            parse_id3v2(&tag, &mut parsed);
+        }
+        audio_start = tag_len;
+    }
+    let has_v1 = parse_id3v1(file, file_size, &mut parsed.meta.tags);
+
+    // 첫 프레임 동기 찾기 (태그 뒤 패딩·쓰레기 바이트 허용)
+    let scan_len = (64 * 1024).min(file_size.saturating_sub(audio_start)) as usize;
+    let scan = read_exact_at(file, audio_start, scan_len)?;
+    let (offset, frame) = (0..scan.len().saturating_sub(4))
+        .find_map(|i| parse_mpeg_header(&scan[i..]).map(|frame| (i, frame)))?;
+    parsed.meta.sample_rate = Some(frame.sample_rate);
+    parsed.meta.channels = Some(frame.channels);
+
+    let samples_per_frame = match (frame.layer, frame.version) {
+        (1, _) => 384,
+        (2, _) | (3, 1) => 1152,
+        _ => 576,
+    };
+    // Xing/Info(VBR) 헤더의 전체 프레임 수
+    let side_info = match (frame.version == 1, frame.channels == 1) {
+        (true, false) => 32,
+        (true, true) | (false, false) => 17,
+        (false, true) => 9,
+    };
+    let xing_at = offset + 4 + side_info;
+    let frames = if matches!(scan.get(xing_at..xing_at + 4), Some(b"Xing" | b"Info")) {
+        let flags = be_u32(&scan, xing_at + 4)?;
+        (flags & 0x1 != 0)
+            .then(|| be_u32(&scan, xing_at + 8))
+            .flatten()
+    } else if scan.get(offset + 36..offset + 40) == Some(&b"VBRI"[..]) {
+        be_u32(&scan, offset + 36 + 14)
+    } else {
+        None
+    };
+
+    let audio_bytes = file_size
+        .saturating_sub(audio_start + offset as u64)
+        .saturating_sub(if has_v1 { 128 } else { 0 });
+    match frames {
+        Some(frames) if frames > 0 => {
+            let duration = frames as f64 * samples_per_frame as f64 / frame.sample_rate as f64;
+            parsed.meta.duration_sec = Some(duration);
+            parsed.meta.bitrate_kbps =
+                Some((audio_bytes as f64 * 8.0 / duration / 1000.0).round() as u32);
+        }
+        _ => {
+            // CBR: 첫 프레임 비트레이트로 길이 추정
+            parsed.meta.bitrate_kbps = Some(frame.bitrate_kbps);
+            parsed.meta.duration_sec =
+                Some(audio_bytes as f64 * 8.0 / (frame.bitrate_kbps as f64 * 1000.0));
+        }
+    }
+    Some(parsed)
+}
+
+// ===== WAV =====
+
+fn parse_wav(file: &mut File, file_size: u64) -> Option<Parsed> {
+    let mut parsed = Parsed::default();
+    parsed.meta.format = "wav".to_string();
+    let mut byte_rate = 0u32;
+    let mut data_size = None;
+    let mut pos = 12u64;
+    while pos + 8 <= file_size {
+        let header = read_exact_at(file, pos, 8)?;
+        let id = &header[0..4];
+        let size = le_u32(&header, 4)? as u64;
+        let body = pos + 8;
+        match id {
+            b"fmt " => {
+                let fmt = read_block(file, body, size.min(40))?;
+                parsed.meta.codec = Some(
+                    match le_u16(&fmt, 0)? {
+                        1 => "pcm",
+                        3 => "pcm_float",
+                        0xFFFE => "pcm_extensible",
+                        0x55 => "mp3",
+                        _ => "other",
+                    }
+                    .to_string(),
+                );
+                parsed.meta.channels = le_u16(&fmt, 2);
+                parsed.meta.sample_rate = le_u32(&fmt, 4);
+                byte_rate = le_u32(&fmt, 8).unwrap_or(0);
+                parsed.meta.bits_per_sample = le_u16(&fmt, 14);
+            }
+            // 큰 파일은 data 크기가 0xFFFFFFFF 등으로 잘못 적혀 있을 수 있다
+            b"data" => data_size = Some(size.min(file_size - body)),
+            b"LIST" => {
+                if let Some(list) = read_block(file, body, size) {
+                    parse_riff_info(&list, &mut parsed.meta.tags);
+                }
+            }
+            b"id3 " | b"ID3 " => {
+                if let Some(tag) = read_block(file, body, size) {
+                    parse_id3v2(&tag, &mut parsed);
+                }
+            }
+            _ => {}
+        }
+        pos = body + size + (size & 1);
+    }
+    parsed.meta.bitrate_kbps = (byte_rate > 0).then_some(byte_rate / 125);
+    if let Some(data_size) = data_size.filter(|_| byte_rate > 0) {
+        parsed.meta.duration_sec = Some(data_size as f64 / byte_rate as f64);
+    }
+    Some(parsed)
+}
+
+/// RIFF `LIST/INFO` 하위 청크 (INAM·IART 등)
+fn parse_riff_info(list: &[u8], tags: &mut AudioTags) {
+    if list.get(0..4) != Some(&b"INFO"[..]) {
+        return;
+    }
+    let mut pos = 4;
+    while pos + 8 <= list.len() {
+        let id = &list[pos..pos + 4];
+        let size = le_u32(list, pos + 4).unwrap_or(0) as usize;
+        let Some(body) = list.get(pos + 8..pos + 8 + size) else {
+            break;
+        };
+        let key = match id {
+            b"INAM" => "title",
+            b"IART" => "artist",
+            b"IPRD" => "album",
+            b"ICRD" => "year",
+            b"IGNR" => "genre",
+            b"ICMT" => "comment",
+            b"ITRK" | b"IPRT" => "track",
+            b"ISFT" => "encoder",
+            _ => "",
+        };
+        if !key.is_empty() {
+            set_tag(tags, key, decode_id3_text(3, body));
+        }
+        pos += 8 + size + (size & 1);
+    }
+}
+
+// ===== FLAC / Vorbis comment =====
+
+/// Vorbis comment 블록 (FLAC VORBIS_COMMENT, Ogg 주석 패킷 공통)
+fn parse_vorbis_comment(block: &[u8], parsed: &mut Parsed) {
+    let Some(vendor_len) = le_u32(block, 0) else {
+        return;
+    };
+    let mut pos = 4 + vendor_len as usize;
+    let Some(count) = le_u32(block, pos) else {
+        return;
+    };
+    pos += 4;
+    for _ in 0..count {
+        let Some(len) = le_u32(block, pos) else { break };
+        let Some(entry) = block.get(pos + 4..pos + 4 + len as usize) else {
+            break;
+        };
+        pos += 4 + len as usize;
+        let entry = String::from_utf8_lossy(entry);
+        let Some((key, value)) = entry.split_once('=') else {
+            continue;
+        };
+        let key = key.to_ascii_uppercase();
+        if key == "METADATA_BLOCK_PICTURE" {
+            use base64::Engine;
+            if let Ok(picture) = base64::engine::general_purpose::STANDARD.decode(value.trim()) {
+                parse_flac_picture(&picture, parsed);
+            }
+            continue;
+        }
+        let mapped = match key.as_str() {
+            "TITLE" => "title",
+            "ARTIST" => "artist",
+            "ALBUM" => "album",
+            "ALBUMARTIST" | "ALBUM ARTIST" => "album_artist",
+            "DATE" | "YEAR" => "year",
+            "TRACKNUMBER" => "track",
+            "GENRE" => "genre",
+            "COMMENT" | "DESCRIPTION" => "comment",
+            other => {
+                set_tag(
+                    &mut parsed.meta.tags,
+                    &other.to_lowercase(),
+                    value.to_string(),
+                );
+                continue;
+            }
+        };
+        set_tag(&mut parsed.meta.tags, mapped, value.to_string());
+    }
+}
+
+/// FLAC PICTURE 블록 (Ogg의 METADATA_BLOCK_PICTURE도 같은 구조)
+fn parse_flac_picture(block: &[u8], parsed: &mut Parsed) {
+    let parse = || -> Option<(u32, CoverArt)> {
+        let picture_type = be_u32(block, 0)?;
+        let mime_len = be_u32(block, 4)? as usize;
+        let mime = String::from_utf8_lossy(block.get(8..8 + mime_len)?).to_string();
+        let mut pos = 8 + mime_len;
+        let desc_len = be_u32(block, pos)? as usize;
+        // 설명 + 너비·높이·색 깊이·팔레트 수 (4 x u32)
+        pos += 4 + desc_len + 16;
+        let data_len = be_u32(block, pos)? as usize;
+        let data = block.get(pos + 4..pos + 4 + data_len)?.to_vec();
+        Some((picture_type, CoverArt { mime, data }))
+    };
+    if let Some((picture_type, cover)) = parse() {
+        keep_cover(&mut parsed.cover, cover, picture_type);
+    }
+}
+
+fn parse_flac(file: &mut File, start: u64) -> Option<Parsed> {
+    let mut parsed = Parsed::default();
+    parsed.meta.format = "flac".to_string();
+    parsed.meta.codec = Some("flac".to_string());
+    let mut pos = start + 4;
+    loop {
+        let Some(header) = read_exact_at(file, pos, 4) else {
+            break;
+        };
+        let is_last = header[0] & 0x80 != 0;
+        let block_type = header[0] & 0x7F;
+        let len = u32::from_be_bytes([0, header[1], header[2], header[3]]) as u64;
+        let body = pos + 4;
+        match block_type {
+            0 => {
+                let info = read_block(file, body, len)?;
+                // 샘플레이트 20비트 · 채널-1 3비트 · 비트수-1 5비트 · 전체 샘플 36비트
+                let packed = u64::from_be_bytes(info.get(10..18)?.try_into().ok()?);
+                let sample_rate = (packed >> 44) as u32;
+                let total_samples = packed & 0xF_FFFF_FFFF;
+                parsed.meta.sample_rate = Some(sample_rate);
+                parsed.meta.channels = Some(((packed >> 41) & 0x7) as u16 + 1);
+                parsed.meta.bits_per_sample = Some(((packed >> 36) & 0x1F) as u16 + 1);
+                if sample_rate > 0 && total_samples > 0 {
+                    parsed.meta.duration_sec = Some(total_samples as f64 / sample_rate as f64);
+                }
+            }
+            4 => {
+                if let Some(block) = read_block(file, body, len) {
+                    parse_vorbis_comment(&block, &mut parsed);
+                }
+            }
+            6 => {
+                if let Some(block) = read_block(file, body, len) {
+                    parse_flac_picture(&block, &mut parsed);
+                }
+            }
+            _ => {}
+        }
+        pos = body + len;
+        if is_last {
+            break;
+        }
+    }
+    Some(parsed)
+}
+
+// ===== Ogg (Vorbis / Opus) =====
+
+/// 앞쪽 페이지들에서 처음 `count`개 패킷을 이어 붙여 반환
+fn read_ogg_packets(file: &mut File, file_size: u64, count: usize) -> Vec<Vec<u8>> {
+    let mut packets = Vec::new();
+    let mut current = Vec::new();
+    let mut pos = 0u64;
+    while packets.len() < count && pos + 27 <= file_size {
+        let Some(header) = read_exact_at(file, pos, 27) else {
+            break;
+        };
+        if !header.starts_with(b"OggS") {
+            break;
+        }
+        let segments = header[26] as usize;
+        let Some(table) = read_exact_at(file, pos + 27, segments) else {
+            break;
+        };
+        let body_len: u64 = table.iter().map(|s| *s as u64).sum();
+        let Some(body) = read_exact_at(file, pos + 27 + segments as u64, body_len as usize) else {
+            break;
+        };
+        let mut offset = 0;
+        for lacing in table {
+            current.extend_from_slice(&body[offset..offset + lacing as usize]);
+            offset += lacing as usize;
+            // 255 미만 lacing 값이 패킷 끝
+            if lacing < 255 {
+                packets.push(std::mem::take(&mut current));
+                if packets.len() == count {
+                    break;
+                }
+            }
+        }
+        if current.len() as u64 > MAX_BLOCK_BYTES {
+            break;
+        }
+        pos += 27 + segments as u64 + body_len;
+    }
+    packets
+}
+
+/// 마지막 페이지의 granule position (전체 샘플 수)
+fn last_ogg_granule(file: &mut File, file_size: u64) -> Option<u64> {
+    let start = file_size.saturating_sub(OGG_TAIL_BYTES);
+    let tail = read_exact_at(file, start, (file_size - start) as usize)?;
+    (0..tail.len().saturating_sub(14))
+        .rev()
+        .filter(|&i| &tail[i..i + 4] == b"OggS")
+        .find_map(|i| {
+            let granule = i64::from_le_bytes(tail.get(i + 6..i + 14)?.try_into().ok()?);
+            (granule > 0).then_some(granule as u64)
+        })
+}
+
+fn parse_ogg(file: &mut File, file_size: u64) -> Option<Parsed> {
+    let mut parsed = Parsed::default();
+    let packets = read_ogg_packets(file, file_size, 2);
+    let ident = packets.first()?;
+    let (rate_for_granule, pre_skip) = if ident.starts_with(b"\x01vorbis") {
+        parsed.meta.format = "ogg".to_string();
+        parsed.meta.codec = Some("vorbis".to_string());
+        parsed.meta.channels = ident.get(11).map(|c| *c as u16);
+        let rate = le_u32(ident, 12).filter(|rate| *rate > 0)?;
+        parsed.meta.sample_rate = Some(rate);
+        let nominal = le_u32(ident, 20).unwrap_or(0) as i32;
+        if nominal > 0 {
+            parsed.meta.bitrate_kbps = Some(nominal as u32 / 1000);
+        }
+        (rate, 0)
+    } else if ident.starts_with(b"OpusHead") {
+        parsed.meta.format = "opus".to_string();
+        parsed.meta.codec = Some("opus".to_string());
+        parsed.meta.channels = ident.get(9).map(|c| *c as u16);
+        parsed.meta.sample_rate = le_u32(ident, 12).filter(|rate| *rate > 0);
+        // Opus granule은 항상 48kHz 기준
+        (48_000, le_u16(ident, 10).unwrap_or(0) as u64)
+    } else {
+        return None;
+    };
+
+    if let Some(comment) = packets.get(1) {
+        if let Some(body) = comment
+            .strip_prefix(b"\x03vorbis")
+            .or_else(|| comment.strip_prefix(b"OpusTags"))
+        {
+            parse_vorbis_comment(body, &mut parsed);
+        }
+    }
+    if let Some(granule) = last_ogg_granule(file, file_size) {
+        parsed.meta.duration_sec =
+            Some(granule.saturating_sub(pre_skip) as f64 / rate_for_granule as f64);
+    }
+    Some(parsed)
+}
+
+// ===== MP4 / M4A =====
+
+/// `data` 안의 자식 atom 목록 (타입, 본문)
+fn mp4_atoms(data: &[u8]) -> Vec<(&[u8], &[u8])> {
+    let mut atoms = Vec::new();
+    let mut pos = 0;
+    while pos + 8 <= data.len() {
+        let size = be_u32(data, pos).unwrap_or(0) as usize;
+        let kind = &data[pos + 4..pos + 8];
+        let (header, size) = match size {
+            0 => (8, data.len() - pos),
+            1 => {
+                let Some(large) = data.get(pos + 8..pos + 16) else {
+                    break;
+                };
+                let large = u64::from_be_bytes(large.try_into().unwrap_or_default());
+                // 32비트에서 잘리는 크기도 손상으로 본다
+                let Ok(large) = usize::try_from(large) else {
+                    break;
+                };
+                (16, large)
+            }
+            size => (8, size),
+        };
+        // 손상된 크기: 뺄셈으로 비교해 `pos + size` 오버플로를 피한다
+        if size < header || size > data.len() - pos {
+            break;
+        }
+        atoms.push((kind, &data[pos + header..pos + size]));
+        let Some(next) = pos.checked_add(size) else {
+            break;
+        };
+        pos = next;
+    }
+    atoms
+}
+
+fn mp4_child<'a>(data: &'a [u8], kind: &[u8]) -> Option<&'a [u8]> {
+    mp4_atoms(data)
+        .into_iter()
+        .find(|(k, _)| *k == kind)
+        .map(|(_, body)| body)
+}
+
+/// `moov` atom 위치를 찾아 본문만 읽는다 (`mdat` 뒤에 있어도 건너뛰며 탐색)
+fn read_mp4_moov(file: &mut File, file_size: u64) -> Option<Vec<u8>> {
+    let mut pos = 0u64;
+    while pos + 8 <= file_size {
+        let header = read_exact_at(file, pos, 16.min((file_size - pos) as usize))?;
+        let mut size = be_u32(&header, 0)? as u64;
+        let mut header_len = 8;
+        if size == 1 {
+            size = u64::from_be_bytes(header.get(8..16)?.try_into().ok()?);
+            header_len = 16;
+        } else if size == 0 {
+            size = file_size - pos;
+        }
+        if size < header_len || size > file_size - pos {
+            return None;
+        }
+        if &header[4..8] == b"moov" {
+            return read_block(file, pos + header_len, size - header_len);
+        }
+        pos = pos.checked_add(size)?;
+    }
+    None
+}
+
+fn parse_mp4(file: &mut File, file_size: u64) -> Option<Parsed> {
+    let mut parsed = Parsed::default();
+    parsed.meta.format = "m4a".to_string();
+    let moov = read_mp4_moov(file, file_size)?;
+
+    if let Some(mvhd) = mp4_child(&moov, b"mvhd") {
+        let (timescale, duration) = if mvhd.first() == Some(&1) {
+            (
+                be_u32(mvhd, 20)?,
+                u64::from_be_bytes(mvhd.get(24..32)?.try_into().ok()?),
+            )
+        } else {
+            (be_u32(mvhd, 12)?, be_u32(mvhd, 16)? as u64)
+        };
+        if timescale > 0 {
+            parsed.meta.duration_sec = Some(duration as f64 / timescale as f64);
+        }
+    }
+
+    // 첫 오디오 트랙의 샘플 항목 (mp4a·alac 등)
+    for (kind, trak) in mp4_atoms(&moov) {
+        if kind != b"trak" {
+            continue;
+        }
+        let Some(mdia) = mp4_child(trak, b"mdia") else {
+            continue;
+        };
+        let is_sound = mp4_child(mdia, b"hdlr").and_then(|h| h.get(8..12)) == Some(&b"soun"[..]);
+        if !is_sound {
+            continue;
+        }
+        let stsd = mp4_child(mdia, b"minf")
+            .and_then(|minf| mp4_child(minf, b"stbl"))
+            .and_then(|stbl| mp4_child(stbl, b"stsd"));
+        if let Some((codec, entry)) =
+            stsd.and_then(|stsd| mp4_atoms(stsd.get(8..)?).into_iter().next())
+        {
+            parsed.meta.codec = Some(String::from_utf8_lossy(codec).trim().to_string());
+            parsed.meta.channels = be_u16(entry, 16);
+            parsed.meta.bits_per_sample = be_u16(entry, 18);
+            parsed.meta.sample_rate = be_u32(entry, 24).map(|rate| rate >> 16);
+        }
+        break;
+    }
+
+    // udta/meta/ilst (meta는 버전·플래그 4바이트가 앞에 오는 full box)
+    let ilst = mp4_child(&moov, b"udta")
+        .and_then(|udta| mp4_child(udta, b"meta"))
+        .and_then(|meta| mp4_child(meta, b"ilst").or_else(|| mp4_child(meta.get(4..)?, b"ilst")));
+    if let Some(ilst) = ilst {
+        for (kind, item) in mp4_atoms(ilst) {
+            let Some(data) = mp4_child(item, b"data") else {
+                continue;
+            };
+            let data_type = be_u32(data, 0).unwrap_or(0);
+            let Some(value) = data.get(8..) else { continue };
+            if kind == b"covr" {
+                let mime = match data_type {
+                    14 => "image/png",
+                    _ => "image/jpeg",
+                };
+                let cover = CoverArt {
+                    mime: mime.to_string(),
+                    data: value.to_vec(),
+                };
+                keep_cover(&mut parsed.cover, cover, 3);
+                continue;
+            }
+            let key = match kind {
+                b"\xA9nam" => "title",
+                b"\xA9ART" => "artist",
+                b"\xA9alb" => "album",
+                b"aART" => "album_artist",
+                b"\xA9day" => "year",
+                b"\xA9gen" => "genre",
+                b"\xA9cmt" => "comment",
+                b"\xA9wrt" => "composer",
+                b"\xA9too" => "encoder",
+                b"trkn" | b"disk" => {
+                    // 예약 2바이트 + 번호 u16 + 전체 u16
+                    let number = be_u16(value, 2).unwrap_or(0);
+                    let total = be_u16(value, 4).unwrap_or(0);
+                    let text = match total {
+                        0 => number.to_string(),
+                        total => format!("{}/{}", number, total),
+                    };
+                    if number > 0 {
+                        let key = if kind == b"trkn" { "track" } else { "disc" };
+                        set_tag(&mut parsed.meta.tags, key, text);
+                    }
+                    continue;
+                }
+                _ => continue,
+            };
+            if data_type == 1 {
+                set_tag(
+                    &mut parsed.meta.tags,
+                    key,
+                    String::from_utf8_lossy(value).trim().to_string(),
+                );
+            }
+        }
+    }
+    Some(parsed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_bytes(name: &str, bytes: &[u8]) -> Parsed {
+        let path = std::env::temp_dir().join(name);
+        std::fs::write(&path, bytes).unwrap();
+        let mut file = File::open(&path).unwrap();
+        let ext = name.rsplit('.').next().unwrap();
+        let parsed = parse_audio_file(&mut file, ext).unwrap();
+        let _ = std::fs::remove_file(&path);
+        parsed
+    }
+
+    #[test]
+    fn parses_wav_stream_info_and_riff_tags() {
+        // 16-bit 스테레오 44.1kHz, 1초 분량 data + LIST/INFO INAM
+        let data_len = 44_100 * 4;
+        let mut wav = b"RIFF\0\0\0\0WAVEfmt ".to_vec();
+        wav.extend_from_slice(&16u32.to_le_bytes());
+        wav.extend_from_slice(&1u16.to_le_bytes());
+        wav.extend_from_slice(&2u16.to_le_bytes());
+        wav.extend_from_slice(&44_100u32.to_le_bytes());
+        wav.extend_from_slice(&(44_100u32 * 4).to_le_bytes());
+        wav.extend_from_slice(&4u16.to_le_bytes());
+        wav.extend_from_slice(&16u16.to_le_bytes());
+        wav.extend_from_slice(b"LIST");
+        wav.extend_from_slice(&18u32.to_le_bytes());
+        wav.extend_from_slice(b"INFOINAM");
+        wav.extend_from_slice(&6u32.to_le_bytes());
+        wav.extend_from_slice(b"Intro\0");
+        wav.extend_from_slice(b"data");
+        wav.extend_from_slice(&(data_len as u32).to_le_bytes());
+        wav.resize(wav.len() + data_len, 0);
+
+        let parsed = parse_bytes("audio_test_meta.wav", &wav);
+        let meta = parsed.meta;
+        assert_eq!(meta.format, "wav");
+        assert_eq!(
+            (meta.sample_rate, meta.channels, meta.bits_per_sample),
+            (Some(44_100), Some(2), Some(16))
+        );
+        assert_eq!(meta.bitrate_kbps, Some(1411));
+        assert!((meta.duration_sec.unwrap() - 1.0).abs() < 1e-9);
+        assert_eq!(meta.tags.title.as_deref(), Some("Intro"));
+        assert!(!meta.has_cover);
+    }
+
+    #[test]
+    fn parses_id3v2_text_and_front_cover() {
+        let mut frames = Vec::new();
+        let mut frame = |id: &[u8], body: &[u8]| {
+            frames.extend_from_slice(id);
+            frames.extend_from_slice(&(body.len() as u32).to_be_bytes());
+            frames.extend_from_slice(&[0, 0]);
+            frames.extend_from_slice(body);
+        };
+        frame(b"TIT2", b"\x03Song");
+        // UTF-16 BOM 리틀 엔디언 "가"
+        frame(b"TPE1", b"\x01\xFF\xFE\x00\xAC");
+        frame(b"APIC", b"\x00image/png\x00\x03\x00PNGDATA");
+        let mut tag = b"ID3\x03\x00\x00".to_vec();
+        let size = frames.len() as u32;
+        tag.extend_from_slice(&[
+            (size >> 21) as u8 & 0x7F,
+            (size >> 14) as u8 & 0x7F,
+            (size >> 7) as u8 & 0x7F,
+            size as u8 & 0x7F,
+        ]);
+        tag.extend_from_slice(&frames);
+
+        let mut parsed = Parsed::default();
+        parse_id3v2(&tag, &mut parsed);
+        assert_eq!(parsed.meta.tags.title.as_deref(), Some("Song"));
+        assert_eq!(parsed.meta.tags.artist.as_deref(), Some("가"));
+        let cover = parsed.cover.unwrap();
+        assert_eq!(
+            (cover.mime.as_str(), cover.data.as_slice()),
+            ("image/png", &b"PNGDATA"[..])
+        );
+
+        // MPEG1 Layer III 128kbps 44.1kHz 스테레오 CBR 프레임 헤더
+        let header = parse_mpeg_header(&[0xFF, 0xFB, 0x90, 0x00]).unwrap();
+        assert_eq!(
+            (
+                header.version,
+                header.layer,
+                header.bitrate_kbps,
+                header.sample_rate
+            ),
+            (1, 3, 128, 44_100)
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_mp4_atoms_without_overflow() {
+        // 정상 atom 뒤에 64비트 크기가 u64::MAX인 atom — 앞의 atom만 돌려주고 멈춘다
+        let mut data = Vec::new();
+        data.extend_from_slice(&12u32.to_be_bytes());
+        data.extend_from_slice(b"free\0\0\0\0");
+        data.extend_from_slice(&1u32.to_be_bytes());
+        data.extend_from_slice(b"moov");
+        data.extend_from_slice(&u64::MAX.to_be_bytes());
+        let atoms = mp4_atoms(&data);
+        assert_eq!(atoms.len(), 1);
+        assert_eq!(atoms[0].0, b"free");
+
+        // 남은 길이보다 큰 32비트 크기
+        let mut short = 0xFFFF_FFF0u32.to_be_bytes().to_vec();
+        short.extend_from_slice(b"trak");
+        assert!(mp4_atoms(&short).is_empty());
+
+        let mut file = b"\0\0\0\x10ftypM4A \0\0\0\0".to_vec();
+        file.extend_from_slice(&data[12..]);
+        let path = std::env::temp_dir().join("audio_test_bad_atoms.m4a");
+        std::fs::write(&path, &file).unwrap();
+        let parsed = parse_audio_file(&mut File::open(&path).unwrap(), "m4a");
+        let _ = std::fs::remove_file(&path);
+        assert!(parsed.is_none());
+    }
+
+    #[test]
+    fn reports_aac_aiff_wma_as_unsupported() {
+        // ADTS 프레임 동기 — mp3 프레임으로 오인하지 않는다
+        let parsed = parse_bytes("audio_test_adts.aac", b"\xFF\xF1\x50\x80\x02\x1F\xFC");
+        assert_eq!(parsed.meta.format, "aac");
+        assert!(!parsed.meta.supported);
+        assert!(parsed.meta.duration_sec.is_none());
+
+        let parsed = parse_bytes("audio_test_form.aiff", b"FORM\0\0\0\x04AIFF");
+        assert_eq!(
+            (parsed.meta.format.as_str(), parsed.meta.supported),
+            ("aiff", false)
+        );
+    }
+}
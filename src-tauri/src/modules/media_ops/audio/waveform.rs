@@ -0,0 +1,264 @@
+//! 파형 미리보기 (FFmpeg로 저샘플레이트 모노 PCM을 받아 구간별 최소/최대값으로 축약)
+
+use std::io::Read;
+use std::path::Path;
+
+use crate::modules::error::{AppError, Result};
+use crate::modules::tool_ops::find_ffmpeg_path;
+
+/// 파형용 디코딩 샘플레이트 (피크 표시에는 충분하고 긴 파일도 빠르게 읽는다)
+const DECODE_SAMPLE_RATE: u32 = 8_000;
+/// 1차 축약 단위 샘플 수 (1시간 ≈ 45만 블록)
+const BLOCK_SAMPLES: usize = 64;
+pub(super) const MAX_BUCKETS: u32 = 4096;
+const WAVEFORM_COLOR: [u8; 4] = [96, 165, 250, 255];
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct AudioWaveform {
+    pub duration_sec: f64,
+    /// 구간별 최소/최대 (-1.0 ~ 1.0, 길이 = 요청 너비)
+    pub min: Vec<f32>,
+    pub max: Vec<f32>,
+    /// 같은 피크로 그린 PNG 캐시 경로 (asset 프로토콜용)
+    pub png_path: String,
+}
+
+/// 스트리밍 디코딩 중 블록 단위 최소/최대 누적
+#[derive(Default)]
+struct PeakAccumulator {
+    blocks: Vec<(f32, f32)>,
+    current: Option<(f32, f32)>,
+    in_block: usize,
+    total: u64,
+}
+
+impl PeakAccumulator {
+    fn push(&mut self, sample: f32) {
+        let sample = if sample.is_finite() {
+            sample.clamp(-1.0, 1.0)
+        } else {
+            0.0
+        };
+        let (lo, hi) = self.current.get_or_insert((sample, sample));
+        *lo = lo.min(sample);
+        *hi = hi.max(sample);
+        self.in_block += 1;
+        self.total += 1;
+        if self.in_block == BLOCK_SAMPLES {
+            self.blocks.extend(self.current.take());
+            self.in_block = 0;
+        }
+    }
+
+    fn finish(mut self) -> (Vec<(f32, f32)>, u64) {
+        self.blocks.extend(self.current.take());
+        (self.blocks, self.total)
+    }
+}
+
+/// 블록 피크를 `buckets`개 구간으로 축약 (블록이 더 적으면 늘려 채운다)
+fn reduce_peaks(blocks: &[(f32, f32)], buckets: usize) -> (Vec<f32>, Vec<f32>) {
+    let mut min = Vec::with_capacity(buckets);
+    let mut max = Vec::with_capacity(buckets);
+    if blocks.is_empty() {
+        return (vec![0.0; buckets], vec![0.0; buckets]);
+    }
+    let n = blocks.len();
+    for i in 0..buckets {
+        let start = i * n / buckets;
+        let end = ((i + 1) * n / buckets).max(start + 1).min(n);
+        let (lo, hi) = blocks[start..end]
+            .iter()
+            .fold((f32::MAX, f32::MIN), |(lo, hi), &(a, b)| {
+                (lo.min(a), hi.max(b))
+            });
+        min.push(lo);
+        max.push(hi);
+    }
+    (min, max)
+}
+
+/// 가운데 기준선 위아래로 구간별 세로선을 그린 투명 배경 PNG
+fn render_waveform_png(min: &[f32], max: &[f32], height: u32) -> Result<Vec<u8>> {
+    let width = min.len() as u32;
+    let mut img = image::RgbaImage::new(width.max(1), height.max(1));
+    let mid = (height as f32 - 1.0) / 2.0;
+    for (x, (&lo, &hi)) in min.iter().zip(max).enumerate() {
+        let top = (mid - hi * mid).round().clamp(0.0, height as f32 - 1.0) as u32;
+        let bottom = (mid - lo * mid).round().clamp(0.0, height as f32 - 1.0) as u32;
+        // 무음 구간도 기준선 1px은 보이게
+        for y in top.min(bottom)..=bottom.max(top) {
+            img.put_pixel(x as u32, y, image::Rgba(WAVEFORM_COLOR));
+        }
+    }
+    let mut buf = vec![];
+    image::DynamicImage::ImageRgba8(img)
+        .write_to(&mut std::io::Cursor::new(&mut buf), image::ImageFormat::Png)?;
+    Ok(buf)
+}
+
+/// ffmpeg로 모노 f32le PCM을 받아 블록 피크 누적
+fn decode_peaks(input: &str) -> Result<(Vec<(f32, f32)>, u64)> {
+    let ffmpeg_path = find_ffmpeg_path().ok_or_else(|| AppError::ToolNotFound {
+        tool: "FFmpeg".to_string(),
+    })?;
+    let mut cmd = std::process::Command::new(ffmpeg_path);
+    cmd.args([
+        "-v", "error", "-i", input, "-map", "0:a:0", "-ac", "1", "-ar",
+    ])
+    .arg(DECODE_SAMPLE_RATE.to_string())
+    .args(["-f", "f32le", "pipe:1"]);
+
+    // Windows: 콘솔 창 숨기기
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt;
+        cmd.creation_flags(0x08000000);
+    }
+
+    let mut child = cmd
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .spawn()
+        .map_err(|e| AppError::VideoProcessing(format!("ffmpeg 실행 실패: {}", e)))?;
+
+    let stderr = child.stderr.take();
+    let stderr_thread = std::thread::spawn(move || {
+        let mut output = String::new();
+        if let Some(mut s) = stderr {
+            let _ = s.read_to_string(&mut output);
+        }
+        output
+    });
+
+    let mut acc = PeakAccumulator::default();
+    let stdout = child.stdout.take();
+    let read_result = (|| -> std::io::Result<()> {
+        let Some(mut stdout) = stdout else {
+            return Ok(());
+        };
+        let mut buf = vec![0u8; 64 * 1024];
+        let mut carry: Vec<u8> = Vec::with_capacity(4);
+        loop {
+            let n = stdout.read(&mut buf)?;
+            if n == 0 {
+                break;
+            }
+            // 읽기 경계에서 잘린 샘플은 다음 읽기와 이어 붙인다
+            let mut chunk = &buf[..n];
+            if !carry.is_empty() {
+                let need = (4 - carry.len()).min(chunk.len());
+                carry.extend_from_slice(&chunk[..need]);
+                chunk = &chunk[need..];
+                if carry.len() == 4 {
+                    acc.push(f32::from_le_bytes([carry[0], carry[1], carry[2], carry[3]]));
+                    carry.clear();
+                }
+            }
+            let mut samples = chunk.chunks_exact(4);
+            for s in &mut samples {
+                acc.push(f32::from_le_bytes([s[0], s[1], s[2], s[3]]));
+            }
+            carry.extend_from_slice(samples.remainder());
+        }
+        Ok(())
+    })();
+    // 읽기 오류로 끝나도 ffmpeg를 종료·회수해 좀비 프로세스를 남기지 않는다
+    if let Err(e) = read_result {
+        let _ = child.kill();
+        let _ = child.wait();
+        let _ = stderr_thread.join();
+        return Err(e.into());
+    }
+
+    let status = child.wait()?;
+    let stderr_output = stderr_thread.join().unwrap_or_default();
+    let (blocks, total) = acc.finish();
+    if !status.success() || total == 0 {
+        return Err(AppError::VideoProcessing(format!(
+            "파형 디코딩 실패: {}",
+            stderr_output
+                .lines()
+                .last()
+                .unwrap_or("오디오 스트림이 없습니다")
+        )));
+    }
+    Ok((blocks, total))
+}
+
+/// 파형 계산 후 PNG와 JSON을 `cache_dir`에 저장. 같은 파일·크기면 캐시를 그대로 쓴다.
+pub(super) fn load_or_render_waveform(
+    cache_dir: &Path,
+    path: &str,
+    width: u32,
+    height: u32,
+) -> Result<AudioWaveform> {
+    let meta = std::fs::metadata(path)?;
+    let identity = crate::modules::types::file_identity(&meta);
+    let (w, h) = (width.to_string(), height.to_string());
+    let key = crate::helpers::stable_cache_key(&[
+        b"waveform-v1",
+        path.as_bytes(),
+        identity.as_bytes(),
+        w.as_bytes(),
+        h.as_bytes(),
+    ]);
+    std::fs::create_dir_all(cache_dir)?;
+    let png_file = cache_dir.join(format!("{}.png", key));
+    let json_file = cache_dir.join(format!("{}.json", key));
+    if png_file.exists() {
+        if let Some(cached) = std::fs::read(&json_file)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice::<AudioWaveform>(&bytes).ok())
+        {
+            return Ok(cached);
+        }
+    }
+
+    let (blocks, total) = decode_peaks(path)?;
+    let (min, max) = reduce_peaks(&blocks, width as usize);
+    std::fs::write(&png_file, render_waveform_png(&min, &max, height)?)?;
+    let waveform = AudioWaveform {
+        duration_sec: total as f64 / DECODE_SAMPLE_RATE as f64,
+        min,
+        max,
+        png_path: png_file.to_string_lossy().to_string(),
+    };
+    if let Ok(json) = serde_json::to_vec(&waveform) {
+        let _ = std::fs::write(&json_file, json);
+    }
+    Ok(waveform)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reduces_streamed_samples_to_bucket_peaks_and_renders_png() {
+        let mut acc = PeakAccumulator::default();
+        for i in 0..(BLOCK_SAMPLES * 4) {
+            // 블록마다 진폭이 다른 사각파
+            let amp = (i / BLOCK_SAMPLES + 1) as f32 * 0.2;
+            acc.push(if i % 2 == 0 { amp } else { -amp });
+        }
+        acc.push(2.0);
+        let (blocks, total) = acc.finish();
+        assert_eq!((blocks.len(), total), (5, BLOCK_SAMPLES as u64 * 4 + 1));
+        assert_eq!(blocks[4], (1.0, 1.0));
+
+        let (min, max) = reduce_peaks(&blocks, 2);
+        assert_eq!(max.len(), 2);
+        assert!((max[0] - 0.4).abs() < 1e-6 && (min[0] + 0.4).abs() < 1e-6);
+        assert_eq!(max[1], 1.0);
+
+        // 블록보다 구간이 많으면 늘려 채움
+        let (min, max) = reduce_peaks(&blocks, 10);
+        assert_eq!((min.len(), max[9]), (10, 1.0));
+
+        let png = render_waveform_png(&min, &max, 16).unwrap();
+        let img = image::load_from_memory(&png).unwrap();
+        assert_eq!((img.width(), img.height()), (10, 16));
+    }
+}
@@ -0,0 +1,462 @@
+//! 미디어 정보 조회 (번들 FFmpeg의 `ffmpeg -i` 배너 파싱)
+//! ffprobe는 번들에 없으므로 입력 정보 출력만 읽는다. 결과는 파일 정체성별로 캐시한다.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Mutex, OnceLock};
+
+use crate::modules::archive_ops::materialize_archive_path_in_cache;
+use crate::modules::error::{AppError, Result};
+use crate::modules::tool_ops::find_ffmpeg_path;
+
+const PROBE_CACHE_LIMIT: usize = 5_000;
+
+#[derive(serde::Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MediaStreamKind {
+    Video,
+    Audio,
+    Subtitle,
+    Data,
+    Attachment,
+}
+
+#[derive(serde::Serialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaStream {
+    /// 입력 내 스트림 번호 (`-map 0:N`)
+    pub index: u32,
+    pub kind: MediaStreamKind,
+    pub codec: String,
+    /// 코덱 프로파일 (High, LC 등)
+    pub profile: Option<String>,
+    pub language: Option<String>,
+    pub is_default: bool,
+    /// 앨범 아트처럼 영상 스트림으로 붙은 정지 이미지
+    pub is_attached_pic: bool,
+    pub bitrate_kbps: Option<u32>,
+    // 영상
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    pub frame_rate: Option<f64>,
+    pub pixel_format: Option<String>,
+    /// 재생 시 시계 방향 회전 각도 (0/90/180/270, rotate 태그·display matrix 기준)
+    pub rotation: i32,
+    // 오디오
+    pub sample_rate: Option<u32>,
+    pub channels: Option<u16>,
+    pub channel_layout: Option<String>,
+}
+
+#[derive(serde::Serialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaProbe {
+    /// 컨테이너 (demuxer 이름 목록, 예: "mov,mp4,m4a,3gp,3g2,mj2")
+    pub container: String,
+    pub duration_sec: Option<f64>,
+    pub start_sec: Option<f64>,
+    /// 전체 비트레이트
+    pub bitrate_kbps: Option<u32>,
+    pub streams: Vec<MediaStream>,
+    /// 컨테이너 메타데이터 (title, creation_time 등)
+    pub tags: BTreeMap<String, String>,
+}
+
+impl MediaStream {
+    fn new(index: u32, kind: MediaStreamKind, codec: String) -> Self {
+        Self {
+            index,
+            kind,
+            codec,
+            profile: None,
+            language: None,
+            is_default: false,
+            is_attached_pic: false,
+            bitrate_kbps: None,
+            width: None,
+            height: None,
+            frame_rate: None,
+            pixel_format: None,
+            rotation: 0,
+            sample_rate: None,
+            channels: None,
+            channel_layout: None,
+        }
+    }
+}
+
+/// 괄호 밖의 쉼표로만 나눈다 (`yuv420p(tv, bt709)`는 한 항목)
+fn split_top_level(s: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' | '[' => depth += 1,
+            ')' | ']' => depth -= 1,
+            ',' if depth <= 0 => {
+                parts.push(s[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(s[start..].trim());
+    parts
+}
+
+/// "HH:MM:SS.xx" → 초
+fn parse_clock(s: &str) -> Option<f64> {
+    let mut secs = 0.0;
+    let mut parts = 0;
+    for part in s.trim().split(':') {
+        secs = secs * 60.0 + part.parse::<f64>().ok()?;
+        parts += 1;
+    }
+    (parts == 3).then_some(secs)
+}
+
+/// "1234 kb/s" → 1234
+fn parse_kbps(s: &str) -> Option<u32> {
+    let value = s.trim().strip_suffix("kb/s")?.trim();
+    value.parse::<f64>().ok().map(|v| v.round() as u32)
+}
+
+/// "1920x1080 [SAR 1:1 DAR 16:9]" → (1920, 1080)
+fn parse_resolution(s: &str) -> Option<(u32, u32)> {
+    let token = s.split_whitespace().next()?;
+    let (w, h) = token.split_once('x')?;
+    Some((w.parse().ok()?, h.parse().ok()?))
+}
+
+fn channels_from_layout(layout: &str) -> Option<u16> {
+    let base = layout.split('(').next().unwrap_or(layout).trim();
+    match base {
+        "mono" => Some(1),
+        "stereo" | "downmix" => Some(2),
+        "2.1" | "3.0" => Some(3),
+        "quad" | "4.0" | "3.1" => Some(4),
+        "5.0" | "4.1" => Some(5),
+        "5.1" | "6.0" => Some(6),
+        "6.1" | "7.0" => Some(7),
+        "7.1" => Some(8),
+        _ => base
+            .strip_suffix("channels")
+            .and_then(|n| n.trim().parse().ok()),
+    }
+}
+
+fn normalize_rotation(degrees: f64) -> i32 {
+    ((degrees.round() as i32 % 360) + 360) % 360
+}
+
+/// `Video: h264 (High) (avc1 / 0x31637661), yuv420p(...), 1920x1080 [...], 30 fps` 형식 본문 파싱
+fn parse_stream_body(stream: &mut MediaStream, body: &str) {
+    let parts = split_top_level(body);
+    // 코덱 뒤 첫 괄호는 프로파일, `(avc1 / 0x...)` 같은 태그 표기는 제외
+    stream.profile = parts
+        .first()
+        .and_then(|first| first.strip_prefix(stream.codec.as_str()))
+        .and_then(|rest| rest.trim_start().strip_prefix('('))
+        .and_then(|rest| rest.split_once(')'))
+        .map(|(profile, _)| profile)
+        .filter(|profile| !profile.contains(" / "))
+        .map(str::to_string);
+    for (i, part) in parts.iter().enumerate().skip(1) {
+        let value = part.split(" (").next().unwrap_or(part).trim();
+        let bare = part.split('(').next().unwrap_or(part).trim();
+        if let Some(kbps) = parse_kbps(value) {
+            stream.bitrate_kbps = Some(kbps);
+            continue;
+        }
+        match stream.kind {
+            MediaStreamKind::Video => {
+                if let Some((w, h)) = parse_resolution(value) {
+                    stream.width = Some(w);
+                    stream.height = Some(h);
+                } else if let Some(fps) = value.strip_suffix(" fps") {
+                    stream.frame_rate = parse_rate(fps);
+                } else if let Some(tbr) = value.strip_suffix(" tbr") {
+                    stream.frame_rate = stream.frame_rate.or_else(|| parse_rate(tbr));
+                } else if i == 1 && !bare.contains(' ') {
+                    stream.pixel_format = Some(bare.to_string());
+                }
+            }
+            MediaStreamKind::Audio => {
+                if let Some(hz) = value.strip_suffix(" Hz") {
+                    stream.sample_rate = hz.trim().parse().ok();
+                } else if i == 2 {
+                    stream.channel_layout = Some(part.to_string());
+                    stream.channels = channels_from_layout(part);
+                }
+            }
+            _ => {}
+        }
+    }
+    stream.is_default = body.contains("(default)");
+    stream.is_attached_pic = body.contains("(attached pic)");
+}
+
+/// "29.97" / "30k" → fps
+fn parse_rate(s: &str) -> Option<f64> {
+    let s = s.trim();
+    match s.strip_suffix('k') {
+        Some(k) => k.parse::<f64>().ok().map(|v| v * 1000.0),
+        None => s.parse().ok(),
+    }
+}
+
+/// "Stream #0:1[0x2](eng): Audio: ..." → 번호·언어·종류·코덱을 채운 스트림과 본문
+fn parse_stream_header(line: &str) -> Option<(MediaStream, &str)> {
+    let rest = line.strip_prefix("Stream #")?;
+    let (id, rest) = rest.split_once(": ")?;
+    let index_part = id.split_once(':')?.1;
+    let index_digits: String = index_part
+        .chars()
+        .take_while(|c| c.is_ascii_digit())
+        .collect();
+    let index = index_digits.parse().ok()?;
+    let language = id
+        .rsplit_once('(')
+        .and_then(|(_, l)| l.strip_suffix(')'))
+        .filter(|l| !l.is_empty() && *l != "und")
+        .map(str::to_string);
+    let (kind_name, body) = rest.split_once(": ")?;
+    let kind = match kind_name.trim() {
+        "Video" => MediaStreamKind::Video,
+        "Audio" => MediaStreamKind::Audio,
+        "Subtitle" => MediaStreamKind::Subtitle,
+        "Attachment" => MediaStreamKind::Attachment,
+        _ => MediaStreamKind::Data,
+    };
+    let codec = body
+        .split(|c: char| c == ' ' || c == ',')
+        .next()
+        .unwrap_or_default()
+        .to_string();
+    let mut stream = MediaStream::new(index, kind, codec);
+    stream.language = language;
+    Some((stream, body))
+}
+
+/// `key : value` 메타데이터 줄
+fn parse_tag(line: &str) -> Option<(String, String)> {
+    let (key, value) = line.split_once(':')?;
+    let key = key.trim();
+    if key.is_empty() || key.contains(' ') {
+        return None;
+    }
+    Some((key.to_lowercase(), value.trim().to_string()))
+}
+
+/// `ffmpeg -i` stderr 전체 파싱 (첫 입력만)
+fn parse_ffmpeg_banner(stderr: &str) -> Option<MediaProbe> {
+    let mut lines = stderr.lines().skip_while(|l| !l.starts_with("Input #0"));
+    let header = lines.next()?;
+    let container = header
+        .strip_prefix("Input #0, ")?
+        .rsplit_once(", from ")?
+        .0
+        .to_string();
+    let mut probe = MediaProbe {
+        container,
+        duration_sec: None,
+        start_sec: None,
+        bitrate_kbps: None,
+        streams: Vec::new(),
+        tags: BTreeMap::new(),
+    };
+
+    // 들여쓰기로 구역 구분: 2칸 = 입력 정보, 4칸 = 입력/스트림 메타데이터, 6칸 = 값
+    #[derive(PartialEq)]
+    enum Section {
+        None,
+        InputMetadata,
+        StreamMetadata,
+        SideData,
+    }
+    let mut section = Section::None;
+    for line in lines {
+        if !line.starts_with("  ") {
+            break; // "Output #0" / "At least one output file..." 등
+        }
+        let trimmed = line.trim();
+        let indent = line.len() - line.trim_start().len();
+        if indent == 2 {
+            section = Section::None;
+            if trimmed == "Metadata:" {
+                section = Section::InputMetadata;
+            } else if let Some(rest) = trimmed.strip_prefix("Duration:") {
+                for field in rest.split(',') {
+                    let field = field.trim();
+                    if let Some(v) = field.strip_prefix("start:") {
+                        probe.start_sec = v.trim().parse().ok();
+                    } else if let Some(v) = field.strip_prefix("bitrate:") {
+                        probe.bitrate_kbps = parse_kbps(v);
+                    } else {
+                        probe.duration_sec = parse_clock(field);
+                    }
+                }
+            } else if let Some((mut stream, body)) = parse_stream_header(trimmed) {
+                parse_stream_body(&mut stream, body);
+                probe.streams.push(stream);
+            }
+            continue;
+        }
+        if indent == 4 && section != Section::InputMetadata {
+            section = match trimmed {
+                "Metadata:" => Section::StreamMetadata,
+                "Side data:" => Section::SideData,
+                _ => Section::None,
+            };
+            continue;
+        }
+        match section {
+            Section::InputMetadata if indent == 4 => {
+                if let Some((key, value)) = parse_tag(trimmed) {
+                    probe.tags.insert(key, value);
+                }
+            }
+            Section::StreamMetadata => {
+                if let (Some(stream), Some((key, value))) =
+                    (probe.streams.last_mut(), parse_tag(trimmed))
+                {
+                    if key == "rotate" {
+                        if let Ok(deg) = value.parse::<f64>() {
+                            stream.rotation = normalize_rotation(deg);
+                        }
+                    }
+                }
+            }
+            Section::SideData => {
+                // "displaymatrix: rotation of -90.00 degrees" — 반시계 기준이라 부호를 뒤집는다
+                let degrees = trimmed
+                    .strip_prefix("displaymatrix: rotation of ")
+                    .and_then(|r| r.strip_suffix(" degrees"))
+                    .and_then(|r| r.trim().parse::<f64>().ok());
+                if let (Some(stream), Some(deg)) = (probe.streams.last_mut(), degrees) {
+                    stream.rotation = normalize_rotation(-deg);
+                }
+            }
+            _ => {}
+        }
+    }
+    Some(probe)
+}
+
+fn run_probe(path: &str) -> Result<MediaProbe> {
+    let ffmpeg_path = find_ffmpeg_path().ok_or_else(|| AppError::ToolNotFound {
+        tool: "FFmpeg".to_string(),
+    })?;
+    let mut cmd = std::process::Command::new(ffmpeg_path);
+    cmd.args(["-hide_banner", "-i", path]);
+
+    // Windows: 콘솔 창 숨기기
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt;
+        cmd.creation_flags(0x08000000);
+    }
+
+    // 출력 파일을 주지 않으므로 종료 코드는 항상 실패 — 입력 정보만 읽는다
+    let output = cmd.output()?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    parse_ffmpeg_banner(&stderr).ok_or_else(|| {
+        AppError::VideoProcessing(format!(
+            "미디어 정보를 읽을 수 없습니다: {}",
+            stderr.lines().last().unwrap_or(path)
+        ))
+    })
+}
+
+/// 파일 정체성(크기·수정 시각 포함) 기준 캐시 — 같은 파일을 여러 편집 창에서 반복 조회
+pub(crate) fn probe_media_cached(path: &std::path::Path) -> Result<MediaProbe> {
+    static CACHE: OnceLock<Mutex<HashMap<String, MediaProbe>>> = OnceLock::new();
+    let meta = std::fs::metadata(path)?;
+    let key = format!(
+        "{}|{}",
+        path.display(),
+        crate::modules::types::file_identity(&meta)
+    );
+    let cache = CACHE.get_or_init(Default::default);
+    if let Some(hit) = cache.lock().unwrap_or_else(|e| e.into_inner()).get(&key) {
+        return Ok(hit.clone());
+    }
+    let probe = run_probe(&path.to_string_lossy())?;
+    let mut cache = cache.lock().unwrap_or_else(|e| e.into_inner());
+    if cache.len() >= PROBE_CACHE_LIMIT {
+        cache.clear();
+    }
+    cache.insert(key, probe.clone());
+    Ok(probe)
+}
+
+/// 컨테이너·길이·스트림별 코덱/해상도/프레임레이트/회전/채널/비트레이트 조회
+#[tauri::command]
+pub async fn probe_media(app: tauri::AppHandle, path: String) -> Result<MediaProbe> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let resolved_path = materialize_archive_path_in_cache(&app, &path)?
+            .unwrap_or_else(|| std::path::PathBuf::from(&path));
+        probe_media_cached(&resolved_path)
+    })
+    .await
+    .map_err(|e| AppError::Internal(format!("미디어 정보 조회 태스크 실패: {}", e)))?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BANNER: &str = "Input #0, mov,mp4,m4a,3gp,3g2,mj2, from 'clip.mov':
+  Metadata:
+    major_brand     : qt
+    creation_time   : 2024-05-01T10:00:00.000000Z
+  Duration: 00:01:02.50, start: 0.000000, bitrate: 8123 kb/s
+  Stream #0:0[0x1](und): Video: hevc (Main 10) (hvc1 / 0x31637668), yuv420p10le(tv, bt2020nc/bt2020/arib-std-b67), 3840x2160, 7900 kb/s, 29.97 fps, 29.97 tbr, 600 tbn (default)
+    Metadata:
+      handler_name    : Core Media Video
+    Side data:
+      displaymatrix: rotation of -90.00 degrees
+  Stream #0:1[0x2](eng): Audio: aac (LC) (mp4a / 0x6134706D), 48000 Hz, 5.1(side), fltp, 192 kb/s (default)
+  Stream #0:2[0x3](und): Video: mjpeg (Baseline), yuvj420p(pc, bt470bg/unknown/unknown), 600x600 [SAR 1:1 DAR 1:1], 90k tbr, 90k tbn (attached pic)
+At least one output file must be specified
+";
+
+    #[test]
+    fn parses_ffmpeg_input_banner() {
+        let probe = parse_ffmpeg_banner(BANNER).unwrap();
+        assert_eq!(probe.container, "mov,mp4,m4a,3gp,3g2,mj2");
+        assert_eq!(probe.duration_sec, Some(62.5));
+        assert_eq!(probe.bitrate_kbps, Some(8123));
+        assert_eq!(
+            probe.tags.get("major_brand").map(String::as_str),
+            Some("qt")
+        );
+        assert_eq!(probe.streams.len(), 3);
+
+        let video = &probe.streams[0];
+        assert_eq!(video.kind, MediaStreamKind::Video);
+        assert_eq!(video.codec, "hevc");
+        assert_eq!(video.profile.as_deref(), Some("Main 10"));
+        assert_eq!(video.pixel_format.as_deref(), Some("yuv420p10le"));
+        assert_eq!((video.width, video.height), (Some(3840), Some(2160)));
+        assert_eq!(video.frame_rate, Some(29.97));
+        assert_eq!(video.bitrate_kbps, Some(7900));
+        assert_eq!(video.rotation, 90);
+        assert!(video.is_default && !video.is_attached_pic);
+
+        let audio = &probe.streams[1];
+        assert_eq!(audio.language.as_deref(), Some("eng"));
+        assert_eq!(audio.sample_rate, Some(48000));
+        assert_eq!(audio.channels, Some(6));
+        assert_eq!(audio.channel_layout.as_deref(), Some("5.1(side)"));
+        assert_eq!(audio.bitrate_kbps, Some(192));
+
+        let cover = &probe.streams[2];
+        assert!(cover.is_attached_pic);
+        assert_eq!(cover.frame_rate, Some(90000.0));
+
+        assert!(
+            parse_ffmpeg_banner("clip.mov: Invalid data found when processing input").is_none()
+        );
+    }
+}
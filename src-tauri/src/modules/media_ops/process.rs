@@ -0,0 +1,228 @@
+//! FFmpeg 작업 프로세스 추적
+//!
+//! 미디어 command가 작업 ID로 `MediaJob`을 등록하고 ffmpeg를 `spawn`/`output`으로 실행하면
+//! 자식 PID가 작업 ID별로 기록된다. `cancel_media_job`은 취소 플래그를 세우고 기록된 프로세스
+//! 트리를 종료하며, 실패한 단계를 돌려받은 호출자는 `check_and_discard`로 부분 출력을 지우고
+//! `AppError::Cancelled`를 반환한다.
+//!
+//! 종료 신호는 기록 잠금을 쥔 채 보내고, 기록은 자식이 끝난 뒤 회수(reap)하기 전에 같은 잠금
+//! 아래에서 지운다. 회수 전에는 PID(Windows는 열린 핸들)가 재사용되지 않으므로, 기록에 남은 PID는
+//! 항상 아직 우리 자식이다.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::process::{Child, Command, ExitStatus, Output};
+use std::sync::{Mutex, OnceLock};
+
+use crate::modules::error::Result;
+use crate::modules::jobs::{cancel_job, new_job_id, register_job, JobHandle};
+
+fn process_registry() -> &'static Mutex<HashMap<String, Vec<u32>>> {
+    static PROCESSES: OnceLock<Mutex<HashMap<String, Vec<u32>>>> = OnceLock::new();
+    PROCESSES.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// 프로세스 그룹(Windows는 하위 트리) 전체 강제 종료
+fn kill_process_tree(pid: u32) {
+    #[cfg(unix)]
+    {
+        // spawn 시 자기 PID로 새 그룹을 만들었으므로 그룹 전체에 보낸다
+        unsafe {
+            libc::kill(-(pid as libc::pid_t), libc::SIGKILL);
+        }
+    }
+    #[cfg(windows)]
+    {
+        use std::os::windows::process::CommandExt;
+        let _ = Command::new("taskkill")
+            .args(["/PID", &pid.to_string(), "/T", "/F"])
+            .creation_flags(0x08000000)
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .status();
+    }
+}
+
+/// 실행 중인 미디어 작업 (드롭 시 등록 해제)
+pub(crate) struct MediaJob {
+    handle: JobHandle,
+}
+
+impl MediaJob {
+    /// 프런트가 준 작업 ID(없으면 발급)로 등록
+    pub(crate) fn start(job_id: Option<String>) -> Result<Self> {
+        let handle = register_job(job_id.unwrap_or_else(|| new_job_id("media")))?;
+        Ok(Self { handle })
+    }
+
+    pub(crate) fn id(&self) -> &str {
+        self.handle.id()
+    }
+
+    pub(crate) fn is_cancelled(&self) -> bool {
+        self.handle.token().is_cancelled()
+    }
+
+    /// 취소되었으면 `AppError::Cancelled`
+    pub(crate) fn check(&self) -> Result<()> {
+        self.handle.token().check()
+    }
+
+    /// 취소되었으면 부분 출력 파일을 지우고 `AppError::Cancelled`
+    pub(crate) fn check_and_discard(&self, partial_output: &Path) -> Result<()> {
+        if self.is_cancelled() {
+            let _ = std::fs::remove_file(partial_output);
+        }
+        self.check()
+    }
+
+    /// 자식 프로세스 실행 후 PID 기록. 이미 취소된 작업이면 실행하지 않는다.
+    pub(crate) fn spawn(&self, cmd: &mut Command) -> std::io::Result<Child> {
+        if self.is_cancelled() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Interrupted,
+                "작업이 취소되었습니다",
+            ));
+        }
+        #[cfg(unix)]
+        {
+            use std::os::unix::process::CommandExt;
+            cmd.process_group(0);
+        }
+        let child = cmd.spawn()?;
+        let pid = child.id();
+        process_registry()
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .entry(self.handle.id().to_string())
+            .or_default()
+            .push(pid);
+        // 등록 직전에 취소 요청이 지나갔으면 여기서 종료
+        if self.is_cancelled() {
+            kill_process_tree(pid);
+        }
+        Ok(child)
+    }
+
+    /// 종료 대기 후 PID 기록 해제. 회수 전에 기록을 지워 취소가 재사용된 PID를 건드리지 않게 한다.
+    pub(crate) fn wait(&self, child: &mut Child) -> std::io::Result<ExitStatus> {
+        // unix: 좀비 상태로 두고 종료만 기다린 뒤 기록을 지우고 회수
+        #[cfg(unix)]
+        {
+            wait_exit_without_reaping(child.id());
+            self.forget(child.id());
+            child.wait()
+        }
+        // Windows: Child가 핸들을 쥐고 있는 동안은 PID가 재사용되지 않는다
+        #[cfg(not(unix))]
+        {
+            let status = child.wait();
+            self.forget(child.id());
+            status
+        }
+    }
+
+    /// `Command::output`과 같지만 취소 가능 (stdout/stderr는 호출자가 설정하지 않으면 파이프)
+    pub(crate) fn output(&self, cmd: &mut Command) -> std::io::Result<Output> {
+        cmd.stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped());
+        let mut child = self.spawn(cmd)?;
+        let stdout = child.stdout.take().map(read_to_end_thread);
+        let stderr = child.stderr.take().map(read_to_end_thread);
+        let status = self.wait(&mut child)?;
+        let join = |reader: Option<std::thread::JoinHandle<Vec<u8>>>| {
+            reader.and_then(|h| h.join().ok()).unwrap_or_default()
+        };
+        Ok(Output {
+            status,
+            stdout: join(stdout),
+            stderr: join(stderr),
+        })
+    }
+
+    fn forget(&self, pid: u32) {
+        let mut processes = process_registry().lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(pids) = processes.get_mut(self.handle.id()) {
+            pids.retain(|p| *p != pid);
+        }
+    }
+}
+
+fn read_to_end_thread<R: std::io::Read + Send + 'static>(
+    mut reader: R,
+) -> std::thread::JoinHandle<Vec<u8>> {
+    std::thread::spawn(move || {
+        let mut buf = Vec::new();
+        let _ = reader.read_to_end(&mut buf);
+        buf
+    })
+}
+
+/// 자식이 끝날 때까지 기다리되 회수하지 않음 (WNOWAIT)
+#[cfg(unix)]
+fn wait_exit_without_reaping(pid: u32) {
+    loop {
+        let mut info: libc::siginfo_t = unsafe { std::mem::zeroed() };
+        let rc = unsafe {
+            libc::waitid(
+                libc::P_PID,
+                pid as libc::id_t,
+                &mut info,
+                libc::WEXITED | libc::WNOWAIT,
+            )
+        };
+        if rc == 0 || std::io::Error::last_os_error().kind() != std::io::ErrorKind::Interrupted {
+            return;
+        }
+    }
+}
+
+impl Drop for MediaJob {
+    fn drop(&mut self) {
+        let mut processes = process_registry().lock().unwrap_or_else(|e| e.into_inner());
+        processes.remove(self.handle.id());
+    }
+}
+
+/// 진행 중인 FFmpeg 작업 취소 (이미 끝났으면 false). 원래 호출은 `Cancelled` 에러로 끝난다.
+#[tauri::command]
+pub fn cancel_media_job(job_id: String) -> bool {
+    let found = cancel_job(&job_id);
+    // 잠금을 쥔 채 종료해야 그 사이 회수·재사용된 PID를 죽이지 않는다
+    let processes = process_registry().lock().unwrap_or_else(|e| e.into_inner());
+    for pid in processes.get(&job_id).into_iter().flatten() {
+        kill_process_tree(*pid);
+    }
+    found
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use crate::modules::error::AppError;
+
+    #[test]
+    fn cancel_kills_tracked_process_and_discards_output() {
+        let job = MediaJob::start(Some(new_job_id("test-media"))).unwrap();
+        let id = job.handle.id().to_string();
+        let mut child = job
+            .spawn(Command::new("sleep").arg("30"))
+            .expect("sleep 실행");
+        let partial = std::env::temp_dir().join(format!("{}.partial", id));
+        std::fs::write(&partial, b"x").unwrap();
+
+        assert!(cancel_media_job(id.clone()));
+        let status = job.wait(&mut child).unwrap();
+        assert!(!status.success());
+        assert!(matches!(
+            job.check_and_discard(&partial),
+            Err(AppError::Cancelled(_))
+        ));
+        assert!(!partial.exists());
+        // 취소된 작업은 새 프로세스를 띄우지 않음
+        assert!(job.spawn(&mut Command::new("true")).is_err());
+
+        drop(job);
+        assert!(!cancel_media_job(id));
+    }
+}
@@ -146,6 +146,7 @@ pub async fn ensure_thumbnails_batch(
                         crate::modules::image_ops::get_psd_thumbnail_path(app, task_path, size).await
                     }
                     "video" => get_video_thumbnail_path(app, task_path, size).await,
+                    "audio" => super::audio::get_audio_thumbnail_path(app, task_path, size).await,
                     _ => Ok(None),
                 }
             });
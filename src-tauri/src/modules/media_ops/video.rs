@@ -1,14 +1,23 @@
 //! 비디오 압축/편집/변환 처리 모듈
+//! 모든 command는 선택적 `job_id`를 받아 `cancel_media_job`으로 중단할 수 있다.
 
+mod batch;
 mod compress;
 mod concat;
 mod edit;
 pub(crate) mod encoders;
 mod gif;
+mod lossless;
 mod progress;
 
 use crate::modules::error::Result;
 
+pub use batch::{VideoBatchItem, VideoBatchOptions, VideoBatchProgress};
+pub use lossless::VideoEditMode;
+
+pub(super) use edit::run_edit_encode_attempt;
+pub(super) use progress::parse_ffmpeg_time;
+
 #[derive(Clone, serde::Serialize)]
 pub struct VideoProgress {
     pub percent: f32,
@@ -21,9 +30,20 @@ pub async fn compress_video(
     input: String,
     quality: String,
     scale_percent: Option<u32>,
+    job_id: Option<String>,
     on_progress: tauri::ipc::Channel<VideoProgress>,
 ) -> Result<String> {
-    compress::compress_video(input, quality, scale_percent, on_progress).await
+    compress::compress_video(input, quality, scale_percent, job_id, on_progress).await
+}
+
+/// 여러 파일을 같은 프리셋으로 압축 (항목별 상태가 담긴 큐 진행률 전송)
+#[tauri::command]
+pub async fn compress_videos_batch(
+    inputs: Vec<String>,
+    options: VideoBatchOptions,
+    on_progress: tauri::ipc::Channel<VideoBatchProgress>,
+) -> Result<VideoBatchProgress> {
+    batch::compress_videos_batch(inputs, options, on_progress).await
 }
 
 #[tauri::command]
@@ -37,6 +57,8 @@ pub async fn trim_video(
     crop_h: Option<i32>,
     scale_width: Option<i32>,
     speed: Option<f64>,
+    mode: Option<VideoEditMode>,
+    job_id: Option<String>,
     on_progress: tauri::ipc::Channel<VideoProgress>,
 ) -> Result<String> {
     edit::trim_video(
@@ -49,6 +71,8 @@ pub async fn trim_video(
         crop_h,
         scale_width,
         speed,
+        mode,
+        job_id,
         on_progress,
     )
     .await
@@ -59,17 +83,26 @@ pub async fn cut_video(
     input: String,
     start_sec: f64,
     end_sec: f64,
+    mode: Option<VideoEditMode>,
+    job_id: Option<String>,
     on_progress: tauri::ipc::Channel<VideoProgress>,
 ) -> Result<String> {
-    edit::cut_video(input, start_sec, end_sec, on_progress).await
+    edit::cut_video(input, start_sec, end_sec, mode, job_id, on_progress).await
+}
+
+/// 첫 비디오 스트림의 키프레임 시각(초) — 무손실 트림/구간 삭제가 구간 경계를 맞추는 위치
+#[tauri::command]
+pub async fn list_video_keyframes(app: tauri::AppHandle, path: String) -> Result<Vec<f64>> {
+    lossless::list_video_keyframes(app, path).await
 }
 
 #[tauri::command]
 pub async fn concat_videos(
     paths: Vec<String>,
+    job_id: Option<String>,
     on_progress: tauri::ipc::Channel<VideoProgress>,
 ) -> Result<String> {
-    concat::concat_videos(paths, on_progress).await
+    concat::concat_videos(paths, job_id, on_progress).await
 }
 
 #[tauri::command]
@@ -83,6 +116,7 @@ pub async fn video_to_gif(
     crop_h: Option<i32>,
     scale_width: Option<i32>,
     speed: Option<f64>,
+    job_id: Option<String>,
     on_progress: tauri::ipc::Channel<VideoProgress>,
 ) -> Result<String> {
     gif::video_to_gif(
@@ -95,6 +129,7 @@ pub async fn video_to_gif(
         crop_h,
         scale_width,
         speed,
+        job_id,
         on_progress,
     )
     .await
@@ -0,0 +1,330 @@
+//! 비디오 일괄 압축 큐
+//! 같은 프리셋(품질·축소 비율·출력 폴더)으로 여러 파일을 처리하고 항목별 상태를 담은
+//! 큐 진행률을 보낸다. 작업 ID 하나를 공유하므로 취소하면 실행 중인 인코딩이 모두 멈춘다.
+
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use super::compress::{compress_file, compressed_output_path, max_parallel_encodes};
+use super::VideoProgress;
+use crate::modules::error::{AppError, Result};
+use crate::modules::media_ops::probe::probe_media_cached;
+use crate::modules::media_ops::process::MediaJob;
+
+/// 항목 내부 진행률 전송 최소 간격 (상태 변화는 즉시 전송)
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(200);
+
+/// 일괄 압축 공통 프리셋 (생략한 필드는 기본값)
+#[derive(Clone, Default, serde::Serialize, serde::Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct VideoBatchOptions {
+    pub job_id: Option<String>,
+    /// low / medium / high (기본 medium)
+    pub quality: Option<String>,
+    pub scale_percent: Option<u32>,
+    /// 없으면 각 입력 파일과 같은 폴더에 저장
+    pub output_dir: Option<String>,
+    /// 동시 인코딩 수 (하드웨어 인코더가 허용하는 수로 제한, 기본 1)
+    pub concurrency: Option<usize>,
+}
+
+#[derive(Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoBatchItem {
+    pub id: u32,
+    pub input: String,
+    pub name: String,
+    /// pending / active / completed / failed / cancelled
+    pub status: String,
+    pub percent: f32,
+    pub output: Option<String>,
+    pub error: Option<String>,
+}
+
+/// 일괄 압축 큐 진행률 (`TransferQueueProgress`와 같은 구성)
+#[derive(Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoBatchProgress {
+    pub job_id: String,
+    /// encoding / done / cancelled
+    pub phase: String,
+    pub done_items: u32,
+    pub failed_items: u32,
+    pub total_items: u32,
+    /// 끝난 항목과 진행 중 항목의 진행률을 합친 전체 진행률
+    pub percent: f32,
+    pub items: Vec<VideoBatchItem>,
+}
+
+impl VideoBatchProgress {
+    /// 항목 상태로 카운트와 전체 진행률 재계산
+    fn refresh_totals(&mut self) {
+        let mut finished = 0.0f32;
+        self.done_items = 0;
+        self.failed_items = 0;
+        for item in &self.items {
+            match item.status.as_str() {
+                "completed" => self.done_items += 1,
+                "failed" => self.failed_items += 1,
+                _ => {}
+            }
+            finished += match item.status.as_str() {
+                "completed" | "failed" | "cancelled" => 1.0,
+                "active" => item.percent / 100.0,
+                _ => 0.0,
+            };
+        }
+        self.percent = finished / self.total_items.max(1) as f32 * 100.0;
+    }
+}
+
+/// 워커 스레드가 공유하는 큐 상태와 전송 채널
+struct BatchQueue {
+    progress: VideoBatchProgress,
+    last_sent: Option<Instant>,
+    on_progress: tauri::ipc::Channel<VideoBatchProgress>,
+}
+
+impl BatchQueue {
+    fn report(&mut self, force: bool) {
+        if !force
+            && self
+                .last_sent
+                .is_some_and(|t| t.elapsed() < PROGRESS_INTERVAL)
+        {
+            return;
+        }
+        self.progress.refresh_totals();
+        let _ = self.on_progress.send(self.progress.clone());
+        self.last_sent = Some(Instant::now());
+    }
+}
+
+fn lock_queue(queue: &Mutex<BatchQueue>) -> std::sync::MutexGuard<'_, BatchQueue> {
+    queue.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// 항목 하나 압축. 결과는 큐 상태에 기록한다.
+fn process_item(
+    queue: &Arc<Mutex<BatchQueue>>,
+    index: usize,
+    options: &VideoBatchOptions,
+    job: &MediaJob,
+) {
+    if job.is_cancelled() {
+        return;
+    }
+    let input = lock_queue(queue).progress.items[index].input.clone();
+    let input_path = Path::new(&input);
+
+    // 출력 경로 확정과 자리 확보를 한 번에 해 동시 실행 항목끼리 이름이 겹치지 않게 한다
+    let reserved = {
+        let mut q = lock_queue(queue);
+        let reserved = if input_path.is_file() {
+            let dir = match &options.output_dir {
+                Some(dir) => Path::new(dir),
+                None => input_path.parent().unwrap_or(Path::new(".")),
+            };
+            let output = compressed_output_path(input_path, dir);
+            std::fs::File::create(&output)
+                .map(|_| output)
+                .map_err(AppError::from)
+        } else {
+            Err(AppError::NotFound(input.clone()))
+        };
+        let item = &mut q.progress.items[index];
+        match &reserved {
+            Ok(_) => item.status = "active".to_string(),
+            Err(e) => {
+                item.status = "failed".to_string();
+                item.error = Some(e.to_string());
+            }
+        }
+        q.report(true);
+        reserved
+    };
+    let Ok(output_path) = reserved else {
+        return;
+    };
+
+    // ffmpeg는 경과 시간(초)을 보내므로 길이를 알 때만 항목 진행률로 환산
+    let duration = probe_media_cached(input_path)
+        .ok()
+        .and_then(|p| p.duration_sec)
+        .filter(|d| *d > 0.0);
+    let progress_queue = Arc::clone(queue);
+    let on_item_progress = move |p: VideoProgress| {
+        let Some(duration) = duration else {
+            return;
+        };
+        if p.percent < 0.0 {
+            return;
+        }
+        let mut q = lock_queue(&progress_queue);
+        q.progress.items[index].percent =
+            (p.percent as f64 / duration * 100.0).clamp(0.0, 99.9) as f32;
+        q.report(false);
+    };
+
+    let result = compress_file(
+        &input,
+        &output_path,
+        options.quality.as_deref().unwrap_or("medium"),
+        options.scale_percent,
+        job,
+        &on_item_progress,
+    );
+
+    let mut q = lock_queue(queue);
+    let item = &mut q.progress.items[index];
+    match result {
+        Ok(()) => {
+            item.status = "completed".to_string();
+            item.percent = 100.0;
+            item.output = Some(output_path.to_string_lossy().to_string());
+        }
+        Err(e) => {
+            // 인코딩 실패 시 지워지지만, 실행 전 실패(도구 없음 등)는 확보한 빈 파일이 남는다
+            let _ = std::fs::remove_file(&output_path);
+            if matches!(e, AppError::Cancelled(_)) {
+                item.status = "cancelled".to_string();
+            } else {
+                item.status = "failed".to_string();
+                item.error = Some(e.to_string());
+            }
+        }
+    }
+    q.report(true);
+}
+
+pub(super) async fn compress_videos_batch(
+    inputs: Vec<String>,
+    options: VideoBatchOptions,
+    on_progress: tauri::ipc::Channel<VideoBatchProgress>,
+) -> Result<VideoBatchProgress> {
+    if inputs.is_empty() {
+        return Err(AppError::InvalidInput(
+            "압축할 파일을 선택하세요".to_string(),
+        ));
+    }
+    if let Some(dir) = &options.output_dir {
+        std::fs::create_dir_all(dir)?;
+    }
+    let job = MediaJob::start(options.job_id.clone())?;
+
+    let items = inputs
+        .into_iter()
+        .enumerate()
+        .map(|(i, input)| VideoBatchItem {
+            id: i as u32,
+            name: Path::new(&input)
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_else(|| input.clone()),
+            input,
+            status: "pending".to_string(),
+            percent: 0.0,
+            output: None,
+            error: None,
+        })
+        .collect::<Vec<_>>();
+    let total = items.len();
+    let queue = Arc::new(Mutex::new(BatchQueue {
+        progress: VideoBatchProgress {
+            job_id: job.id().to_string(),
+            phase: "encoding".to_string(),
+            done_items: 0,
+            failed_items: 0,
+            total_items: total as u32,
+            percent: 0.0,
+            items,
+        },
+        last_sent: None,
+        on_progress,
+    }));
+    lock_queue(&queue).report(true);
+
+    let workers = options
+        .concurrency
+        .unwrap_or(1)
+        .clamp(1, max_parallel_encodes())
+        .min(total);
+    let next = AtomicUsize::new(0);
+    tauri::async_runtime::spawn_blocking({
+        let queue = Arc::clone(&queue);
+        move || {
+            std::thread::scope(|s| {
+                for _ in 0..workers {
+                    s.spawn(|| loop {
+                        let index = next.fetch_add(1, Ordering::SeqCst);
+                        if index >= total || job.is_cancelled() {
+                            break;
+                        }
+                        process_item(&queue, index, &options, &job);
+                    });
+                }
+            });
+            finish_batch(&queue, &job)
+        }
+    })
+    .await
+    .map_err(|e| AppError::Internal(format!("일괄 압축 태스크 실패: {}", e)))?
+}
+
+/// 마지막 진행률 전송. 취소된 경우 남은 항목을 cancelled로 표시하고 `Cancelled`를 반환한다.
+fn finish_batch(queue: &Mutex<BatchQueue>, job: &MediaJob) -> Result<VideoBatchProgress> {
+    let mut q = lock_queue(queue);
+    if job.is_cancelled() {
+        for item in &mut q.progress.items {
+            if item.status == "pending" || item.status == "active" {
+                item.status = "cancelled".to_string();
+            }
+        }
+        q.progress.phase = "cancelled".to_string();
+        q.report(true);
+        job.check()?;
+    }
+    q.progress.phase = "done".to_string();
+    q.report(true);
+    Ok(q.progress.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(id: u32, status: &str, percent: f32) -> VideoBatchItem {
+        VideoBatchItem {
+            id,
+            input: format!("/v/{id}.mp4"),
+            name: format!("{id}.mp4"),
+            status: status.to_string(),
+            percent,
+            output: None,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn totals_count_finished_items_and_active_progress() {
+        let mut progress = VideoBatchProgress {
+            job_id: "media-test".to_string(),
+            phase: "encoding".to_string(),
+            done_items: 0,
+            failed_items: 0,
+            total_items: 4,
+            percent: 0.0,
+            items: vec![
+                item(0, "completed", 100.0),
+                item(1, "failed", 0.0),
+                item(2, "active", 50.0),
+                item(3, "pending", 0.0),
+            ],
+        };
+        progress.refresh_totals();
+        assert_eq!((progress.done_items, progress.failed_items), (1, 1));
+        assert!((progress.percent - 62.5).abs() < 1e-4);
+    }
+}
@@ -1,6 +1,7 @@
 use super::VideoProgress;
 use crate::helpers::find_unique_path;
 use crate::modules::error::{AppError, Result};
+use crate::modules::media_ops::process::MediaJob;
 use crate::modules::tool_ops::find_ffmpeg_path;
 
 /// 인코더 후보 (라벨은 에러 메시지용)
@@ -116,16 +117,29 @@ fn encoder_candidates(quality: &str) -> Vec<EncoderAttempt> {
     list
 }
 
+/// 동시에 돌려도 느려지지 않는 인코딩 수.
+/// Apple Silicon VideoToolbox는 인코드 엔진을 나눠 쓸 수 있어 2개까지, 그 외(Media Foundation/SW)는 1개.
+pub(super) fn max_parallel_encodes() -> usize {
+    if cfg!(all(target_os = "macos", target_arch = "aarch64")) {
+        2
+    } else {
+        1
+    }
+}
+
 /// 단일 인코더로 인코딩 시도. 실패 시 stderr 요약을 Err로 반환.
-fn run_encode_attempt(
+fn run_encode_attempt<P>(
     ffmpeg_path: &std::path::Path,
     input: &str,
-    output_str: &str,
     output_path: &std::path::Path,
     hwaccel_args: &[String],
     video_args: &[String],
-    on_progress: &tauri::ipc::Channel<VideoProgress>,
-) -> std::result::Result<(), String> {
+    on_progress: &P,
+    job: &MediaJob,
+) -> std::result::Result<(), String>
+where
+    P: Fn(VideoProgress) + Clone + Send + 'static,
+{
     let mut cmd = std::process::Command::new(ffmpeg_path);
     cmd.arg("-y");
     cmd.args(hwaccel_args); // 입력 옵션이므로 -i 앞에 위치해야 함
@@ -133,7 +147,7 @@ fn run_encode_attempt(
     cmd.args(video_args);
     cmd.args(["-c:a", "aac", "-b:a", "128k"]);
     cmd.args(["-progress", "pipe:1"]);
-    cmd.arg(output_str);
+    cmd.arg(output_path);
 
     // Windows: 콘솔 창 숨기기 (CREATE_NO_WINDOW)
     #[cfg(target_os = "windows")]
@@ -142,11 +156,9 @@ fn run_encode_attempt(
         cmd.creation_flags(0x08000000);
     }
 
-    let mut child = cmd
-        .stdout(std::process::Stdio::piped())
-        .stderr(std::process::Stdio::piped())
-        .spawn()
-        .map_err(|e| format!("실행 실패: {e}"))?;
+    cmd.stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped());
+    let mut child = job.spawn(&mut cmd).map_err(|e| format!("실행 실패: {e}"))?;
 
     // stdout에서 -progress 출력 파싱 (별도 스레드)
     let stdout = child.stdout.take();
@@ -160,7 +172,7 @@ fn run_encode_attempt(
                 if let Some(val) = line.strip_prefix("out_time_ms=") {
                     if let Ok(us) = val.parse::<i64>() {
                         let secs = us as f32 / 1_000_000.0;
-                        let _ = on_progress_clone.send(VideoProgress {
+                        on_progress_clone(VideoProgress {
                             percent: secs,
                             speed: String::new(),
                             fps: 0.0,
@@ -168,7 +180,7 @@ fn run_encode_attempt(
                     }
                 } else if let Some(val) = line.strip_prefix("speed=") {
                     let speed_str = val.trim().to_string();
-                    let _ = on_progress_clone.send(VideoProgress {
+                    on_progress_clone(VideoProgress {
                         percent: -2.0, // 스피드만 업데이트 신호
                         speed: speed_str,
                         fps: 0.0,
@@ -189,7 +201,9 @@ fn run_encode_attempt(
         output
     });
 
-    let status = child.wait().map_err(|e| format!("대기 실패: {e}"))?;
+    let status = job
+        .wait(&mut child)
+        .map_err(|e| format!("대기 실패: {e}"))?;
     let _ = progress_thread.join();
     let stderr_output = stderr_thread.join().unwrap_or_default();
 
@@ -223,17 +237,51 @@ pub async fn compress_video(
     input: String,
     quality: String,
     scale_percent: Option<u32>,
+    job_id: Option<String>,
     on_progress: tauri::ipc::Channel<VideoProgress>,
 ) -> Result<String> {
+    let job = MediaJob::start(job_id)?;
+
     // 출력 파일명: {이름}_comp.{확장자}, 충돌 시 _comp_2, _comp_3 ...
     let input_path = std::path::Path::new(&input);
-    let stem = input_path.file_stem().unwrap_or_default().to_string_lossy();
-    let ext = input_path.extension().unwrap_or_default().to_string_lossy();
     let parent = input_path.parent().unwrap_or(std::path::Path::new("."));
+    let output_path = compressed_output_path(input_path, parent);
+
+    compress_file(
+        &input,
+        &output_path,
+        &quality,
+        scale_percent,
+        &job,
+        &move |p: VideoProgress| {
+            let _ = on_progress.send(p);
+        },
+    )?;
+    Ok(output_path.to_string_lossy().to_string())
+}
 
-    let output_path = find_unique_path(parent, &stem, "_comp", &format!(".{}", ext));
-    let output_str = output_path.to_string_lossy().to_string();
+/// `{이름}_comp.{확장자}` 출력 경로 (충돌 시 _comp_2, _comp_3 ...)
+pub(super) fn compressed_output_path(
+    input_path: &std::path::Path,
+    output_dir: &std::path::Path,
+) -> std::path::PathBuf {
+    let stem = input_path.file_stem().unwrap_or_default().to_string_lossy();
+    let ext = input_path.extension().unwrap_or_default().to_string_lossy();
+    find_unique_path(output_dir, &stem, "_comp", &format!(".{}", ext))
+}
 
+/// 파일 하나를 `output_path`로 압축 (인코더 후보를 순서대로 시도)
+pub(super) fn compress_file<P>(
+    input: &str,
+    output_path: &std::path::Path,
+    quality: &str,
+    scale_percent: Option<u32>,
+    job: &MediaJob,
+    on_progress: &P,
+) -> Result<()>
+where
+    P: Fn(VideoProgress) + Clone + Send + 'static,
+{
     // ffmpeg 경로 결정 (번들 → 다운로드본 → 패키지 관리자 → 시스템 PATH)
     let ffmpeg_path = find_ffmpeg_path().ok_or_else(|| AppError::ToolNotFound {
         tool: "FFmpeg".to_string(),
@@ -260,7 +308,7 @@ pub async fn compress_video(
 
     // 인코더 후보를 순서대로 시도 — 앞 순서 실패(빌드에 인코더 없음 등) 시 다음 후보로 폴백
     let mut errors: Vec<String> = Vec::new();
-    for attempt in encoder_candidates(&quality) {
+    for attempt in encoder_candidates(quality) {
         let mut video_args = attempt.video_args.clone();
         if let Some(vf) = &scale_filter {
             video_args.push("-vf".into());
@@ -270,15 +318,17 @@ pub async fn compress_video(
         for hwaccel_args in [&hw_args, &no_hw_args] {
             match run_encode_attempt(
                 &ffmpeg_path,
-                &input,
-                &output_str,
-                &output_path,
+                input,
+                output_path,
                 hwaccel_args,
                 &video_args,
-                &on_progress,
+                on_progress,
+                job,
             ) {
-                Ok(()) => return Ok(output_str),
+                Ok(()) => return Ok(()),
                 Err(e) => {
+                    // 취소는 다음 후보로 넘어가지 않고 부분 출력을 지운 뒤 종료
+                    job.check_and_discard(output_path)?;
                     let mode = if hwaccel_args.is_empty() { "sw" } else { "hw" };
                     eprintln!("⚠️ 인코더 {} ({mode} 디코드) 실패: {e}", attempt.label);
                     errors.push(format!("[{}/{mode}] {e}", attempt.label));
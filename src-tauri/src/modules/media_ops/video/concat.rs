@@ -2,13 +2,16 @@ use super::encoders::h264_encoder_candidates;
 use super::VideoProgress;
 use crate::helpers::find_unique_path;
 use crate::modules::error::{AppError, Result};
+use crate::modules::media_ops::process::MediaJob;
 use crate::modules::tool_ops::find_ffmpeg_path;
 
 // --- 동영상 이어붙이기 (concat) ---
 pub async fn concat_videos(
     paths: Vec<String>,
+    job_id: Option<String>,
     on_progress: tauri::ipc::Channel<VideoProgress>,
 ) -> Result<String> {
+    let job = MediaJob::start(job_id)?;
     if paths.is_empty() {
         return Err(AppError::InvalidInput(
             "이어붙일 파일이 없습니다.".to_string(),
@@ -88,12 +91,16 @@ pub async fn concat_videos(
             output_str.clone(),
         ]);
 
-        match run_concat_attempt(&ffmpeg_path, &args, &output_path, &on_progress) {
+        match run_concat_attempt(&ffmpeg_path, &args, &output_path, &on_progress, &job) {
             Ok(()) => {
                 let _ = std::fs::remove_dir_all(&tmp_dir);
                 return Ok(output_str);
             }
             Err(e) => {
+                if let Err(cancelled) = job.check_and_discard(&output_path) {
+                    let _ = std::fs::remove_dir_all(&tmp_dir);
+                    return Err(cancelled);
+                }
                 eprintln!("⚠️ 인코더 {} 실패: {e}", attempt.label);
                 errors.push(format!("[{}] {e}", attempt.label));
             }
@@ -110,6 +117,7 @@ fn run_concat_attempt(
     args: &[String],
     output_path: &std::path::Path,
     on_progress: &tauri::ipc::Channel<VideoProgress>,
+    job: &MediaJob,
 ) -> std::result::Result<(), String> {
     let mut cmd = std::process::Command::new(ffmpeg_path);
     cmd.args(args);
@@ -121,11 +129,9 @@ fn run_concat_attempt(
         cmd.creation_flags(0x08000000);
     }
 
-    let mut child = cmd
-        .stdout(std::process::Stdio::piped())
-        .stderr(std::process::Stdio::piped())
-        .spawn()
-        .map_err(|e| format!("실행 실패: {e}"))?;
+    cmd.stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped());
+    let mut child = job.spawn(&mut cmd).map_err(|e| format!("실행 실패: {e}"))?;
 
     let stdout = child.stdout.take();
     let on_progress_clone = on_progress.clone();
@@ -165,7 +171,9 @@ fn run_concat_attempt(
         output
     });
 
-    let status = child.wait().map_err(|e| format!("대기 실패: {}", e))?;
+    let status = job
+        .wait(&mut child)
+        .map_err(|e| format!("대기 실패: {}", e))?;
     let _ = progress_thread.join();
     let stderr_output = stderr_thread.join().unwrap_or_default();
 
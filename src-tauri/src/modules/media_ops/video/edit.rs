@@ -1,10 +1,12 @@
 use super::encoders::h264_encoder_candidates;
+use super::lossless::{export_lossless, KeepRange, VideoEditMode};
 use super::VideoProgress;
 use crate::helpers::find_unique_path;
 use crate::modules::error::{AppError, Result};
+use crate::modules::media_ops::process::MediaJob;
 use crate::modules::tool_ops::find_ffmpeg_path;
 
-fn format_seconds(sec: f64) -> String {
+pub(super) fn format_seconds(sec: f64) -> String {
     let mut value = format!("{:.6}", sec.max(0.0));
     while value.contains('.') && value.ends_with('0') {
         value.pop();
@@ -209,8 +211,11 @@ pub async fn trim_video(
     crop_h: Option<i32>,
     scale_width: Option<i32>,
     speed: Option<f64>,
+    mode: Option<VideoEditMode>,
+    job_id: Option<String>,
     on_progress: tauri::ipc::Channel<VideoProgress>,
 ) -> Result<String> {
+    let job = MediaJob::start(job_id)?;
     let input_path = std::path::Path::new(&input);
     let stem = input_path
         .file_stem()
@@ -245,6 +250,28 @@ pub async fn trim_video(
         _ => None,
     };
 
+    // 무손실 모드: 크롭·축소·배속은 필터가 필요하므로 재인코딩으로 진행
+    let mode = mode.unwrap_or_default();
+    let needs_filters =
+        crop.is_some() || scale_width.is_some_and(|w| w > 0) || normalize_speed(speed).is_some();
+    if mode != VideoEditMode::Reencode && !needs_filters {
+        let ranges = [KeepRange {
+            start: start_sec,
+            end: Some(end_sec),
+        }];
+        if let Some(output) = export_lossless(
+            &ffmpeg_path,
+            &input,
+            &ranges,
+            mode,
+            "_trim",
+            &job,
+            &on_progress,
+        )? {
+            return Ok(output);
+        }
+    }
+
     // 인코더 후보를 순서대로 시도 — 번들 LGPL FFmpeg에 없는 인코더는 다음 후보로 폴백
     let mut errors: Vec<String> = Vec::new();
     for attempt in h264_encoder_candidates(18) {
@@ -259,9 +286,17 @@ pub async fn trim_video(
             &attempt.video_args,
         );
         add_progress_before_output(&mut args);
-        match run_edit_encode_attempt(&ffmpeg_path, &args, duration, &output_path, &on_progress) {
+        match run_edit_encode_attempt(
+            &ffmpeg_path,
+            &args,
+            duration,
+            &output_path,
+            &on_progress,
+            &job,
+        ) {
             Ok(()) => return Ok(output_str),
             Err(e) => {
+                job.check_and_discard(&output_path)?;
                 eprintln!("⚠️ 인코더 {} 실패: {e}", attempt.label);
                 errors.push(format!("[{}] {e}", attempt.label));
             }
@@ -271,13 +306,14 @@ pub async fn trim_video(
 }
 
 /// 단일 인코더로 트림 인코딩 시도. -progress 출력을 퍼센트로 변환해 전달하고,
-/// 실패 시 stderr 요약을 Err로 반환한다.
-fn run_edit_encode_attempt(
+/// 실패 시 stderr 요약을 Err로 반환한다. (오디오 변환도 같은 실행기를 쓴다)
+pub(crate) fn run_edit_encode_attempt(
     ffmpeg_path: &std::path::Path,
     args: &[String],
     duration: f32,
     output_path: &std::path::Path,
     on_progress: &tauri::ipc::Channel<VideoProgress>,
+    job: &MediaJob,
 ) -> std::result::Result<(), String> {
     let mut cmd = std::process::Command::new(ffmpeg_path);
     cmd.args(args);
@@ -289,11 +325,9 @@ fn run_edit_encode_attempt(
         cmd.creation_flags(0x08000000);
     }
 
-    let mut child = cmd
-        .stdout(std::process::Stdio::piped())
-        .stderr(std::process::Stdio::piped())
-        .spawn()
-        .map_err(|e| format!("실행 실패: {e}"))?;
+    cmd.stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped());
+    let mut child = job.spawn(&mut cmd).map_err(|e| format!("실행 실패: {e}"))?;
 
     let stdout = child.stdout.take();
     let on_progress_clone = on_progress.clone();
@@ -335,7 +369,9 @@ fn run_edit_encode_attempt(
         output
     });
 
-    let status = child.wait().map_err(|e| format!("대기 실패: {}", e))?;
+    let status = job
+        .wait(&mut child)
+        .map_err(|e| format!("대기 실패: {}", e))?;
     let _ = progress_thread.join();
     let stderr_output = stderr_thread.join().unwrap_or_default();
 
@@ -525,6 +561,7 @@ fn extract_cut_part(
     part_path: &std::path::Path,
     start_sec: Option<f64>,
     duration_sec: Option<f64>,
+    job: &MediaJob,
 ) -> Result<bool> {
     let part_str = part_path.to_string_lossy().to_string();
     let mut errors: Vec<String> = Vec::new();
@@ -537,13 +574,12 @@ fn extract_cut_part(
             use std::os::windows::process::CommandExt;
             cmd.creation_flags(0x08000000);
         }
-        let output = cmd
-            .stdout(std::process::Stdio::null())
-            .output()
-            .map_err(|e| AppError::ToolExecution {
-                tool: "FFmpeg".to_string(),
-                reason: format!("부분 추출 실행 실패: {}", e),
-            })?;
+        let output = job.output(&mut cmd).map_err(|e| AppError::ToolExecution {
+            tool: "FFmpeg".to_string(),
+            reason: format!("부분 추출 실행 실패: {}", e),
+        });
+        job.check_and_discard(part_path)?;
+        let output = output?;
         let stderr_output = String::from_utf8_lossy(&output.stderr);
         // 구간이 비어 출력이 없는 경우는 인코더 문제가 아니므로 "내용 없음"으로 정상 처리
         if ffmpeg_output_is_empty(&stderr_output) {
@@ -574,8 +610,11 @@ pub async fn cut_video(
     input: String,
     start_sec: f64,
     end_sec: f64,
+    mode: Option<VideoEditMode>,
+    job_id: Option<String>,
     on_progress: tauri::ipc::Channel<VideoProgress>,
 ) -> Result<String> {
+    let job = MediaJob::start(job_id)?;
     let input_path = std::path::Path::new(&input);
     let stem = input_path
         .file_stem()
@@ -601,6 +640,33 @@ pub async fn cut_video(
         tool: "FFmpeg".to_string(),
     })?;
 
+    // 무손실 모드: 앞 구간(0 ~ start)과 뒷 구간(end ~ 끝)을 복사해 이어붙임
+    let mode = mode.unwrap_or_default();
+    if mode != VideoEditMode::Reencode {
+        let mut ranges = Vec::new();
+        if start_sec > 0.001 {
+            ranges.push(KeepRange {
+                start: 0.0,
+                end: Some(start_sec),
+            });
+        }
+        ranges.push(KeepRange {
+            start: end_sec,
+            end: None,
+        });
+        if let Some(output) = export_lossless(
+            &ffmpeg_path,
+            &input,
+            &ranges,
+            mode,
+            "_cut",
+            &job,
+            &on_progress,
+        )? {
+            return Ok(output);
+        }
+    }
+
     // 임시 디렉토리 생성 (프로세스 ID 포함으로 충돌 방지)
     let pid = std::process::id();
     let tmp_dir = std::env::temp_dir().join(format!("qf_cut_video_{}", pid));
@@ -625,20 +691,22 @@ pub async fn cut_video(
     let mut has_part1 = false;
     if start_sec > 0.001 {
         send_progress(0, 0.0);
-        has_part1 = match extract_cut_part(&ffmpeg_path, &input, &part1, None, Some(start_sec)) {
-            Ok(v) => v,
-            Err(e) => {
-                let _ = std::fs::remove_dir_all(&tmp_dir);
-                return Err(e);
-            }
-        };
+        has_part1 =
+            match extract_cut_part(&ffmpeg_path, &input, &part1, None, Some(start_sec), &job) {
+                Ok(v) => v,
+                Err(e) => {
+                    let _ = std::fs::remove_dir_all(&tmp_dir);
+                    return Err(e);
+                }
+            };
         send_progress(0, 1.0);
     }
 
     // --- 뒷 부분 추출 (end_sec ~ 끝) ---
     // end_sec가 충분히 크면 뒷 부분이 없을 수 있으므로 결과 파일 크기로 판단
     send_progress(1, 0.0);
-    let has_part2 = match extract_cut_part(&ffmpeg_path, &input, &part2, Some(end_sec), None) {
+    let has_part2 = match extract_cut_part(&ffmpeg_path, &input, &part2, Some(end_sec), None, &job)
+    {
         Ok(v) => v,
         Err(e) => {
             let _ = std::fs::remove_dir_all(&tmp_dir);
@@ -690,22 +758,25 @@ pub async fn cut_video(
             use std::os::windows::process::CommandExt;
             cmd.creation_flags(0x08000000);
         }
-        let status = cmd
-            .stdout(std::process::Stdio::null())
-            .stderr(std::process::Stdio::null())
-            .spawn()
+        cmd.stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null());
+        let status = job
+            .spawn(&mut cmd)
             .map_err(|e| AppError::ToolExecution {
                 tool: "FFmpeg".to_string(),
                 reason: format!("실행 실패 (합치기): {}", e),
-            })?
-            .wait()
-            .map_err(|e| AppError::ToolExecution {
-                tool: "FFmpeg".to_string(),
-                reason: format!("대기 실패 (합치기): {}", e),
-            })?;
-        if !status.success() {
+            })
+            .and_then(|mut child| {
+                job.wait(&mut child).map_err(|e| AppError::ToolExecution {
+                    tool: "FFmpeg".to_string(),
+                    reason: format!("대기 실패 (합치기): {}", e),
+                })
+            });
+        if !status.as_ref().is_ok_and(|s| s.success()) {
             let _ = std::fs::remove_dir_all(&tmp_dir);
             let _ = std::fs::remove_file(&output_path);
+            job.check()?;
+            status?;
             return Err(AppError::VideoProcessing(
                 "ffmpeg concat 합치기 실패".to_string(),
             ));
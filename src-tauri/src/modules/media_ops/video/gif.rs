@@ -1,6 +1,7 @@
 use super::VideoProgress;
 use crate::helpers::find_unique_path;
 use crate::modules::error::{AppError, Result};
+use crate::modules::media_ops::process::MediaJob;
 use crate::modules::tool_ops::find_ffmpeg_path;
 
 // --- 동영상 구간을 GIF로 변환 ---
@@ -14,8 +15,10 @@ pub async fn video_to_gif(
     crop_h: Option<i32>,
     scale_width: Option<i32>,
     speed: Option<f64>,
+    job_id: Option<String>,
     on_progress: tauri::ipc::Channel<VideoProgress>,
 ) -> Result<String> {
+    let job = MediaJob::start(job_id)?;
     let input_path = std::path::Path::new(&input);
     let stem = input_path
         .file_stem()
@@ -83,22 +86,25 @@ pub async fn video_to_gif(
         cmd1.creation_flags(0x08000000);
     }
 
-    let status1 = cmd1
-        .stdout(std::process::Stdio::null())
-        .stderr(std::process::Stdio::null())
-        .spawn()
+    cmd1.stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null());
+    let status1 = job
+        .spawn(&mut cmd1)
         .map_err(|e| AppError::ToolExecution {
             tool: "FFmpeg".to_string(),
             reason: format!("실행 실패 (팔레트): {}", e),
-        })?
-        .wait()
-        .map_err(|e| AppError::ToolExecution {
-            tool: "FFmpeg".to_string(),
-            reason: format!("대기 실패 (팔레트): {}", e),
-        })?;
-
-    if !status1.success() || !palette_path.exists() {
+        })
+        .and_then(|mut child| {
+            job.wait(&mut child).map_err(|e| AppError::ToolExecution {
+                tool: "FFmpeg".to_string(),
+                reason: format!("대기 실패 (팔레트): {}", e),
+            })
+        });
+
+    if !status1.as_ref().is_ok_and(|s| s.success()) || !palette_path.exists() {
         let _ = std::fs::remove_dir_all(&tmp_dir);
+        job.check()?;
+        status1?;
         return Err(AppError::VideoProcessing("팔레트 생성 실패".to_string()));
     }
 
@@ -132,14 +138,19 @@ pub async fn video_to_gif(
         cmd2.creation_flags(0x08000000);
     }
 
-    let mut child = cmd2
-        .stdout(std::process::Stdio::piped())
-        .stderr(std::process::Stdio::piped())
-        .spawn()
-        .map_err(|e| AppError::ToolExecution {
-            tool: "FFmpeg".to_string(),
-            reason: format!("실행 실패 (GIF): {}", e),
-        })?;
+    cmd2.stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped());
+    let mut child = match job.spawn(&mut cmd2) {
+        Ok(child) => child,
+        Err(e) => {
+            let _ = std::fs::remove_dir_all(&tmp_dir);
+            job.check()?;
+            return Err(AppError::ToolExecution {
+                tool: "FFmpeg".to_string(),
+                reason: format!("실행 실패 (GIF): {}", e),
+            });
+        }
+    };
 
     let stdout = child.stdout.take();
     let on_progress_clone = on_progress.clone();
@@ -173,7 +184,7 @@ pub async fn video_to_gif(
         output
     });
 
-    let status = child.wait().map_err(|e| AppError::ToolExecution {
+    let status = job.wait(&mut child).map_err(|e| AppError::ToolExecution {
         tool: "FFmpeg".to_string(),
         reason: format!("대기 실패: {}", e),
     })?;
@@ -185,6 +196,7 @@ pub async fn video_to_gif(
 
     if !status.success() {
         let _ = std::fs::remove_file(&output_path);
+        job.check()?;
         let err_msg = stderr_output
             .lines()
             .filter(|l| l.contains("Error") || l.contains("error") || l.contains("not found"))
@@ -0,0 +1,1009 @@
+//! 무손실(스트림 복사) 트림/구간 삭제
+//! `keyframe` 모드는 남길 구간의 시작을 가장 가까운 키프레임에 맞춰 스트림을 그대로 복사하고,
+//! `smart` 모드는 시작 키프레임 앞의 불완전한 GOP만 원본과 같은 파라미터의 H.264로 재인코딩해
+//! 프레임 단위로 자른다. 오디오는 스트림 복사로 패킷 경계에서 자른다.
+//! 구간 끝은 키프레임이 필요 없으므로 요청한 위치 그대로 둔다.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+
+use super::edit::format_seconds;
+use super::encoders::{h264_encoder_candidates, EncoderAttempt};
+use super::VideoProgress;
+use crate::helpers::find_unique_path;
+use crate::modules::archive_ops::materialize_archive_path_in_cache;
+use crate::modules::error::{AppError, Result};
+use crate::modules::media_ops::probe::{probe_media_cached, MediaStream, MediaStreamKind};
+use crate::modules::media_ops::process::MediaJob;
+use crate::modules::tool_ops::find_ffmpeg_path;
+
+const KEYFRAME_CACHE_LIMIT: usize = 200;
+/// 키프레임 위치 비교 허용 오차 (타임베이스 반올림 흡수)
+const KEYFRAME_EPSILON: f64 = 0.001;
+
+/// 트림/구간 삭제 처리 방식
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum VideoEditMode {
+    /// 필터 체인으로 전체 재인코딩 (크롭·축소·배속 지원)
+    #[default]
+    Reencode,
+    /// 키프레임 단위 스트림 복사 (원본 컨테이너 유지)
+    Keyframe,
+    /// 경계 GOP만 재인코딩하고 나머지는 복사 (H.264 원본만, 그 외 코덱은 전체 재인코딩)
+    Smart,
+}
+
+/// 결과물에 남길 원본 구간 (end가 None이면 파일 끝까지)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) struct KeepRange {
+    pub(super) start: f64,
+    pub(super) end: Option<f64>,
+}
+
+/// 경계 GOP 재인코딩 결과를 복사 구간과 이어붙일 수 있게 맞출 원본 비디오 파라미터
+#[derive(Clone, Debug, Default, PartialEq)]
+struct SourceVideo {
+    pix_fmt: Option<String>,
+    /// ffmpeg 표기 프로파일 ("High", "Constrained Baseline" 등)
+    profile: Option<String>,
+    /// "4.1" 형식
+    level: Option<String>,
+    fps: Option<f64>,
+}
+
+impl SourceVideo {
+    fn from_stream(stream: &MediaStream, level: Option<String>) -> Self {
+        Self {
+            pix_fmt: stream.pixel_format.clone(),
+            profile: stream.profile.clone(),
+            level,
+            fps: stream.frame_rate.filter(|fps| *fps > 0.0),
+        }
+    }
+}
+
+/// 실행 단위 조각
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Piece {
+    Copy { start: f64, end: Option<f64> },
+    Encode { start: f64, end: Option<f64> },
+}
+
+/// showinfo 출력에서 프레임 시각 추출 (`-skip_frame nokey`로 키프레임만 디코딩한 결과)
+fn parse_showinfo_keyframes(stderr: &str) -> Vec<f64> {
+    let mut times: Vec<f64> = stderr
+        .lines()
+        .filter(|line| line.contains("Parsed_showinfo"))
+        .filter_map(|line| {
+            let rest = line.split_once("pts_time:")?.1;
+            rest.split_whitespace().next()?.parse::<f64>().ok()
+        })
+        .filter(|t| t.is_finite())
+        .collect();
+    times.sort_by(f64::total_cmp);
+    times.dedup_by(|a, b| (*a - *b).abs() < KEYFRAME_EPSILON);
+    times
+}
+
+/// 전체 키프레임 디코딩 스캔. 편집 작업 중이면 `job`으로 실행해 취소 시 바로 중단된다.
+fn scan_keyframes(input: &str, job: Option<&MediaJob>) -> Result<Vec<f64>> {
+    let ffmpeg_path = find_ffmpeg_path().ok_or_else(|| AppError::ToolNotFound {
+        tool: "FFmpeg".to_string(),
+    })?;
+    let mut cmd = std::process::Command::new(ffmpeg_path);
+    cmd.args([
+        "-hide_banner",
+        "-nostats",
+        "-skip_frame",
+        "nokey",
+        "-i",
+        input,
+        "-map",
+        "0:v:0",
+        "-an",
+        "-sn",
+        "-dn",
+        "-vf",
+        "showinfo",
+        "-f",
+        "null",
+        "-",
+    ]);
+
+    // Windows: 콘솔 창 숨기기
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt;
+        cmd.creation_flags(0x08000000);
+    }
+
+    let output = match job {
+        Some(job) => job.output(&mut cmd)?,
+        None => cmd.output()?,
+    };
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    if !output.status.success() {
+        if let Some(job) = job {
+            job.check()?;
+        }
+        return Err(AppError::VideoProcessing(format!(
+            "키프레임 목록을 읽을 수 없습니다: {}",
+            stderr.lines().last().unwrap_or(input)
+        )));
+    }
+    Ok(parse_showinfo_keyframes(&stderr))
+}
+
+/// 파일 정체성 기준 캐시 — 편집 창에서 구간을 옮길 때마다 다시 스캔하지 않는다
+/// 취소로 중단된 스캔의 짧은 목록은 캐시하지 않는다.
+pub(super) fn keyframes_cached(path: &Path, job: Option<&MediaJob>) -> Result<Vec<f64>> {
+    static CACHE: OnceLock<Mutex<HashMap<String, Vec<f64>>>> = OnceLock::new();
+    let meta = std::fs::metadata(path)?;
+    let key = format!(
+        "{}|{}",
+        path.display(),
+        crate::modules::types::file_identity(&meta)
+    );
+    let cache = CACHE.get_or_init(Default::default);
+    if let Some(hit) = cache.lock().unwrap_or_else(|e| e.into_inner()).get(&key) {
+        return Ok(hit.clone());
+    }
+    let keyframes = scan_keyframes(&path.to_string_lossy(), job)?;
+    if let Some(job) = job {
+        job.check()?;
+    }
+    let mut cache = cache.lock().unwrap_or_else(|e| e.into_inner());
+    if cache.len() >= KEYFRAME_CACHE_LIMIT {
+        cache.clear();
+    }
+    cache.insert(key, keyframes.clone());
+    Ok(keyframes)
+}
+
+pub(super) async fn list_video_keyframes(app: tauri::AppHandle, path: String) -> Result<Vec<f64>> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let resolved_path =
+            materialize_archive_path_in_cache(&app, &path)?.unwrap_or_else(|| PathBuf::from(&path));
+        keyframes_cached(&resolved_path, None)
+    })
+    .await
+    .map_err(|e| AppError::Internal(format!("키프레임 조회 태스크 실패: {}", e)))?
+}
+
+fn nearest_keyframe(keyframes: &[f64], t: f64) -> f64 {
+    keyframes
+        .iter()
+        .copied()
+        .min_by(|a, b| (a - t).abs().total_cmp(&(b - t).abs()))
+        .unwrap_or(0.0)
+}
+
+/// 길이가 없거나 파일 끝 이후에서 시작하는 구간
+fn is_empty_span(start: f64, end: Option<f64>, duration: Option<f64>) -> bool {
+    let limit = match (end, duration) {
+        (Some(end), Some(duration)) => Some(end.min(duration)),
+        (end, duration) => end.or(duration),
+    };
+    limit.is_some_and(|limit| start >= limit - KEYFRAME_EPSILON)
+}
+
+/// keyframe 모드: 첫 구간 시작은 가장 가까운 키프레임으로 옮기고, 삭제한 구간 뒤에 오는 구간은
+/// 앞으로 물리면 지운 내용이 다시 들어가므로 다음 키프레임으로 옮겨 복사
+fn plan_keyframe_pieces(
+    ranges: &[KeepRange],
+    keyframes: &[f64],
+    duration: Option<f64>,
+) -> Vec<Piece> {
+    let mut pieces = Vec::new();
+    for (i, range) in ranges.iter().enumerate() {
+        let start = if range.start <= KEYFRAME_EPSILON {
+            0.0
+        } else if i == 0 {
+            nearest_keyframe(keyframes, range.start)
+        } else {
+            match keyframes
+                .iter()
+                .copied()
+                .find(|k| *k >= range.start - KEYFRAME_EPSILON)
+            {
+                Some(key) => key,
+                None => continue,
+            }
+        };
+        if !is_empty_span(range.start, range.end, duration)
+            && !is_empty_span(start, range.end, duration)
+        {
+            pieces.push(Piece::Copy {
+                start,
+                end: range.end,
+            });
+        }
+    }
+    pieces
+}
+
+/// smart 모드: 시작~다음 키프레임은 재인코딩, 키프레임부터 구간 끝까지는 복사
+fn plan_smart_pieces(ranges: &[KeepRange], keyframes: &[f64], duration: Option<f64>) -> Vec<Piece> {
+    let mut pieces = Vec::new();
+    for range in ranges {
+        if is_empty_span(range.start, range.end, duration) {
+            continue;
+        }
+        if range.start <= KEYFRAME_EPSILON {
+            pieces.push(Piece::Copy {
+                start: 0.0,
+                end: range.end,
+            });
+            continue;
+        }
+        let next_key = keyframes
+            .iter()
+            .copied()
+            .find(|k| *k >= range.start - KEYFRAME_EPSILON)
+            .filter(|k| !is_empty_span(*k, range.end, duration));
+        match next_key {
+            Some(key) if key - range.start < KEYFRAME_EPSILON => pieces.push(Piece::Copy {
+                start: key,
+                end: range.end,
+            }),
+            Some(key) => {
+                pieces.push(Piece::Encode {
+                    start: range.start,
+                    end: Some(key),
+                });
+                pieces.push(Piece::Copy {
+                    start: key,
+                    end: range.end,
+                });
+            }
+            // 구간 안에 키프레임이 없으면 전부 재인코딩
+            None => pieces.push(Piece::Encode {
+                start: range.start,
+                end: range.end,
+            }),
+        }
+    }
+    pieces
+}
+
+/// `-ss`/`-t` 입력 구간 인자 (입력 앞 -ss는 복사 시 키프레임, 디코딩 시 정확한 프레임에서 시작)
+fn seek_args(input: &str, start: f64, end: Option<f64>) -> Vec<String> {
+    let mut args = vec!["-y".to_string()];
+    if start > 0.0 {
+        args.extend(["-ss".to_string(), format_seconds(start)]);
+    }
+    args.extend(["-i".to_string(), input.to_string()]);
+    if let Some(end) = end {
+        args.extend(["-t".to_string(), format_seconds((end - start).max(0.001))]);
+    }
+    args
+}
+
+/// 오디오 구간을 잘라 이어붙이는 filter_complex (원본 오디오를 결과 컨테이너에 그대로 담지 못할 때의 AAC 폴백)
+fn build_audio_concat_filter(input_index: u32, ranges: &[KeepRange]) -> String {
+    let mut chains = Vec::new();
+    let mut labels = String::new();
+    for (i, range) in ranges.iter().enumerate() {
+        let trim = match range.end {
+            Some(end) => format!(
+                "atrim=start={}:end={}",
+                format_seconds(range.start),
+                format_seconds(end)
+            ),
+            None => format!("atrim=start={}", format_seconds(range.start)),
+        };
+        chains.push(format!(
+            "[{}:a:0]{},asetpts=PTS-STARTPTS[a{}]",
+            input_index, trim, i
+        ));
+        labels.push_str(&format!("[a{}]", i));
+    }
+    chains.push(format!("{}concat=n={}:v=0:a=1[aout]", labels, ranges.len()));
+    chains.join(";")
+}
+
+fn ffmpeg_error_line(stderr: &str, fallback: &str) -> String {
+    stderr
+        .lines()
+        .filter(|l| {
+            l.contains("Error")
+                || l.contains("error")
+                || l.contains("Unknown")
+                || l.contains("not found")
+        })
+        .last()
+        .unwrap_or(fallback)
+        .to_string()
+}
+
+/// ffmpeg 한 단계 실행. 실패하면 출력 파일을 지우고 stderr 요약을 Err로 반환한다.
+fn run_ffmpeg_step(
+    ffmpeg_path: &Path,
+    args: &[String],
+    output_path: &Path,
+    job: &MediaJob,
+) -> std::result::Result<(), String> {
+    let mut cmd = std::process::Command::new(ffmpeg_path);
+    cmd.args(args);
+
+    // Windows: 콘솔 창 숨기기
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt;
+        cmd.creation_flags(0x08000000);
+    }
+
+    let output = job
+        .output(&mut cmd)
+        .map_err(|e| format!("실행 실패: {e}"))?;
+    if !output.status.success() {
+        let _ = std::fs::remove_file(output_path);
+        return Err(ffmpeg_error_line(
+            &String::from_utf8_lossy(&output.stderr),
+            "ffmpeg 무손실 편집 실패",
+        ));
+    }
+    if !std::fs::metadata(output_path).is_ok_and(|m| m.len() > 0) {
+        return Err("ffmpeg가 출력 파일을 생성하지 않았습니다.".to_string());
+    }
+    Ok(())
+}
+
+/// trace_headers 출력의 SPS `level_idc` → "4.1"
+fn parse_level_idc(stderr: &str) -> Option<String> {
+    let idc = stderr
+        .lines()
+        .find(|line| line.contains("level_idc"))?
+        .rsplit_once('=')?
+        .1
+        .trim()
+        .parse::<u32>()
+        .ok()
+        .filter(|idc| *idc > 0)?;
+    Some(format!("{}.{}", idc / 10, idc % 10))
+}
+
+/// 원본 H.264 레벨 (배너에는 나오지 않아 첫 패킷의 SPS를 읽는다)
+fn probe_h264_level(ffmpeg_path: &Path, input: &str, job: &MediaJob) -> Option<String> {
+    let mut cmd = std::process::Command::new(ffmpeg_path);
+    cmd.args([
+        "-hide_banner",
+        "-nostats",
+        "-i",
+        input,
+        "-map",
+        "0:v:0",
+        "-c",
+        "copy",
+        "-bsf:v",
+        "trace_headers",
+        "-frames:v",
+        "1",
+        "-f",
+        "null",
+        "-",
+    ]);
+
+    // Windows: 콘솔 창 숨기기
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt;
+        cmd.creation_flags(0x08000000);
+    }
+
+    let output = job.output(&mut cmd).ok()?;
+    parse_level_idc(&String::from_utf8_lossy(&output.stderr))
+}
+
+/// ffmpeg 표기 H.264 프로파일 → 인코더 `-profile:v` 값
+fn encoder_profile(profile: &str) -> Option<&'static str> {
+    match profile {
+        "Baseline" | "Constrained Baseline" => Some("baseline"),
+        "Main" => Some("main"),
+        "High" => Some("high"),
+        "High 10" => Some("high10"),
+        "High 4:2:2" => Some("high422"),
+        "High 4:4:4 Predictive" => Some("high444"),
+        _ => None,
+    }
+}
+
+/// 원본과 같은 픽셀 포맷·프로파일·레벨·프레임레이트로 인코딩하는 인자.
+/// H.264가 아니거나 원본 픽셀 포맷을 낼 수 없는 인코더면 None (이어붙인 스트림이 깨진다).
+fn matched_encoder_args(attempt: &EncoderAttempt, source: &SourceVideo) -> Option<Vec<String>> {
+    let pix_fmt = source.pix_fmt.as_deref().unwrap_or("yuv420p");
+    let yuv420_only = matches!(attempt.label, "h264_mf" | "h264_videotoolbox");
+    if attempt.label == "mpeg4" || (yuv420_only && !matches!(pix_fmt, "yuv420p" | "nv12")) {
+        return None;
+    }
+    let mut args = Vec::new();
+    let mut rest = attempt.video_args.iter();
+    while let Some(arg) = rest.next() {
+        if arg == "-pix_fmt" {
+            rest.next();
+        } else {
+            args.push(arg.clone());
+        }
+    }
+    // h264_mf는 내부적으로 nv12를 쓰므로 픽셀 포맷을 강제하지 않는다
+    if attempt.label != "h264_mf" {
+        args.extend(["-pix_fmt".to_string(), pix_fmt.to_string()]);
+    }
+    if matches!(attempt.label, "libx264" | "h264_videotoolbox") {
+        // VideoToolbox는 8비트 4:2:0 프로파일만 지원
+        let profile = source
+            .profile
+            .as_deref()
+            .and_then(encoder_profile)
+            .filter(|p| attempt.label == "libx264" || matches!(*p, "baseline" | "main" | "high"));
+        if let Some(profile) = profile {
+            args.extend(["-profile:v".to_string(), profile.to_string()]);
+        }
+        if let Some(level) = &source.level {
+            args.extend(["-level".to_string(), level.clone()]);
+        }
+    }
+    if let Some(fps) = source.fps {
+        args.extend(["-r".to_string(), format_seconds(fps)]);
+    }
+    Some(args)
+}
+
+/// 경계 GOP 재인코딩 — 원본에 맞출 수 있는 H.264 인코더 후보를 순서대로 시도해 비디오만 MPEG-TS로 저장
+fn encode_piece(
+    ffmpeg_path: &Path,
+    input: &str,
+    start: f64,
+    end: Option<f64>,
+    source: &SourceVideo,
+    part_path: &Path,
+    job: &MediaJob,
+) -> Result<()> {
+    let part_str = part_path.to_string_lossy().to_string();
+    let mut errors: Vec<String> = Vec::new();
+    for attempt in h264_encoder_candidates(18) {
+        let Some(video_args) = matched_encoder_args(&attempt, source) else {
+            continue;
+        };
+        let mut args = seek_args(input, start, end);
+        args.extend(["-map", "0:v:0", "-an", "-sn", "-dn"].map(String::from));
+        args.extend(video_args);
+        args.extend(["-f".to_string(), "mpegts".to_string(), part_str.clone()]);
+        match run_ffmpeg_step(ffmpeg_path, &args, part_path, job) {
+            Ok(()) => return Ok(()),
+            Err(e) => {
+                job.check_and_discard(part_path)?;
+                eprintln!("⚠️ 인코더 {} 실패: {e}", attempt.label);
+                errors.push(format!("[{}] {e}", attempt.label));
+            }
+        }
+    }
+    if errors.is_empty() {
+        return Err(AppError::VideoProcessing(format!(
+            "원본 픽셀 포맷({})으로 인코딩할 수 있는 H.264 인코더가 없습니다.",
+            source.pix_fmt.as_deref().unwrap_or("?")
+        )));
+    }
+    Err(AppError::VideoProcessing(errors.join(" / ")))
+}
+
+fn copy_piece(
+    ffmpeg_path: &Path,
+    args: Vec<String>,
+    part_path: &Path,
+    job: &MediaJob,
+) -> Result<()> {
+    run_ffmpeg_step(ffmpeg_path, &args, part_path, job).or_else(|e| {
+        job.check_and_discard(part_path)?;
+        Err(AppError::VideoProcessing(e))
+    })
+}
+
+fn write_concat_list(list_file: &Path, parts: &[PathBuf]) -> Result<()> {
+    let list_content = parts
+        .iter()
+        .map(|part| format!("file '{}'", part.to_string_lossy().replace('\'', "'\\''")))
+        .collect::<Vec<_>>()
+        .join("\n");
+    std::fs::write(list_file, list_content)?;
+    Ok(())
+}
+
+fn concat_args(list_file: &Path) -> Vec<String> {
+    vec![
+        "-y".to_string(),
+        "-f".to_string(),
+        "concat".to_string(),
+        "-safe".to_string(),
+        "0".to_string(),
+        "-i".to_string(),
+        list_file.to_string_lossy().to_string(),
+    ]
+}
+
+/// 원본 컨테이너 그대로 조각을 복사하고 이어붙인다
+fn run_keyframe_copy(
+    ffmpeg_path: &Path,
+    input: &str,
+    pieces: &[Piece],
+    tmp_dir: &Path,
+    output_path: &Path,
+    job: &MediaJob,
+    send_step: &dyn Fn(usize),
+) -> Result<()> {
+    let ext = Path::new(input)
+        .extension()
+        .map(|e| e.to_string_lossy().to_string())
+        .unwrap_or_else(|| "mp4".to_string());
+    let mut parts = Vec::new();
+    for (i, piece) in pieces.iter().enumerate() {
+        let Piece::Copy { start, end } = *piece else {
+            continue;
+        };
+        // 조각이 하나면 임시 파일 없이 바로 출력
+        let part = if pieces.len() == 1 {
+            output_path.to_path_buf()
+        } else {
+            tmp_dir.join(format!("part{}.{}", i, ext))
+        };
+        let mut args = seek_args(input, start, end);
+        args.extend(
+            [
+                "-map",
+                "0:v:0",
+                "-map",
+                "0:a?",
+                "-c",
+                "copy",
+                "-avoid_negative_ts",
+                "make_zero",
+            ]
+            .map(String::from),
+        );
+        args.push(part.to_string_lossy().to_string());
+        copy_piece(ffmpeg_path, args, &part, job)?;
+        parts.push(part);
+        send_step(i + 1);
+    }
+    if pieces.len() > 1 {
+        let list_file = tmp_dir.join("list.txt");
+        write_concat_list(&list_file, &parts)?;
+        let mut args = concat_args(&list_file);
+        args.extend(["-c".to_string(), "copy".to_string()]);
+        args.push(output_path.to_string_lossy().to_string());
+        copy_piece(ffmpeg_path, args, output_path, job)?;
+    }
+    Ok(())
+}
+
+/// 오디오를 구간별로 스트림 복사해 이어붙일 concat 목록 생성 (입력 앞 -ss로 패킷 경계에서 잘린다)
+fn copy_audio_parts(
+    ffmpeg_path: &Path,
+    input: &str,
+    ranges: &[KeepRange],
+    tmp_dir: &Path,
+    job: &MediaJob,
+) -> Result<PathBuf> {
+    let mut parts = Vec::new();
+    for (i, range) in ranges.iter().enumerate() {
+        let part = tmp_dir.join(format!("audio{}.mka", i));
+        let mut args = seek_args(input, range.start, range.end);
+        args.extend(
+            [
+                "-map",
+                "0:a:0",
+                "-vn",
+                "-sn",
+                "-dn",
+                "-c:a",
+                "copy",
+                "-avoid_negative_ts",
+                "make_zero",
+                "-f",
+                "matroska",
+            ]
+            .map(String::from),
+        );
+        args.push(part.to_string_lossy().to_string());
+        copy_piece(ffmpeg_path, args, &part, job)?;
+        parts.push(part);
+    }
+    let list_file = tmp_dir.join("audio_list.txt");
+    write_concat_list(&list_file, &parts)?;
+    Ok(list_file)
+}
+
+/// 비디오 concat 목록과 스트림 복사한 오디오 concat 목록을 그대로 합친다
+fn mux_copied_audio(
+    ffmpeg_path: &Path,
+    input: &str,
+    ranges: &[KeepRange],
+    video_list: &Path,
+    tmp_dir: &Path,
+    output_path: &Path,
+    job: &MediaJob,
+) -> Result<()> {
+    let audio_list = copy_audio_parts(ffmpeg_path, input, ranges, tmp_dir, job)?;
+    let mut args = concat_args(video_list);
+    args.extend(["-f", "concat", "-safe", "0", "-i"].map(String::from));
+    args.push(audio_list.to_string_lossy().to_string());
+    args.extend(
+        [
+            "-map",
+            "0:v",
+            "-map",
+            "1:a",
+            "-c",
+            "copy",
+            "-movflags",
+            "+faststart",
+        ]
+        .map(String::from),
+    );
+    args.push(output_path.to_string_lossy().to_string());
+    copy_piece(ffmpeg_path, args, output_path, job)
+}
+
+/// 비디오 조각(경계 재인코딩 + 복사)을 MPEG-TS로 이어붙이고, 오디오는 요청 구간대로 복사해 합친다
+#[allow(clippy::too_many_arguments)]
+fn run_smart_cut(
+    ffmpeg_path: &Path,
+    input: &str,
+    ranges: &[KeepRange],
+    pieces: &[Piece],
+    source: &SourceVideo,
+    has_audio: bool,
+    tmp_dir: &Path,
+    output_path: &Path,
+    job: &MediaJob,
+    send_step: &dyn Fn(usize),
+) -> Result<()> {
+    let mut parts = Vec::new();
+    for (i, piece) in pieces.iter().enumerate() {
+        let part = tmp_dir.join(format!("part{}.ts", i));
+        match *piece {
+            Piece::Encode { start, end } => {
+                encode_piece(ffmpeg_path, input, start, end, source, &part, job)?
+            }
+            Piece::Copy { start, end } => {
+                // Annex B로 바꿔 재인코딩 조각과 파라미터 셋(SPS/PPS)이 섞여도 디코딩되게 한다
+                let mut args = seek_args(input, start, end);
+                args.extend(
+                    [
+                        "-map",
+                        "0:v:0",
+                        "-an",
+                        "-sn",
+                        "-dn",
+                        "-c:v",
+                        "copy",
+                        "-bsf:v",
+                        "h264_mp4toannexb",
+                        "-f",
+                        "mpegts",
+                    ]
+                    .map(String::from),
+                );
+                args.push(part.to_string_lossy().to_string());
+                copy_piece(ffmpeg_path, args, &part, job)?;
+            }
+        }
+        parts.push(part);
+        send_step(i + 1);
+    }
+
+    let list_file = tmp_dir.join("list.txt");
+    write_concat_list(&list_file, &parts)?;
+    if has_audio {
+        match mux_copied_audio(
+            ffmpeg_path,
+            input,
+            ranges,
+            &list_file,
+            tmp_dir,
+            output_path,
+            job,
+        ) {
+            Ok(()) => return Ok(()),
+            Err(e) => {
+                // 결과 컨테이너에 담을 수 없는 오디오 코덱 등 — AAC로 다시 인코딩
+                job.check()?;
+                eprintln!("⚠️ 오디오 스트림 복사 실패, AAC로 재인코딩: {e}");
+            }
+        }
+    }
+    let mut args = concat_args(&list_file);
+    if has_audio {
+        args.extend(["-i".to_string(), input.to_string()]);
+        args.extend([
+            "-filter_complex".to_string(),
+            build_audio_concat_filter(1, ranges),
+        ]);
+        args.extend(
+            [
+                "-map", "0:v", "-map", "[aout]", "-c:a", "aac", "-b:a", "128k",
+            ]
+            .map(String::from),
+        );
+    } else {
+        args.extend(["-map".to_string(), "0:v".to_string()]);
+    }
+    args.extend(["-c:v", "copy", "-movflags", "+faststart"].map(String::from));
+    args.push(output_path.to_string_lossy().to_string());
+    copy_piece(ffmpeg_path, args, output_path, job)
+}
+
+/// 무손실 모드로 `ranges`를 남긴 결과물 생성.
+/// smart 모드인데 원본이 H.264가 아니면 `Ok(None)` — 호출자가 전체 재인코딩으로 진행한다.
+pub(super) fn export_lossless(
+    ffmpeg_path: &Path,
+    input: &str,
+    ranges: &[KeepRange],
+    mode: VideoEditMode,
+    suffix: &str,
+    job: &MediaJob,
+    on_progress: &tauri::ipc::Channel<VideoProgress>,
+) -> Result<Option<String>> {
+    let input_path = Path::new(input);
+    let probe = probe_media_cached(input_path)?;
+    let video = probe
+        .streams
+        .iter()
+        .find(|s| s.kind == MediaStreamKind::Video && !s.is_attached_pic)
+        .ok_or_else(|| AppError::VideoProcessing("비디오 스트림이 없습니다.".to_string()))?;
+    if mode == VideoEditMode::Smart && video.codec != "h264" {
+        return Ok(None);
+    }
+    let source = if mode == VideoEditMode::Smart {
+        SourceVideo::from_stream(video, probe_h264_level(ffmpeg_path, input, job))
+    } else {
+        SourceVideo::default()
+    };
+    let has_audio = probe
+        .streams
+        .iter()
+        .any(|s| s.kind == MediaStreamKind::Audio);
+
+    // 파일 끝 이후 구간은 오디오 필터에서도 빼야 concat이 빈 입력을 받지 않는다
+    let ranges: Vec<KeepRange> = ranges
+        .iter()
+        .copied()
+        .filter(|r| !is_empty_span(r.start, r.end, probe.duration_sec))
+        .collect();
+    let keyframes = keyframes_cached(input_path, Some(job))?;
+    let pieces = match mode {
+        VideoEditMode::Smart => plan_smart_pieces(&ranges, &keyframes, probe.duration_sec),
+        _ => plan_keyframe_pieces(&ranges, &keyframes, probe.duration_sec),
+    };
+    if pieces.is_empty() {
+        return Err(AppError::VideoProcessing(
+            "삭제 후 남은 영상이 없습니다.".to_string(),
+        ));
+    }
+
+    // keyframe 모드는 원본 컨테이너 유지, smart 모드는 mp4로 다시 담는다
+    let stem = input_path.file_stem().unwrap_or_default().to_string_lossy();
+    let ext = match (mode, input_path.extension()) {
+        (VideoEditMode::Keyframe, Some(ext)) => format!(".{}", ext.to_string_lossy()),
+        _ => ".mp4".to_string(),
+    };
+    let parent = input_path.parent().unwrap_or(Path::new("."));
+    let output_path = find_unique_path(parent, &stem, suffix, &ext);
+
+    // 작업 ID를 넣어 동시에 여러 편집을 돌려도 임시 파일이 겹치지 않게 한다
+    let tmp_dir = std::env::temp_dir().join(format!("qf_lossless_{}", job.id()));
+    std::fs::create_dir_all(&tmp_dir)?;
+
+    let total_steps = pieces.len() + 1;
+    let send_step = |step: usize| {
+        let _ = on_progress.send(VideoProgress {
+            percent: (step as f32 / total_steps as f32 * 100.0).min(99.0),
+            speed: String::new(),
+            fps: 0.0,
+        });
+    };
+    send_step(0);
+    let result = match mode {
+        VideoEditMode::Smart => run_smart_cut(
+            ffmpeg_path,
+            input,
+            &ranges,
+            &pieces,
+            &source,
+            has_audio,
+            &tmp_dir,
+            &output_path,
+            job,
+            &send_step,
+        ),
+        _ => run_keyframe_copy(
+            ffmpeg_path,
+            input,
+            &pieces,
+            &tmp_dir,
+            &output_path,
+            job,
+            &send_step,
+        ),
+    };
+    let _ = std::fs::remove_dir_all(&tmp_dir);
+    result?;
+
+    let _ = on_progress.send(VideoProgress {
+        percent: 100.0,
+        speed: String::new(),
+        fps: 0.0,
+    });
+    Ok(Some(output_path.to_string_lossy().to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_keyframe_times_from_showinfo() {
+        let stderr = "[Parsed_showinfo_0 @ 0x1] config in time_base: 1/15360, frame_rate: 30/1
+[Parsed_showinfo_0 @ 0x1] n:   0 pts:      0 pts_time:0       duration:    512 iskey:1 type:I
+[Parsed_showinfo_0 @ 0x1] n:   1 pts:  76800 pts_time:5       duration:    512 iskey:1 type:I
+[Parsed_showinfo_0 @ 0x1] n:   2 pts:  38400 pts_time:2.5     duration:    512 iskey:1 type:I
+frame=    3 fps=0.0 q=-0.0 Lsize=N/A time=00:00:05.03";
+        assert_eq!(parse_showinfo_keyframes(stderr), vec![0.0, 2.5, 5.0]);
+    }
+
+    #[test]
+    fn plans_snap_copy_or_reencode_boundary_gops() {
+        let keyframes = [0.0, 2.0, 4.0, 6.0, 8.0];
+        let trim = [KeepRange {
+            start: 2.9,
+            end: Some(7.0),
+        }];
+        // keyframe: 시작을 가장 가까운 키프레임(2.0)으로, 끝은 그대로
+        assert_eq!(
+            plan_keyframe_pieces(&trim, &keyframes, Some(10.0)),
+            vec![Piece::Copy {
+                start: 2.0,
+                end: Some(7.0)
+            }]
+        );
+        // smart: 2.9~4.0만 재인코딩하고 나머지 복사
+        assert_eq!(
+            plan_smart_pieces(&trim, &keyframes, Some(10.0)),
+            vec![
+                Piece::Encode {
+                    start: 2.9,
+                    end: Some(4.0)
+                },
+                Piece::Copy {
+                    start: 4.0,
+                    end: Some(7.0)
+                },
+            ]
+        );
+
+        // 구간 삭제: 앞 구간은 0부터 복사, 뒤 구간이 키프레임에 맞으면 복사만
+        let cut = [
+            KeepRange {
+                start: 0.0,
+                end: Some(3.0),
+            },
+            KeepRange {
+                start: 6.0,
+                end: None,
+            },
+        ];
+        assert_eq!(
+            plan_smart_pieces(&cut, &keyframes, Some(10.0)),
+            vec![
+                Piece::Copy {
+                    start: 0.0,
+                    end: Some(3.0)
+                },
+                Piece::Copy {
+                    start: 6.0,
+                    end: None
+                },
+            ]
+        );
+
+        // keyframe 구간 삭제: 뒤 구간을 앞 키프레임(4.0)으로 물리면 지운 4~5초가 다시 들어가므로 6.0으로
+        let cut_mid = [
+            KeepRange {
+                start: 0.0,
+                end: Some(3.0),
+            },
+            KeepRange {
+                start: 5.0,
+                end: None,
+            },
+        ];
+        assert_eq!(
+            plan_keyframe_pieces(&cut_mid, &keyframes, Some(10.0)),
+            vec![
+                Piece::Copy {
+                    start: 0.0,
+                    end: Some(3.0)
+                },
+                Piece::Copy {
+                    start: 6.0,
+                    end: None
+                },
+            ]
+        );
+
+        // 파일 끝 이후 구간은 버림
+        let tail = [KeepRange {
+            start: 10.0,
+            end: None,
+        }];
+        assert!(plan_keyframe_pieces(&tail, &keyframes, Some(10.0)).is_empty());
+        assert!(plan_smart_pieces(&tail, &keyframes, Some(10.0)).is_empty());
+    }
+
+    #[test]
+    fn boundary_encode_matches_source_parameters() {
+        let stderr = "[trace_headers @ 0x1] 24          level_idc                 00101001 = 41";
+        assert_eq!(parse_level_idc(stderr).as_deref(), Some("4.1"));
+
+        let source = SourceVideo {
+            pix_fmt: Some("yuv420p10le".to_string()),
+            profile: Some("High 10".to_string()),
+            level: Some("5.1".to_string()),
+            fps: Some(24.0),
+        };
+        let x264 = EncoderAttempt {
+            label: "libx264",
+            video_args: ["-c:v", "libx264", "-pix_fmt", "yuv420p"]
+                .map(String::from)
+                .to_vec(),
+        };
+        assert_eq!(
+            matched_encoder_args(&x264, &source).unwrap(),
+            [
+                "-c:v",
+                "libx264",
+                "-pix_fmt",
+                "yuv420p10le",
+                "-profile:v",
+                "high10",
+                "-level",
+                "5.1",
+                "-r",
+                "24"
+            ]
+        );
+        // 10비트 원본은 4:2:0 8비트 전용 인코더로 맞출 수 없고, mpeg4는 이어붙일 수 없다
+        let mf = EncoderAttempt {
+            label: "h264_mf",
+            video_args: ["-c:v", "h264_mf"].map(String::from).to_vec(),
+        };
+        assert!(matched_encoder_args(&mf, &source).is_none());
+        let mpeg4 = EncoderAttempt {
+            label: "mpeg4",
+            video_args: ["-c:v", "mpeg4"].map(String::from).to_vec(),
+        };
+        assert!(matched_encoder_args(&mpeg4, &SourceVideo::default()).is_none());
+    }
+
+    #[test]
+    fn audio_concat_filter_trims_each_kept_range() {
+        let ranges = [
+            KeepRange {
+                start: 0.0,
+                end: Some(1.5),
+            },
+            KeepRange {
+                start: 3.0,
+                end: None,
+            },
+        ];
+        assert_eq!(
+            build_audio_concat_filter(1, &ranges),
+            "[1:a:0]atrim=start=0:end=1.5,asetpts=PTS-STARTPTS[a0];\
+             [1:a:0]atrim=start=3,asetpts=PTS-STARTPTS[a1];\
+             [a0][a1]concat=n=2:v=0:a=1[aout]"
+        );
+    }
+}
@@ -5,6 +5,7 @@ pub mod error;
 pub mod file_ops;
 pub mod hwp_ops;
 pub mod image_ops;
+pub mod jobs;
 pub mod laigter_maps;
 pub mod media_ops;
 pub mod system_ops;
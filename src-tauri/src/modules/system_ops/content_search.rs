@@ -0,0 +1,496 @@
+//! 파일 내용 검색
+//! 루트 아래 텍스트 파일을 읽어 줄 단위로 일치를 찾고 Channel로 묶어 보낸다.
+//! 바이너리는 앞부분 시그니처·NUL 바이트로 건너뛰고, BOM이 없는 파일은 UTF-8 → UTF-16 추정 →
+//! EUC-KR 순으로 디코딩한다(EUC-KR로도 깨지면 건너뜀). HWP/HWPX(hwarang)와 PDF(lopdf)는 추출한
+//! 본문 텍스트를 검색한다. PDF는 페이지마다 취소를 확인하고, 도중에 멈출 수 없는 HWP 추출은
+//! 크기를 더 작게 제한한다.
+
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use tauri::ipc::Channel;
+
+use super::super::constants::SEARCH_MAX_DEPTH;
+use super::super::error::{AppError, Result};
+use super::super::file_ops::sniff_header;
+use super::super::jobs::{cancel_job, new_job_id, register_job, CancelToken};
+use crate::helpers::is_hidden_file;
+
+#[cfg(target_os = "windows")]
+use crate::helpers::is_system_file;
+
+const DEFAULT_MAX_HITS: usize = 1_000;
+const DEFAULT_MAX_HITS_PER_FILE: usize = 50;
+/// 이보다 큰 파일은 읽지 않음 (기본 50MB)
+const DEFAULT_MAX_FILE_SIZE: u64 = 50 * 1024 * 1024;
+/// HWP/HWPX는 추출 중 취소할 수 없어 이보다 크면 건너뜀
+const MAX_HWP_EXTRACT_SIZE: u64 = 20 * 1024 * 1024;
+/// 바이너리 판별에 쓰는 앞부분 크기
+const SNIFF_LEN: usize = 8 * 1024;
+/// 일치 앞뒤로 보여줄 최대 글자 수
+const SNIPPET_CONTEXT_CHARS: usize = 60;
+const BATCH_INTERVAL: Duration = Duration::from_millis(150);
+const BATCH_SIZE: usize = 100;
+
+/// 내용 검색 옵션 (생략한 필드는 기본값)
+#[derive(Clone, Default, serde::Serialize, serde::Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ContentSearchOptions {
+    /// 취소용 작업 ID (없으면 발급). `cancel_content_search`로 중단.
+    pub job_id: Option<String>,
+    /// 검색어를 정규식으로 해석 (기본은 일반 텍스트)
+    pub regex: bool,
+    pub case_sensitive: bool,
+    /// 숨김 파일·폴더 포함
+    pub show_hidden: bool,
+    /// 전체 일치 수 상한 (기본 1000)
+    pub max_hits: Option<usize>,
+    /// 파일당 일치 수 상한 (기본 50)
+    pub max_hits_per_file: Option<usize>,
+    /// 이보다 큰 파일은 건너뜀 (바이트, 기본 50MB)
+    pub max_file_size: Option<u64>,
+}
+
+/// 일치한 줄 하나
+#[derive(serde::Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentMatch {
+    pub path: String,
+    /// 1부터 시작하는 줄 번호 (HWP/PDF는 추출한 텍스트 기준)
+    pub line: usize,
+    /// 일치 주변 텍스트 (긴 줄은 앞뒤를 잘라 `…` 표시)
+    pub snippet: String,
+    /// snippet 안 일치 구간 (UTF-16 오프셋, JS `slice`에 바로 사용)
+    pub match_start: usize,
+    pub match_end: usize,
+}
+
+/// Channel로 보내는 일치 묶음
+#[derive(serde::Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentSearchBatch {
+    pub job_id: String,
+    pub matches: Vec<ContentMatch>,
+    /// 지금까지 읽은 파일 수
+    pub files_scanned: u64,
+}
+
+/// `search_file_contents` 응답 (묶음 전송이 모두 끝난 뒤 반환)
+#[derive(serde::Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentSearchSummary {
+    pub job_id: String,
+    pub files_scanned: u64,
+    pub files_matched: u64,
+    pub total_hits: u64,
+    /// 바이너리·크기 초과·읽기 실패로 건너뛴 파일 수
+    pub files_skipped: u64,
+    /// 일치 수 상한에 걸려 중단됨
+    pub truncated: bool,
+}
+
+/// 검색어를 정규식으로 컴파일 (일반 텍스트는 이스케이프)
+pub(crate) fn build_content_matcher(
+    query: &str,
+    regex: bool,
+    case_sensitive: bool,
+) -> Result<regex::Regex> {
+    if query.is_empty() {
+        return Err(AppError::InvalidInput("검색어를 입력하세요".to_string()));
+    }
+    let pattern = if regex {
+        query.to_string()
+    } else {
+        regex::escape(query)
+    };
+    regex::RegexBuilder::new(&pattern)
+        .case_insensitive(!case_sensitive)
+        .build()
+        .map_err(|e| AppError::InvalidInput(format!("잘못된 정규식: {}", e)))
+}
+
+/// NUL 바이트가 홀수/짝수 위치에 몰려 있으면 BOM 없는 UTF-16으로 추정
+fn guess_utf16(bytes: &[u8]) -> Option<&'static encoding_rs::Encoding> {
+    let sample = &bytes[..bytes.len().min(SNIFF_LEN) & !1];
+    if sample.len() < 16 {
+        return None;
+    }
+    let pairs = sample.len() / 2;
+    let even_nul = sample.iter().step_by(2).filter(|b| **b == 0).count();
+    let odd_nul = sample
+        .iter()
+        .skip(1)
+        .step_by(2)
+        .filter(|b| **b == 0)
+        .count();
+    if odd_nul * 10 >= pairs * 3 && even_nul * 10 < pairs {
+        Some(encoding_rs::UTF_16LE)
+    } else if even_nul * 10 >= pairs * 3 && odd_nul * 10 < pairs {
+        Some(encoding_rs::UTF_16BE)
+    } else {
+        None
+    }
+}
+
+/// 텍스트 파일 바이트 디코딩. 바이너리로 보이거나 어느 인코딩으로도 맞지 않으면 None.
+pub(crate) fn decode_text(bytes: &[u8]) -> Option<String> {
+    // BOM이 있으면 그 인코딩을 따른다 (UTF-8/UTF-16LE/UTF-16BE)
+    if let Some((encoding, bom_len)) = encoding_rs::Encoding::for_bom(bytes) {
+        let (text, _) = encoding.decode_without_bom_handling(&bytes[bom_len..]);
+        return Some(text.into_owned());
+    }
+    if let Some(encoding) = guess_utf16(bytes) {
+        let (text, _) = encoding.decode_without_bom_handling(bytes);
+        return Some(text.into_owned());
+    }
+    // 시그니처는 텍스트와 겹칠 수 있으므로("true", "MZ" 등) UTF-8이 아닐 때만 본다
+    let head = &bytes[..bytes.len().min(SNIFF_LEN)];
+    let head_is_utf8 = std::str::from_utf8(head).map_or_else(|e| e.error_len().is_none(), |_| true);
+    if head.contains(&0) || (!head_is_utf8 && sniff_header(head).is_some()) {
+        return None;
+    }
+    match std::str::from_utf8(bytes) {
+        Ok(text) => Some(text.to_string()),
+        Err(e) if e.error_len().is_none() && e.valid_up_to() + 4 > bytes.len() => {
+            // 끝에서 잘린 멀티바이트 문자만 문제면 UTF-8로 본다
+            Some(String::from_utf8_lossy(bytes).into_owned())
+        }
+        Err(_) => {
+            // 대체 문자가 섞인 결과는 검색해도 의미가 없으므로 깨끗이 디코딩될 때만 쓴다
+            let (text, _, had_errors) = encoding_rs::EUC_KR.decode(bytes);
+            (!had_errors).then(|| text.into_owned())
+        }
+    }
+}
+
+/// 페이지 단위로 본문 추출 (페이지 사이마다 취소 확인, 읽지 못한 페이지는 건너뜀)
+fn extract_pdf_text(path: &Path, token: &CancelToken) -> Result<Option<String>> {
+    let Ok(doc) = lopdf::Document::load(path) else {
+        return Ok(None);
+    };
+    let mut text = String::new();
+    for page in doc.get_pages().into_keys() {
+        token.check()?;
+        if let Ok(page_text) = doc.extract_text(&[page]) {
+            text.push_str(&page_text);
+        }
+    }
+    Ok(Some(text))
+}
+
+/// 검색 대상 텍스트 읽기 (문서는 본문 추출). 건너뛸 파일이면 None.
+fn read_searchable_text(
+    path: &Path,
+    size: u64,
+    max_file_size: u64,
+    token: &CancelToken,
+) -> Result<Option<String>> {
+    if size > max_file_size {
+        return Ok(None);
+    }
+    let ext = path
+        .extension()
+        .map(|e| e.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    Ok(match ext.as_str() {
+        "hwp" | "hwpx" if size > MAX_HWP_EXTRACT_SIZE => None,
+        "hwp" | "hwpx" => hwarang::extract_text_from_file(path).ok(),
+        "pdf" => return extract_pdf_text(path, token),
+        _ => std::fs::read(path)
+            .ok()
+            .and_then(|bytes| decode_text(&bytes)),
+    })
+}
+
+/// 긴 줄은 일치 주변만 남겨 snippet과 UTF-16 기준 일치 구간을 만든다
+fn make_snippet(line: &str, start: usize, end: usize) -> (String, usize, usize) {
+    let before: Vec<char> = line[..start].chars().collect();
+    let after: Vec<char> = line[end..].chars().collect();
+    let matched = &line[start..end];
+
+    let mut snippet = String::new();
+    let kept_before = if before.len() > SNIPPET_CONTEXT_CHARS {
+        snippet.push('…');
+        &before[before.len() - SNIPPET_CONTEXT_CHARS..]
+    } else {
+        &before[..]
+    };
+    snippet.extend(kept_before.iter());
+    let prefix = snippet.trim_start().to_string();
+    let match_start = prefix.encode_utf16().count();
+    let mut snippet = prefix;
+    snippet.push_str(matched);
+    let match_end = match_start + matched.encode_utf16().count();
+    if after.len() > SNIPPET_CONTEXT_CHARS {
+        snippet.extend(after[..SNIPPET_CONTEXT_CHARS].iter());
+        snippet.push('…');
+    } else {
+        snippet.extend(after.iter());
+    }
+    (snippet, match_start, match_end)
+}
+
+/// 텍스트에서 줄 단위 일치 수집 (한 줄에 여러 번 나와도 첫 일치만)
+pub(crate) fn find_line_matches(
+    path: &str,
+    text: &str,
+    matcher: &regex::Regex,
+    limit: usize,
+) -> Vec<ContentMatch> {
+    let mut matches = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        if matches.len() >= limit {
+            break;
+        }
+        let Some(m) = matcher.find(line) else {
+            continue;
+        };
+        let (snippet, match_start, match_end) = make_snippet(line, m.start(), m.end());
+        matches.push(ContentMatch {
+            path: path.to_string(),
+            line: i + 1,
+            snippet,
+            match_start,
+            match_end,
+        });
+    }
+    matches
+}
+
+/// 내용 검색 본체. `emit`이 묶음을 받는다 (테스트에서는 수집용 클로저).
+fn search_contents<F>(
+    root: &str,
+    matcher: &regex::Regex,
+    options: &ContentSearchOptions,
+    job_id: &str,
+    token: &CancelToken,
+    mut emit: F,
+) -> Result<ContentSearchSummary>
+where
+    F: FnMut(ContentSearchBatch) -> Result<()>,
+{
+    let max_hits = options.max_hits.unwrap_or(DEFAULT_MAX_HITS).max(1);
+    let per_file = options
+        .max_hits_per_file
+        .unwrap_or(DEFAULT_MAX_HITS_PER_FILE)
+        .max(1);
+    let max_file_size = options.max_file_size.unwrap_or(DEFAULT_MAX_FILE_SIZE);
+    let show_hidden = options.show_hidden;
+
+    let mut summary = ContentSearchSummary {
+        job_id: job_id.to_string(),
+        files_scanned: 0,
+        files_matched: 0,
+        total_hits: 0,
+        files_skipped: 0,
+        truncated: false,
+    };
+    let mut pending: Vec<ContentMatch> = Vec::new();
+    let mut last_emit = Instant::now();
+
+    let walker = walkdir::WalkDir::new(root)
+        .max_depth(SEARCH_MAX_DEPTH)
+        .follow_links(false)
+        .into_iter()
+        .filter_entry(|entry| {
+            if show_hidden || entry.depth() == 0 {
+                return true;
+            }
+            if is_hidden_file(&entry.file_name().to_string_lossy()) {
+                return false;
+            }
+            #[cfg(target_os = "windows")]
+            {
+                if let Ok(meta) = entry.metadata() {
+                    if is_system_file(&meta) {
+                        return false;
+                    }
+                }
+            }
+            true
+        });
+
+    for entry in walker.flatten() {
+        token.check()?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let size = entry.metadata().map(|m| m.len()).unwrap_or(0);
+        summary.files_scanned += 1;
+        let Some(text) = read_searchable_text(entry.path(), size, max_file_size, token)? else {
+            summary.files_skipped += 1;
+            continue;
+        };
+
+        let remaining = (max_hits as u64 - summary.total_hits) as usize;
+        let path = entry.path().to_string_lossy().to_string();
+        let found = find_line_matches(&path, &text, matcher, per_file.min(remaining));
+        if !found.is_empty() {
+            summary.files_matched += 1;
+            summary.total_hits += found.len() as u64;
+            pending.extend(found);
+        }
+
+        if !pending.is_empty()
+            && (pending.len() >= BATCH_SIZE || last_emit.elapsed() >= BATCH_INTERVAL)
+        {
+            emit(ContentSearchBatch {
+                job_id: job_id.to_string(),
+                matches: std::mem::take(&mut pending),
+                files_scanned: summary.files_scanned,
+            })?;
+            last_emit = Instant::now();
+        }
+        if summary.total_hits >= max_hits as u64 {
+            summary.truncated = true;
+            break;
+        }
+    }
+
+    if !pending.is_empty() {
+        emit(ContentSearchBatch {
+            job_id: job_id.to_string(),
+            matches: pending,
+            files_scanned: summary.files_scanned,
+        })?;
+    }
+    Ok(summary)
+}
+
+// 루트 아래 파일 내용 검색 (일반 텍스트/정규식, 대소문자 옵션)
+// 일치는 묶음으로 on_batch에 보내고, 끝나면 요약을 반환
+#[tauri::command]
+pub async fn search_file_contents(
+    root: String,
+    query: String,
+    options: Option<ContentSearchOptions>,
+    on_batch: Channel<ContentSearchBatch>,
+) -> Result<ContentSearchSummary> {
+    let options = options.unwrap_or_default();
+    let matcher = build_content_matcher(&query, options.regex, options.case_sensitive)?;
+    let job_id = options
+        .job_id
+        .clone()
+        .unwrap_or_else(|| new_job_id("content-search"));
+    let handle = register_job(job_id)?;
+    tauri::async_runtime::spawn_blocking(move || {
+        search_contents(
+            &root,
+            &matcher,
+            &options,
+            handle.id(),
+            handle.token(),
+            |batch| {
+                on_batch
+                    .send(batch)
+                    .map_err(|e| AppError::Internal(format!("검색 결과 전송 실패: {}", e)))
+            },
+        )
+    })
+    .await
+    .map_err(|e| AppError::Internal(format!("내용 검색 태스크 실패: {}", e)))?
+}
+
+/// 진행 중인 내용 검색 중단 (이미 끝났으면 false)
+#[tauri::command]
+pub fn cancel_content_search(job_id: String) -> bool {
+    cancel_job(&job_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_bom_utf16_euc_kr_and_skips_binary() {
+        let mut utf16 = vec![0xFF, 0xFE];
+        utf16.extend("한글 검색".encode_utf16().flat_map(|u| u.to_le_bytes()));
+        assert_eq!(decode_text(&utf16).as_deref(), Some("한글 검색"));
+
+        let bare_utf16: Vec<u8> = "plain ascii text"
+            .encode_utf16()
+            .flat_map(|u| u.to_le_bytes())
+            .collect();
+        assert_eq!(
+            decode_text(&bare_utf16).as_deref(),
+            Some("plain ascii text")
+        );
+
+        let (euc_kr, _, _) = encoding_rs::EUC_KR.encode("보고서 초안");
+        assert_eq!(decode_text(&euc_kr).as_deref(), Some("보고서 초안"));
+        // UTF-8도 EUC-KR도 아닌 바이트는 대체 문자로 채우지 않고 건너뜀
+        assert_eq!(decode_text(b"report \xff\xfe\xff draft"), None);
+
+        assert_eq!(decode_text(b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR"), None);
+        assert_eq!(decode_text(b"abc\0def"), None);
+    }
+
+    #[test]
+    fn streams_line_matches_with_limits_and_snippets() {
+        let dir =
+            std::env::temp_dir().join(format!("quickfolder_content_search_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join(".hidden")).unwrap();
+        std::fs::write(dir.join("a.txt"), "first\nTODO: fix\nnothing\ntodo again\n").unwrap();
+        std::fs::write(dir.join(".hidden/b.txt"), "TODO hidden").unwrap();
+        std::fs::write(dir.join("c.bin"), b"TODO\0\0binary").unwrap();
+        let long_line = format!("{}TODO{}", "x".repeat(200), "y".repeat(200));
+        std::fs::write(dir.join("d.txt"), &long_line).unwrap();
+
+        let run = |options: ContentSearchOptions, query: &str| {
+            let matcher =
+                build_content_matcher(query, options.regex, options.case_sensitive).unwrap();
+            let mut matches = Vec::new();
+            let summary = search_contents(
+                &dir.to_string_lossy(),
+                &matcher,
+                &options,
+                "test",
+                &CancelToken::new(),
+                |batch| {
+                    matches.extend(batch.matches);
+                    Ok(())
+                },
+            )
+            .unwrap();
+            matches.sort_by(|a, b| (&a.path, a.line).cmp(&(&b.path, b.line)));
+            (matches, summary)
+        };
+
+        let (matches, summary) = run(ContentSearchOptions::default(), "todo");
+        assert_eq!(matches.len(), 3);
+        assert_eq!((matches[0].line, matches[1].line), (2, 4));
+        assert_eq!(summary.files_skipped, 1);
+        let long = &matches[2];
+        assert!(long.snippet.starts_with('…') && long.snippet.ends_with('…'));
+        let highlighted: String = long
+            .snippet
+            .encode_utf16()
+            .skip(long.match_start)
+            .take(long.match_end - long.match_start)
+            .map(|u| char::from_u32(u as u32).unwrap())
+            .collect();
+        assert_eq!(highlighted, "TODO");
+
+        // 대소문자 구분 + 정규식 + 숨김 포함
+        let options = ContentSearchOptions {
+            regex: true,
+            case_sensitive: true,
+            show_hidden: true,
+            ..Default::default()
+        };
+        let (matches, _) = run(options, r"^TODO\b");
+        assert_eq!(matches.len(), 2);
+
+        // 전체 상한
+        let options = ContentSearchOptions {
+            max_hits: Some(1),
+            ..Default::default()
+        };
+        let (matches, summary) = run(options, "todo");
+        assert_eq!((matches.len(), summary.truncated), (1, true));
+
+        assert!(build_content_matcher("(", true, false).is_err());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
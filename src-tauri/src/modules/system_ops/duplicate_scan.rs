@@ -0,0 +1,513 @@
+//! 중복 파일 탐색 (진행률·취소 지원)
+//! 크기 → 앞뒤 4KB 해시 → 전체 xxh3 해시 순으로 후보를 줄여 큰 파일을 끝까지 읽는 일을 줄인다.
+//! 같은 파일(Unix inode, Windows 볼륨 일련번호+파일 인덱스)을 가리키는 하드 링크는 한 파일로 취급해
+//! 중복으로 보고하지 않는다.
+
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use tauri::ipc::Channel;
+use xxhash_rust::xxh3::Xxh3;
+
+use super::super::constants::{
+    DUPLICATE_SCAN_MAX_DEPTH, MAX_DUPLICATE_GROUPS, MAX_DUPLICATE_SCAN_FILES,
+};
+use super::super::error::{AppError, Result};
+use super::super::jobs::{cancel_job, new_job_id, register_job, CancelToken};
+use super::super::types::FileEntry;
+use super::file_search::{search_hit_entry, DuplicateGroup};
+use crate::helpers::{glob_match, is_hidden_file, is_system_filename};
+
+#[cfg(target_os = "windows")]
+use crate::helpers::is_system_file;
+
+/// 1차 비교에 쓰는 앞·뒤 구간 크기
+const EDGE_LEN: u64 = 4 * 1024;
+const HASH_BUF_LEN: usize = 64 * 1024;
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(200);
+
+/// 중복 탐색 옵션 (생략한 필드는 기본값)
+#[derive(Clone, Default, serde::Serialize, serde::Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct DuplicateScanOptions {
+    /// 취소용 작업 ID (없으면 발급). `cancel_duplicate_scan`으로 중단.
+    pub job_id: Option<String>,
+    /// 이보다 작은 파일은 비교하지 않음 (바이트, 기본 0)
+    pub min_size: Option<u64>,
+    /// 하나라도 일치하는 파일만 비교. 이름 와일드카드이며 `/`가 있으면 루트 기준 상대 경로와 비교.
+    pub include: Vec<String>,
+    /// 일치하는 파일·폴더 제외 (폴더는 하위 전체)
+    pub exclude: Vec<String>,
+    /// 숨김 파일·폴더 포함
+    pub show_hidden: bool,
+    /// 탐색할 최대 파일 수 (기본 100,000)
+    pub max_files: Option<usize>,
+}
+
+/// 진행률 (200ms 간격, 단계가 바뀌면 즉시 전송)
+#[derive(Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DuplicateScanProgress {
+    pub job_id: String,
+    /// scanning(목록 수집) / comparing(앞뒤 구간 비교) / hashing(전체 해시) / done
+    pub phase: String,
+    pub files_scanned: u64,
+    /// 현재 단계에서 비교하는 후보 파일 수
+    pub candidates: u64,
+    /// 현재 단계에서 읽은 바이트 (전체 해시 단계에 들어가면 0부터 다시 셈)
+    pub bytes_hashed: u64,
+    /// 전체 해시 단계에서 읽을 바이트 (그 전에는 0)
+    pub bytes_to_hash: u64,
+}
+
+/// `find_duplicate_files_stream` 응답
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DuplicateScanSummary {
+    pub job_id: String,
+    pub groups: Vec<DuplicateGroup>,
+    pub files_scanned: u64,
+    /// 이미 본 파일과 같은 inode라 비교에서 뺀 하드 링크 수
+    pub hardlinks_skipped: u64,
+    /// 최대 파일 수에 걸려 일부만 탐색함
+    pub truncated: bool,
+}
+
+struct Reporter<F> {
+    progress: DuplicateScanProgress,
+    last_sent: Option<Instant>,
+    emit: F,
+}
+
+impl<F: FnMut(DuplicateScanProgress) -> Result<()>> Reporter<F> {
+    fn report(&mut self, force: bool) -> Result<()> {
+        if !force
+            && self
+                .last_sent
+                .is_some_and(|t| t.elapsed() < PROGRESS_INTERVAL)
+        {
+            return Ok(());
+        }
+        self.last_sent = Some(Instant::now());
+        (self.emit)(self.progress.clone())
+    }
+
+    fn enter_phase(&mut self, phase: &str, candidates: u64) -> Result<()> {
+        self.progress.phase = phase.to_string();
+        self.progress.candidates = candidates;
+        self.report(true)
+    }
+}
+
+/// 하드 링크 식별 키 (링크가 2개 이상인 파일만)
+#[cfg(unix)]
+fn link_key(_path: &Path, meta: &std::fs::Metadata) -> Option<(u64, u64)> {
+    use std::os::unix::fs::MetadataExt;
+    (meta.nlink() > 1).then(|| (meta.dev(), meta.ino()))
+}
+
+/// Windows는 stable std로 파일 인덱스를 읽을 수 없어 핸들로 직접 조회
+/// (std의 metadata처럼 접근 권한 없이 열어 잠긴 파일도 조회 가능)
+#[cfg(windows)]
+fn link_key(path: &Path, _meta: &std::fs::Metadata) -> Option<(u64, u64)> {
+    use std::os::windows::fs::OpenOptionsExt;
+    use std::os::windows::io::AsRawHandle;
+    use winapi::um::fileapi::{GetFileInformationByHandle, BY_HANDLE_FILE_INFORMATION};
+
+    let file = std::fs::OpenOptions::new().access_mode(0).open(path).ok()?;
+    let mut info: BY_HANDLE_FILE_INFORMATION = unsafe { std::mem::zeroed() };
+    if unsafe { GetFileInformationByHandle(file.as_raw_handle() as _, &mut info) } == 0 {
+        return None;
+    }
+    let index = ((info.nFileIndexHigh as u64) << 32) | info.nFileIndexLow as u64;
+    (info.nNumberOfLinks > 1).then_some((info.dwVolumeSerialNumber as u64, index))
+}
+
+#[cfg(not(any(unix, windows)))]
+fn link_key(_path: &Path, _meta: &std::fs::Metadata) -> Option<(u64, u64)> {
+    None
+}
+
+/// `/`가 있는 패턴은 루트 기준 상대 경로, 없으면 이름과 비교
+fn pattern_matches(pattern: &str, name: &str, relative: &str) -> bool {
+    if pattern.contains(['/', '\\']) {
+        glob_match(&pattern.replace('\\', "/"), relative)
+    } else {
+        glob_match(pattern, name)
+    }
+}
+
+fn relative_path(root: &Path, path: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+/// 앞·뒤 `EDGE_LEN` 바이트 해시와 읽은 바이트 수 (`EDGE_LEN * 2` 이하 파일은 전체 내용)
+fn edge_hash(path: &Path, size: u64) -> std::io::Result<(u64, u64)> {
+    let mut file = std::fs::File::open(path)?;
+    let mut hasher = Xxh3::new();
+    if size <= EDGE_LEN * 2 {
+        let mut content = Vec::with_capacity(size as usize);
+        file.read_to_end(&mut content)?;
+        hasher.update(&content);
+        return Ok((hasher.digest(), content.len() as u64));
+    }
+    let mut buf = [0u8; EDGE_LEN as usize];
+    file.read_exact(&mut buf)?;
+    hasher.update(&buf);
+    file.seek(SeekFrom::End(-(EDGE_LEN as i64)))?;
+    file.read_exact(&mut buf)?;
+    hasher.update(&buf);
+    Ok((hasher.digest(), EDGE_LEN * 2))
+}
+
+/// 전체 내용 해시. 읽기 실패면 None (그 파일만 건너뜀), 취소되면 `Cancelled`.
+fn full_hash<F: FnMut(DuplicateScanProgress) -> Result<()>>(
+    path: &Path,
+    token: &CancelToken,
+    reporter: &mut Reporter<F>,
+) -> Result<Option<u64>> {
+    let Ok(mut file) = std::fs::File::open(path) else {
+        return Ok(None);
+    };
+    let mut hasher = Xxh3::new();
+    let mut buf = vec![0u8; HASH_BUF_LEN];
+    loop {
+        token.check()?;
+        match file.read(&mut buf) {
+            Ok(0) => break,
+            Ok(n) => {
+                hasher.update(&buf[..n]);
+                reporter.progress.bytes_hashed += n as u64;
+                reporter.report(false)?;
+            }
+            Err(_) => return Ok(None),
+        }
+    }
+    Ok(Some(hasher.digest()))
+}
+
+/// 키가 같은 항목이 2개 이상인 묶음만 남김
+fn regroup<K: Hash + Eq>(items: Vec<(K, FileEntry)>) -> Vec<Vec<FileEntry>> {
+    let mut map: HashMap<K, Vec<FileEntry>> = HashMap::new();
+    for (key, entry) in items {
+        map.entry(key).or_default().push(entry);
+    }
+    map.into_values().filter(|files| files.len() >= 2).collect()
+}
+
+pub(super) fn scan_duplicates(
+    root: &str,
+    options: &DuplicateScanOptions,
+    job_id: &str,
+    token: &CancelToken,
+    emit: impl FnMut(DuplicateScanProgress) -> Result<()>,
+) -> Result<DuplicateScanSummary> {
+    let root_path = Path::new(root);
+    if !root_path.is_dir() {
+        return Err(AppError::InvalidInput(
+            "유효한 폴더 경로가 아닙니다".to_string(),
+        ));
+    }
+    let min_size = options.min_size.unwrap_or(0);
+    let max_files = options.max_files.unwrap_or(MAX_DUPLICATE_SCAN_FILES) as u64;
+
+    let mut reporter = Reporter {
+        progress: DuplicateScanProgress {
+            job_id: job_id.to_string(),
+            phase: "scanning".to_string(),
+            files_scanned: 0,
+            candidates: 0,
+            bytes_hashed: 0,
+            bytes_to_hash: 0,
+        },
+        last_sent: None,
+        emit,
+    };
+    let mut summary = DuplicateScanSummary {
+        job_id: job_id.to_string(),
+        groups: Vec::new(),
+        files_scanned: 0,
+        hardlinks_skipped: 0,
+        truncated: false,
+    };
+    reporter.report(true)?;
+
+    // 1단계: 크기별 후보 수집 (같은 inode는 처음 본 경로만)
+    let mut by_size: HashMap<u64, Vec<FileEntry>> = HashMap::new();
+    let mut seen_links: HashSet<(u64, u64)> = HashSet::new();
+    let walker = walkdir::WalkDir::new(root)
+        .max_depth(DUPLICATE_SCAN_MAX_DEPTH)
+        .follow_links(false)
+        .into_iter()
+        .filter_entry(|entry| {
+            if entry.depth() == 0 {
+                return true;
+            }
+            let name = entry.file_name().to_string_lossy();
+            if !options.show_hidden && is_hidden_file(&name) {
+                return false;
+            }
+            #[cfg(target_os = "windows")]
+            {
+                if !options.show_hidden {
+                    if let Ok(meta) = entry.metadata() {
+                        if is_system_file(&meta) {
+                            return false;
+                        }
+                    }
+                }
+            }
+            if options.exclude.is_empty() {
+                return true;
+            }
+            let relative = relative_path(root_path, entry.path());
+            !options
+                .exclude
+                .iter()
+                .any(|p| pattern_matches(p, &name, &relative))
+        });
+
+    for entry in walker.flatten() {
+        token.check()?;
+        // 심볼릭 링크는 복사본이 아니므로 비교하지 않음
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        if summary.files_scanned >= max_files {
+            summary.truncated = true;
+            break;
+        }
+        summary.files_scanned += 1;
+        reporter.progress.files_scanned = summary.files_scanned;
+        reporter.report(false)?;
+
+        let name = entry.file_name().to_string_lossy().to_string();
+        if is_system_filename(&name) {
+            continue;
+        }
+        if !options.include.is_empty() {
+            let relative = relative_path(root_path, entry.path());
+            if !options
+                .include
+                .iter()
+                .any(|p| pattern_matches(p, &name, &relative))
+            {
+                continue;
+            }
+        }
+        let Ok(meta) = entry.metadata() else {
+            continue;
+        };
+        if meta.len() < min_size {
+            continue;
+        }
+        if let Some(key) = link_key(entry.path(), &meta) {
+            if !seen_links.insert(key) {
+                summary.hardlinks_skipped += 1;
+                continue;
+            }
+        }
+        by_size
+            .entry(meta.len())
+            .or_default()
+            .push(search_hit_entry(entry.path(), name, &meta, false));
+    }
+
+    let mut groups: Vec<Vec<FileEntry>> = Vec::new();
+    let mut same_size: Vec<(u64, Vec<FileEntry>)> = Vec::new();
+    for (size, files) in by_size.into_iter().filter(|(_, files)| files.len() >= 2) {
+        // 0바이트 파일은 해시 없이 크기만으로 중복 판정
+        if size == 0 {
+            groups.push(files);
+        } else {
+            same_size.push((size, files));
+        }
+    }
+
+    // 2단계: 앞뒤 구간 해시로 후보 축소
+    let candidates = same_size.iter().map(|(_, files)| files.len() as u64).sum();
+    reporter.enter_phase("comparing", candidates)?;
+    let mut by_edge = Vec::new();
+    for (size, files) in same_size {
+        for file in files {
+            token.check()?;
+            let Ok((hash, read)) = edge_hash(Path::new(&file.path), size) else {
+                continue;
+            };
+            reporter.progress.bytes_hashed += read;
+            reporter.report(false)?;
+            by_edge.push(((size, hash), file));
+        }
+    }
+    // 작은 파일은 앞뒤 구간이 곧 전체 내용이라 여기서 확정
+    let (complete, partial): (Vec<_>, Vec<_>) = regroup(by_edge)
+        .into_iter()
+        .partition(|files| files[0].size <= EDGE_LEN * 2);
+    groups.extend(complete);
+
+    // 3단계: 남은 후보만 전체 해시 (읽은 바이트는 bytes_to_hash 대비 진행률이 되도록 0부터)
+    reporter.progress.bytes_hashed = 0;
+    reporter.progress.bytes_to_hash = partial.iter().flatten().map(|f| f.size).sum();
+    let candidates = partial.iter().map(|files| files.len() as u64).sum();
+    reporter.enter_phase("hashing", candidates)?;
+    let mut by_hash = Vec::new();
+    for file in partial.into_iter().flatten() {
+        if let Some(hash) = full_hash(Path::new(&file.path), token, &mut reporter)? {
+            by_hash.push(((file.size, hash), file));
+        }
+    }
+    groups.extend(regroup(by_hash));
+
+    summary.groups = groups
+        .into_iter()
+        .map(|mut files| {
+            files.sort_by(|a, b| a.path.cmp(&b.path));
+            let size = files.first().map(|f| f.size).unwrap_or(0);
+            DuplicateGroup { size, files }
+        })
+        .collect();
+    // 큰 파일 그룹 우선, 동일 크기면 파일 수 많은 순
+    summary.groups.sort_by(|a, b| {
+        b.size
+            .cmp(&a.size)
+            .then_with(|| b.files.len().cmp(&a.files.len()))
+    });
+    summary.groups.truncate(MAX_DUPLICATE_GROUPS);
+
+    reporter.enter_phase("done", 0)?;
+    Ok(summary)
+}
+
+// 폴더 하위 중복 파일 탐색 (진행률은 on_progress 채널, 취소는 cancel_duplicate_scan)
+#[tauri::command]
+pub async fn find_duplicate_files_stream(
+    root: String,
+    options: Option<DuplicateScanOptions>,
+    on_progress: Channel<DuplicateScanProgress>,
+) -> Result<DuplicateScanSummary> {
+    let options = options.unwrap_or_default();
+    let job_id = options
+        .job_id
+        .clone()
+        .unwrap_or_else(|| new_job_id("duplicates"));
+    let handle = register_job(job_id)?;
+    tauri::async_runtime::spawn_blocking(move || {
+        scan_duplicates(&root, &options, handle.id(), handle.token(), |progress| {
+            on_progress
+                .send(progress)
+                .map_err(|e| AppError::Internal(format!("진행률 전송 실패: {}", e)))
+        })
+    })
+    .await
+    .map_err(|e| AppError::Internal(format!("중복 파일 탐색 태스크 실패: {}", e)))?
+}
+
+/// 진행 중인 중복 탐색 중단 (이미 끝났으면 false)
+#[tauri::command]
+pub fn cancel_duplicate_scan(job_id: String) -> bool {
+    cancel_job(&job_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn staged_hashing_groups_true_duplicates_only() {
+        let root = std::env::temp_dir().join(format!(
+            "quickfolder_duplicate_scan_{}_{}",
+            std::process::id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+        std::fs::create_dir_all(root.join("cache")).unwrap();
+        let big: Vec<u8> = (0..20_000u32).map(|i| (i % 251) as u8).collect();
+        // 앞뒤 4KB는 같고 가운데만 다른 파일 → 전체 해시 단계에서 걸러진다
+        let mut middle_differs = big.clone();
+        middle_differs[10_000] ^= 0xFF;
+        std::fs::write(root.join("a.psd"), &big).unwrap();
+        std::fs::write(root.join("b.psd"), &big).unwrap();
+        std::fs::write(root.join("c.psd"), &middle_differs).unwrap();
+        std::fs::write(root.join("x.txt"), b"small dup").unwrap();
+        std::fs::write(root.join("y.txt"), b"small dup").unwrap();
+        std::fs::write(root.join("cache/z.txt"), b"small dup").unwrap();
+        std::fs::write(root.join("e1.log"), b"").unwrap();
+        std::fs::write(root.join("e2.log"), b"").unwrap();
+        std::fs::hard_link(root.join("a.psd"), root.join("a-link.psd")).unwrap();
+
+        let options = DuplicateScanOptions {
+            min_size: Some(1),
+            exclude: vec!["cache".to_string()],
+            ..Default::default()
+        };
+        let mut phases = Vec::new();
+        let mut bytes_by_phase = HashMap::new();
+        let summary = scan_duplicates(
+            &root.to_string_lossy(),
+            &options,
+            "duplicates-test",
+            &CancelToken::new(),
+            |p| {
+                if phases.last() != Some(&p.phase) {
+                    phases.push(p.phase.clone());
+                }
+                bytes_by_phase
+                    .entry(p.phase.clone())
+                    .or_insert((p.bytes_hashed, p.bytes_to_hash));
+                Ok(())
+            },
+        )
+        .unwrap();
+
+        let names: Vec<Vec<&str>> = summary
+            .groups
+            .iter()
+            .map(|g| g.files.iter().map(|f| f.name.as_str()).collect())
+            .collect();
+        assert_eq!(names, vec![vec!["a.psd", "b.psd"], vec!["x.txt", "y.txt"]]);
+        assert_eq!(phases, ["scanning", "comparing", "hashing", "done"]);
+        // 단계에 들어갈 때의 값 — 전체 해시 단계는 남은 3개 파일을 0부터 다시 센다
+        assert_eq!(bytes_by_phase["hashing"], (0, 20_000 * 3));
+        assert_eq!(bytes_by_phase["done"], (20_000 * 3, 20_000 * 3));
+        assert_eq!(summary.hardlinks_skipped, 1);
+
+        // include는 이름 와일드카드, 0바이트 파일은 크기만으로 묶인다
+        let options = DuplicateScanOptions {
+            include: vec!["*.log".to_string()],
+            ..Default::default()
+        };
+        let summary = scan_duplicates(
+            &root.to_string_lossy(),
+            &options,
+            "duplicates-test",
+            &CancelToken::new(),
+            |_| Ok(()),
+        )
+        .unwrap();
+        assert_eq!(summary.groups.len(), 1);
+        assert_eq!(summary.groups[0].size, 0);
+
+        let cancelled = CancelToken::new();
+        cancelled.cancel();
+        assert!(matches!(
+            scan_duplicates(
+                &root.to_string_lossy(),
+                &DuplicateScanOptions::default(),
+                "duplicates-test",
+                &cancelled,
+                |_| Ok(()),
+            ),
+            Err(AppError::Cancelled(_))
+        ));
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+}
@@ -132,34 +132,72 @@ fn spawn_terminal(path: &str, command: Option<&str>) -> Result<(), String> {
         .map_err(|e| format!("Terminal 실행 실패: {}", e))
 }
 
+/// `$TERMINAL`이 없을 때 순서대로 시도할 터미널 에뮬레이터
+#[cfg(target_os = "linux")]
+const LINUX_TERMINALS: &[&str] = &[
+    "x-terminal-emulator",
+    "gnome-terminal",
+    "konsole",
+    "xfce4-terminal",
+    "kitty",
+    "alacritty",
+    "wezterm",
+    "foot",
+    "xterm",
+];
+
+/// 터미널별 작업 폴더 지정·명령 실행 인자 (모르는 터미널은 관례적인 `-e`)
+#[cfg(any(target_os = "linux", test))]
+fn linux_terminal_args(program: &str, path: &str, shell_script: &str) -> Vec<String> {
+    let name = Path::new(program)
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let run = ["sh", "-lc", shell_script];
+    let (prefix, separator): (Vec<String>, &str) = match name.as_str() {
+        "gnome-terminal" => (vec![format!("--working-directory={}", path)], "--"),
+        "konsole" => (vec!["--workdir".into(), path.into()], "-e"),
+        "xfce4-terminal" => (vec!["--working-directory".into(), path.into()], "-x"),
+        "kitty" => (vec!["--directory".into(), path.into()], ""),
+        "alacritty" => (vec!["--working-directory".into(), path.into()], "-e"),
+        "wezterm" => (vec!["start".into(), "--cwd".into(), path.into()], "--"),
+        "foot" => (vec![format!("--working-directory={}", path)], ""),
+        _ => (Vec::new(), "-e"),
+    };
+    prefix
+        .into_iter()
+        .chain((!separator.is_empty()).then(|| separator.to_string()))
+        .chain(run.iter().map(|arg| arg.to_string()))
+        .collect()
+}
+
 #[cfg(target_os = "linux")]
 fn spawn_terminal(path: &str, command: Option<&str>) -> Result<(), String> {
     let trimmed = command.map(str::trim).filter(|value| !value.is_empty());
+    // 명령이 끝나도 창이 닫히지 않게 사용자 셸로 이어감
     let shell_script = if let Some(command) = trimmed {
-        format!("cd {}; {}; exec sh", quote_posix_literal(path), command)
+        format!(
+            "cd {}; {}; exec \"${{SHELL:-sh}}\"",
+            quote_posix_literal(path),
+            command
+        )
     } else {
-        format!("cd {}; exec sh", quote_posix_literal(path))
+        format!("cd {}; exec \"${{SHELL:-sh}}\"", quote_posix_literal(path))
     };
 
-    let attempts = [
-        (
-            "x-terminal-emulator",
-            vec!["-e", "sh", "-lc", shell_script.as_str()],
-        ),
-        (
-            "gnome-terminal",
-            vec!["--", "sh", "-lc", shell_script.as_str()],
-        ),
-        ("konsole", vec!["-e", "sh", "-lc", shell_script.as_str()]),
-        (
-            "xfce4-terminal",
-            vec!["-e", "sh", "-lc", shell_script.as_str()],
-        ),
-    ];
-
-    for (program, args) in attempts {
+    let preferred = std::env::var("TERMINAL")
+        .ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty());
+    let candidates = preferred
+        .iter()
+        .map(String::as_str)
+        .chain(LINUX_TERMINALS.iter().copied());
+
+    for program in candidates {
         if std::process::Command::new(program)
-            .args(args)
+            .args(linux_terminal_args(program, path, &shell_script))
+            .current_dir(path)
             .spawn()
             .is_ok()
         {
@@ -167,7 +205,7 @@ fn spawn_terminal(path: &str, command: Option<&str>) -> Result<(), String> {
         }
     }
 
-    Err("사용 가능한 터미널 앱을 찾을 수 없습니다".to_string())
+    Err("사용 가능한 터미널 앱을 찾을 수 없습니다 ($TERMINAL 환경 변수로 지정 가능)".to_string())
 }
 
 #[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
@@ -561,6 +599,20 @@ mod tests {
         assert_eq!(quoted, "'D:\\Work\\John''s Project'");
     }
 
+    #[test]
+    fn linux_terminal_args_use_each_emulators_working_directory_flag() {
+        let args = linux_terminal_args("/usr/bin/gnome-terminal", "/home/me", "cd x");
+        assert_eq!(
+            args,
+            ["--working-directory=/home/me", "--", "sh", "-lc", "cd x"]
+        );
+        let args = linux_terminal_args("kitty", "/home/me", "cd x");
+        assert_eq!(args, ["--directory", "/home/me", "sh", "-lc", "cd x"]);
+        // 알 수 없는 $TERMINAL 값은 -e 관례로 실행
+        let args = linux_terminal_args("st", "/home/me", "cd x");
+        assert_eq!(args, ["-e", "sh", "-lc", "cd x"]);
+    }
+
     #[test]
     fn powershell_run_script_keeps_terminal_open_and_runs_in_path() {
         let script =
@@ -1,10 +1,18 @@
 //! 파일 검색 및 최근 파일 조회 모듈
-//! Spotlight/Windows Search Index 활용 + walkdir 폴백
+//! Spotlight/Windows Search Index 활용 → 앱 파일명 인덱스(search_index) → walkdir 폴백
 
+use super::super::archive_ops::{
+    is_zip_archive_path, list_archive_tree, BROWSABLE_ARCHIVE_SUFFIXES,
+};
 use super::super::constants::{
-    DUPLICATE_SCAN_MAX_DEPTH, MAX_DUPLICATE_GROUPS, MAX_DUPLICATE_SCAN_FILES, SEARCH_MAX_DEPTH,
+    ARCHIVE_SEARCH_BUDGET_MS, ARCHIVE_SEARCH_MAX_ARCHIVES, ARCHIVE_SEARCH_MAX_STREAM_SIZE,
+    RECENT_ARCHIVE_MAX_ENTRIES, SEARCH_MAX_DEPTH, SEARCH_SORT_CANDIDATES,
 };
-use super::super::types::{classify_file, file_identity, FileEntry, FileType};
+use super::super::jobs::{cancel_job, new_job_id, register_job, CancelToken};
+use super::super::types::{classify_file, file_identity, FileDetails, FileEntry, FileType};
+use super::duplicate_scan::{scan_duplicates, DuplicateScanOptions};
+use super::search_index::search_in_index;
+use super::search_query::{HitCollector, SearchQuery, SearchSort};
 use crate::helpers::{is_hidden_file, is_system_filename};
 
 #[cfg(target_os = "windows")]
@@ -15,9 +23,19 @@ use crate::helpers::is_system_file;
 // ===== 최근 변경 파일 조회 =====
 
 // 지정된 루트 디렉토리들에서 최근 N일 이내 변경된 파일 조회
+// show_hidden이 아니면 숨김·시스템·임시 파일 제외
+// include_archives면 루트의 zip 안 항목도 가상 경로로 포함 (압축 파일당 최근 항목 일부만)
+// tar·7z 등은 항목 시각이 없어 모두 압축 파일 시각이 되므로 제외
 // spawn_blocking으로 네트워크 파일시스템 차단 방지
 #[tauri::command]
-pub async fn get_recent_files(roots: Vec<String>, days: u32) -> Result<Vec<FileEntry>, String> {
+pub async fn get_recent_files(
+    roots: Vec<String>,
+    days: u32,
+    show_hidden: Option<bool>,
+    include_archives: Option<bool>,
+) -> Result<Vec<FileEntry>, String> {
+    let show_hidden = show_hidden.unwrap_or(false);
+    let include_archives = include_archives.unwrap_or(false);
     tauri::async_runtime::spawn_blocking(move || -> Result<Vec<FileEntry>, String> {
         let now = std::time::SystemTime::now();
         let cutoff = std::time::Duration::from_secs(days as u64 * 24 * 60 * 60);
@@ -44,24 +62,13 @@ pub async fn get_recent_files(roots: Vec<String>, days: u32) -> Result<Vec<FileE
                 }
 
                 let name = entry.file_name().to_string_lossy().to_string();
+                let is_symlink = entry.file_type().is_ok_and(|t| t.is_symlink());
+                let details = FileDetails::read(&name, &entry.path(), &meta, is_symlink);
 
-                // 숨김 파일 제외
-                if is_hidden_file(&name) {
-                    continue;
-                }
-
-                // 시스템/임시 파일 제외
-                if is_system_filename(&name) {
+                // 숨김 파일과 시스템/임시 파일 제외
+                if !show_hidden && (details.is_hidden || is_system_filename(&name)) {
                     continue;
                 }
-
-                // Windows: 숨김(HIDDEN) 또는 시스템(SYSTEM) 속성 파일 제외
-                #[cfg(target_os = "windows")]
-                {
-                    if is_system_file(&meta) {
-                        continue;
-                    }
-                }
                 let modified = meta
                     .modified()
                     .ok()
@@ -77,15 +84,28 @@ pub async fn get_recent_files(roots: Vec<String>, days: u32) -> Result<Vec<FileE
                     continue;
                 }
 
+                let path = entry.path().to_string_lossy().to_string();
+                if include_archives && is_zip_archive_path(&entry.path()) {
+                    let cutoff_ms = modified_cutoff_ms(now, cutoff);
+                    let mut recent: Vec<FileEntry> = archive_entries(&path, show_hidden)
+                        .filter(|e| !e.is_dir && e.modified >= cutoff_ms)
+                        .collect();
+                    recent.sort_by(|a, b| b.modified.cmp(&a.modified));
+                    recent.truncate(RECENT_ARCHIVE_MAX_ENTRIES);
+                    results.extend(recent);
+                }
+
                 let file_type = classify_file(&name);
                 results.push(FileEntry {
-                    path: entry.path().to_string_lossy().to_string(),
+                    path,
                     is_dir: false,
                     size: meta.len(),
                     modified,
                     identity: file_identity(&meta),
                     file_type,
                     name,
+                    is_symlink,
+                    details,
                 });
             }
         }
@@ -103,51 +123,147 @@ pub async fn get_recent_files(roots: Vec<String>, days: u32) -> Result<Vec<FileE
 // ===== 파일 검색 =====
 
 // 글로벌 파일 검색 (하위 폴더 재귀 탐색)
+// query는 이름 검색어와 조건(ext: size: modified: type: name:)을 섞어 쓴다 (search_query 참고)
 // macOS: Spotlight 인덱스(mdfind) 활용으로 즉시 검색, 실패 시 walkdir 폴백
 // Windows: Windows Search Index(ADODB) 활용, 실패 시 walkdir 폴백
+// 그 외 OS 또는 위 검색 실패 시 앱 파일명 인덱스가 덮는 경로면 인덱스로 검색
+// 어느 백엔드든 결과는 같은 조건으로 다시 거르고 sort(relevance/size/modified/name) 순으로 정렬
+// include_archives면 루트 아래 압축 파일 안 항목도 가상 경로로 검색 (선택 사항, 느릴 수 있음)
+// 압축 파일은 같은 탐색에서 함께 찾고, 큰 tar 계열과 시간 예산을 넘긴 나머지는 건너뜀
+// job_id로 `cancel_file_search` 중단 가능 (새 검색어 입력 시 이전 검색 취소용)
+// show_hidden이 아니면 숨김 항목과 숨김 폴더 하위는 제외
 #[tauri::command]
 pub async fn search_files(
     root: String,
     query: String,
     max_results: usize,
+    show_hidden: Option<bool>,
+    sort: Option<String>,
+    include_archives: Option<bool>,
+    job_id: Option<String>,
 ) -> Result<Vec<FileEntry>, String> {
+    let show_hidden = show_hidden.unwrap_or(false);
+    let include_archives = include_archives.unwrap_or(false);
+    let query = SearchQuery::parse(&query).map_err(|e| e.to_string())?;
+    let sort = SearchSort::parse(sort.as_deref()).map_err(|e| e.to_string())?;
+    // 정렬 결과가 의미 있도록 요청 개수보다 넉넉히 모은 뒤 자른다
+    let limit = max_results.max(SEARCH_SORT_CANDIDATES);
+    let job_id = job_id.unwrap_or_else(|| new_job_id("file-search"));
+    let handle = register_job(job_id).map_err(|e| e.to_string())?;
     tauri::async_runtime::spawn_blocking(move || -> Result<Vec<FileEntry>, String> {
-        // macOS: mdfind (Spotlight 인덱스) 먼저 시도
-        #[cfg(target_os = "macos")]
-        {
-            if let Ok(entries) = search_with_mdfind(&root, &query, max_results) {
-                return Ok(entries);
-            }
+        let token = handle.token();
+        let hits = collect_search_hits(
+            &root,
+            &query,
+            sort,
+            limit,
+            include_archives,
+            show_hidden,
+            token,
+        )?;
+        let (mut entries, archives) = hits.into_parts();
+        entries.extend(search_in_archives(
+            &archives,
+            &query,
+            limit,
+            show_hidden,
+            token,
+        )?);
+        query.sort_results(&mut entries, sort, max_results);
+        Ok(entries)
+    })
+    .await
+    .map_err(|e| format!("파일 검색 태스크 실패: {}", e))?
+}
+
+/// 진행 중인 파일 검색 중단 (이미 끝났으면 false)
+#[tauri::command]
+pub fn cancel_file_search(job_id: String) -> bool {
+    cancel_job(&job_id)
+}
+
+// 백엔드 순서대로 시도해 조건에 맞는 항목을 최대 limit개 수집
+// 크기·수정 시각 정렬이면 limit에서 멈추지 않고 전체 중 상위 limit개를 고른다
+// include_archives면 조건과 무관하게 압축 파일 경로도 함께 모은다
+fn collect_search_hits(
+    root: &str,
+    query: &SearchQuery,
+    sort: SearchSort,
+    limit: usize,
+    include_archives: bool,
+    show_hidden: bool,
+    token: &CancelToken,
+) -> Result<HitCollector, String> {
+    let new_hits = || {
+        let hits = HitCollector::new(sort, limit);
+        if include_archives {
+            hits.with_archives(ARCHIVE_SEARCH_MAX_ARCHIVES)
+        } else {
+            hits
         }
+    };
+
+    // macOS: mdfind (Spotlight 인덱스) 먼저 시도
+    #[cfg(target_os = "macos")]
+    {
+        let mut hits = new_hits();
+        if search_with_mdfind(root, query, &mut hits, show_hidden).is_ok() {
+            return Ok(hits);
+        }
+    }
 
-        // Windows: Windows Search Index (ADODB) 먼저 시도
-        #[cfg(target_os = "windows")]
+    // Windows: Windows Search Index (ADODB) 먼저 시도
+    #[cfg(target_os = "windows")]
+    {
+        let mut hits = new_hits();
+        if search_with_windows_index(root, query, &mut hits, show_hidden).is_ok()
+            && !hits.is_empty()
         {
-            if let Ok(entries) = search_with_windows_index(&root, &query, max_results) {
-                if !entries.is_empty() {
-                    return Ok(entries);
-                }
-            }
+            return Ok(hits);
         }
+    }
 
-        // 폴백: walkdir 기반 직접 탐색
-        search_with_walkdir(&root, &query, max_results)
-    })
-    .await
-    .map_err(|e| format!("파일 검색 태스크 실패: {}", e))?
+    // 앱 파일명 인덱스 (색인된 루트 아래일 때만)
+    let mut hits = new_hits();
+    if search_in_index(root, query, &mut hits, show_hidden) {
+        return Ok(hits);
+    }
+
+    // 폴백: walkdir 기반 직접 탐색
+    let mut hits = new_hits();
+    search_with_walkdir(root, query, &mut hits, show_hidden, token)?;
+    Ok(hits)
+}
+
+// 압축 파일 확장자 조건 (인덱스 쿼리를 넓혀 압축 파일도 받아 오는 데 사용)
+#[cfg_attr(not(any(target_os = "macos", target_os = "windows")), allow(dead_code))]
+fn archive_query() -> SearchQuery {
+    SearchQuery {
+        extensions: BROWSABLE_ARCHIVE_SUFFIXES
+            .iter()
+            .map(|suffix| suffix.trim_start_matches('.').to_string())
+            .collect(),
+        ..Default::default()
+    }
 }
 
 // macOS Spotlight 인덱스(mdfind) 기반 즉시 검색
+// 이름·확장자·크기·수정 시각 조건은 Spotlight 쿼리로 옮겨 좁힌다
 #[cfg(target_os = "macos")]
 fn search_with_mdfind(
     root: &str,
-    query: &str,
-    max_results: usize,
-) -> Result<Vec<FileEntry>, String> {
+    query: &SearchQuery,
+    hits: &mut HitCollector,
+    show_hidden: bool,
+) -> Result<(), String> {
     use std::process::Command;
 
+    let mut spotlight = query.to_spotlight();
+    if hits.collects_archives() {
+        spotlight = format!("({}) || ({})", spotlight, archive_query().to_spotlight());
+    }
     let output = Command::new("mdfind")
-        .args(["-onlyin", root, "-name", query])
+        .args(["-onlyin", root, &spotlight])
         .output()
         .map_err(|e| format!("mdfind 실행 실패: {}", e))?;
 
@@ -156,7 +272,6 @@ fn search_with_mdfind(
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let mut result = vec![];
 
     for line in stdout.lines() {
         if line.is_empty() {
@@ -168,7 +283,7 @@ fn search_with_mdfind(
         let has_hidden = path
             .components()
             .any(|c| is_hidden_file(&c.as_os_str().to_string_lossy()));
-        if has_hidden {
+        if has_hidden && !show_hidden {
             continue;
         }
 
@@ -181,58 +296,69 @@ fn search_with_mdfind(
             .file_name()
             .map(|n| n.to_string_lossy().to_string())
             .unwrap_or_default();
-
-        let modified = meta
-            .modified()
-            .ok()
-            .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
-            .map(|d| d.as_millis() as u64)
-            .unwrap_or(0);
-
-        let is_dir = meta.is_dir();
-        let file_type = if is_dir {
-            FileType::Directory
-        } else {
-            classify_file(&name)
-        };
-
-        result.push(FileEntry {
-            path: line.to_string(),
-            is_dir,
-            size: if is_dir { 0 } else { meta.len() },
-            modified,
-            identity: file_identity(&meta),
-            file_type,
-            name,
-        });
-
-        if result.len() >= max_results {
+        let is_symlink = crate::modules::file_ops::is_symlink(path);
+        let entry = search_hit_entry(path, name, &meta, is_symlink);
+        if entry.details.is_hidden && !show_hidden {
+            continue;
+        }
+        if hits.wants_archive(&entry.name, entry.is_dir) {
+            hits.note_archive(entry.path.clone());
+        }
+        // Spotlight로 옮기지 못한 조건(한 글자 와일드카드, 확장자 없는 문서 등)은 여기서 확인
+        if !query.matches(&entry) {
+            continue;
+        }
+        if hits.push(entry) {
             break;
         }
     }
 
-    Ok(result)
+    Ok(())
 }
 
 // Windows Search Index (ADODB COM) 기반 즉시 검색
 // Windows는 기본적으로 사용자 폴더를 인덱싱하므로 Spotlight과 유사한 속도
+// 조건은 SQL WHERE로 옮기고, 크기·날짜 정렬은 ORDER BY로 인덱스에서 상위 항목을 고른다
+// 옮기지 못한 종류 조건이 있거나 압축 파일도 함께 받으면 결과를 다시 거르며 줄어들므로 TOP으로 자르지 않는다
 #[cfg(target_os = "windows")]
 fn search_with_windows_index(
     root: &str,
-    query: &str,
-    max_results: usize,
-) -> Result<Vec<FileEntry>, String> {
+    query: &SearchQuery,
+    hits: &mut HitCollector,
+    show_hidden: bool,
+) -> Result<(), String> {
     use std::process::Command;
 
-    // SQL 인젝션 방지: 작은따옴표 이스케이프
-    let safe_query = query.replace('\'', "''");
     // Windows Search scope는 file:/// URL 형식 사용
-    let scope_path = root.replace('\\', "/");
+    let scope_path = root.replace('\\', "/").replace('\'', "''");
     let scope = if scope_path.starts_with("//") {
         format!("file:{}", scope_path)
     } else {
         format!("file:///{}", scope_path)
     };
+    // SQL 인젝션 방지: 조건 값의 작은따옴표는 search_query에서 이스케이프
+    let mut conditions = vec![format!("SCOPE='{}'", scope)];
+    let query_conditions = query.to_windows_conditions();
+    let collects_archives = hits.collects_archives();
+    if collects_archives && !query_conditions.is_empty() {
+        conditions.push(format!(
+            "(({}) OR ({}))",
+            query_conditions.join(" AND "),
+            archive_query().to_windows_conditions().join(" AND ")
+        ));
+    } else {
+        conditions.extend(query_conditions);
+    }
+    let order_by = match hits.sort() {
+        SearchSort::Size => " ORDER BY System.Size DESC",
+        SearchSort::Modified => " ORDER BY System.DateModified DESC",
+        SearchSort::Relevance | SearchSort::Name => "",
+    };
+    let top = if query.types_are_native() && !collects_archives {
+        format!("TOP {} ", hits.limit())
+    } else {
+        String::new()
+    };
 
     // PowerShell로 Windows Search Index 쿼리 (ADODB COM)
     let ps_script = format!(
@@ -240,12 +366,14 @@ fn search_with_windows_index(
             "$ErrorActionPreference='SilentlyContinue';",
             "$c=New-Object -Com ADODB.Connection;",
             "$c.Open('Provider=Search.CollatorDSO;Extended Properties=''Application=Windows'';');",
-            "$r=$c.Execute(\"SELECT TOP {} System.ItemPathDisplay FROM SystemIndex ",
-            "WHERE SCOPE='{}' AND System.FileName LIKE '%{}%'\");",
+            "$r=$c.Execute(\"SELECT {}System.ItemPathDisplay FROM SystemIndex ",
+            "WHERE {}{}\");",
             "while(-not $r.EOF){{$r.Fields.Item('System.ItemPathDisplay').Value;$r.MoveNext()}};",
             "if($r){{$r.Close()}};$c.Close()"
         ),
-        max_results, scope, safe_query
+        top,
+        conditions.join(" AND "),
+        order_by
     );
 
     use std::os::windows::process::CommandExt;
@@ -261,7 +389,6 @@ fn search_with_windows_index(
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let mut result = vec![];
 
     for line in stdout.lines() {
         let line = line.trim();
@@ -275,69 +402,49 @@ fn search_with_windows_index(
             .map(|n| n.to_string_lossy().to_string())
             .unwrap_or_default();
 
-        // 숨김 파일 제외
-        if is_hidden_file(&name) {
-            continue;
-        }
-
         let meta = match std::fs::metadata(path) {
             Ok(m) => m,
             Err(_) => continue,
         };
 
-        // Windows 시스템/숨김 파일 제외
-        if is_system_file(&meta) {
+        // 숨김 파일과 Windows 시스템/숨김 속성 파일 제외
+        let is_symlink = crate::modules::file_ops::is_symlink(path);
+        let entry = search_hit_entry(path, name, &meta, is_symlink);
+        if entry.details.is_hidden && !show_hidden {
             continue;
         }
-
-        let modified = meta
-            .modified()
-            .ok()
-            .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
-            .map(|d| d.as_millis() as u64)
-            .unwrap_or(0);
-
-        let is_dir = meta.is_dir();
-        let file_type = if is_dir {
-            FileType::Directory
-        } else {
-            classify_file(&name)
-        };
-
-        result.push(FileEntry {
-            path: line.to_string(),
-            is_dir,
-            size: if is_dir { 0 } else { meta.len() },
-            modified,
-            identity: file_identity(&meta),
-            file_type,
-            name,
-        });
-
-        if result.len() >= max_results {
+        if hits.wants_archive(&entry.name, entry.is_dir) {
+            hits.note_archive(entry.path.clone());
+        }
+        if !query.matches(&entry) {
+            continue;
+        }
+        if hits.push(entry) {
             break;
         }
     }
 
-    Ok(result)
+    Ok(())
 }
 
 // walkdir 기반 직접 재귀 탐색 (인덱스 검색 폴백)
 fn search_with_walkdir(
     root: &str,
-    query: &str,
-    max_results: usize,
-) -> Result<Vec<FileEntry>, String> {
+    query: &SearchQuery,
+    hits: &mut HitCollector,
+    show_hidden: bool,
+    token: &CancelToken,
+) -> Result<(), String> {
     use walkdir::WalkDir;
 
-    let query_lower = query.to_lowercase();
-    let mut result = vec![];
-
     let walker = WalkDir::new(root)
         .max_depth(SEARCH_MAX_DEPTH)
         .follow_links(false)
         .into_iter()
         .filter_entry(|entry| {
+            if show_hidden {
+                return true;
+            }
             let name = entry.file_name().to_string_lossy();
             // 숨김 파일/디렉토리 전체 서브트리 제외
             if is_hidden_file(&name) {
@@ -356,12 +463,18 @@ fn search_with_walkdir(
         });
 
     for entry in walker.flatten() {
+        token.check().map_err(|e| e.to_string())?;
         if entry.depth() == 0 {
             continue;
         }
 
+        // 이름 조건으로 먼저 거른 뒤 메타데이터 조회
         let name = entry.file_name().to_string_lossy().to_string();
-        if !name.to_lowercase().contains(&query_lower) {
+        let is_dir = entry.file_type().is_dir();
+        if hits.wants_archive(&name, is_dir) {
+            hits.note_archive(entry.path().to_string_lossy().to_string());
+        }
+        if !query.matches_name(&name, is_dir) {
             continue;
         }
 
@@ -370,38 +483,120 @@ fn search_with_walkdir(
             Err(_) => continue,
         };
 
-        let modified = meta
-            .modified()
-            .ok()
-            .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
-            .map(|d| d.as_millis() as u64)
-            .unwrap_or(0);
+        let file_entry = search_hit_entry(entry.path(), name, &meta, entry.path_is_symlink());
+        if file_entry.details.is_hidden && !show_hidden {
+            continue;
+        }
+        if !query.matches(&file_entry) {
+            continue;
+        }
+        if hits.push(file_entry) {
+            break;
+        }
+    }
 
-        let is_dir = meta.is_dir();
-        let file_type = if is_dir {
-            FileType::Directory
-        } else {
-            classify_file(&name)
-        };
+    Ok(())
+}
 
-        result.push(FileEntry {
-            path: entry.path().to_string_lossy().to_string(),
-            is_dir,
-            size: if is_dir { 0 } else { meta.len() },
-            modified,
-            identity: file_identity(&meta),
-            file_type,
-            name,
-        });
+// ===== 압축 파일 내부 검색 =====
 
-        if result.len() >= max_results {
+fn modified_cutoff_ms(now: std::time::SystemTime, age: std::time::Duration) -> u64 {
+    now.checked_sub(age)
+        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+// 압축 파일 안 항목 (가상 경로). 목록을 못 읽는 압축 파일은 건너뜀
+// show_hidden이 아니면 숨김 이름이나 __MACOSX 같은 부가 폴더 아래 항목은 제외
+fn archive_entries(archive: &str, show_hidden: bool) -> impl Iterator<Item = FileEntry> {
+    let root_len = archive.len() + 1;
+    let entries = list_archive_tree(std::path::Path::new(archive)).unwrap_or_default();
+    (0..entries.len()).filter_map(move |i| {
+        let entry = &entries[i];
+        let hidden = entry.path[root_len.min(entry.path.len())..]
+            .split(['/', '\\'])
+            .any(|part| is_hidden_file(part) || part == "__MACOSX");
+        (show_hidden || !hidden).then(|| entry.clone())
+    })
+}
+
+// 검색 중 찾은 압축 파일 내부 항목 중 조건에 맞는 것을 최대 limit개 수집
+// zip이 아닌 큰 압축 파일은 목록에도 전체 해제가 필요해 건너뛰고,
+// 시간 예산을 넘기면 남은 압축 파일은 열지 않는다
+fn search_in_archives(
+    archives: &[String],
+    query: &SearchQuery,
+    limit: usize,
+    show_hidden: bool,
+    token: &CancelToken,
+) -> Result<Vec<FileEntry>, String> {
+    let started = std::time::Instant::now();
+    let budget = std::time::Duration::from_millis(ARCHIVE_SEARCH_BUDGET_MS);
+    let mut result = vec![];
+    for archive in archives {
+        token.check().map_err(|e| e.to_string())?;
+        if started.elapsed() > budget {
             break;
         }
+        let path = std::path::Path::new(archive);
+        let too_large = std::fs::metadata(path)
+            .map_or(true, |meta| meta.len() > ARCHIVE_SEARCH_MAX_STREAM_SIZE);
+        if !is_zip_archive_path(path) && too_large {
+            continue;
+        }
+        for entry in archive_entries(archive, show_hidden) {
+            if token.is_cancelled() {
+                break;
+            }
+            if !query.matches(&entry) {
+                continue;
+            }
+            result.push(entry);
+            if result.len() >= limit {
+                return Ok(result);
+            }
+        }
     }
-
+    token.check().map_err(|e| e.to_string())?;
     Ok(result)
 }
 
+/// 검색 결과 항목 구성 (walkdir·앱 인덱스 공통)
+pub(super) fn search_hit_entry(
+    path: &std::path::Path,
+    name: String,
+    meta: &std::fs::Metadata,
+    is_symlink: bool,
+) -> FileEntry {
+    let modified = meta
+        .modified()
+        .ok()
+        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0);
+
+    let is_dir = meta.is_dir();
+    let file_type = if is_dir {
+        FileType::Directory
+    } else {
+        classify_file(&name)
+    };
+
+    let details = FileDetails::read(&name, path, meta, is_symlink);
+    FileEntry {
+        path: path.to_string_lossy().to_string(),
+        is_dir,
+        size: if is_dir { 0 } else { meta.len() },
+        modified,
+        identity: file_identity(meta),
+        file_type,
+        name,
+        is_symlink,
+        details,
+    }
+}
+
 // ===== 중복 파일 탐색 =====
 
 /// 동일 내용(해시) 파일 그룹
@@ -412,7 +607,7 @@ pub struct DuplicateGroup {
 }
 
 // 폴더 하위를 재귀 탐색해 내용이 동일한 파일 그룹 반환
-// 1단계: 크기로 후보 축소 → 2단계: xxh3 해시로 동일 내용 확인
+// 크기 → 앞뒤 구간 해시 → 전체 xxh3 해시 순으로 확인 (진행률·취소가 필요하면 find_duplicate_files_stream)
 #[tauri::command]
 pub async fn find_duplicate_files(root: String) -> Result<Vec<DuplicateGroup>, String> {
     tauri::async_runtime::spawn_blocking(move || find_duplicates_blocking(&root))
@@ -421,140 +616,13 @@ pub async fn find_duplicate_files(root: String) -> Result<Vec<DuplicateGroup>, S
 }
 
 fn find_duplicates_blocking(root: &str) -> Result<Vec<DuplicateGroup>, String> {
-    use std::collections::HashMap;
-    use std::io::Read;
-    use std::path::Path;
-    use walkdir::WalkDir;
-    use xxhash_rust::xxh3::Xxh3;
-
-    let root_path = Path::new(root);
-    if !root_path.is_dir() {
-        return Err("유효한 폴더 경로가 아닙니다".to_string());
-    }
-
-    // 크기별 파일 후보 수집
-    let mut by_size: HashMap<u64, Vec<FileEntry>> = HashMap::new();
-    let mut scanned = 0usize;
-
-    let walker = WalkDir::new(root)
-        .max_depth(DUPLICATE_SCAN_MAX_DEPTH)
-        .follow_links(false)
-        .into_iter()
-        .filter_entry(|entry| {
-            let name = entry.file_name().to_string_lossy();
-            if is_hidden_file(&name) {
-                return false;
-            }
-            #[cfg(target_os = "windows")]
-            {
-                if let Ok(meta) = entry.metadata() {
-                    if is_system_file(&meta) {
-                        return false;
-                    }
-                }
-            }
-            true
-        });
-
-    for entry in walker.flatten() {
-        if entry.depth() == 0 {
-            continue;
-        }
-
-        let meta = match entry.metadata() {
-            Ok(m) => m,
-            Err(_) => continue,
-        };
-        if meta.is_dir() {
-            continue;
-        }
-
-        scanned += 1;
-        if scanned > MAX_DUPLICATE_SCAN_FILES {
-            break;
-        }
-
-        let name = entry.file_name().to_string_lossy().to_string();
-        if is_system_filename(&name) {
-            continue;
-        }
-
-        let modified = meta
-            .modified()
-            .ok()
-            .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
-            .map(|d| d.as_millis() as u64)
-            .unwrap_or(0);
-
-        let size = meta.len();
-        let file_type = classify_file(&name);
-        let file_entry = FileEntry {
-            path: entry.path().to_string_lossy().to_string(),
-            is_dir: false,
-            size,
-            modified,
-            identity: file_identity(&meta),
-            file_type,
-            name,
-        };
-
-        by_size.entry(size).or_default().push(file_entry);
-    }
-
-    // 크기가 같은 파일 2개 이상인 그룹만 해시 비교
-    let mut by_hash: HashMap<u64, Vec<FileEntry>> = HashMap::new();
-
-    for (size, candidates) in by_size.into_iter().filter(|(_, v)| v.len() >= 2) {
-        // 0바이트 파일은 해시 없이 크기만으로 중복 판정
-        if size == 0 {
-            by_hash.insert(0, candidates);
-            continue;
-        }
-
-        for file in candidates {
-            let path = Path::new(&file.path);
-            let mut file_handle = match std::fs::File::open(path) {
-                Ok(f) => f,
-                Err(_) => continue,
-            };
-            let mut hasher = Xxh3::new();
-            let mut buf = [0u8; 65536];
-            let mut hash_ok = true;
-            loop {
-                match file_handle.read(&mut buf) {
-                    Ok(0) => break,
-                    Ok(n) => hasher.update(&buf[..n]),
-                    Err(_) => {
-                        hash_ok = false;
-                        break;
-                    }
-                }
-            }
-            if !hash_ok {
-                continue;
-            }
-            let hash = hasher.digest();
-            by_hash.entry(hash).or_default().push(file);
-        }
-    }
-
-    let mut groups: Vec<DuplicateGroup> = by_hash
-        .into_iter()
-        .filter(|(_, files)| files.len() >= 2)
-        .map(|(_, mut files)| {
-            files.sort_by(|a, b| a.path.cmp(&b.path));
-            let size = files.first().map(|f| f.size).unwrap_or(0);
-            DuplicateGroup { size, files }
-        })
-        .collect();
-
-    // 큰 파일 그룹 우선, 동일 크기면 파일 수 많은 순
-    groups.sort_by(|a, b| {
-        b.size
-            .cmp(&a.size)
-            .then_with(|| b.files.len().cmp(&a.files.len()))
-    });
-    groups.truncate(MAX_DUPLICATE_GROUPS);
-
-    Ok(groups)
+    scan_duplicates(
+        root,
+        &DuplicateScanOptions::default(),
+        "duplicates",
+        &CancelToken::new(),
+        |_| Ok(()),
+    )
+    .map(|summary| summary.groups)
+    .map_err(|e| e.to_string())
 }
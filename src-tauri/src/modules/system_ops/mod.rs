@@ -1,5 +1,5 @@
 //! 시스템 통합 모듈
-//! 파일 탐색기 연동, 클립보드, 파일 검색, 최근 파일, 아이콘 추출
+//! 파일 탐색기 연동, 클립보드, 파일 검색(이름·내용), 최근 파일, 아이콘 추출
 
 // 공통 타입
 #[derive(serde::Serialize)]
@@ -11,15 +11,22 @@ pub struct FolderSelection {
 // 서브모듈
 mod app_activation;
 mod clipboard;
+mod content_search;
+mod duplicate_scan;
 mod file_explorer;
 mod file_icon;
 mod file_search;
 mod google_drive;
+mod search_index;
+mod search_query;
 
 // Re-export all public functions
 pub use app_activation::*;
 pub use clipboard::*;
+pub use content_search::*;
+pub use duplicate_scan::*;
 pub use file_explorer::*;
 pub use file_icon::*;
 pub use file_search::*;
 pub use google_drive::*;
+pub use search_index::*;
@@ -0,0 +1,562 @@
+//! 앱 자체 파일명 인덱스
+//! Spotlight/Windows Search를 쓸 수 없거나 실패한 환경을 위해 사용자가 고른 루트의 폴더별
+//! 하위 항목 이름을 앱 데이터 폴더에 저장한다. 갱신은 수정 시각이 바뀐 폴더만 다시 읽으므로
+//! 전체 탐색보다 훨씬 가볍고, `search_files`는 walkdir 폴백 전에 이 인덱스를 먼저 조회한다.
+//! FAT은 항목이 바뀌어도 폴더 수정 시각을 갱신하지 않고 SMB는 서버·캐시에 따라 늦게 반영되므로,
+//! 수정 시각만으로는 변경을 놓칠 수 있다. 그래서 일정 주기마다 캐시 없이 전체를 다시 읽는다.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock};
+
+use super::super::constants::{
+    SEARCH_INDEX_FULL_RESCAN_SECS, SEARCH_INDEX_MAX_ENTRIES, SEARCH_INDEX_REFRESH_SECS,
+};
+use super::super::types::{is_hidden_entry, FileEntry};
+use super::file_search::search_hit_entry;
+use super::search_query::{HitCollector, SearchQuery};
+use crate::helpers::stable_cache_key;
+
+const INDEX_DIR: &str = "search_index";
+const ROOTS_FILE: &str = "roots.json";
+const INDEX_FORMAT_VERSION: u32 = 1;
+
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+struct IndexedChild {
+    name: String,
+    is_dir: bool,
+    hidden: bool,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+struct IndexedDir {
+    /// 폴더 수정 시각(ms) — 바뀌면 하위 항목을 다시 읽는다
+    mtime: Option<u64>,
+    /// 자신 또는 상위 폴더가 숨김
+    hidden: bool,
+    /// 바뀌지 않은 폴더는 이전 인덱스와 목록을 공유한다
+    children: Arc<Vec<IndexedChild>>,
+}
+
+/// 루트 하나의 인덱스 (폴더 경로 → 하위 항목)
+#[derive(serde::Serialize, serde::Deserialize, Clone, Default)]
+struct RootIndex {
+    version: u32,
+    root: String,
+    dirs: BTreeMap<String, IndexedDir>,
+    file_count: u64,
+    unreadable_dirs: u64,
+    truncated: bool,
+    last_updated: u64,
+    /// 마지막 전체 재색인 시각 (ms)
+    #[serde(default)]
+    full_scan_at: u64,
+}
+
+#[derive(Default)]
+struct IndexState {
+    data_dir: Option<PathBuf>,
+    roots: Vec<String>,
+    indexes: HashMap<String, Arc<RootIndex>>,
+    /// 색인 중인 루트
+    busy: HashSet<String>,
+    errors: HashMap<String, String>,
+}
+
+fn lock_state() -> MutexGuard<'static, IndexState> {
+    static STATE: OnceLock<Mutex<IndexState>> = OnceLock::new();
+    STATE
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+}
+
+/// 색인 루트별 상태
+#[derive(serde::Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexRootStatus {
+    pub root: String,
+    /// pending(대기) / building(첫 색인) / refreshing(증분 갱신) / ready / error
+    pub state: String,
+    pub dir_count: u64,
+    pub file_count: u64,
+    /// 권한 등으로 읽지 못한 폴더 수
+    pub unreadable_dirs: u64,
+    /// 항목 수 제한으로 일부만 색인됨 (검색에는 쓰지 않음)
+    pub truncated: bool,
+    /// 마지막 색인 완료 시각 (ms)
+    pub last_updated: Option<u64>,
+    pub error: Option<String>,
+}
+
+#[derive(serde::Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexStatus {
+    pub roots: Vec<IndexRootStatus>,
+    pub total_files: u64,
+}
+
+fn now_ms() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+fn mtime_ms(meta: &std::fs::Metadata) -> Option<u64> {
+    meta.modified()
+        .ok()
+        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|d| d.as_millis() as u64)
+}
+
+/// 폴더 한 단계 읽기 (심볼릭 링크는 따라가지 않는다)
+fn read_children(dir: &Path) -> std::io::Result<Vec<IndexedChild>> {
+    let mut children = Vec::new();
+    for entry in std::fs::read_dir(dir)?.flatten() {
+        let name = entry.file_name().to_string_lossy().to_string();
+        let Ok(meta) = entry.metadata() else {
+            continue;
+        };
+        children.push(IndexedChild {
+            hidden: is_hidden_entry(&name, &meta),
+            is_dir: meta.is_dir(),
+            name,
+        });
+    }
+    Ok(children)
+}
+
+/// 루트 색인. `previous`에 같은 수정 시각으로 기록된 폴더는 다시 읽지 않는다.
+/// 다시 읽은 폴더가 있거나 폴더 구성이 바뀌었으면 두 번째 값이 true (저장 필요).
+fn scan_root(root: &str, previous: Option<&RootIndex>) -> (RootIndex, bool) {
+    let mut index = RootIndex {
+        version: INDEX_FORMAT_VERSION,
+        root: root.to_string(),
+        full_scan_at: previous.map_or_else(now_ms, |p| p.full_scan_at),
+        ..Default::default()
+    };
+    let mut changed = previous.is_none();
+    let mut entries = 0usize;
+    let mut stack = vec![(PathBuf::from(root), false)];
+    while let Some((dir, hidden)) = stack.pop() {
+        let key = dir.to_string_lossy().to_string();
+        let mtime = std::fs::metadata(&dir).ok().and_then(|m| mtime_ms(&m));
+        let cached = previous
+            .and_then(|p| p.dirs.get(&key))
+            .filter(|d| mtime.is_some() && d.mtime == mtime);
+        let children = match cached {
+            Some(d) => Arc::clone(&d.children),
+            None => match read_children(&dir) {
+                Ok(children) => {
+                    changed = true;
+                    Arc::new(children)
+                }
+                Err(_) => {
+                    index.unreadable_dirs += 1;
+                    continue;
+                }
+            },
+        };
+        for child in children.iter() {
+            if child.is_dir {
+                stack.push((dir.join(&child.name), hidden || child.hidden));
+            } else {
+                index.file_count += 1;
+            }
+        }
+        entries += children.len();
+        index.dirs.insert(
+            key,
+            IndexedDir {
+                mtime,
+                hidden,
+                children,
+            },
+        );
+        if entries >= SEARCH_INDEX_MAX_ENTRIES {
+            index.truncated = true;
+            break;
+        }
+    }
+    index.last_updated = now_ms();
+    // 하위 폴더가 사라지면 부모 수정 시각이 바뀌어 다시 읽히지만, 개수·상태로 한 번 더 확인
+    changed |= previous.map_or(true, |p| {
+        p.dirs.len() != index.dirs.len()
+            || p.unreadable_dirs != index.unreadable_dirs
+            || p.truncated != index.truncated
+    });
+    (index, changed)
+}
+
+/// 인덱스 안에서 `root` 아래 항목 중 검색 조건에 맞는 것
+fn search_root_index(
+    index: &RootIndex,
+    root: &str,
+    query: &SearchQuery,
+    hits: &mut HitCollector,
+    show_hidden: bool,
+) {
+    let root_path = Path::new(root);
+    // 숨김 폴더 안에서 검색하면 그 아래는 숨김으로 치지 않는다
+    let root_hidden = index.dirs.get(root).is_some_and(|d| d.hidden);
+    // 같은 접두사를 가진 키는 정렬상 연속이므로 범위 조회 후 경로 단위로 다시 거른다
+    let dirs = index
+        .dirs
+        .range(root.to_string()..)
+        .take_while(|(dir, _)| dir.starts_with(root))
+        .filter(|(dir, _)| Path::new(dir.as_str()).starts_with(root_path));
+    for (dir, record) in dirs {
+        if !show_hidden && record.hidden && !root_hidden {
+            continue;
+        }
+        for child in record.children.iter() {
+            if !show_hidden && child.hidden {
+                continue;
+            }
+            if hits.wants_archive(&child.name, child.is_dir) {
+                hits.note_archive(
+                    Path::new(dir)
+                        .join(&child.name)
+                        .to_string_lossy()
+                        .to_string(),
+                );
+            }
+            if !query.matches_name(&child.name, child.is_dir) {
+                continue;
+            }
+            let path = Path::new(dir).join(&child.name);
+            // 색인 이후 삭제된 항목은 건너뜀
+            let Ok(meta) = std::fs::symlink_metadata(&path) else {
+                continue;
+            };
+            let is_symlink = meta.file_type().is_symlink();
+            let entry = search_hit_entry(&path, child.name.clone(), &meta, is_symlink);
+            // 크기·수정 시각 조건은 현재 메타데이터로 확인
+            if !query.matches(&entry) {
+                continue;
+            }
+            if hits.push(entry) {
+                return;
+            }
+        }
+    }
+}
+
+/// `root`를 덮는 완전한 인덱스가 있으면 `hits`에 모으고 true, 없으면 false (호출자가 직접 탐색)
+pub(super) fn search_in_index(
+    root: &str,
+    query: &SearchQuery,
+    hits: &mut HitCollector,
+    show_hidden: bool,
+) -> bool {
+    let index = {
+        let state = lock_state();
+        state
+            .indexes
+            .values()
+            .filter(|i| !i.truncated && Path::new(root).starts_with(&i.root))
+            .max_by_key(|i| i.root.len())
+            .cloned()
+    };
+    let Some(index) = index else {
+        return false;
+    };
+    search_root_index(&index, root, query, hits, show_hidden);
+    true
+}
+
+// ===== 저장 =====
+
+fn index_file(data_dir: &Path, root: &str) -> PathBuf {
+    let key = stable_cache_key(&[b"search-index-v1", root.as_bytes()]);
+    data_dir.join(INDEX_DIR).join(format!("{}.json", key))
+}
+
+/// 임시 파일에 쓴 뒤 교체 (쓰는 중 종료돼도 이전 인덱스 유지)
+fn write_json_atomic<T: serde::Serialize>(path: &Path, value: &T) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let tmp = path.with_extension("json.tmp");
+    let mut writer = std::io::BufWriter::new(std::fs::File::create(&tmp)?);
+    serde_json::to_writer(&mut writer, value)?;
+    std::io::Write::flush(&mut writer)?;
+    drop(writer);
+    std::fs::rename(&tmp, path)
+}
+
+fn load_index(data_dir: &Path, root: &str) -> Option<RootIndex> {
+    let file = std::fs::File::open(index_file(data_dir, root)).ok()?;
+    let index: RootIndex = serde_json::from_reader(std::io::BufReader::new(file)).ok()?;
+    (index.version == INDEX_FORMAT_VERSION && index.root == root).then_some(index)
+}
+
+fn load_roots(data_dir: &Path) -> Vec<String> {
+    std::fs::read(data_dir.join(INDEX_DIR).join(ROOTS_FILE))
+        .ok()
+        .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+        .unwrap_or_default()
+}
+
+// ===== 백그라운드 색인 =====
+
+/// 루트 하나를 (증분) 색인하고 저장. 이미 색인 중이거나 제거된 루트면 건너뛴다.
+fn update_root(root: String) {
+    let (previous, data_dir) = {
+        let mut state = lock_state();
+        if !state.roots.contains(&root) || !state.busy.insert(root.clone()) {
+            return;
+        }
+        (state.indexes.get(&root).cloned(), state.data_dir.clone())
+    };
+
+    // 전체 재색인 주기가 지났으면 이전 목록을 쓰지 않는다
+    let full_rescan_ms = SEARCH_INDEX_FULL_RESCAN_SECS * 1000;
+    let previous = previous.filter(|p| now_ms().saturating_sub(p.full_scan_at) < full_rescan_ms);
+    let result = if Path::new(&root).is_dir() {
+        let (index, changed) = scan_root(&root, previous.as_deref());
+        match &data_dir {
+            // 바뀐 것이 없으면 디스크에 다시 쓰지 않는다 (메모리의 완료 시각만 갱신)
+            Some(dir) if changed => write_json_atomic(&index_file(dir, &root), &index)
+                .map(|_| index)
+                .map_err(|e| format!("인덱스 저장 실패: {}", e)),
+            _ => Ok(index),
+        }
+    } else {
+        Err("폴더를 찾을 수 없습니다".to_string())
+    };
+
+    let mut state = lock_state();
+    state.busy.remove(&root);
+    if !state.roots.contains(&root) {
+        // 색인 중 루트가 빠졌으면 방금 쓴 파일도 정리
+        if let Some(dir) = &data_dir {
+            let _ = std::fs::remove_file(index_file(dir, &root));
+        }
+        return;
+    }
+    match result {
+        Ok(index) => {
+            state.errors.remove(&root);
+            state.indexes.insert(root, Arc::new(index));
+        }
+        Err(e) => {
+            state.errors.insert(root, e);
+        }
+    }
+}
+
+fn update_roots_in_background(roots: Vec<String>) {
+    std::thread::spawn(move || {
+        for root in roots {
+            update_root(root);
+        }
+    });
+}
+
+/// 주기적 증분 갱신 스레드 (한 번만 시작)
+fn spawn_refresh_loop() {
+    static STARTED: OnceLock<()> = OnceLock::new();
+    if STARTED.set(()).is_err() {
+        return;
+    }
+    std::thread::spawn(|| loop {
+        std::thread::sleep(std::time::Duration::from_secs(SEARCH_INDEX_REFRESH_SECS));
+        let roots = lock_state().roots.clone();
+        for root in roots {
+            update_root(root);
+        }
+    });
+}
+
+/// 앱 시작 시 저장된 루트와 인덱스를 읽고 증분 갱신 시작 (setup에서 별도 스레드로 호출)
+pub fn load_search_index<R: tauri::Runtime>(app: &tauri::AppHandle<R>) {
+    use tauri::Manager;
+
+    let Ok(data_dir) = app.path().app_data_dir() else {
+        return;
+    };
+    let roots = load_roots(&data_dir);
+    let loaded: Vec<(String, RootIndex)> = roots
+        .iter()
+        .filter_map(|root| load_index(&data_dir, root).map(|index| (root.clone(), index)))
+        .collect();
+    {
+        let mut state = lock_state();
+        // 읽는 사이 set_index_roots가 먼저 실행됐으면 그 설정을 따른다
+        if state.data_dir.is_some() {
+            return;
+        }
+        state.data_dir = Some(data_dir);
+        state.roots = roots.clone();
+        for (root, index) in loaded {
+            state.indexes.insert(root, Arc::new(index));
+        }
+    }
+    spawn_refresh_loop();
+    update_roots_in_background(roots);
+}
+
+fn status_snapshot() -> IndexStatus {
+    let state = lock_state();
+    let roots: Vec<IndexRootStatus> = state
+        .roots
+        .iter()
+        .map(|root| {
+            let index = state.indexes.get(root);
+            let error = state.errors.get(root).cloned();
+            let status = if state.busy.contains(root) {
+                if index.is_some() {
+                    "refreshing"
+                } else {
+                    "building"
+                }
+            } else if error.is_some() {
+                "error"
+            } else if index.is_some() {
+                "ready"
+            } else {
+                "pending"
+            };
+            IndexRootStatus {
+                root: root.clone(),
+                state: status.to_string(),
+                dir_count: index.map_or(0, |i| i.dirs.len() as u64),
+                file_count: index.map_or(0, |i| i.file_count),
+                unreadable_dirs: index.map_or(0, |i| i.unreadable_dirs),
+                truncated: index.is_some_and(|i| i.truncated),
+                last_updated: index.map(|i| i.last_updated),
+                error,
+            }
+        })
+        .collect();
+    let total_files = roots.iter().map(|r| r.file_count).sum();
+    IndexStatus { roots, total_files }
+}
+
+// ===== Commands =====
+
+// 색인할 루트 지정 후 백그라운드 색인 시작
+// 빠진 루트의 인덱스 파일은 삭제하고, 남은 루트는 바뀐 폴더만 다시 읽는다
+#[tauri::command]
+pub async fn set_index_roots(
+    app: tauri::AppHandle,
+    roots: Vec<String>,
+) -> Result<IndexStatus, String> {
+    use tauri::Manager;
+
+    let data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("앱 데이터 폴더를 찾을 수 없습니다: {}", e))?;
+    let mut seen = HashSet::new();
+    let roots: Vec<String> = roots
+        .into_iter()
+        .filter(|root| !root.is_empty() && seen.insert(root.clone()))
+        .collect();
+
+    let removed: Vec<String> = {
+        let mut state = lock_state();
+        state.data_dir = Some(data_dir.clone());
+        let removed = state
+            .roots
+            .iter()
+            .filter(|r| !roots.contains(r))
+            .cloned()
+            .collect::<Vec<_>>();
+        for root in &removed {
+            state.indexes.remove(root);
+            state.errors.remove(root);
+        }
+        state.roots = roots.clone();
+        removed
+    };
+    write_json_atomic(&data_dir.join(INDEX_DIR).join(ROOTS_FILE), &roots)
+        .map_err(|e| format!("색인 설정 저장 실패: {}", e))?;
+    for root in &removed {
+        let _ = std::fs::remove_file(index_file(&data_dir, root));
+    }
+
+    spawn_refresh_loop();
+    update_roots_in_background(roots);
+    Ok(status_snapshot())
+}
+
+// 색인 루트별 진행 상태·항목 수·마지막 갱신 시각
+#[tauri::command]
+pub fn index_status() -> IndexStatus {
+    status_snapshot()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::search_query::SearchSort;
+    use super::*;
+
+    fn search(index: &RootIndex, root: &str, query: &str, show_hidden: bool) -> Vec<FileEntry> {
+        let mut hits = HitCollector::new(SearchSort::Relevance, 10);
+        let query = SearchQuery::parse(query).unwrap();
+        search_root_index(index, root, &query, &mut hits, show_hidden);
+        hits.into_parts().0
+    }
+
+    #[test]
+    fn incremental_scan_reuses_unchanged_dirs_and_searches_by_name() {
+        let root = std::env::temp_dir().join(format!(
+            "quickfolder_search_index_{}_{}",
+            std::process::id(),
+            now_ms()
+        ));
+        std::fs::create_dir_all(root.join("docs/deep")).unwrap();
+        std::fs::create_dir_all(root.join(".cache")).unwrap();
+        std::fs::write(root.join("docs/deep/Report-final.txt"), b"x").unwrap();
+        std::fs::write(root.join(".cache/report.tmp"), b"x").unwrap();
+        std::fs::write(root.join("docs/bundle.zip"), b"x").unwrap();
+        let root_str = root.to_string_lossy().to_string();
+
+        let (mut index, changed) = scan_root(&root_str, None);
+        assert!(changed);
+        assert_eq!((index.file_count, index.truncated), (3, false));
+        let hits = search(&index, &root_str, "report", false);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].name, "Report-final.txt");
+        assert_eq!(search(&index, &root_str, "report", true).len(), 2);
+        assert_eq!(search(&index, &root_str, "report ext:txt", true).len(), 1);
+
+        // 압축 파일은 검색어와 무관하게 같은 탐색에서 함께 모은다
+        let mut hits = HitCollector::new(SearchSort::Relevance, 10).with_archives(10);
+        let query = SearchQuery::parse("report").unwrap();
+        search_root_index(&index, &root_str, &query, &mut hits, false);
+        let (entries, archives) = hits.into_parts();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(
+            archives,
+            [root.join("docs/bundle.zip").to_string_lossy().to_string()]
+        );
+
+        // 수정 시각이 같은 폴더는 저장된 목록을 그대로 쓴다
+        let deep_key = root.join("docs/deep").to_string_lossy().to_string();
+        Arc::make_mut(&mut index.dirs.get_mut(&deep_key).unwrap().children).push(IndexedChild {
+            name: "cached-only.txt".to_string(),
+            is_dir: false,
+            hidden: false,
+        });
+        let (refreshed, changed) = scan_root(&root_str, Some(&index));
+        // 다시 읽은 폴더가 없으면 저장하지 않는다
+        assert!(!changed);
+        assert!(refreshed.dirs[&deep_key]
+            .children
+            .iter()
+            .any(|c| c.name == "cached-only.txt"));
+
+        // 폴더가 삭제되면 그 하위도 빠진다
+        std::fs::remove_dir_all(root.join("docs")).unwrap();
+        let (refreshed, changed) = scan_root(&root_str, Some(&refreshed));
+        assert!(changed);
+        assert!(!refreshed.dirs.contains_key(&deep_key));
+        assert_eq!(refreshed.file_count, 1);
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+}
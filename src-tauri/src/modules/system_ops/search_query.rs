@@ -0,0 +1,875 @@
+//! 파일 검색 쿼리 문법
+//! `ext:psd size:>500mb modified:<30d type:video name:"boss*"`처럼 조건을 붙인 검색어를 해석한다.
+//! 조건이 없는 단어는 이름 부분 일치로 취급하고, 모든 조건을 만족해야 결과에 포함된다.
+//! 백엔드(mdfind·Windows Search·앱 인덱스·walkdir)는 옮길 수 있는 조건을 네이티브 조건으로 좁히고
+//! 결과는 모두 `SearchQuery::matches`로 다시 걸러 어느 백엔드를 쓰든 같은 결과가 되게 한다.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, TimeZone, Utc};
+
+use super::super::archive_ops::is_browsable_archive_path;
+use super::super::error::{AppError, Result};
+use super::super::types::{classify_file, file_type_extensions, FileEntry, FileType};
+use crate::helpers::{glob_match, natural_cmp};
+
+const KB: f64 = 1024.0;
+const DAY_MS: u64 = 24 * 60 * 60 * 1000;
+
+/// 해석된 검색 쿼리
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(super) struct SearchQuery {
+    /// 이름에 모두 들어가야 하는 단어 (소문자)
+    pub terms: Vec<String>,
+    /// `name:` 와일드카드 패턴 (이름 전체와 비교, 와일드카드가 없으면 `terms`로 들어감)
+    pub name_globs: Vec<String>,
+    /// 확장자 후보 (소문자, 앞의 점 제외) — 하나라도 일치
+    pub extensions: Vec<String>,
+    /// 파일 종류 후보 — 하나라도 일치
+    pub types: Vec<FileType>,
+    /// 크기 범위 (바이트, 양끝 포함). 지정하면 폴더는 제외된다.
+    pub size: Option<(u64, u64)>,
+    /// 수정 시각 범위 (epoch ms, 시작 포함·끝 제외)
+    pub modified: Option<(u64, u64)>,
+}
+
+/// 결과 정렬 기준
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum SearchSort {
+    /// 이름 일치 정도 (기본)
+    Relevance,
+    /// 큰 파일 먼저
+    Size,
+    /// 최근 수정 먼저
+    Modified,
+    Name,
+}
+
+impl SearchSort {
+    pub fn parse(value: Option<&str>) -> Result<Self> {
+        match value.map(|v| v.to_lowercase()).as_deref() {
+            None | Some("") | Some("relevance") => Ok(Self::Relevance),
+            Some("size") => Ok(Self::Size),
+            Some("modified") | Some("date") => Ok(Self::Modified),
+            Some("name") => Ok(Self::Name),
+            Some(other) => Err(AppError::InvalidInput(format!(
+                "알 수 없는 정렬 기준: {}",
+                other
+            ))),
+        }
+    }
+}
+
+/// 크기·수정 시각 정렬 후보 (같은 값이면 먼저 찾은 항목이 남는다)
+struct RankedHit {
+    key: u64,
+    seq: usize,
+    entry: FileEntry,
+}
+
+impl PartialEq for RankedHit {
+    fn eq(&self, other: &Self) -> bool {
+        (self.key, self.seq) == (other.key, other.seq)
+    }
+}
+
+impl Eq for RankedHit {}
+
+impl PartialOrd for RankedHit {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for RankedHit {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (self.key, Reverse(self.seq)).cmp(&(other.key, Reverse(other.seq)))
+    }
+}
+
+/// 백엔드가 찾은 항목 수집. 크기·수정 시각 정렬은 `limit`에서 멈추면 먼저 찾은 항목만 남으므로
+/// 끝까지 훑으면서 상위 `limit`개만 최소 힙으로 유지한다.
+/// 압축 파일 내부 검색을 켜면 같은 탐색에서 압축 파일 경로도 함께 모은다.
+pub(super) struct HitCollector {
+    sort: SearchSort,
+    limit: usize,
+    hits: Vec<FileEntry>,
+    top: BinaryHeap<Reverse<RankedHit>>,
+    seq: usize,
+    /// 찾은 압축 파일 경로와 최대 개수
+    archives: Option<(Vec<String>, usize)>,
+}
+
+impl HitCollector {
+    pub fn new(sort: SearchSort, limit: usize) -> Self {
+        Self {
+            sort,
+            limit,
+            hits: Vec::new(),
+            top: BinaryHeap::new(),
+            seq: 0,
+            archives: None,
+        }
+    }
+
+    /// 압축 파일 경로도 최대 `max`개 모은다
+    pub fn with_archives(mut self, max: usize) -> Self {
+        self.archives = Some((Vec::new(), max));
+        self
+    }
+
+    #[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+    pub fn sort(&self) -> SearchSort {
+        self.sort
+    }
+
+    #[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// 아직 압축 파일을 더 모으는 중
+    pub fn collects_archives(&self) -> bool {
+        self.archives
+            .as_ref()
+            .is_some_and(|(found, max)| found.len() < *max)
+    }
+
+    /// 모으는 중이고 압축 파일 이름이면 true (이름 조건과 무관하게 메타데이터를 읽어야 함)
+    pub fn wants_archive(&self, name: &str, is_dir: bool) -> bool {
+        !is_dir && self.collects_archives() && is_browsable_archive_path(name)
+    }
+
+    pub fn note_archive(&mut self, path: String) {
+        if let Some((found, max)) = &mut self.archives {
+            if found.len() < *max {
+                found.push(path);
+            }
+        }
+    }
+
+    #[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+    pub fn is_empty(&self) -> bool {
+        self.hits.is_empty()
+            && self.top.is_empty()
+            && self
+                .archives
+                .as_ref()
+                .map_or(true, |(found, _)| found.is_empty())
+    }
+
+    /// 항목 추가. 더 모을 필요가 없으면 true (크기·수정 시각 정렬은 항상 false).
+    pub fn push(&mut self, entry: FileEntry) -> bool {
+        let key = match self.sort {
+            SearchSort::Size => entry.size,
+            SearchSort::Modified => entry.modified,
+            SearchSort::Relevance | SearchSort::Name => {
+                // 압축 파일을 모으느라 계속 훑는 동안에도 결과는 limit개까지만
+                if self.hits.len() < self.limit {
+                    self.hits.push(entry);
+                }
+                return self.hits.len() >= self.limit && !self.collects_archives();
+            }
+        };
+        self.seq += 1;
+        let hit = RankedHit {
+            key,
+            seq: self.seq,
+            entry,
+        };
+        if self.top.len() < self.limit {
+            self.top.push(Reverse(hit));
+        } else if self.top.peek().is_some_and(|Reverse(min)| hit > *min) {
+            self.top.pop();
+            self.top.push(Reverse(hit));
+        }
+        false
+    }
+
+    /// 찾은 항목과 압축 파일 경로
+    pub fn into_parts(mut self) -> (Vec<FileEntry>, Vec<String>) {
+        // Reverse의 오름차순 = 큰 값 먼저
+        self.hits.extend(
+            self.top
+                .into_sorted_vec()
+                .into_iter()
+                .map(|Reverse(hit)| hit.entry),
+        );
+        let archives = self.archives.map(|(found, _)| found).unwrap_or_default();
+        (self.hits, archives)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Cmp {
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+}
+
+/// 값 앞의 비교 연산자 분리 (없으면 Eq)
+fn split_cmp(value: &str) -> (Cmp, &str) {
+    for (prefix, cmp) in [
+        (">=", Cmp::Ge),
+        ("<=", Cmp::Le),
+        (">", Cmp::Gt),
+        ("<", Cmp::Lt),
+        ("=", Cmp::Eq),
+    ] {
+        if let Some(rest) = value.strip_prefix(prefix) {
+            return (cmp, rest.trim());
+        }
+    }
+    (Cmp::Eq, value)
+}
+
+/// 공백으로 나누되 큰따옴표 안 공백은 유지. 따옴표로 시작한 토큰은 조건으로 해석하지 않는다.
+fn tokenize(input: &str) -> Vec<(String, bool)> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut quoted_start = false;
+    let mut in_quotes = false;
+    for c in input.chars() {
+        match c {
+            '"' => {
+                if current.is_empty() && !in_quotes {
+                    quoted_start = true;
+                }
+                in_quotes = !in_quotes;
+            }
+            c if c.is_whitespace() && !in_quotes => {
+                if !current.is_empty() {
+                    tokens.push((std::mem::take(&mut current), quoted_start));
+                }
+                quoted_start = false;
+            }
+            c => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        tokens.push((current, quoted_start));
+    }
+    tokens
+}
+
+/// `500mb`, `1.5g`, `2048` → 바이트 (1024 단위)
+fn parse_size(value: &str) -> Option<u64> {
+    let value = value.trim().to_lowercase();
+    let split = value
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(split);
+    let number: f64 = number.parse().ok()?;
+    let scale = match unit.trim() {
+        "" | "b" => 1.0,
+        "k" | "kb" => KB,
+        "m" | "mb" => KB * KB,
+        "g" | "gb" => KB * KB * KB,
+        "t" | "tb" => KB * KB * KB * KB,
+        _ => return None,
+    };
+    Some((number * scale).round() as u64)
+}
+
+fn parse_size_range(value: &str) -> Option<(u64, u64)> {
+    if let Some((from, to)) = value.split_once("..") {
+        let from = if from.is_empty() {
+            0
+        } else {
+            parse_size(from)?
+        };
+        let to = if to.is_empty() {
+            u64::MAX
+        } else {
+            parse_size(to)?
+        };
+        return Some((from, to));
+    }
+    let (cmp, rest) = split_cmp(value);
+    let size = parse_size(rest)?;
+    Some(match cmp {
+        Cmp::Gt => (size.saturating_add(1), u64::MAX),
+        Cmp::Ge => (size, u64::MAX),
+        Cmp::Lt if size == 0 => (1, 0),
+        Cmp::Lt => (0, size - 1),
+        Cmp::Le => (0, size),
+        Cmp::Eq => (size, size),
+    })
+}
+
+/// `30d`, `12h`, `2w`, `3mo`, `1y` → 밀리초
+fn parse_age(value: &str) -> Option<u64> {
+    let value = value.trim().to_lowercase();
+    let split = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(split);
+    let number: u64 = number.parse().ok()?;
+    let unit_ms = match unit {
+        "h" => DAY_MS / 24,
+        "d" => DAY_MS,
+        "w" => 7 * DAY_MS,
+        "mo" => 30 * DAY_MS,
+        "y" => 365 * DAY_MS,
+        _ => return None,
+    };
+    number.checked_mul(unit_ms)
+}
+
+fn local_day_start_ms(date: NaiveDate) -> Option<u64> {
+    let start = date
+        .and_hms_opt(0, 0, 0)?
+        .and_local_timezone(Local)
+        .earliest()?;
+    u64::try_from(start.timestamp_millis()).ok()
+}
+
+/// 하루 단위 구간 `[시작, 다음 날 시작)` (ms)
+fn day_span(date: NaiveDate) -> Option<(u64, u64)> {
+    let start = local_day_start_ms(date)?;
+    let end = local_day_start_ms(date.succ_opt()?)?;
+    Some((start, end))
+}
+
+/// `today`, `thismonth` 같은 이름 있는 기간
+fn named_span(value: &str, now: DateTime<Local>) -> Option<(u64, u64)> {
+    let today = now.date_naive();
+    let since = |date: NaiveDate| local_day_start_ms(date).map(|start| (start, u64::MAX));
+    match value {
+        "today" => since(today),
+        "yesterday" => day_span(today.pred_opt()?),
+        "thisweek" => since(today - Duration::days(today.weekday().num_days_from_monday() as i64)),
+        "thismonth" => since(today.with_day(1)?),
+        "thisyear" => since(today.with_ordinal(1)?),
+        _ => None,
+    }
+}
+
+fn parse_modified_range(value: &str, now: DateTime<Local>) -> Option<(u64, u64)> {
+    let value = value.to_lowercase();
+    if let Some(span) = named_span(&value, now) {
+        return Some(span);
+    }
+    let parse_date = |s: &str| NaiveDate::parse_from_str(s.trim(), "%Y-%m-%d").ok();
+    if let Some((from, to)) = value.split_once("..") {
+        let from = if from.is_empty() {
+            0
+        } else {
+            day_span(parse_date(from)?)?.0
+        };
+        let to = if to.is_empty() {
+            u64::MAX
+        } else {
+            day_span(parse_date(to)?)?.1
+        };
+        return Some((from, to));
+    }
+    let (cmp, rest) = split_cmp(&value);
+    if let Some(date) = parse_date(rest) {
+        let (start, end) = day_span(date)?;
+        return Some(match cmp {
+            Cmp::Gt => (end, u64::MAX),
+            Cmp::Ge => (start, u64::MAX),
+            Cmp::Lt => (0, start),
+            Cmp::Le => (0, end),
+            Cmp::Eq => (start, end),
+        });
+    }
+    // 상대 기간은 "지금으로부터 경과 시간" 비교: `<30d`는 최근 30일 이내, `>30d`는 그보다 오래됨
+    let age = parse_age(rest)?;
+    let now_ms = u64::try_from(now.timestamp_millis()).ok()?;
+    let boundary = now_ms.saturating_sub(age);
+    Some(match cmp {
+        Cmp::Gt | Cmp::Ge => (0, boundary),
+        Cmp::Lt | Cmp::Le | Cmp::Eq => (boundary, u64::MAX),
+    })
+}
+
+fn parse_file_type(value: &str) -> Option<FileType> {
+    Some(match value {
+        "image" | "images" | "img" => FileType::Image,
+        "video" | "videos" | "movie" => FileType::Video,
+        "audio" | "music" | "sound" => FileType::Audio,
+        "document" | "documents" | "doc" | "docs" => FileType::Document,
+        "code" => FileType::Code,
+        "archive" | "archives" => FileType::Archive,
+        "font" | "fonts" => FileType::Font,
+        "folder" | "folders" | "dir" | "directory" => FileType::Directory,
+        "other" => FileType::Other,
+        _ => return None,
+    })
+}
+
+/// 두 범위의 교집합 (조건을 여러 번 쓰면 모두 만족해야 함)
+fn intersect(current: Option<(u64, u64)>, next: (u64, u64)) -> Option<(u64, u64)> {
+    Some(match current {
+        Some((from, to)) => (from.max(next.0), to.min(next.1)),
+        None => next,
+    })
+}
+
+fn invalid(key: &str, value: &str) -> AppError {
+    AppError::InvalidInput(format!("잘못된 검색 조건: {}:{}", key, value))
+}
+
+/// 이름이 `.ext`로 끝나는지 (`tar.gz` 같은 겹확장자 포함, 소문자 이름 기준)
+fn has_extension(name_lower: &str, ext: &str) -> bool {
+    name_lower.len() > ext.len() + 1
+        && name_lower.ends_with(ext)
+        && name_lower[..name_lower.len() - ext.len()].ends_with('.')
+}
+
+impl SearchQuery {
+    pub fn parse(input: &str) -> Result<Self> {
+        Self::parse_at(input, Local::now())
+    }
+
+    /// 상대 날짜(`30d`, `today`) 기준 시각을 지정해 해석
+    pub fn parse_at(input: &str, now: DateTime<Local>) -> Result<Self> {
+        let mut query = SearchQuery::default();
+        for (token, quoted) in tokenize(input) {
+            let condition = token
+                .split_once(':')
+                .filter(|_| !quoted)
+                .map(|(key, value)| (key.to_lowercase(), value.trim()));
+            let Some((key, value)) = condition else {
+                query.terms.push(token.to_lowercase());
+                continue;
+            };
+            // 입력 중인 빈 조건은 무시
+            if value.is_empty()
+                && matches!(
+                    key.as_str(),
+                    "ext" | "size" | "modified" | "date" | "type" | "kind" | "name"
+                )
+            {
+                continue;
+            }
+            match key.as_str() {
+                "ext" => query.extensions.extend(
+                    value
+                        .split(',')
+                        .map(|e| e.trim().trim_start_matches('.').to_lowercase())
+                        .filter(|e| !e.is_empty()),
+                ),
+                "size" => {
+                    let range = parse_size_range(value).ok_or_else(|| invalid(&key, value))?;
+                    query.size = intersect(query.size, range);
+                }
+                "modified" | "date" => {
+                    let range =
+                        parse_modified_range(value, now).ok_or_else(|| invalid(&key, value))?;
+                    query.modified = intersect(query.modified, range);
+                }
+                "type" | "kind" => {
+                    for kind in value.split(',').map(|k| k.trim().to_lowercase()) {
+                        let file_type =
+                            parse_file_type(&kind).ok_or_else(|| invalid(&key, value))?;
+                        if !query.types.contains(&file_type) {
+                            query.types.push(file_type);
+                        }
+                    }
+                }
+                "name" if value.contains(['*', '?']) => query.name_globs.push(value.to_string()),
+                "name" => query.terms.push(value.to_lowercase()),
+                // 알 수 없는 키(`C:`, `12:30` 등)는 이름 검색어로 취급
+                _ => query.terms.push(token.to_lowercase()),
+            }
+        }
+        Ok(query)
+    }
+
+    /// 이름과 폴더 여부만으로 판단하는 조건 (메타데이터를 읽기 전에 거르는 용도)
+    pub fn matches_name(&self, name: &str, is_dir: bool) -> bool {
+        let name_lower = name.to_lowercase();
+        if !self.terms.iter().all(|t| name_lower.contains(t.as_str())) {
+            return false;
+        }
+        if !self.name_globs.iter().all(|g| glob_match(g, name)) {
+            return false;
+        }
+        if !self.extensions.is_empty()
+            && (is_dir
+                || !self
+                    .extensions
+                    .iter()
+                    .any(|e| has_extension(&name_lower, e)))
+        {
+            return false;
+        }
+        if self.size.is_some() && is_dir {
+            return false;
+        }
+        self.types.is_empty()
+            || self.types.iter().any(|t| match t {
+                FileType::Directory => is_dir,
+                t => !is_dir && classify_file(name) == *t,
+            })
+    }
+
+    pub fn matches(&self, entry: &FileEntry) -> bool {
+        if !self.matches_name(&entry.name, entry.is_dir) {
+            return false;
+        }
+        let in_range = |range: Option<(u64, u64)>, value: u64, end_inclusive: bool| {
+            range.map_or(true, |(from, to)| {
+                value >= from && (value < to || (end_inclusive && value == to))
+            })
+        };
+        in_range(self.size, entry.size, true) && in_range(self.modified, entry.modified, false)
+    }
+
+    /// 이름 일치 정도 (클수록 먼저). 완전 일치 > 확장자 제외 일치 > 앞부분 > 단어 시작 > 부분 일치.
+    fn relevance(&self, name: &str) -> u32 {
+        let name_lower = name.to_lowercase();
+        let stem = name_lower
+            .rsplit_once('.')
+            .map_or(name_lower.as_str(), |(stem, _)| stem);
+        self.terms
+            .iter()
+            .map(|term| {
+                if name_lower == *term {
+                    100
+                } else if stem == term.as_str() {
+                    80
+                } else if name_lower.starts_with(term.as_str()) {
+                    60
+                } else if name_lower.match_indices(term.as_str()).any(|(i, _)| {
+                    !name_lower[..i]
+                        .chars()
+                        .next_back()
+                        .is_some_and(char::is_alphanumeric)
+                }) {
+                    40
+                } else {
+                    20
+                }
+            })
+            .sum()
+    }
+
+    /// 정렬 후 `max_results`개만 남긴다
+    pub fn sort_results(&self, entries: &mut Vec<FileEntry>, sort: SearchSort, max_results: usize) {
+        match sort {
+            SearchSort::Relevance => {
+                // 점수가 같으면 얕은 경로·짧은 이름 먼저
+                entries.sort_by_cached_key(|e| {
+                    (
+                        std::cmp::Reverse(self.relevance(&e.name)),
+                        e.path.matches(['/', '\\']).count(),
+                        e.name.chars().count(),
+                    )
+                })
+            }
+            SearchSort::Size => entries.sort_by(|a, b| b.size.cmp(&a.size)),
+            SearchSort::Modified => entries.sort_by(|a, b| b.modified.cmp(&a.modified)),
+            SearchSort::Name => entries.sort_by(|a, b| natural_cmp(&a.name, &b.name)),
+        }
+        entries.truncate(max_results);
+    }
+
+    /// 확장자로 옮길 수 있는 종류·확장자 조건 (소문자 확장자 목록).
+    /// None이면 확장자로 표현할 수 없어 네이티브 조건에서 뺀다.
+    fn native_extensions(&self) -> Option<Vec<&str>> {
+        let mut extensions: Vec<&str> = Vec::new();
+        for file_type in &self.types {
+            match file_type {
+                FileType::Document | FileType::Other => return None,
+                // 폴더는 별도 조건
+                FileType::Directory => {}
+                t => extensions.extend(file_type_extensions(*t)),
+            }
+        }
+        Some(extensions)
+    }
+
+    /// 종류 조건을 모두 네이티브 조건으로 옮길 수 있는지.
+    /// false면 네이티브 결과를 다시 거르며 개수가 줄어들므로 네이티브 쪽에서 개수를 자르면 안 된다.
+    #[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+    pub fn types_are_native(&self) -> bool {
+        self.native_extensions().is_some()
+    }
+
+    /// Spotlight 쿼리 (`mdfind` 인자). 옮길 수 없는 조건은 빼고 결과를 `matches`로 다시 거른다.
+    #[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+    pub fn to_spotlight(&self) -> String {
+        fn quote(value: &str, keep_wildcards: bool) -> String {
+            let mut out = String::new();
+            for c in value.chars() {
+                match c {
+                    '"' | '\\' => {
+                        out.push('\\');
+                        out.push(c);
+                    }
+                    '*' if !keep_wildcards => out.push_str("\\*"),
+                    // Spotlight에는 한 글자 와일드카드가 없어 `*`로 넓힌다
+                    '?' if keep_wildcards => out.push('*'),
+                    c => out.push(c),
+                }
+            }
+            out
+        }
+        let name_is = |pattern: String| format!("kMDItemFSName == \"{}\"cd", pattern);
+        let mut parts: Vec<String> = Vec::new();
+        parts.extend(
+            self.terms
+                .iter()
+                .map(|t| name_is(format!("*{}*", quote(t, false)))),
+        );
+        parts.extend(self.name_globs.iter().map(|g| name_is(quote(g, true))));
+        if !self.extensions.is_empty() {
+            let any = self
+                .extensions
+                .iter()
+                .map(|e| name_is(format!("*.{}", quote(e, false))))
+                .collect::<Vec<_>>();
+            parts.push(format!("({})", any.join(" || ")));
+        }
+        if let Some(extensions) = self.native_extensions().filter(|_| !self.types.is_empty()) {
+            let mut any = extensions
+                .iter()
+                .map(|e| name_is(format!("*.{}", e)))
+                .collect::<Vec<_>>();
+            if self.types.contains(&FileType::Directory) {
+                any.push("kMDItemContentType == \"public.folder\"".to_string());
+            }
+            parts.push(format!("({})", any.join(" || ")));
+        }
+        if let Some((from, to)) = self.size {
+            if from > 0 {
+                parts.push(format!("kMDItemFSSize >= {}", from));
+            }
+            if to < u64::MAX {
+                parts.push(format!("kMDItemFSSize <= {}", to));
+            }
+        }
+        if let Some((from, to)) = self.modified {
+            let iso = |ms: u64| {
+                DateTime::<Utc>::from_timestamp_millis(ms as i64)
+                    .map(|t| t.format("%Y-%m-%dT%H:%M:%SZ").to_string())
+            };
+            if let Some(from) = iso(from).filter(|_| from > 0) {
+                parts.push(format!("kMDItemFSContentChangeDate >= $time.iso({})", from));
+            }
+            if let Some(to) = iso(to).filter(|_| to < u64::MAX) {
+                parts.push(format!("kMDItemFSContentChangeDate < $time.iso({})", to));
+            }
+        }
+        if parts.is_empty() {
+            return name_is("*".to_string());
+        }
+        parts.join(" && ")
+    }
+
+    /// Windows Search SQL WHERE 조건 (SCOPE 제외). 옮길 수 없는 조건은 빼고 결과를 다시 거른다.
+    #[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+    pub fn to_windows_conditions(&self) -> Vec<String> {
+        // LIKE 패턴 이스케이프: 작은따옴표는 SQL, `%`·`_`·`[`는 LIKE 특수 문자
+        fn like(value: &str, keep_wildcards: bool) -> String {
+            let mut out = String::new();
+            for c in value.chars() {
+                match c {
+                    '\'' => out.push_str("''"),
+                    '%' | '_' | '[' => {
+                        out.push('[');
+                        out.push(c);
+                        out.push(']');
+                    }
+                    '*' if keep_wildcards => out.push('%'),
+                    '?' if keep_wildcards => out.push('_'),
+                    c => out.push(c),
+                }
+            }
+            out
+        }
+        let name_like = |pattern: String| format!("System.FileName LIKE '{}'", pattern);
+        let mut conditions: Vec<String> = Vec::new();
+        conditions.extend(
+            self.terms
+                .iter()
+                .map(|t| name_like(format!("%{}%", like(t, false)))),
+        );
+        conditions.extend(self.name_globs.iter().map(|g| name_like(like(g, true))));
+        if !self.extensions.is_empty() {
+            let any = self
+                .extensions
+                .iter()
+                .map(|e| name_like(format!("%.{}", like(e, false))))
+                .collect::<Vec<_>>();
+            conditions.push(format!("({})", any.join(" OR ")));
+        }
+        if let Some(extensions) = self.native_extensions().filter(|_| !self.types.is_empty()) {
+            let mut any = extensions
+                .iter()
+                .map(|e| name_like(format!("%.{}", e)))
+                .collect::<Vec<_>>();
+            if self.types.contains(&FileType::Directory) {
+                any.push("System.ItemType = 'Directory'".to_string());
+            }
+            conditions.push(format!("({})", any.join(" OR ")));
+        }
+        if let Some((from, to)) = self.size {
+            if from > 0 {
+                conditions.push(format!("System.Size >= {}", from));
+            }
+            if to < u64::MAX {
+                conditions.push(format!("System.Size <= {}", to));
+            }
+        }
+        if let Some((from, to)) = self.modified {
+            // Windows Search 날짜 비교는 UTC 기준
+            let utc = |ms: u64| {
+                Utc.timestamp_millis_opt(ms as i64)
+                    .single()
+                    .map(|t| t.format("%Y-%m-%d %H:%M:%S").to_string())
+            };
+            if let Some(from) = utc(from).filter(|_| from > 0) {
+                conditions.push(format!("System.DateModified >= '{}'", from));
+            }
+            if let Some(to) = utc(to).filter(|_| to < u64::MAX) {
+                conditions.push(format!("System.DateModified < '{}'", to));
+            }
+        }
+        conditions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(name: &str, is_dir: bool, size: u64, modified: u64) -> FileEntry {
+        let path = std::env::temp_dir().join(name);
+        let meta = std::fs::metadata(std::env::temp_dir()).unwrap();
+        let mut entry =
+            super::super::file_search::search_hit_entry(&path, name.to_string(), &meta, false);
+        entry.is_dir = is_dir;
+        entry.file_type = if is_dir {
+            FileType::Directory
+        } else {
+            classify_file(name)
+        };
+        entry.size = size;
+        entry.modified = modified;
+        entry
+    }
+
+    #[test]
+    fn parses_conditions_and_matches_entries() {
+        let now = Local.with_ymd_and_hms(2024, 5, 20, 12, 0, 0).unwrap();
+        let now_ms = now.timestamp_millis() as u64;
+        let query = SearchQuery::parse_at(
+            r#"ext:psd,.PSB size:>500mb modified:<30d type:image name:"boss*" final"#,
+            now,
+        )
+        .unwrap();
+        assert_eq!(query.extensions, vec!["psd", "psb"]);
+        assert_eq!(query.size, Some((500 * 1024 * 1024 + 1, u64::MAX)));
+        assert_eq!(query.modified, Some((now_ms - 30 * DAY_MS, u64::MAX)));
+        assert_eq!(query.name_globs, vec!["boss*"]);
+        assert_eq!(query.terms, vec!["final"]);
+
+        let big = 600 * 1024 * 1024;
+        let recent = now_ms - DAY_MS;
+        assert!(query.matches(&entry("Boss_final.psd", false, big, recent)));
+        assert!(!query.matches(&entry("Boss_final.psd", false, 1024, recent)));
+        assert!(!query.matches(&entry("Boss_final.psd", false, big, now_ms - 40 * DAY_MS)));
+        assert!(!query.matches(&entry("Boss_final.png", false, big, recent)));
+        assert!(!query.matches(&entry("old_boss_final.psd", false, big, recent)));
+        assert!(!query.matches(&entry("Boss_final.psd", true, 0, recent)));
+
+        // 날짜·범위·따옴표 토큰과 알 수 없는 키
+        let query = SearchQuery::parse_at(
+            r#"modified:2024-05-01..2024-05-02 size:1k..2k "ext:x" C:"#,
+            now,
+        )
+        .unwrap();
+        let may1 = local_day_start_ms(NaiveDate::from_ymd_opt(2024, 5, 1).unwrap()).unwrap();
+        assert_eq!(query.modified, Some((may1, may1 + 2 * DAY_MS)));
+        assert_eq!(query.size, Some((1024, 2048)));
+        assert_eq!(query.terms, vec!["ext:x", "c:"]);
+
+        let folders = SearchQuery::parse_at("type:folder assets", now).unwrap();
+        assert!(folders.matches(&entry("assets", true, 0, 0)));
+        assert!(!folders.matches(&entry("assets.zip", false, 10, 0)));
+
+        assert!(SearchQuery::parse_at("size:>lots", now).is_err());
+        assert!(SearchQuery::parse_at("type:spreadsheet", now).is_err());
+        assert_eq!(
+            SearchQuery::parse_at("ext: size:", now).unwrap(),
+            SearchQuery::default()
+        );
+    }
+
+    #[test]
+    fn translates_to_native_predicates_and_sorts() {
+        let now = Local.with_ymd_and_hms(2024, 5, 20, 12, 0, 0).unwrap();
+        let query = SearchQuery::parse_at(r#"ext:psd size:>=1mb name:"boss?*" 50%"#, now).unwrap();
+        assert_eq!(
+            query.to_spotlight(),
+            "kMDItemFSName == \"*50%*\"cd && kMDItemFSName == \"boss**\"cd \
+             && (kMDItemFSName == \"*.psd\"cd) && kMDItemFSSize >= 1048576"
+        );
+        assert_eq!(
+            query.to_windows_conditions(),
+            vec![
+                "System.FileName LIKE '%50[%]%'",
+                "System.FileName LIKE 'boss_%'",
+                "(System.FileName LIKE '%.psd')",
+                "System.Size >= 1048576",
+            ]
+        );
+        // 확장자로 표현할 수 없는 종류는 네이티브 조건에서 빠진다
+        let docs = SearchQuery::parse_at("type:document", now).unwrap();
+        assert!(docs.to_windows_conditions().is_empty());
+        assert_eq!(docs.to_spotlight(), "kMDItemFSName == \"*\"cd");
+
+        let query = SearchQuery::parse_at("boss", now).unwrap();
+        let mut entries = vec![
+            entry("miniboss.png", false, 3, 1),
+            entry("boss_theme.mp3", false, 1, 3),
+            entry("BOSS.psd", false, 2, 2),
+        ];
+        query.sort_results(&mut entries, SearchSort::Relevance, 10);
+        let names: Vec<_> = entries.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, ["BOSS.psd", "boss_theme.mp3", "miniboss.png"]);
+        query.sort_results(&mut entries, SearchSort::Size, 2);
+        let names: Vec<_> = entries.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, ["miniboss.png", "BOSS.psd"]);
+    }
+
+    #[test]
+    fn collector_keeps_top_hits_beyond_limit_for_size_sort() {
+        let sizes = [5, 1, 9, 3, 9, 7];
+        let mut by_size = HitCollector::new(SearchSort::Size, 3);
+        for (i, size) in sizes.iter().enumerate() {
+            assert!(!by_size.push(entry(&format!("{}.bin", i), false, *size, 0)));
+        }
+        let names: Vec<_> = by_size.into_parts().0.into_iter().map(|e| e.name).collect();
+        // 같은 크기면 먼저 찾은 항목이 앞
+        assert_eq!(names, ["2.bin", "4.bin", "5.bin"]);
+
+        let mut by_relevance = HitCollector::new(SearchSort::Relevance, 2);
+        assert!(!by_relevance.push(entry("a", false, 0, 0)));
+        assert!(by_relevance.push(entry("b", false, 0, 0)));
+        assert_eq!(by_relevance.into_parts().0.len(), 2);
+
+        // 압축 파일을 모으는 동안은 결과가 차도 멈추지 않는다
+        let mut with_archives = HitCollector::new(SearchSort::Relevance, 1).with_archives(1);
+        assert!(with_archives.wants_archive("a.tar.gz", false));
+        assert!(!with_archives.wants_archive("a.tar.gz", true));
+        assert!(!with_archives.push(entry("a", false, 0, 0)));
+        with_archives.note_archive("/x/a.zip".to_string());
+        assert!(!with_archives.wants_archive("b.zip", false));
+        assert!(with_archives.push(entry("b", false, 0, 0)));
+        let (entries, archives) = with_archives.into_parts();
+        assert_eq!((entries.len(), archives), (1, vec!["/x/a.zip".to_string()]));
+
+        let docs = SearchQuery::parse("type:document").unwrap();
+        assert!(!docs.types_are_native());
+        assert!(SearchQuery::parse("type:image").unwrap().types_are_native());
+    }
+}
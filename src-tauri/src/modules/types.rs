@@ -1,7 +1,7 @@
 // 공통 타입 정의
 
 // 파일 타입 enum (프론트엔드 FileType 유니온과 1:1 매핑)
-#[derive(serde::Serialize, serde::Deserialize, Clone, Copy)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
 pub enum FileType {
     Image,
@@ -9,6 +9,7 @@ pub enum FileType {
     Document,
     Code,
     Archive,
+    Audio,
     Font,
     Directory,
     Other,
@@ -24,6 +25,213 @@ pub struct FileEntry {
     pub modified: u64, // epoch ms
     pub identity: String,
     pub file_type: FileType,
+    /// 심볼릭 링크 여부 (링크를 따라간 대상 기준으로 나머지 필드를 채움)
+    #[serde(default)]
+    pub is_symlink: bool,
+    /// 속성 패널용 부가 메타데이터 (JSON에서는 같은 수준 필드로 펼쳐짐)
+    #[serde(flatten)]
+    pub details: FileDetails,
+}
+
+/// 파일 부가 메타데이터. 가상 항목(드라이브·압축 내부)은 기본값.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct FileDetails {
+    pub created: u64,  // epoch ms, 지원하지 않는 파일 시스템이면 0
+    pub accessed: u64, // epoch ms
+    pub readonly: bool,
+    pub executable: bool,
+    /// unix 소유자·그룹 이름 (이름을 찾지 못하면 uid/gid 숫자 문자열)
+    pub owner: Option<String>,
+    pub group: Option<String>,
+    /// unix 권한 비트 (`0o755` 등, 파일 종류 비트 제외)
+    pub mode: Option<u32>,
+    /// 심볼릭 링크가 가리키는 경로 (링크에 적힌 그대로)
+    pub link_target: Option<String>,
+    /// 점 파일, 숨김 속성, OS가 만드는 설정 파일
+    pub is_hidden: bool,
+    /// 확장자 기준 MIME (목록에서 `Other`인 파일은 내용 시그니처 기준)
+    pub mime: Option<String>,
+}
+
+fn epoch_ms(time: std::io::Result<std::time::SystemTime>) -> u64 {
+    time.ok()
+        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+impl FileDetails {
+    /// `meta`는 링크를 따라간 메타데이터, `is_symlink`이면 링크 자체의 대상 경로를 함께 읽는다
+    pub fn read(
+        name: &str,
+        path: &std::path::Path,
+        meta: &std::fs::Metadata,
+        is_symlink: bool,
+    ) -> Self {
+        let link_target = if is_symlink {
+            std::fs::read_link(path)
+                .ok()
+                .map(|target| target.to_string_lossy().to_string())
+        } else {
+            None
+        };
+        let mut details = Self {
+            created: epoch_ms(meta.created()),
+            accessed: epoch_ms(meta.accessed()),
+            readonly: meta.permissions().readonly(),
+            executable: false,
+            owner: None,
+            group: None,
+            mode: None,
+            link_target,
+            is_hidden: is_hidden_entry(name, meta),
+            mime: if meta.is_dir() {
+                None
+            } else {
+                mime_from_extension(name).map(str::to_string)
+            },
+        };
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::MetadataExt;
+            let mode = meta.mode() & 0o7777;
+            details.mode = Some(mode);
+            details.executable = !meta.is_dir() && mode & 0o111 != 0;
+            details.owner = Some(unix_user_name(meta.uid()));
+            details.group = Some(unix_group_name(meta.gid()));
+        }
+
+        #[cfg(not(unix))]
+        {
+            let ext = name.rsplit_once('.').map(|(_, ext)| ext.to_lowercase());
+            details.executable = !meta.is_dir()
+                && matches!(
+                    ext.as_deref(),
+                    Some("exe" | "com" | "bat" | "cmd" | "msi" | "ps1")
+                );
+        }
+
+        details
+    }
+}
+
+/// 탐색기에서 숨김으로 취급할 항목 (점 파일, Windows 숨김·시스템 속성, macOS `chflags hidden`,
+/// OS가 폴더마다 만드는 설정 파일)
+pub fn is_hidden_entry(name: &str, meta: &std::fs::Metadata) -> bool {
+    if name.starts_with('.') {
+        return true;
+    }
+    let lower = name.to_lowercase();
+    if matches!(lower.as_str(), "desktop.ini" | "thumbs.db" | "ntuser.dat") {
+        return true;
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::fs::MetadataExt;
+        // FILE_ATTRIBUTE_HIDDEN(0x2) | FILE_ATTRIBUTE_SYSTEM(0x4)
+        meta.file_attributes() & 0x6 != 0
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        use std::os::macos::fs::MetadataExt;
+        // UF_HIDDEN
+        meta.st_flags() & 0x8000 != 0
+    }
+
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    {
+        let _ = meta;
+        false
+    }
+}
+
+#[cfg(unix)]
+type UnixNameCache = std::sync::OnceLock<std::sync::Mutex<std::collections::HashMap<u32, String>>>;
+
+/// `getpwuid_r`/`getgrgid_r` 버퍼 최대 크기 (큰 LDAP 그룹 대비, ERANGE마다 두 배)
+#[cfg(unix)]
+const UNIX_NAME_BUF_MAX: usize = 1024 * 1024;
+
+/// uid/gid → 이름 공통 조회. NSS(LDAP 등) 조회는 느릴 수 있어 캐시 잠금 밖에서 하고,
+/// 찾은 이름만 프로세스 동안 캐시한다 (실패 시 숫자 id를 돌려주되 캐시하지 않음).
+/// `lookup`은 버퍼를 받아 `(반환 코드, 찾은 이름)`을 돌려준다.
+#[cfg(unix)]
+fn unix_id_name(
+    cache: &'static UnixNameCache,
+    id: u32,
+    buf_size_name: libc::c_int,
+    lookup: impl Fn(&mut [libc::c_char]) -> (libc::c_int, Option<String>),
+) -> String {
+    let names = cache.get_or_init(Default::default);
+    if let Some(name) = names.lock().unwrap_or_else(|e| e.into_inner()).get(&id) {
+        return name.clone();
+    }
+    // SAFETY: sysconf는 부작용 없는 조회 (-1이면 제한 없음/알 수 없음)
+    let suggested = unsafe { libc::sysconf(buf_size_name) };
+    let mut len = if suggested > 0 {
+        suggested as usize
+    } else {
+        4096
+    };
+    let found = loop {
+        let mut buf = vec![0 as libc::c_char; len];
+        match lookup(&mut buf) {
+            (libc::ERANGE, _) if len < UNIX_NAME_BUF_MAX => len *= 2,
+            (_, name) => break name,
+        }
+    };
+    match found {
+        Some(name) => {
+            names
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .insert(id, name.clone());
+            name
+        }
+        None => id.to_string(),
+    }
+}
+
+/// uid → 사용자 이름
+#[cfg(unix)]
+fn unix_user_name(uid: u32) -> String {
+    static NAMES: UnixNameCache = std::sync::OnceLock::new();
+    unix_id_name(&NAMES, uid, libc::_SC_GETPW_R_SIZE_MAX, |buf| {
+        let mut pwd: libc::passwd = unsafe { std::mem::zeroed() };
+        let mut result: *mut libc::passwd = std::ptr::null_mut();
+        // SAFETY: 버퍼와 출력 포인터는 호출 동안 유효하고, 성공 시 pw_name은 buf 안을 가리킨다
+        let rc =
+            unsafe { libc::getpwuid_r(uid, &mut pwd, buf.as_mut_ptr(), buf.len(), &mut result) };
+        let name = (rc == 0 && !result.is_null() && !pwd.pw_name.is_null()).then(|| {
+            unsafe { std::ffi::CStr::from_ptr(pwd.pw_name) }
+                .to_string_lossy()
+                .to_string()
+        });
+        (rc, name)
+    })
+}
+
+/// gid → 그룹 이름
+#[cfg(unix)]
+fn unix_group_name(gid: u32) -> String {
+    static NAMES: UnixNameCache = std::sync::OnceLock::new();
+    unix_id_name(&NAMES, gid, libc::_SC_GETGR_R_SIZE_MAX, |buf| {
+        let mut grp: libc::group = unsafe { std::mem::zeroed() };
+        let mut result: *mut libc::group = std::ptr::null_mut();
+        // SAFETY: 버퍼와 출력 포인터는 호출 동안 유효하고, 성공 시 gr_name은 buf 안을 가리킨다
+        let rc =
+            unsafe { libc::getgrgid_r(gid, &mut grp, buf.as_mut_ptr(), buf.len(), &mut result) };
+        let name = (rc == 0 && !result.is_null() && !grp.gr_name.is_null()).then(|| {
+            unsafe { std::ffi::CStr::from_ptr(grp.gr_name) }
+                .to_string_lossy()
+                .to_string()
+        });
+        (rc, name)
+    })
 }
 
 pub fn file_identity(meta: &std::fs::Metadata) -> String {
@@ -65,45 +273,213 @@ pub fn file_identity(meta: &std::fs::Metadata) -> String {
 // 파일 타입 분류 헬퍼
 pub fn classify_file(name: &str) -> FileType {
     let ext = name.rsplit('.').next().unwrap_or("").to_lowercase();
-    match ext.as_str() {
-        "jpg" | "jpeg" | "png" | "gif" | "webp" | "bmp" | "svg" | "ico" | "icns" | "psd"
-        | "psb" => FileType::Image,
-        "mp4" | "mov" | "avi" | "mkv" | "webm" => FileType::Video,
-        "pdf" | "doc" | "docx" | "xls" | "xlsx" | "ppt" | "pptx" | "txt" | "md" | "gslides"
-        | "gdoc" | "gsheet" | "gmap" => FileType::Document,
-        "rs" | "js" | "ts" | "tsx" | "jsx" | "py" | "go" | "java" | "c" | "cpp" | "h" | "css"
-        | "html" | "json" | "toml" | "yaml" | "yml" | "cs" | "shader" | "glsl" | "hlsl" | "lua"
-        | "rb" | "php" | "swift" | "kt" | "sh" | "bat" | "ps1" | "r" | "sql" | "scala" | "dart"
-        | "zig" | "xml" | "csv" | "log" => FileType::Code,
-        "zip" | "tar" | "gz" | "7z" | "rar" | "dmg" | "pkg" | "unitypackage" => FileType::Archive,
-        "ttf" | "otf" | "woff" | "woff2" | "ttc" => FileType::Font,
-        _ => {
-            // 확장자 없는 알려진 텍스트 파일 감지
-            let lower_name = name
-                .rsplit('/')
-                .next()
-                .unwrap_or(name)
-                .rsplit('\\')
-                .next()
-                .unwrap_or(name)
-                .to_lowercase();
-            match lower_name.as_str() {
-                "license" | "licence" | "readme" | "makefile" | "dockerfile" | "gemfile"
-                | "rakefile" | "procfile" | "vagrantfile" | ".gitignore" | ".gitattributes"
-                | ".editorconfig" | ".env" | ".npmrc" | ".prettierrc" | ".eslintrc"
-                | ".dockerignore" => FileType::Document,
-                _ => FileType::Other,
-            }
-        }
+    if let Some((file_type, _)) = FILE_TYPE_EXTENSIONS
+        .iter()
+        .find(|(_, extensions)| extensions.contains(&ext.as_str()))
+    {
+        return *file_type;
+    }
+    // 확장자 없는 알려진 텍스트 파일 감지
+    let lower_name = name
+        .rsplit('/')
+        .next()
+        .unwrap_or(name)
+        .rsplit('\\')
+        .next()
+        .unwrap_or(name)
+        .to_lowercase();
+    match lower_name.as_str() {
+        "license" | "licence" | "readme" | "makefile" | "dockerfile" | "gemfile" | "rakefile"
+        | "procfile" | "vagrantfile" | ".gitignore" | ".gitattributes" | ".editorconfig"
+        | ".env" | ".npmrc" | ".prettierrc" | ".eslintrc" | ".dockerignore" => FileType::Document,
+        _ => FileType::Other,
     }
 }
 
+/// 타입별 확장자 표 (`classify_file`과 검색 쿼리의 종류 조건이 함께 쓴다)
+const FILE_TYPE_EXTENSIONS: &[(FileType, &[&str])] = &[
+    (
+        FileType::Image,
+        &[
+            "jpg", "jpeg", "png", "gif", "webp", "bmp", "svg", "ico", "icns", "psd", "psb",
+        ],
+    ),
+    (FileType::Video, &["mp4", "mov", "avi", "mkv", "webm"]),
+    (
+        FileType::Audio,
+        &[
+            "mp3", "wav", "ogg", "oga", "opus", "flac", "m4a", "aac", "aif", "aiff", "wma",
+        ],
+    ),
+    (
+        FileType::Document,
+        &[
+            "pdf", "doc", "docx", "xls", "xlsx", "ppt", "pptx", "txt", "md", "gslides", "gdoc",
+            "gsheet", "gmap",
+        ],
+    ),
+    (
+        FileType::Code,
+        &[
+            "rs", "js", "ts", "tsx", "jsx", "py", "go", "java", "c", "cpp", "h", "css", "html",
+            "json", "toml", "yaml", "yml", "cs", "shader", "glsl", "hlsl", "lua", "rb", "php",
+            "swift", "kt", "sh", "bat", "ps1", "r", "sql", "scala", "dart", "zig", "xml", "csv",
+            "log",
+        ],
+    ),
+    (
+        FileType::Archive,
+        &[
+            "zip",
+            "tar",
+            "gz",
+            "7z",
+            "rar",
+            "dmg",
+            "pkg",
+            "unitypackage",
+        ],
+    ),
+    (FileType::Font, &["ttf", "otf", "woff", "woff2", "ttc"]),
+];
+
+/// `classify_file`이 해당 타입으로 분류하는 확장자 목록 (검색 쿼리를 네이티브 조건으로 옮길 때 사용)
+/// Document의 확장자 없는 텍스트 파일(README 등)과 Directory/Other는 확장자로 표현할 수 없다.
+pub fn file_type_extensions(file_type: FileType) -> &'static [&'static str] {
+    FILE_TYPE_EXTENSIONS
+        .iter()
+        .find_map(|(t, extensions)| (*t == file_type).then_some(*extensions))
+        .unwrap_or_default()
+}
+
+// 확장자 → MIME (알 수 없으면 None, 코드 파일은 text/plain)
+pub fn mime_from_extension(name: &str) -> Option<&'static str> {
+    let ext = name.rsplit_once('.')?.1.to_lowercase();
+    let mime = match ext.as_str() {
+        "jpg" | "jpeg" => "image/jpeg",
+        "png" => "image/png",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "bmp" => "image/bmp",
+        "svg" => "image/svg+xml",
+        "ico" => "image/x-icon",
+        "icns" => "image/icns",
+        "psd" | "psb" => "image/vnd.adobe.photoshop",
+        "avif" => "image/avif",
+        "heic" => "image/heic",
+        "tif" | "tiff" => "image/tiff",
+        "mp4" => "video/mp4",
+        "mov" => "video/quicktime",
+        "avi" => "video/x-msvideo",
+        "mkv" => "video/x-matroska",
+        "webm" => "video/webm",
+        "mp3" => "audio/mpeg",
+        "opus" => "audio/opus",
+        "aif" | "aiff" => "audio/aiff",
+        "wav" => "audio/wav",
+        "ogg" => "audio/ogg",
+        "flac" => "audio/flac",
+        "m4a" => "audio/mp4",
+        "aac" => "audio/aac",
+        "pdf" => "application/pdf",
+        "doc" => "application/msword",
+        "docx" => "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+        "xls" => "application/vnd.ms-excel",
+        "xlsx" => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        "ppt" => "application/vnd.ms-powerpoint",
+        "pptx" => "application/vnd.openxmlformats-officedocument.presentationml.presentation",
+        "hwp" => "application/x-hwp",
+        "hwpx" => "application/hwp+zip",
+        "txt" | "log" => "text/plain",
+        "md" => "text/markdown",
+        "html" => "text/html",
+        "css" => "text/css",
+        "js" => "text/javascript",
+        "json" => "application/json",
+        "xml" => "application/xml",
+        "csv" => "text/csv",
+        "zip" => "application/zip",
+        "tar" => "application/x-tar",
+        "gz" => "application/gzip",
+        "7z" => "application/x-7z-compressed",
+        "rar" => "application/vnd.rar",
+        "dmg" => "application/x-apple-diskimage",
+        "ttf" => "font/ttf",
+        "otf" => "font/otf",
+        "ttc" => "font/collection",
+        "woff" => "font/woff",
+        "woff2" => "font/woff2",
+        _ if matches!(classify_file(name), FileType::Code) => "text/plain",
+        _ => return None,
+    };
+    Some(mime)
+}
+
 // ===== 테스트 =====
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[cfg(unix)]
+    #[test]
+    fn test_unix_id_name_retries_erange_and_skips_caching_fallback() {
+        static NAMES: UnixNameCache = std::sync::OnceLock::new();
+        let calls = std::cell::Cell::new(0);
+        // 큰 그룹처럼 버퍼가 64KB 이상이어야 성공
+        let big_group = |buf: &mut [libc::c_char]| {
+            calls.set(calls.get() + 1);
+            if buf.len() < 64 * 1024 {
+                (libc::ERANGE, None)
+            } else {
+                (0, Some("staff".to_string()))
+            }
+        };
+        assert_eq!(
+            unix_id_name(&NAMES, 20, libc::_SC_GETGR_R_SIZE_MAX, big_group),
+            "staff"
+        );
+        let scanned = calls.replace(0);
+        assert!(scanned > 1);
+        assert_eq!(
+            unix_id_name(&NAMES, 20, libc::_SC_GETGR_R_SIZE_MAX, big_group),
+            "staff"
+        );
+        assert_eq!(calls.get(), 0);
+
+        let missing = |_: &mut [libc::c_char]| {
+            calls.set(calls.get() + 1);
+            (libc::EIO, None)
+        };
+        assert_eq!(
+            unix_id_name(&NAMES, 7, libc::_SC_GETGR_R_SIZE_MAX, missing),
+            "7"
+        );
+        assert_eq!(
+            unix_id_name(&NAMES, 7, libc::_SC_GETGR_R_SIZE_MAX, missing),
+            "7"
+        );
+        // 실패한 조회는 캐시하지 않아 다음에 다시 조회
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn test_file_type_extensions_match_classify_file() {
+        for file_type in [
+            FileType::Image,
+            FileType::Video,
+            FileType::Audio,
+            FileType::Document,
+            FileType::Code,
+            FileType::Archive,
+            FileType::Font,
+        ] {
+            for ext in file_type_extensions(file_type) {
+                assert!(classify_file(&format!("a.{}", ext)) == file_type, "{}", ext);
+            }
+        }
+    }
+
     #[test]
     fn test_classify_file_images() {
         assert_eq!(matches!(classify_file("test.png"), FileType::Image), true);
@@ -201,4 +577,13 @@ mod tests {
             true
         );
     }
+
+    #[test]
+    fn test_mime_from_extension() {
+        assert_eq!(mime_from_extension("Photo.JPG"), Some("image/jpeg"));
+        assert_eq!(mime_from_extension("report.hwp"), Some("application/x-hwp"));
+        assert_eq!(mime_from_extension("main.rs"), Some("text/plain"));
+        assert_eq!(mime_from_extension("data.xyz"), None);
+        assert_eq!(mime_from_extension("noextension"), None);
+    }
 }
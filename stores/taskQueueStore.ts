@@ -1,8 +1,9 @@
 import { useSyncExternalStore } from 'react';
 import { v4 as uuidv4 } from 'uuid';
+import type { ConflictPolicy } from '../types';
 
 export type TransferFileStatus = 'pending' | 'active' | 'completed' | 'failed';
-export type TransferJobStatus = 'scanning' | 'running' | 'completed' | 'failed';
+export type TransferJobStatus = 'scanning' | 'running' | 'paused' | 'completed' | 'cancelled' | 'failed';
 export type TransferOperation = 'copy' | 'move';
 
 export interface TransferFileItem {
@@ -23,10 +24,26 @@ export interface TransferJob {
   activeId: number | null;
   error?: string;
   createdAt: number;
+  bytesDone?: number;
+  bytesTotal?: number;
+  bytesPerSec?: number;
+  etaSecs?: number | null;
+}
+
+/** WebView → Rust transfer_items_with_progress / copy_items_with_progress 옵션 */
+export interface TransferOptions {
+  jobId?: string;
+  /** 복사 후 xxh3 검증 (불일치 시 1회 재복사) */
+  verify?: boolean;
+  /** 심볼릭 링크 처리: 링크로 복사(기본) / 따라가기 / 건너뛰기 */
+  symlinks?: 'copy_link' | 'follow' | 'skip';
+  /** 충돌 처리 (생략 시 overwrite 인자로 덮어쓰기/건너뛰기) */
+  conflict?: ConflictPolicy;
 }
 
 /** Rust transfer_items_with_progress → WebView */
 export interface TransferQueueProgress {
+  jobId?: string;
   phase: string;
   operation: string;
   doneFiles: number;
@@ -35,6 +52,25 @@ export interface TransferQueueProgress {
   percent: number;
   activeId?: number | null;
   files?: TransferFileItem[] | null;
+  /** phase === 'cancelled'일 때 이미 완료된 대상 경로 */
+  completed?: string[] | null;
+  bytesDone?: number;
+  bytesTotal?: number;
+  /** 최근 처리 속도 (바이트/초) */
+  bytesPerSec?: number;
+  etaSecs?: number | null;
+  /** 현재 파일 진행률 (0~100) */
+  activePercent?: number | null;
+}
+
+/** Rust list_pending_transfers → WebView (앱 종료 등으로 중단된 전송, resume_transfer로 재개) */
+export interface PendingTransfer {
+  jobId: string;
+  operation: TransferOperation;
+  dest: string;
+  createdAt: number;
+  doneSteps: number;
+  totalSteps: number;
 }
 
 type Listener = () => void;
@@ -87,8 +123,13 @@ export function setTaskQueuePanelExpanded(expanded: boolean) {
   emit();
 }
 
+/** 아직 끝나지 않은 작업 (일시정지 포함) */
+export function isTransferJobActive(status: TransferJobStatus) {
+  return status === 'scanning' || status === 'running' || status === 'paused';
+}
+
 export function hasActiveTransferJobs() {
-  return jobs.some((j) => j.status === 'scanning' || j.status === 'running');
+  return jobs.some((j) => isTransferJobActive(j.status));
 }
 
 /** 진행 중 작업이 없으면 큐 패널을 닫고 목록을 비움 */
@@ -138,6 +179,11 @@ function normalizeProgress(msg: TransferQueueProgress & Record<string, unknown>)
     percent: Number(msg.percent ?? 0),
     activeId: (msg.activeId ?? msg.active_id ?? null) as number | null,
     files: (msg.files ?? null) as TransferFileItem[] | null | undefined,
+    bytesDone: Number(msg.bytesDone ?? 0),
+    bytesTotal: Number(msg.bytesTotal ?? 0),
+    bytesPerSec: Number(msg.bytesPerSec ?? 0),
+    etaSecs: (msg.etaSecs ?? null) as number | null,
+    activePercent: (msg.activePercent ?? null) as number | null,
   };
 }
 
@@ -157,7 +203,8 @@ export function applyTransferProgress(jobId: string, raw: TransferQueueProgress)
     } else if (files.length > 0) {
       files = files.map((f) => {
         if (msg.activeId != null && f.id === msg.activeId) {
-          return { ...f, status: 'active' as const, percent: Math.max(f.percent, 50) };
+          const percent = msg.activePercent ?? Math.max(f.percent, 50);
+          return { ...f, status: 'active' as const, percent };
         }
         if (msg.activeId != null && f.id < msg.activeId) {
           return { ...f, status: 'completed' as const, percent: 100 };
@@ -176,6 +223,8 @@ export function applyTransferProgress(jobId: string, raw: TransferQueueProgress)
     let status: TransferJobStatus = job.status;
     if (phase === 'scanning') status = 'scanning';
     else if (phase === 'transferring') status = 'running';
+    else if (phase === 'paused') status = 'paused';
+    else if (phase === 'cancelled') status = 'cancelled';
     else if (phase === 'done') status = 'completed';
 
     return {
@@ -185,6 +234,10 @@ export function applyTransferProgress(jobId: string, raw: TransferQueueProgress)
       totalCount: msg.totalFiles,
       files,
       activeId: msg.activeId ?? null,
+      bytesDone: msg.bytesDone,
+      bytesTotal: msg.bytesTotal,
+      bytesPerSec: msg.bytesPerSec,
+      etaSecs: msg.etaSecs,
     };
   });
   emit();
@@ -192,7 +245,8 @@ export function applyTransferProgress(jobId: string, raw: TransferQueueProgress)
 
 export function failTransferJob(jobId: string, error: string) {
   jobs = jobs.map((job) =>
-    job.id === jobId ? { ...job, status: 'failed' as const, error } : job,
+    // 취소는 invoke 오류로도 돌아오므로 이미 받은 cancelled 상태를 유지
+    job.id === jobId && job.status !== 'cancelled' ? { ...job, status: 'failed' as const, error } : job,
   );
   emit();
 }
@@ -11,7 +11,7 @@ const mocks = vi.hoisted(() => ({
   ensureThumbnailsBatch: vi.fn(),
   fallbackCommand: vi.fn(),
   getFileIcon: vi.fn(),
-  listDirectory: vi.fn(),
+  listDirectoryStream: vi.fn(),
   readCachedListing: vi.fn(),
   writeCachedListing: vi.fn(),
 }));
@@ -39,7 +39,7 @@ vi.mock('../../utils/tauriCommands', () => {
     ensureThumbnailsBatch: mocks.ensureThumbnailsBatch,
     getFileIcon: mocks.getFileIcon,
     getRecentFiles: vi.fn(async () => []),
-    listDirectory: mocks.listDirectory,
+    listDirectoryStream: mocks.listDirectoryStream,
     listSystemRoots: vi.fn(async () => []),
     readCachedListing: mocks.readCachedListing,
     writeCachedListing: mocks.writeCachedListing,
@@ -129,9 +129,13 @@ describe('FileExplorer initialPath 로딩', () => {
     };
     writeExplorerTabs('pane-1', [tab]);
     writeExplorerActiveTabId('pane-1', tab.id);
-    mocks.listDirectory.mockResolvedValue([
-      fileEntry(`${downloadPath}/sample.txt`, 'sample.txt'),
-    ]);
+    mocks.listDirectoryStream.mockImplementation(
+      async (_path: string, onBatch: { onmessage?: (message: unknown) => void }) => {
+        const entries = [fileEntry(`${downloadPath}/sample.txt`, 'sample.txt')];
+        onBatch.onmessage?.({ jobId: 'listing-test', offset: 0, entries });
+        return { jobId: 'listing-test', total: entries.length, sent: entries.length };
+      },
+    );
     const { default: FileExplorer } = await import('../../components/FileExplorer');
 
     render(
@@ -150,7 +154,7 @@ describe('FileExplorer initialPath 로딩', () => {
     );
 
     await waitFor(() => {
-      expect(mocks.listDirectory).toHaveBeenCalledWith(downloadPath);
+      expect(mocks.listDirectoryStream).toHaveBeenCalledWith(downloadPath, expect.anything(), expect.anything());
     });
     expect(await screen.findByText('sample.txt')).toBeInTheDocument();
     expect(screen.queryByText('폴더가 비어 있습니다')).not.toBeInTheDocument();
@@ -16,7 +16,7 @@ export interface Category {
 }
 
 // 파일 탐색기 관련 타입
-export type FileType = 'image' | 'video' | 'document' | 'code' | 'archive' | 'other' | 'directory';
+export type FileType = 'image' | 'video' | 'document' | 'code' | 'archive' | 'audio' | 'other' | 'directory';
 
 export interface FileEntry {
   name: string;
@@ -26,6 +26,252 @@ export interface FileEntry {
   modified: number;   // unix timestamp (ms)
   identity?: string;   // OS 메타 기반 파일 정체성(dev/inode/birth/ctime 등)
   file_type: FileType;
+  is_symlink?: boolean; // 심볼릭 링크 (나머지 필드는 링크 대상 기준)
+  // 부가 메타데이터 (가상 항목·이전 캐시에는 없을 수 있음)
+  created?: number;   // unix timestamp (ms), 알 수 없으면 0
+  accessed?: number;  // unix timestamp (ms)
+  readonly?: boolean;
+  executable?: boolean;
+  owner?: string | null; // unix 소유자 (이름을 못 찾으면 uid)
+  group?: string | null;
+  mode?: number | null;  // unix 권한 비트 (0o755 등)
+  link_target?: string | null; // 심볼릭 링크가 가리키는 경로
+  is_hidden?: boolean;   // 점 파일·숨김 속성·OS 설정 파일
+  mime?: string | null;  // 확장자 기준, 목록에서 'other'였던 파일은 내용 시그니처 기준
+}
+
+/** detect_file_type 결과 */
+export interface DetectedFileType {
+  fileType: FileType;
+  mime: string | null;
+  fromContent: boolean; // false면 확장자 기준
+}
+
+/** 오디오 태그 (get_audio_metadata, ID3·Vorbis·MP4 공통 이름) */
+export interface AudioTags {
+  title: string | null;
+  artist: string | null;
+  album: string | null;
+  albumArtist: string | null;
+  year: string | null;
+  track: string | null; // "3" 또는 "3/12"
+  genre: string | null;
+  comment: string | null;
+  other: Record<string, string>; // 작곡가·디스크 등 (소문자 키)
+}
+
+export interface AudioMetadata {
+  format: string; // mp3 | wav | flac | ogg | opus | m4a (aac | aiff | wma는 supported: false)
+  codec: string | null;
+  durationSec: number | null;
+  sampleRate: number | null;
+  channels: number | null;
+  bitsPerSample: number | null;
+  bitrateKbps: number | null;
+  tags: AudioTags;
+  hasCover: boolean;
+  supported: boolean; // false면 형식만 알고 길이·태그 등은 읽지 않음
+}
+
+/** 파형 미리보기 (get_audio_waveform) */
+export interface AudioWaveform {
+  durationSec: number;
+  min: number[]; // 구간별 최소 (-1 ~ 1, 길이 = 요청 너비)
+  max: number[];
+  pngPath: string; // convertFileSrc로 표시
+}
+
+export type AudioOutputFormat = 'wav' | 'mp3' | 'ogg' | 'flac' | 'm4a';
+
+/** 미디어 스트림 (probe_media) */
+export interface MediaStream {
+  index: number; // -map 0:N
+  kind: 'video' | 'audio' | 'subtitle' | 'data' | 'attachment';
+  codec: string;
+  profile: string | null;
+  language: string | null;
+  isDefault: boolean;
+  isAttachedPic: boolean; // 앨범 아트 등 정지 이미지
+  bitrateKbps: number | null;
+  width: number | null;
+  height: number | null;
+  frameRate: number | null;
+  pixelFormat: string | null;
+  rotation: number; // 재생 시 시계 방향 회전 (0/90/180/270)
+  sampleRate: number | null;
+  channels: number | null;
+  channelLayout: string | null;
+}
+
+export interface MediaProbe {
+  container: string; // demuxer 이름 목록 ("mov,mp4,m4a,...")
+  durationSec: number | null;
+  startSec: number | null;
+  bitrateKbps: number | null;
+  streams: MediaStream[];
+  tags: Record<string, string>;
+}
+
+/**
+ * 트림/구간 삭제 방식 (trim_video, cut_video의 mode)
+ * keyframe: 구간 시작을 가장 가까운 키프레임에 맞춰 스트림 복사 (무손실, 원본 컨테이너 유지)
+ * smart: 경계 GOP만 재인코딩해 프레임 단위로 자름 (H.264 원본만, 그 외는 reencode로 처리)
+ */
+export type VideoEditMode = 'reencode' | 'keyframe' | 'smart';
+
+/** 일괄 압축 공통 프리셋 (compress_videos_batch) */
+export interface VideoBatchOptions {
+  jobId?: string; // cancelMediaJob(jobId)로 큐 전체 중단
+  quality?: 'low' | 'medium' | 'high';
+  scalePercent?: number;
+  outputDir?: string; // 없으면 원본과 같은 폴더
+  concurrency?: number; // 하드웨어 인코더가 허용하는 수로 제한됨
+}
+
+export interface VideoBatchItem {
+  id: number;
+  input: string;
+  name: string;
+  status: 'pending' | 'active' | 'completed' | 'failed' | 'cancelled';
+  percent: number;
+  output: string | null;
+  error: string | null;
+}
+
+export interface VideoBatchProgress {
+  jobId: string;
+  phase: 'encoding' | 'done' | 'cancelled';
+  doneItems: number;
+  failedItems: number;
+  totalItems: number;
+  percent: number;
+  items: VideoBatchItem[];
+}
+
+/** 앱 파일명 인덱스 루트별 상태 (index_status) */
+export interface IndexRootStatus {
+  root: string;
+  state: 'pending' | 'building' | 'refreshing' | 'ready' | 'error';
+  dirCount: number;
+  fileCount: number;
+  unreadableDirs: number; // 권한 등으로 읽지 못한 폴더
+  truncated: boolean; // 항목 수 제한으로 일부만 색인 (검색에는 쓰지 않음)
+  lastUpdated: number | null; // ms
+  error: string | null;
+}
+
+export interface IndexStatus {
+  roots: IndexRootStatus[];
+  totalFiles: number;
+}
+
+/** 내용 검색 옵션 (search_file_contents) */
+export interface ContentSearchOptions {
+  jobId?: string;
+  regex?: boolean;
+  caseSensitive?: boolean;
+  showHidden?: boolean;
+  maxHits?: number;
+  maxHitsPerFile?: number;
+  maxFileSize?: number;
+}
+
+export interface ContentMatch {
+  path: string;
+  line: number;
+  snippet: string;
+  /** snippet 안 일치 구간 (slice에 바로 사용) */
+  matchStart: number;
+  matchEnd: number;
+}
+
+export interface ContentSearchBatch {
+  jobId: string;
+  matches: ContentMatch[];
+  filesScanned: number;
+}
+
+export interface ContentSearchSummary {
+  jobId: string;
+  filesScanned: number;
+  filesMatched: number;
+  totalHits: number;
+  filesSkipped: number;
+  truncated: boolean;
+}
+
+/** 중복 탐색 옵션 (find_duplicate_files_stream) */
+export interface DuplicateScanOptions {
+  jobId?: string;
+  minSize?: number;
+  /** 이름 와일드카드, `/`가 있으면 루트 기준 상대 경로 */
+  include?: string[];
+  exclude?: string[];
+  showHidden?: boolean;
+  maxFiles?: number;
+}
+
+export interface DuplicateScanProgress {
+  jobId: string;
+  phase: 'scanning' | 'comparing' | 'hashing' | 'done';
+  filesScanned: number;
+  candidates: number;
+  bytesHashed: number;
+  bytesToHash: number;
+}
+
+export interface DuplicateScanSummary {
+  jobId: string;
+  groups: DuplicateFileGroup[];
+  filesScanned: number;
+  hardlinksSkipped: number;
+  truncated: boolean;
+}
+
+/** 폴더 감시 변경 항목 (watch_directory) */
+export interface DirectoryChange {
+  kind: 'created' | 'modified' | 'renamed' | 'removed';
+  path: string;
+  oldPath: string | null;
+  entry: FileEntry | null;
+}
+
+export interface DirectoryChangeBatch {
+  watchId: string;
+  path: string;
+  changes: DirectoryChange[];
+  rootRemoved: boolean; // 감시 폴더 자체가 사라짐 (감시 종료)
+}
+
+export type ListingSortBy = 'name' | 'size' | 'modified' | 'type';
+
+export interface ListingFilter {
+  fileTypes?: FileType[];
+  nameGlob?: string; // `*.exr`, `shot_??_*` (대소문자 무시)
+  minSize?: number; // 크기 조건은 파일에만 적용
+  maxSize?: number;
+}
+
+export interface ListDirectoryOptions {
+  jobId?: string; // cancelDirectoryListing으로 중단할 때 사용
+  batchSize?: number;
+  showHidden?: boolean; // 숨김·시스템 파일 포함 (기본 제외)
+  sort?: { by: ListingSortBy; descending?: boolean }; // 지정하면 전체 정렬 후 구간 전송
+  filter?: ListingFilter;
+  offset?: number;
+  limit?: number;
+}
+
+export interface DirectoryBatch {
+  jobId: string;
+  offset: number; // 묶음 첫 항목의 결과 내 위치
+  entries: FileEntry[];
+}
+
+export interface DirectoryListingSummary {
+  jobId: string;
+  total: number; // 필터를 통과한 전체 항목 수
+  sent: number;
 }
 
 /** 중복 파일 탐색 결과 그룹 */
@@ -56,6 +302,47 @@ export interface FolderMergeAnalysis {
 /** 스마트 폴더 병합 — 충돌 처리 방식 */
 export type FolderMergeConflictMode = 'rename' | 'overwrite_newer' | 'skip';
 
+/**
+ * 복사/이동 충돌 처리 방식 (폴더끼리 겹치면 합치고 하위 파일마다 적용).
+ * 'overwrite'는 겹치는 폴더를 통째로 교체, 'merge'는 폴더를 합치고 겹치는 파일만 덮어씀
+ */
+export type ConflictPolicy = FolderMergeConflictMode | 'overwrite' | 'merge';
+
+/** 전송 전 충돌 미리보기 항목 (check_transfer_conflicts) */
+export interface TransferConflict {
+  sourcePath: string;
+  destPath: string;
+  relativePath: string;
+  sourceIsDir: boolean;
+  destIsDir: boolean;
+  sourceSize: number;
+  destSize: number;
+  sourceModified: number;
+  destModified: number;
+}
+
+/** 일괄 이름 변경 규칙 (확장자 규칙 외에는 확장자를 뺀 이름에 순서대로 적용) */
+export type RenameInsertPosition = 'prefix' | 'suffix';
+export type RenameRule =
+  | { type: 'replace'; find: string; replace?: string; regex?: boolean; case_sensitive?: boolean }
+  | { type: 'insert'; text: string; position?: RenameInsertPosition }
+  | { type: 'number'; start?: number; step?: number; padding?: number; position?: RenameInsertPosition; separator?: string }
+  | { type: 'case'; case: 'lower' | 'upper' | 'title' }
+  | { type: 'date'; source?: 'modified' | 'exif'; format?: string; position?: RenameInsertPosition; separator?: string }
+  | { type: 'extension'; extension: string };
+
+export type BulkRenameStatus = 'ok' | 'unchanged' | 'invalid' | 'duplicate' | 'exists';
+
+export interface BulkRenameItem {
+  oldPath: string;
+  newPath: string;
+}
+
+export interface BulkRenamePreview extends BulkRenameItem {
+  newName: string;
+  status: BulkRenameStatus;
+}
+
 /** 스마트 폴더 병합 모달 요청 */
 export interface FolderMergeRequest {
   sourcePath: string;
@@ -104,6 +391,28 @@ export type UndoAction =
   | { type: 'create_file'; path: string }
   | { type: 'export_maps'; paths: string[] };
 
+/** 백엔드 작업 기록 항목 (경로 쌍은 [원래 경로, 결과 경로]) */
+export type FileOperation =
+  | { kind: 'rename'; from: string; to: string }
+  | { kind: 'bulkRename'; items: [string, string][] }
+  | { kind: 'move'; items: [string, string][] }
+  | { kind: 'copy'; items: [string, string][] }
+  | { kind: 'create'; path: string; directory: boolean }
+  | { kind: 'trash'; paths: string[] }
+  | { kind: 'merge'; items: [string, string][]; moved: boolean };
+
+export interface OperationRecord {
+  id: string;
+  createdAt: number;
+  operation: FileOperation;
+}
+
+/** list_operation_history 응답 (각 목록은 최신 작업이 앞) */
+export interface OperationHistory {
+  undo: OperationRecord[];
+  redo: OperationRecord[];
+}
+
 // Drawing tool types (for DrawingCanvas)
 export type DrawingTool = 'pen' | 'rect' | 'ellipse' | 'eraser';
 export type StrokeType = 'pen' | 'rect' | 'ellipse';
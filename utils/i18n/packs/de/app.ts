@@ -92,6 +92,7 @@ export const deAppTranslations = {
   'toast.preparing': 'Wird vorbereitet...',
   'toast.videoCompressCompleteMulti': 'Videokomprimierung abgeschlossen: {success}/{total}',
   'toast.videoCompressComplete': 'Videokomprimierung abgeschlossen',
+  'toast.videoCompressCancelled': 'Videokomprimierung abgebrochen',
   'videoCompress.scaleSize': 'Größe',
   'videoCompress.scaleOriginal': 'Original (100%)',
   'videoCompress.menu': 'Video komprimieren',
@@ -148,6 +149,7 @@ export const deAppTranslations = {
   'folderSize.exactBytes': 'Genaue Bytes',
   'folderSize.files': 'Dateien',
   'folderSize.folders': 'Ordner',
+  'folderSize.symlinks': 'Symlinks',
   'folderSize.count': '{count}',
   'folderSize.topItems': 'Größte Elemente',
   'folderSize.childCount': '{count} Elemente',
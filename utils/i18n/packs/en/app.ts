@@ -92,6 +92,7 @@ export const enAppTranslations = {
   'toast.preparing': 'Preparing...',
   'toast.videoCompressCompleteMulti': 'Video compression complete: {success}/{total}',
   'toast.videoCompressComplete': 'Video compression complete',
+  'toast.videoCompressCancelled': 'Video compression cancelled',
   'videoCompress.scaleSize': 'Size',
   'videoCompress.scaleOriginal': 'Original (100%)',
   'videoCompress.menu': 'Compress video',
@@ -148,6 +149,7 @@ export const enAppTranslations = {
   'folderSize.exactBytes': 'Exact bytes',
   'folderSize.files': 'Files',
   'folderSize.folders': 'Folders',
+  'folderSize.symlinks': 'Symlinks',
   'folderSize.count': '{count}',
   'folderSize.topItems': 'Largest items',
   'folderSize.childCount': '{count} items',
@@ -92,6 +92,7 @@ export const esAppTranslations = {
   'toast.preparing': 'Preparando...',
   'toast.videoCompressCompleteMulti': 'Compresión de video completada: {success}/{total}',
   'toast.videoCompressComplete': 'Compresión de video completada',
+  'toast.videoCompressCancelled': 'Compresión de video cancelada',
   'videoCompress.scaleSize': 'Tamaño',
   'videoCompress.scaleOriginal': 'Original (100%)',
   'videoCompress.menu': 'Comprimir video',
@@ -148,6 +149,7 @@ export const esAppTranslations = {
   'folderSize.exactBytes': 'Bytes exactos',
   'folderSize.files': 'Archivos',
   'folderSize.folders': 'Carpetas',
+  'folderSize.symlinks': 'Enlaces simbólicos',
   'folderSize.count': '{count}',
   'folderSize.topItems': 'Elementos más grandes',
   'folderSize.childCount': '{count} elementos',
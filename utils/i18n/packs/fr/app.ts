@@ -92,6 +92,7 @@ export const frAppTranslations = {
   'toast.preparing': 'Préparation...',
   'toast.videoCompressCompleteMulti': 'Compression vidéo terminée : {success}/{total}',
   'toast.videoCompressComplete': 'Compression vidéo terminée',
+  'toast.videoCompressCancelled': 'Compression vidéo annulée',
   'videoCompress.scaleSize': 'Taille',
   'videoCompress.scaleOriginal': 'Originale (100%)',
   'videoCompress.menu': 'Compresser la vidéo',
@@ -148,6 +149,7 @@ export const frAppTranslations = {
   'folderSize.exactBytes': 'Octets exacts',
   'folderSize.files': 'Fichiers',
   'folderSize.folders': 'Dossiers',
+  'folderSize.symlinks': 'Liens symboliques',
   'folderSize.count': '{count}',
   'folderSize.topItems': 'Éléments les plus volumineux',
   'folderSize.childCount': '{count} éléments',
@@ -92,6 +92,7 @@ export const itAppTranslations = {
   'toast.preparing': 'Preparazione...',
   'toast.videoCompressCompleteMulti': 'Compressione video completata: {success}/{total}',
   'toast.videoCompressComplete': 'Compressione video completata',
+  'toast.videoCompressCancelled': 'Compressione video annullata',
   'videoCompress.scaleSize': 'Dimensione',
   'videoCompress.scaleOriginal': 'Originale (100%)',
   'videoCompress.menu': 'Comprimi video',
@@ -148,6 +149,7 @@ export const itAppTranslations = {
   'folderSize.exactBytes': 'Byte esatti',
   'folderSize.files': 'File',
   'folderSize.folders': 'Cartelle',
+  'folderSize.symlinks': 'Collegamenti simbolici',
   'folderSize.count': '{count}',
   'folderSize.topItems': 'Elementi più grandi',
   'folderSize.childCount': '{count} elementi',
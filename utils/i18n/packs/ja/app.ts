@@ -92,6 +92,7 @@ export const jaAppTranslations = {
   'toast.preparing': '準備中...',
   'toast.videoCompressCompleteMulti': '動画圧縮完了: {success}/{total}個',
   'toast.videoCompressComplete': '動画圧縮完了',
+  'toast.videoCompressCancelled': '動画の圧縮をキャンセルしました',
   'videoCompress.scaleSize': 'サイズ',
   'videoCompress.scaleOriginal': '元のサイズ (100%)',
   'videoCompress.menu': '動画を圧縮',
@@ -148,6 +149,7 @@ export const jaAppTranslations = {
   'folderSize.exactBytes': '正確なバイト数',
   'folderSize.files': 'ファイル',
   'folderSize.folders': 'フォルダ',
+  'folderSize.symlinks': 'シンボリックリンク',
   'folderSize.count': '{count}個',
   'folderSize.topItems': 'サイズ上位の項目',
   'folderSize.childCount': '{count}個',
@@ -92,6 +92,7 @@ export const koAppTranslations = {
   'toast.preparing': '준비 중...',
   'toast.videoCompressCompleteMulti': '동영상 압축 완료: {success}/{total}개',
   'toast.videoCompressComplete': '동영상 압축 완료',
+  'toast.videoCompressCancelled': '동영상 압축 취소됨',
   'videoCompress.scaleSize': '크기',
   'videoCompress.scaleOriginal': '원본 (100%)',
   'videoCompress.menu': '동영상 압축',
@@ -148,6 +149,7 @@ export const koAppTranslations = {
   'folderSize.exactBytes': '정확한 바이트',
   'folderSize.files': '파일',
   'folderSize.folders': '폴더',
+  'folderSize.symlinks': '심볼릭 링크',
   'folderSize.count': '{count}개',
   'folderSize.topItems': '용량 상위 항목',
   'folderSize.childCount': '{count}개',
@@ -92,6 +92,7 @@ export const ptAppTranslations = {
   'toast.preparing': 'Preparando...',
   'toast.videoCompressCompleteMulti': 'Compactação de vídeo concluída: {success}/{total}',
   'toast.videoCompressComplete': 'Compactação de vídeo concluída',
+  'toast.videoCompressCancelled': 'Compressão de vídeo cancelada',
   'videoCompress.scaleSize': 'Tamanho',
   'videoCompress.scaleOriginal': 'Original (100%)',
   'videoCompress.menu': 'Compactar vídeo',
@@ -148,6 +149,7 @@ export const ptAppTranslations = {
   'folderSize.exactBytes': 'Bytes exatos',
   'folderSize.files': 'Arquivos',
   'folderSize.folders': 'Pastas',
+  'folderSize.symlinks': 'Links simbólicos',
   'folderSize.count': '{count}',
   'folderSize.topItems': 'Itens de maior tamanho',
   'folderSize.childCount': '{count}',
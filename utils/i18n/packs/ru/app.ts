@@ -92,6 +92,7 @@ export const ruAppTranslations = {
   'toast.preparing': 'Подготовка...',
   'toast.videoCompressCompleteMulti': 'Сжатие видео завершено: {success}/{total}',
   'toast.videoCompressComplete': 'Сжатие видео завершено',
+  'toast.videoCompressCancelled': 'Сжатие видео отменено',
   'videoCompress.scaleSize': 'Размер',
   'videoCompress.scaleOriginal': 'Оригинал (100%)',
   'videoCompress.menu': 'Сжать видео',
@@ -148,6 +149,7 @@ export const ruAppTranslations = {
   'folderSize.exactBytes': 'Точное число байт',
   'folderSize.files': 'Файлы',
   'folderSize.folders': 'Папки',
+  'folderSize.symlinks': 'Символические ссылки',
   'folderSize.count': '{count} шт.',
   'folderSize.topItems': 'Самые крупные элементы',
   'folderSize.childCount': '{count} шт.',
@@ -92,6 +92,7 @@ export const zhCNAppTranslations = {
   'toast.preparing': '准备中...',
   'toast.videoCompressCompleteMulti': '视频压缩完成：{success}/{total}个',
   'toast.videoCompressComplete': '视频压缩完成',
+  'toast.videoCompressCancelled': '视频压缩已取消',
   'videoCompress.scaleSize': '尺寸',
   'videoCompress.scaleOriginal': '原始 (100%)',
   'videoCompress.menu': '视频压缩',
@@ -148,6 +149,7 @@ export const zhCNAppTranslations = {
   'folderSize.exactBytes': '精确字节数',
   'folderSize.files': '文件',
   'folderSize.folders': '文件夹',
+  'folderSize.symlinks': '符号链接',
   'folderSize.count': '{count}个',
   'folderSize.topItems': '大小排行项目',
   'folderSize.childCount': '{count}个',
@@ -92,6 +92,7 @@ export const zhTWAppTranslations = {
   'toast.preparing': '準備中...',
   'toast.videoCompressCompleteMulti': '影片壓縮完成：{success}/{total} 個',
   'toast.videoCompressComplete': '影片壓縮完成',
+  'toast.videoCompressCancelled': '影片壓縮已取消',
   'videoCompress.scaleSize': '尺寸',
   'videoCompress.scaleOriginal': '原始 (100%)',
   'videoCompress.menu': '影片壓縮',
@@ -148,6 +149,7 @@ export const zhTWAppTranslations = {
   'folderSize.exactBytes': '精確位元組',
   'folderSize.files': '檔案',
   'folderSize.folders': '資料夾',
+  'folderSize.symlinks': '符號連結',
   'folderSize.count': '{count} 個',
   'folderSize.topItems': '容量最大的項目',
   'folderSize.childCount': '{count} 個',
@@ -1,4 +1,18 @@
-import type { FileEntry } from '../../types';
+import type { Channel } from '@tauri-apps/api/core';
+import type {
+  BulkRenameItem,
+  BulkRenamePreview,
+  DetectedFileType,
+  DirectoryBatch,
+  DirectoryChangeBatch,
+  DirectoryListingSummary,
+  FileEntry,
+  ListDirectoryOptions,
+  OperationHistory,
+  OperationRecord,
+  RenameRule,
+  TransferConflict,
+} from '../../types';
 import { runCommand, runDirectCommand, runLowPriorityCommand } from '../tauriCommandRunner.ts';
 
 export interface ExtractZipResult {
@@ -27,8 +41,31 @@ export const fileCommands = {
   renameItem(oldPath: string, newPath: string) {
     return runCommand<void>('rename_item', { oldPath, newPath });
   },
-  listDirectory(path: string) {
-    return runCommand<FileEntry[]>('list_directory', { path });
+  bulkRenamePreview(paths: string[], rules: RenameRule[]) {
+    return runCommand<BulkRenamePreview[]>('bulk_rename_preview', { paths, rules });
+  },
+  bulkRenameApply(items: BulkRenameItem[]) {
+    return runCommand<BulkRenameItem[]>('bulk_rename_apply', { items });
+  },
+  listDirectory(path: string, showHidden?: boolean) {
+    return runCommand<FileEntry[]>('list_directory', { path, showHidden });
+  },
+  // 항목은 onBatch 채널로 묶음 단위 도착, 전송이 끝나면 전체 개수 요약을 반환
+  listDirectoryStream(path: string, onBatch: Channel<DirectoryBatch>, options?: ListDirectoryOptions) {
+    return runDirectCommand<DirectoryListingSummary>('list_directory_stream', { path, options, onBatch });
+  },
+  cancelDirectoryListing(jobId: string) {
+    return runDirectCommand<boolean>('cancel_directory_listing', { jobId });
+  },
+  // 감시 스레드만 띄우고 곧바로 반환 — 변경 묶음은 onEvent 채널로 도착
+  watchDirectory(path: string, onEvent: Channel<DirectoryChangeBatch>, showHidden?: boolean) {
+    return runDirectCommand<string>('watch_directory', { path, showHidden, onEvent });
+  },
+  unwatchDirectory(watchId: string) {
+    return runDirectCommand<boolean>('unwatch_directory', { watchId });
+  },
+  detectFileType(path: string) {
+    return runCommand<DetectedFileType>('detect_file_type', { path });
   },
   isDirectory(path: string) {
     return runCommand<boolean>('is_directory', { path });
@@ -36,9 +73,9 @@ export const fileCommands = {
   listSystemRoots() {
     return runDirectCommand<FileEntry[]>('list_system_roots');
   },
-  getRecentFiles(roots: string[], days: number) {
+  getRecentFiles(roots: string[], days: number, showHidden?: boolean, includeArchives?: boolean) {
     // 여러 루트 전체 스캔이라 느릴 수 있음 — 일반 레인 점유 방지
-    return runDirectCommand<FileEntry[]>('get_recent_files', { roots, days });
+    return runDirectCommand<FileEntry[]>('get_recent_files', { roots, days, showHidden, includeArchives });
   },
   readCachedListing(path: string) {
     return runDirectCommand<FileEntry[] | null>('read_cached_listing', { path });
@@ -52,6 +89,9 @@ export const fileCommands = {
   checkDuplicateItems(sources: string[], dest: string) {
     return runCommand<string[]>('check_duplicate_items', { sources, dest });
   },
+  checkTransferConflicts(sources: string[], dest: string) {
+    return runCommand<TransferConflict[]>('check_transfer_conflicts', { sources, dest });
+  },
   materializeArchivePaths(paths: string[]) {
     return runCommand<string[]>('materialize_archive_paths', { paths });
   },
@@ -68,6 +108,15 @@ export const fileCommands = {
   restoreTrashItems(originalPaths: string[]) {
     return runCommand<void>('restore_trash_items', { originalPaths });
   },
+  listOperationHistory() {
+    return runCommand<OperationHistory>('list_operation_history');
+  },
+  undoLastOperation() {
+    return runCommand<OperationRecord | null>('undo_last_operation');
+  },
+  redoOperation() {
+    return runCommand<OperationRecord | null>('redo_operation');
+  },
   readTextFile(path: string, maxBytes: number) {
     return runCommand<string>('read_text_file', { path, maxBytes });
   },
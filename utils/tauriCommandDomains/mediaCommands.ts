@@ -1,4 +1,12 @@
 import { runCommand, runDirectCommand, runLowPriorityCommand } from '../tauriCommandRunner.ts';
+import type {
+  AudioMetadata,
+  AudioOutputFormat,
+  AudioWaveform,
+  MediaProbe,
+  VideoBatchOptions,
+  VideoBatchProgress,
+} from '../../types';
 
 export interface ImageCompressPreview {
   dataUrl?: string;
@@ -8,7 +16,7 @@ export interface ImageCompressPreview {
 
 export interface ThumbnailBatchItem {
   path: string;
-  fileType: 'image' | 'video' | 'psd';
+  fileType: 'image' | 'video' | 'psd' | 'audio';
 }
 
 export interface ThumbnailBatchResult {
@@ -69,11 +77,27 @@ export const mediaCommands = {
   downloadFfmpeg() {
     return runDirectCommand<void>('download_ffmpeg');
   },
-  compressVideo(input: string, quality: 'low' | 'medium' | 'high', scalePercent: number, onProgress: unknown) {
-    return runDirectCommand<string>('compress_video', { input, quality, scalePercent, onProgress });
+  // jobId를 넘기면 cancelMediaJob(jobId)로 중단 가능 (원래 호출은 취소 에러로 끝난다)
+  compressVideo(
+    input: string,
+    quality: 'low' | 'medium' | 'high',
+    scalePercent: number,
+    onProgress: unknown,
+    jobId?: string,
+  ) {
+    return runDirectCommand<string>('compress_video', { input, quality, scalePercent, jobId, onProgress });
+  },
+  // 실패한 항목은 건너뛰고 계속 진행, 결과는 항목별 status/output/error로 확인
+  compressVideosBatch(inputs: string[], options: VideoBatchOptions, onProgress: unknown) {
+    return runDirectCommand<VideoBatchProgress>('compress_videos_batch', { inputs, options, onProgress });
+  },
+  // 무손실 트림/구간 삭제 시 구간 경계가 맞춰지는 위치 (초, 오름차순)
+  listVideoKeyframes(path: string) {
+    return runCommand<number[]>('list_video_keyframes', { path });
   },
-  videoToGif(input: string, onProgress: unknown) {
+  videoToGif(input: string, onProgress: unknown, jobId?: string) {
     return runDirectCommand<string>('video_to_gif', {
+      jobId,
       input,
       startSec: 0,
       endSec: 31_536_000,
@@ -89,6 +113,33 @@ export const mediaCommands = {
   gifToMp4(path: string) {
     return runDirectCommand<string>('gif_to_mp4', { path });
   },
+  getAudioMetadata(path: string) {
+    return runCommand<AudioMetadata>('get_audio_metadata', { path });
+  },
+  probeMedia(path: string) {
+    return runCommand<MediaProbe>('probe_media', { path });
+  },
+  getAudioWaveform(path: string, width: number, height: number) {
+    return runCommand<AudioWaveform>('get_audio_waveform', { path, width, height });
+  },
+  convertAudio(
+    input: string,
+    format: AudioOutputFormat,
+    quality: 'low' | 'medium' | 'high',
+    onProgress: unknown,
+    jobId?: string,
+  ) {
+    return runDirectCommand<string>('convert_audio', { input, format, quality, jobId, onProgress });
+  },
+  normalizeAudioLoudness(input: string, targetLufs: number | null, onProgress: unknown, jobId?: string) {
+    return runDirectCommand<string>('normalize_audio_loudness', { input, targetLufs, jobId, onProgress });
+  },
+  trimAudio(input: string, startSec: number, endSec: number, onProgress: unknown, jobId?: string) {
+    return runDirectCommand<string>('trim_audio', { input, startSec, endSec, jobId, onProgress });
+  },
+  cancelMediaJob(jobId: string) {
+    return runDirectCommand<boolean>('cancel_media_job', { jobId });
+  },
   ensureThumbnailsBatch(items: ThumbnailBatchItem[], size: number) {
     return runLowPriorityCommand<ThumbnailBatchResult[]>('ensure_thumbnails_batch', { items, size });
   },
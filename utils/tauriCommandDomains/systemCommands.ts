@@ -1,4 +1,14 @@
+import type { Channel } from '@tauri-apps/api/core';
 import { runCommand, runDirectCommand } from '../tauriCommandRunner.ts';
+import type {
+  ContentSearchBatch,
+  ContentSearchOptions,
+  ContentSearchSummary,
+  DuplicateScanOptions,
+  DuplicateScanProgress,
+  DuplicateScanSummary,
+  IndexStatus,
+} from '../../types';
 
 export const systemCommands = {
   copyPath(path: string) {
@@ -26,6 +36,30 @@ export const systemCommands = {
   readFilesFromClipboard() {
     return runDirectCommand<string[]>('read_files_from_clipboard');
   },
+  // 색인 루트 지정 — 빠진 루트 인덱스는 삭제, 나머지는 백그라운드에서 (증분) 색인
+  setIndexRoots(roots: string[]) {
+    return runDirectCommand<IndexStatus>('set_index_roots', { roots });
+  },
+  indexStatus() {
+    return runDirectCommand<IndexStatus>('index_status');
+  },
+  // 일치는 onBatch 채널로 묶어 도착 — 반환값은 전송이 끝난 뒤의 요약
+  searchFileContents(root: string, query: string, onBatch: Channel<ContentSearchBatch>, options?: ContentSearchOptions) {
+    return runDirectCommand<ContentSearchSummary>('search_file_contents', { root, query, options, onBatch });
+  },
+  cancelFileSearch(jobId: string) {
+    return runDirectCommand<boolean>('cancel_file_search', { jobId });
+  },
+  cancelContentSearch(jobId: string) {
+    return runDirectCommand<boolean>('cancel_content_search', { jobId });
+  },
+  // 진행률은 onProgress 채널로 도착 — 반환값은 탐색이 끝난 뒤의 중복 그룹
+  findDuplicateFilesStream(root: string, onProgress: Channel<DuplicateScanProgress>, options?: DuplicateScanOptions) {
+    return runDirectCommand<DuplicateScanSummary>('find_duplicate_files_stream', { root, options, onProgress });
+  },
+  cancelDuplicateScan(jobId: string) {
+    return runDirectCommand<boolean>('cancel_duplicate_scan', { jobId });
+  },
   startFileDrag(item: string[], image: string, onEvent: unknown) {
     return runDirectCommand<void>('plugin:drag|start_drag', { item, image, onEvent });
   },